@@ -0,0 +1,36 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimatorArrayMut, EstimatorMut},
+};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+const N: usize = 10_000_000;
+
+fn bench_add_all_par(c: &mut Criterion) {
+    let logic = HyperLogLogBuilder::new(N)
+        .log_2_num_reg(12)
+        .build::<usize>()
+        .unwrap();
+    let elements = (0..N).collect::<Vec<_>>();
+    let mut array = SliceEstimatorArray::new(logic.clone(), 1);
+
+    let mut group = c.benchmark_group("add_all_par");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(N as u64));
+    group.bench_function("add_many", |b| {
+        b.iter(|| array.get_estimator_mut(0).add_many(&elements))
+    });
+    group.bench_function("add_all_par", |b| {
+        b.iter(|| logic.add_all_par(array.get_backend_mut(0), &elements))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_add_all_par);
+criterion_main!(benches);
@@ -0,0 +1,40 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimatorArrayMut, EstimatorMut},
+};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+const N: usize = 1_000_000;
+
+fn bench_add_many(c: &mut Criterion) {
+    let logic = HyperLogLogBuilder::new(N)
+        .log_2_num_reg(12)
+        .build::<usize>()
+        .unwrap();
+    let elements = (0..N).collect::<Vec<_>>();
+    let mut array = SliceEstimatorArray::new(logic, 1);
+
+    let mut group = c.benchmark_group("add_many");
+    group.throughput(Throughput::Elements(N as u64));
+    group.bench_function("add", |b| {
+        b.iter(|| {
+            let mut estimator = array.get_estimator_mut(0);
+            for element in &elements {
+                estimator.add(element);
+            }
+        })
+    });
+    group.bench_function("add_many", |b| {
+        b.iter(|| array.get_estimator_mut(0).add_many(&elements))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_add_many);
+criterion_main!(benches);
@@ -0,0 +1,58 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimatorArrayMut, EstimatorMut},
+};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+const NUM_ESTIMATORS: usize = 100_000;
+const NUM_PAIRS: usize = 1_000_000;
+
+fn bench_batch_add(c: &mut Criterion) {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(6)
+        .build::<usize>()
+        .unwrap();
+
+    // A pseudorandom stream of pairs
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let unsorted = (0..NUM_PAIRS)
+        .map(|x| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state as usize % NUM_ESTIMATORS, x)
+        })
+        .collect::<Vec<_>>();
+    let mut sorted = unsorted.clone();
+    sorted.sort_by_key(|&(index, _)| index);
+    let mut array = SliceEstimatorArray::new(logic, NUM_ESTIMATORS);
+
+    let mut group = c.benchmark_group("batch_add");
+    group.throughput(Throughput::Elements(NUM_PAIRS as u64));
+    group.bench_function("get_estimator_mut (unsorted)", |b| {
+        b.iter(|| {
+            for &(index, x) in &unsorted {
+                array.get_estimator_mut(index).add(x);
+            }
+        })
+    });
+    group.bench_function("batch_add (unsorted)", |b| {
+        b.iter(|| array.batch_add(unsorted.iter().copied()))
+    });
+    group.bench_function("batch_add (sorted)", |b| {
+        b.iter(|| array.batch_add(sorted.iter().copied()))
+    });
+    group.bench_function("batch_add_sorted", |b| {
+        b.iter(|| array.batch_add_sorted(sorted.iter().copied()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_batch_add);
+criterion_main!(benches);
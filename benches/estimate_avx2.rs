@@ -0,0 +1,37 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimationLogic, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+const N: usize = 1_000_000;
+
+fn bench_estimate_avx2(c: &mut Criterion) {
+    let logic = HyperLogLogBuilder::new(N)
+        .log_2_num_reg(12)
+        .word_type::<u8>()
+        .build::<usize>()
+        .unwrap();
+    let mut array = SliceEstimatorArray::new(logic.clone(), 1);
+    for i in 0..N {
+        array.get_estimator_mut(0).add(i);
+    }
+    let backend = array.get_backend(0);
+
+    let mut group = c.benchmark_group("estimate_avx2");
+    group.bench_function("scalar", |b| {
+        b.iter(|| logic.estimate_scalar(black_box(backend)))
+    });
+    group.bench_function("simd", |b| b.iter(|| logic.estimate(black_box(backend))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_estimate_avx2);
+criterion_main!(benches);
@@ -0,0 +1,49 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimatorArrayMut, EstimatorMut},
+};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::hint::black_box;
+
+const NUM_ESTIMATORS: usize = 10_000;
+
+fn bench_estimate_batch(c: &mut Criterion) {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build::<usize>()
+        .unwrap();
+    let mut array = SliceEstimatorArray::new(logic.clone(), NUM_ESTIMATORS);
+    for i in 0..NUM_ESTIMATORS {
+        let mut estimator = array.get_estimator_mut(i);
+        for x in 0..i {
+            estimator.add(x);
+        }
+    }
+    let backends = array.as_ref();
+    let mut output = vec![0.0; NUM_ESTIMATORS];
+
+    let mut group = c.benchmark_group("estimate_batch_simd");
+    group.throughput(Throughput::Elements(NUM_ESTIMATORS as u64));
+    group.bench_function("k = 1", |b| {
+        b.iter(|| logic.estimate_batch_simd::<1>(black_box(backends), &mut output))
+    });
+    group.bench_function("k = 4", |b| {
+        b.iter(|| logic.estimate_batch_simd::<4>(black_box(backends), &mut output))
+    });
+    group.bench_function("k = 8", |b| {
+        b.iter(|| logic.estimate_batch_simd::<8>(black_box(backends), &mut output))
+    });
+    group.bench_function("k = 16", |b| {
+        b.iter(|| logic.estimate_batch_simd::<16>(black_box(backends), &mut output))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_estimate_batch);
+criterion_main!(benches);
@@ -0,0 +1,65 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use card_est_array::{
+    impls::{HyperBitBitBuilder, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimatorArray, EstimatorArrayMut, MergeEstimationLogic, SliceEstimationLogic},
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const LEN: usize = 1 << 20;
+
+/// Benchmarks merges between estimators at pseudorandom positions.
+fn traverse<
+    L: MergeEstimationLogic<Item = usize, Backend = [u64]> + SliceEstimationLogic<u64> + Clone,
+>(
+    c: &mut Criterion,
+    name: &str,
+    logic: L,
+) {
+    let mut array = SliceEstimatorArray::new(logic.clone(), LEN);
+    for i in 0..LEN {
+        logic.add(array.get_backend_mut(i), i);
+    }
+
+    let mut helper = logic.new_helper();
+    let mut src = vec![0; logic.backend_len()];
+    let mut state = 0x9E3779B97F4A7C15_u64;
+    c.bench_function(
+        &format!("{} ({} bytes/estimator)", name, logic.backend_len() * 8),
+        |b| {
+            b.iter(|| {
+                // xorshift64
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                let (i, j) = ((state as usize) % LEN, ((state >> 32) as usize) % LEN);
+                logic.set(&mut src, array.get_backend(i));
+                logic.merge_with_helper(array.get_backend_mut(j), &src, &mut helper);
+            })
+        },
+    );
+}
+
+fn bench_traversal(c: &mut Criterion) {
+    traverse(
+        c,
+        "HyperBitBit",
+        HyperBitBitBuilder::new().build::<usize>().unwrap(),
+    );
+    traverse(
+        c,
+        "HyperLogLog",
+        HyperLogLogBuilder::new(LEN)
+            .log_2_num_reg(6)
+            .word_type::<u64>()
+            .build::<usize>()
+            .unwrap(),
+    );
+}
+
+criterion_group!(benches, bench_traversal);
+criterion_main!(benches);
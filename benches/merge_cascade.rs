@@ -0,0 +1,50 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{AsSyncArray, EstimatorArrayMut, EstimatorMut, SliceEstimationLogic},
+    utils::merge_cascade,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const NUM_ESTIMATORS: usize = 1024;
+
+fn bench_merge_cascade(c: &mut Criterion) {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(12)
+        .build::<usize>()
+        .unwrap();
+    let mut array = SliceEstimatorArray::new(logic.clone(), NUM_ESTIMATORS);
+    for i in 0..NUM_ESTIMATORS {
+        let mut estimator = array.get_estimator_mut(i);
+        for x in 0..100 {
+            estimator.add(i * 10 + x);
+        }
+    }
+
+    let mut group = c.benchmark_group("merge_cascade");
+    group.bench_function("merge_all", |b| {
+        b.iter(|| {
+            let mut merged = vec![0; logic.backend_len()];
+            array.merge_all(&mut merged);
+            merged
+        })
+    });
+    let sync_array = array.as_sync_array();
+    group.bench_function("merge_cascade", |b| {
+        b.iter(|| {
+            let mut merged = vec![0; logic.backend_len()];
+            // SAFETY: no other thread accesses the array
+            unsafe { merge_cascade(&sync_array, 0, NUM_ESTIMATORS, &mut merged) };
+            merged
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_merge_cascade);
+criterion_main!(benches);
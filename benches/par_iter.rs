@@ -0,0 +1,54 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimationLogic, EstimatorArray},
+};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use rayon::prelude::*;
+
+const NUM_ESTIMATORS: usize = 1_000_000;
+
+fn bench_par_iter(c: &mut Criterion) {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(6)
+        .build::<usize>()
+        .unwrap();
+    let mut array = SliceEstimatorArray::new(logic.clone(), NUM_ESTIMATORS);
+    array
+        .par_iter_backends_mut()
+        .enumerate()
+        .for_each(|(i, backend)| {
+            for x in 0..i % 1000 {
+                logic.add(backend, i * 10 + x);
+            }
+        });
+
+    let mut group = c.benchmark_group("par_iter");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(NUM_ESTIMATORS as u64));
+    group.bench_function("iter_backends", |b| {
+        b.iter(|| {
+            array
+                .iter_backends()
+                .map(|backend| logic.estimate(backend))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("par_iter_backends", |b| {
+        b.iter(|| {
+            array
+                .par_iter_backends()
+                .map(|backend| logic.estimate(backend))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_par_iter);
+criterion_main!(benches);
@@ -0,0 +1,46 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimatorArrayMut, EstimatorMut, SliceEstimationLogic},
+    utils::parallel_merge_all,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+const NUM_ESTIMATORS: usize = 100_000;
+
+fn bench_parallel_merge_all(c: &mut Criterion) {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(10)
+        .build::<usize>()
+        .unwrap();
+    let mut array = SliceEstimatorArray::new(logic.clone(), NUM_ESTIMATORS);
+    for i in 0..NUM_ESTIMATORS {
+        let mut estimator = array.get_estimator_mut(i);
+        for x in 0..100 {
+            estimator.add(i * 10 + x);
+        }
+    }
+
+    let mut group = c.benchmark_group("parallel_merge_all");
+    group.sample_size(10);
+    group.bench_function("merge_all", |b| {
+        b.iter(|| {
+            let mut merged = vec![0; logic.backend_len()];
+            array.merge_all(&mut merged);
+            merged
+        })
+    });
+    group.bench_function("parallel_merge_all", |b| {
+        b.iter(|| parallel_merge_all(black_box(&array)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_parallel_merge_all);
+criterion_main!(benches);
@@ -0,0 +1,231 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Derive macros for the `card-est-array` crate.
+//!
+//! The macros in this crate implement the estimation-logic traits of
+//! `card-est-array` for newtypes, that is, structures with a single field, by
+//! forwarding all methods to the field. They should be used through the
+//! `derive` feature of `card-est-array`, which re-exports them in the `traits`
+//! module.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields, Member, Type};
+
+/// Returns the only field of a newtype as a member and a type, or an error
+/// spanning the input if the input is not a structure with exactly one field.
+fn newtype_field(input: &DeriveInput, derive: &str) -> syn::Result<(Member, Type)> {
+    let error = || {
+        syn::Error::new_spanned(
+            input,
+            format!(
+                "#[derive({})] requires a structure with exactly one field",
+                derive
+            ),
+        )
+    };
+    let Data::Struct(data) = &input.data else {
+        return Err(error());
+    };
+    let field = match &data.fields {
+        Fields::Named(fields) if fields.named.len() == 1 => &fields.named[0],
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+        _ => return Err(error()),
+    };
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(0.into()),
+    };
+    Ok((member, field.ty.clone()))
+}
+
+/// Forwards [`EstimationLogic`] to the only field of a newtype.
+///
+/// The estimator type is a `DefaultEstimator` wrapping the estimator of the
+/// field, so the newtype must implement [`Clone`].
+#[proc_macro_derive(EstimationLogic)]
+pub fn derive_estimation_logic(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match estimation_logic(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+fn estimation_logic(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let (member, ty) = newtype_field(input, "EstimationLogic")?;
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let where_clause = generics.make_where_clause();
+    where_clause
+        .predicates
+        .push(parse_quote!(#ty: ::card_est_array::traits::EstimationLogic));
+    where_clause
+        .predicates
+        .push(parse_quote!(#name #ty_generics: ::core::clone::Clone));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::card_est_array::traits::EstimationLogic for #name #ty_generics
+            #where_clause
+        {
+            type Item = <#ty as ::card_est_array::traits::EstimationLogic>::Item;
+            type Backend = <#ty as ::card_est_array::traits::EstimationLogic>::Backend;
+            type Estimator<'__a>
+                = ::card_est_array::impls::DefaultEstimator<
+                    Self,
+                    &'__a Self,
+                    <#ty as ::card_est_array::traits::EstimationLogic>::Estimator<'__a>,
+                >
+            where
+                Self: '__a;
+
+            #[inline(always)]
+            fn add(
+                &self,
+                backend: &mut Self::Backend,
+                element: impl ::core::borrow::Borrow<Self::Item>,
+            ) {
+                ::card_est_array::traits::EstimationLogic::add(&self.#member, backend, element)
+            }
+
+            #[inline(always)]
+            fn add_many(&self, backend: &mut Self::Backend, elements: &[Self::Item]) {
+                ::card_est_array::traits::EstimationLogic::add_many(&self.#member, backend, elements)
+            }
+
+            #[inline(always)]
+            fn estimate(&self, backend: &Self::Backend) -> f64 {
+                ::card_est_array::traits::EstimationLogic::estimate(&self.#member, backend)
+            }
+
+            #[inline(always)]
+            fn clear(&self, backend: &mut Self::Backend) {
+                ::card_est_array::traits::EstimationLogic::clear(&self.#member, backend)
+            }
+
+            #[inline(always)]
+            fn set(&self, dst: &mut Self::Backend, src: &Self::Backend) {
+                ::card_est_array::traits::EstimationLogic::set(&self.#member, dst, src)
+            }
+
+            fn new_estimator(&self) -> Self::Estimator<'_> {
+                ::card_est_array::impls::DefaultEstimator::new(
+                    self,
+                    ::card_est_array::traits::EstimationLogic::new_estimator(&self.#member),
+                )
+            }
+        }
+    })
+}
+
+/// Forwards [`MergeEstimationLogic`] to the only field of a newtype.
+///
+/// The newtype must derive `EstimationLogic`, too.
+#[proc_macro_derive(MergeEstimationLogic)]
+pub fn derive_merge_estimation_logic(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match merge_estimation_logic(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+fn merge_estimation_logic(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let (member, ty) = newtype_field(input, "MergeEstimationLogic")?;
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let where_clause = generics.make_where_clause();
+    where_clause
+        .predicates
+        .push(parse_quote!(#ty: ::card_est_array::traits::MergeEstimationLogic));
+    where_clause
+        .predicates
+        .push(parse_quote!(#name #ty_generics: ::core::clone::Clone));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::card_est_array::traits::MergeEstimationLogic for #name #ty_generics
+            #where_clause
+        {
+            type Helper = <#ty as ::card_est_array::traits::MergeEstimationLogic>::Helper;
+
+            #[inline(always)]
+            fn new_helper(&self) -> Self::Helper {
+                ::card_est_array::traits::MergeEstimationLogic::new_helper(&self.#member)
+            }
+
+            #[inline(always)]
+            fn merge(&self, dst: &mut Self::Backend, src: &Self::Backend) {
+                ::card_est_array::traits::MergeEstimationLogic::merge(&self.#member, dst, src)
+            }
+
+            #[inline(always)]
+            fn merge_with_helper(
+                &self,
+                dst: &mut Self::Backend,
+                src: &Self::Backend,
+                helper: &mut Self::Helper,
+            ) {
+                ::card_est_array::traits::MergeEstimationLogic::merge_with_helper(
+                    &self.#member,
+                    dst,
+                    src,
+                    helper,
+                )
+            }
+        }
+    })
+}
+
+/// Forwards [`SliceEstimationLogic`] to the only field of a newtype, for all
+/// the word types supported by the field.
+///
+/// The newtype must derive `EstimationLogic`, too.
+#[proc_macro_derive(SliceEstimationLogic)]
+pub fn derive_slice_estimation_logic(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match slice_estimation_logic(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.into_compile_error().into(),
+    }
+}
+
+fn slice_estimation_logic(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let (member, ty) = newtype_field(input, "SliceEstimationLogic")?;
+    let name = &input.ident;
+    let word = format_ident!("__W");
+    let mut generics = input.generics.clone();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    generics.params.push(parse_quote!(#word));
+    let where_clause = generics.make_where_clause();
+    where_clause
+        .predicates
+        .push(parse_quote!(#ty: ::card_est_array::traits::SliceEstimationLogic<#word>));
+    where_clause
+        .predicates
+        .push(parse_quote!(#name #ty_generics: ::core::clone::Clone));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::card_est_array::traits::SliceEstimationLogic<#word>
+            for #name #ty_generics
+            #where_clause
+        {
+            #[inline(always)]
+            fn backend_len(&self) -> usize {
+                ::card_est_array::traits::SliceEstimationLogic::backend_len(&self.#member)
+            }
+        }
+    })
+}
@@ -0,0 +1,245 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::*;
+use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::hash::{BuildHasher, BuildHasherDefault, DefaultHasher, Hash};
+
+/// A summary of the relative errors observed by an [`AccuracyMonitor`].
+///
+/// Relative errors are signed: a positive error means that the estimator
+/// overestimated the number of distinct elements.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccuracyReport {
+    /// The number of elements added to the monitor.
+    pub elements_added: usize,
+    /// The (possibly approximate) number of distinct elements added to the
+    /// monitor.
+    pub exact_count: f64,
+    /// Whether [`exact_count`](AccuracyReport::exact_count) is exact.
+    pub is_exact: bool,
+    /// The number of observations of the relative error.
+    pub num_observations: usize,
+    /// The relative error at the end of the monitoring.
+    pub final_relative_error: f64,
+    /// The mean of the observed relative errors.
+    pub mean_relative_error: f64,
+    /// The mean of the absolute values of the observed relative errors.
+    pub mean_absolute_relative_error: f64,
+    /// The root mean square of the observed relative errors.
+    pub rms_relative_error: f64,
+    /// The maximum absolute value of the observed relative errors.
+    pub max_absolute_relative_error: f64,
+}
+
+impl Display for AccuracyReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} elements, {}{} distinct, {} observations: final error {:.4}, mean {:.4}, mean abs {:.4}, rms {:.4}, max abs {:.4}",
+            self.elements_added,
+            if self.is_exact { "" } else { "~" },
+            self.exact_count,
+            self.num_observations,
+            self.final_relative_error,
+            self.mean_relative_error,
+            self.mean_absolute_relative_error,
+            self.rms_relative_error,
+            self.max_absolute_relative_error
+        )
+    }
+}
+
+/// A wrapper around a [mutable estimator](EstimatorMut) that measures its
+/// accuracy by counting distinct elements exactly.
+///
+/// Every element [added](AccuracyMonitor::add) to the monitor is added to
+/// the wrapped estimator, and its hash, computed with a [`DefaultHasher`], is
+/// stored in a set of bounded capacity. As long as the capacity is not
+/// exceeded, the size of the set is the exact number of distinct elements
+/// (barring hash collisions), and [`relative_error`](AccuracyMonitor::relative_error)
+/// compares it with the estimate.
+///
+/// When the capacity is exceeded, the monitor switches to approximate
+/// counting by sampling: only hashes whose lowest *k* bits are zero are kept,
+/// where *k* is incremented (and the set pruned) each time the capacity is
+/// exceeded, and the number of distinct elements is estimated as the size of
+/// the set times 2<sup>*k*</sup>. Since the sampling decision depends only on
+/// the hash, repeated elements are handled correctly, and the relative
+/// standard deviation of the count is about the inverse of the square root
+/// of the capacity.
+///
+/// Every `interval` additions the relative error is recorded, and
+/// [`into_report`](AccuracyMonitor::into_report) returns an
+/// [`AccuracyReport`] summarizing the observed errors.
+pub struct AccuracyMonitor<L: ?Sized, E> {
+    estimator: E,
+    sample: HashSet<u64>,
+    capacity: usize,
+    sampling_level: u32,
+    interval: usize,
+    elements_added: usize,
+    errors: Vec<f64>,
+    _marker: std::marker::PhantomData<L>,
+}
+
+impl<L: EstimationLogic + ?Sized, E: EstimatorMut<L>> AccuracyMonitor<L, E>
+where
+    L::Item: Hash,
+{
+    /// Creates a new accuracy monitor.
+    ///
+    /// # Arguments
+    /// * `estimator`: the estimator to wrap; it should be empty.
+    /// * `capacity`: the maximum number of hashes kept for counting.
+    /// * `interval`: the number of additions between observations of the
+    ///   relative error.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `capacity` or `interval` are zero.
+    pub fn new(estimator: E, capacity: usize, interval: usize) -> Self {
+        assert!(capacity > 0, "the capacity must be positive");
+        assert!(interval > 0, "the observation interval must be positive");
+        Self {
+            estimator,
+            sample: HashSet::with_capacity(capacity + 1),
+            capacity,
+            sampling_level: 0,
+            interval,
+            elements_added: 0,
+            errors: vec![],
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Adds an element to the wrapped estimator and to the exact counter.
+    pub fn add(&mut self, element: impl Borrow<L::Item>) {
+        let hash = BuildHasherDefault::<DefaultHasher>::default().hash_one(element.borrow());
+        self.estimator.add(element);
+        self.elements_added += 1;
+
+        if hash.trailing_zeros() >= self.sampling_level {
+            self.sample.insert(hash);
+            while self.sample.len() > self.capacity {
+                self.sampling_level += 1;
+                let level = self.sampling_level;
+                self.sample.retain(|h| h.trailing_zeros() >= level);
+            }
+        }
+
+        if self.elements_added % self.interval == 0 {
+            let error = self.relative_error();
+            self.errors.push(error);
+        }
+    }
+
+    /// Returns the number of elements added so far.
+    pub fn elements_added(&self) -> usize {
+        self.elements_added
+    }
+
+    /// Returns the number of distinct elements added so far.
+    ///
+    /// The result is exact only if [`is_exact`](AccuracyMonitor::is_exact)
+    /// returns `true`.
+    pub fn exact_count(&self) -> f64 {
+        self.sample.len() as f64 * (1_u64 << self.sampling_level) as f64
+    }
+
+    /// Returns `true` if the capacity has never been exceeded, and thus
+    /// [`exact_count`](AccuracyMonitor::exact_count) is exact.
+    pub fn is_exact(&self) -> bool {
+        self.sampling_level == 0
+    }
+
+    /// Returns the relative error of the estimate of the wrapped estimator
+    /// with respect to the [exact count](AccuracyMonitor::exact_count).
+    ///
+    /// The error is zero if no element has been added.
+    pub fn relative_error(&self) -> f64 {
+        let exact = self.exact_count();
+        if exact == 0.0 {
+            0.0
+        } else {
+            (self.estimator.estimate() - exact) / exact
+        }
+    }
+
+    /// Returns the relative errors observed so far.
+    pub fn errors(&self) -> &[f64] {
+        &self.errors
+    }
+
+    /// Returns the wrapped estimator.
+    pub fn into_inner(self) -> E {
+        self.estimator
+    }
+
+    /// Returns a report summarizing the observed relative errors.
+    ///
+    /// The current relative error is included in the statistics, unless it
+    /// has just been observed.
+    pub fn into_report(mut self) -> AccuracyReport {
+        let final_relative_error = self.relative_error();
+        if self.elements_added % self.interval != 0 {
+            self.errors.push(final_relative_error);
+        }
+        let n = self.errors.len() as f64;
+        let (mut sum, mut abs_sum, mut square_sum, mut max_abs) = (0.0, 0.0, 0.0, 0.0_f64);
+        for &error in &self.errors {
+            sum += error;
+            abs_sum += error.abs();
+            square_sum += error * error;
+            max_abs = max_abs.max(error.abs());
+        }
+        let mean = |x: f64| if n == 0.0 { 0.0 } else { x / n };
+        AccuracyReport {
+            elements_added: self.elements_added,
+            exact_count: self.exact_count(),
+            is_exact: self.is_exact(),
+            num_observations: self.errors.len(),
+            final_relative_error,
+            mean_relative_error: mean(sum),
+            mean_absolute_relative_error: mean(abs_sum),
+            rms_relative_error: mean(square_sum).sqrt(),
+            max_absolute_relative_error: max_abs,
+        }
+    }
+}
+
+impl<L: EstimationLogic + ?Sized, E: AsRef<L::Backend>> AsRef<L::Backend>
+    for AccuracyMonitor<L, E>
+{
+    fn as_ref(&self) -> &L::Backend {
+        self.estimator.as_ref()
+    }
+}
+
+impl<L: EstimationLogic + ?Sized, E: Estimator<L>> Estimator<L> for AccuracyMonitor<L, E> {
+    type OwnedEstimator = E::OwnedEstimator;
+
+    fn logic(&self) -> &L {
+        self.estimator.logic()
+    }
+
+    #[inline(always)]
+    fn estimate(&self) -> f64 {
+        self.estimator.estimate()
+    }
+
+    /// Converts the wrapped estimator into an owned version.
+    ///
+    /// The result is no longer monitored.
+    fn into_owned(self) -> Self::OwnedEstimator
+    where
+        for<'a> Box<L::Backend>: From<&'a L::Backend>,
+    {
+        self.estimator.into_owned()
+    }
+}
@@ -0,0 +1,141 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use std::borrow::Borrow;
+use std::f64::consts::LN_2;
+use std::hash::*;
+
+use super::DefaultEstimator;
+use crate::traits::{EstimationLogic, MergeEstimationLogic, SliceEstimationLogic};
+
+/// Estimator logic implementing the Alon–Matias–Szegedy estimator of the
+/// number of distinct elements (F₀) using the minimum hash value of `K`
+/// independent trials.
+///
+/// Each of the `K` words of a backend keeps track of the minimum value, in a
+/// trial, of a hash function mapping elements to (0..1]: if *x* is the minimum
+/// of *n* independent uniform values, 1/*x* − 1 is an estimate of *n*. The
+/// final estimate is the median of the estimates of the trials, multiplied by
+/// ln 2 to correct for the asymmetry of the distribution of the minimum.
+/// Hashes for the trials are obtained by mixing a single hash of the element
+/// with different constants.
+///
+/// To make empty backends all-zero, as required, for example, by
+/// [`SliceEstimatorArray`](super::SliceEstimatorArray), words contain the
+/// bitwise complement of the minimum hash: thus, [`add`](EstimationLogic::add)
+/// and [merges](MergeEstimationLogic::merge) compute element-wise maxima of
+/// the stored words, which correspond to element-wise minima of the hashes.
+///
+/// HyperLogLog provides better precision for the same amount of memory; this
+/// logic is mainly useful as a baseline.
+#[derive(Debug, PartialEq)]
+pub struct AmsF0<T, H, const K: usize> {
+    build_hasher: H,
+    _marker: std::marker::PhantomData<T>,
+}
+
+// We implement Clone manually because we do not want to require that T is
+// Clone.
+impl<T, H: Clone, const K: usize> Clone for AmsF0<T, H, K> {
+    fn clone(&self) -> Self {
+        Self {
+            build_hasher: self.build_hasher.clone(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, H, const K: usize> AmsF0<T, H, K> {
+    /// Creates a new logic using the given [`BuildHasher`].
+    ///
+    /// Calls to this method will not compile if `K` is zero.
+    pub const fn new(build_hasher: H) -> Self {
+        const { assert!(K > 0, "the number of trials must be positive") };
+        Self {
+            build_hasher,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Mixes a hash with the index of a trial, using the SplitMix64 finalizer.
+#[inline(always)]
+pub(super) fn trial_hash(hash: u64, trial: usize) -> u64 {
+    let mut z = hash ^ (trial as u64 + 1).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+impl<T: Hash, H: BuildHasher + Clone, const K: usize> SliceEstimationLogic<u64> for AmsF0<T, H, K> {
+    fn backend_len(&self) -> usize {
+        K
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, const K: usize> EstimationLogic for AmsF0<T, H, K> {
+    type Item = T;
+    type Backend = [u64];
+    type Estimator<'a>
+        = DefaultEstimator<Self, &'a Self, Box<[u64]>>
+    where
+        T: 'a,
+        H: 'a;
+
+    fn new_estimator(&self) -> Self::Estimator<'_> {
+        DefaultEstimator::new(self, vec![0; K].into_boxed_slice())
+    }
+
+    fn add(&self, backend: &mut [u64], element: impl Borrow<T>) {
+        debug_assert_eq!(backend.len(), K);
+        let hash = self.build_hasher.hash_one(element.borrow());
+        for (trial, word) in backend.iter_mut().enumerate() {
+            // Words contain the complement of the minimum hash
+            *word = (*word).max(!trial_hash(hash, trial));
+        }
+    }
+
+    fn estimate(&self, backend: &[u64]) -> f64 {
+        debug_assert_eq!(backend.len(), K);
+        let mut estimates = [0.0; K];
+        for (estimate, &word) in estimates.iter_mut().zip(backend) {
+            if word != 0 {
+                // The minimum hash, mapped to (0..1]
+                let min = ((!word) as f64 + 1.0) / 2.0_f64.powi(64);
+                *estimate = 1.0 / min - 1.0;
+            }
+        }
+        estimates.sort_by(f64::total_cmp);
+        let median = if K % 2 == 1 {
+            estimates[K / 2]
+        } else {
+            (estimates[K / 2 - 1] + estimates[K / 2]) / 2.0
+        };
+        median * LN_2
+    }
+
+    fn clear(&self, backend: &mut [u64]) {
+        backend.fill(0);
+    }
+
+    fn set(&self, dst: &mut [u64], src: &[u64]) {
+        debug_assert_eq!(dst.len(), src.len());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, const K: usize> MergeEstimationLogic for AmsF0<T, H, K> {
+    type Helper = ();
+
+    fn new_helper(&self) -> Self::Helper {}
+
+    fn merge_with_helper(&self, dst: &mut [u64], src: &[u64], _helper: &mut Self::Helper) {
+        debug_assert_eq!(dst.len(), src.len());
+        for (d, &s) in dst.iter_mut().zip(src) {
+            *d = (*d).max(s);
+        }
+    }
+}
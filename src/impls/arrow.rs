@@ -0,0 +1,66 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::{bytes::words_from_le_bytes, SliceEstimatorArray};
+use crate::traits::SliceEstimationLogic;
+use arrow2::{
+    array::FixedSizeBinaryArray,
+    buffer::Buffer,
+    datatypes::DataType,
+    error::{Error as ArrowError, Result},
+};
+use common_traits::{FromBytes, ToBytes};
+use sux::traits::Word;
+
+impl<L: SliceEstimationLogic<W>, W: Word + ToBytes, S: AsRef<[W]>> SliceEstimatorArray<L, W, S> {
+    /// Returns the backends of the array as an [Arrow](https://arrow.apache.org/)
+    /// buffer.
+    ///
+    /// The buffer contains the same bytes returned by
+    /// [`to_bytes`](SliceEstimatorArray::to_bytes), and it can be used as
+    /// the values of a `FixedSizeBinary` column in which each element is a
+    /// backend; see [`to_arrow_array`](SliceEstimatorArray::to_arrow_array).
+    pub fn to_arrow_buffer(&self) -> Buffer<u8> {
+        Buffer::from(self.to_bytes())
+    }
+
+    /// Returns the backends of the array as an [Arrow](https://arrow.apache.org/)
+    /// `FixedSizeBinary` array with no null values, in which each element
+    /// is a backend of `backend_len * size_of::<W>()` bytes.
+    pub fn to_arrow_array(&self) -> FixedSizeBinaryArray {
+        FixedSizeBinaryArray::new(
+            DataType::FixedSizeBinary(self.logic.backend_len() * W::BYTES),
+            self.to_arrow_buffer(),
+            None,
+        )
+    }
+}
+
+impl<L: SliceEstimationLogic<W>, W: Word + FromBytes> SliceEstimatorArray<L, W, Box<[W]>> {
+    /// Creates an array with the provided logic from an
+    /// [Arrow](https://arrow.apache.org/) buffer, as returned by
+    /// [`to_arrow_buffer`](SliceEstimatorArray::to_arrow_buffer).
+    ///
+    /// The values buffer of a `FixedSizeBinary` array returned by
+    /// [`to_arrow_array`](SliceEstimatorArray::to_arrow_array) can be
+    /// passed as well.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the length of `buffer` is not a
+    /// multiple of the size in bytes of a backend of `logic`.
+    pub fn from_arrow_buffer(logic: L, buffer: Buffer<u8>) -> Result<Self> {
+        let backend_bytes = logic.backend_len() * W::BYTES;
+        if buffer.len() % backend_bytes != 0 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "the number of bytes ({}) is not a multiple of the size of a backend ({} bytes)",
+                buffer.len(),
+                backend_bytes
+            )));
+        }
+        Ok(Self::from_parts(logic, words_from_le_bytes(&buffer)))
+    }
+}
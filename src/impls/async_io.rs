@@ -0,0 +1,53 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::{bytes::words_from_le_bytes, SliceEstimatorArray};
+use crate::traits::SliceEstimationLogic;
+use common_traits::{FromBytes, ToBytes};
+use std::io;
+use sux::traits::Word;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+impl<L: SliceEstimationLogic<W>, W: Word + ToBytes, S: AsRef<[W]>> SliceEstimatorArray<L, W, S> {
+    /// Writes asynchronously the backends of the array to a sink.
+    ///
+    /// The format is the same of [`to_bytes`](SliceEstimatorArray::to_bytes),
+    /// so the data can be read back both with
+    /// [`read_from_source`](SliceEstimatorArray::read_from_source) and
+    /// [`from_bytes`](SliceEstimatorArray::from_bytes).
+    ///
+    /// The sink is flushed, but not shut down.
+    pub async fn write_to_sink<K: AsyncWrite + Unpin>(&self, sink: &mut K) -> io::Result<()> {
+        sink.write_all(&self.to_bytes()).await?;
+        sink.flush().await
+    }
+}
+
+impl<L: SliceEstimationLogic<W>, W: Word + FromBytes> SliceEstimatorArray<L, W, Box<[W]>> {
+    /// Reads asynchronously from a source an array with the provided logic,
+    /// as written by [`write_to_sink`](SliceEstimatorArray::write_to_sink) or
+    /// [`to_bytes`](SliceEstimatorArray::to_bytes).
+    ///
+    /// # Arguments
+    /// * `logic`: the logic of the array.
+    /// * `source`: the source to read from.
+    /// * `expected_len`: the number of estimators to read.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error of kind
+    /// [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) if the source contains
+    /// less than `expected_len` backends, or in case of I/O errors.
+    pub async fn read_from_source<K: AsyncRead + Unpin>(
+        logic: L,
+        source: &mut K,
+        expected_len: usize,
+    ) -> io::Result<Self> {
+        let mut bytes = vec![0; expected_len * logic.backend_len() * W::BYTES];
+        source.read_exact(&mut bytes).await?;
+        Ok(Self::from_parts(logic, words_from_le_bytes(&bytes)))
+    }
+}
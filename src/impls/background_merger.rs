@@ -0,0 +1,234 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::SliceEstimatorArray;
+use crate::traits::*;
+use std::borrow::Borrow;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+use sux::traits::Word;
+
+/// A shard of the updates accumulated since the last merge.
+///
+/// The estimator of index `i` is the estimator of index `i / num_shards` of
+/// the shard of index `i % num_shards`.
+struct Shard<L, W> {
+    /// The estimators of the shard receiving new elements.
+    array: SliceEstimatorArray<L, W, Box<[W]>>,
+    /// Whether each estimator of `array` has been modified since the last merge.
+    dirty: Vec<bool>,
+    /// The indices in `array` of the modified estimators, in order of first
+    /// modification.
+    changed: Vec<usize>,
+}
+
+/// A published snapshot.
+type Snapshot<L, W> = Arc<SliceEstimatorArray<L, W, Box<[W]>>>;
+
+/// The state shared between a [`BackgroundMerger`] and its thread.
+struct Shared<L, W> {
+    logic: L,
+    /// The pending updates, sharded so that producers adding to different
+    /// shards do not contend for the same lock.
+    shards: Box<[Mutex<Shard<L, W>>]>,
+    snapshot: Mutex<Snapshot<L, W>>,
+    /// Serializes merges, so that a snapshot is never built from a stale one.
+    merge_lock: Mutex<()>,
+    stop: AtomicBool,
+}
+
+impl<L: SliceEstimationLogic<W> + MergeEstimationLogic + Clone, W: Word> Shared<L, W> {
+    fn merge_all_changed(&self) -> usize {
+        let _guard = self.merge_lock.lock().unwrap();
+        let backend_len = self.logic.backend_len();
+
+        // We copy the modified backends and clear them one shard at a time,
+        // so that producers are blocked only for the time of the copy of
+        // their shard.
+        let num_shards = self.shards.len();
+        let mut changed = Vec::new();
+        let mut backends = Vec::new();
+        for (shard_index, shard) in self.shards.iter().enumerate() {
+            let mut shard = shard.lock().unwrap();
+            let shard = &mut *shard;
+            backends.reserve(shard.changed.len() * backend_len);
+            for local_index in shard.changed.drain(..) {
+                let backend = shard.array.get_backend_mut(local_index);
+                backends.extend_from_slice(backend);
+                self.logic.clear(backend);
+                shard.dirty[local_index] = false;
+                changed.push(local_index * num_shards + shard_index);
+            }
+        }
+
+        if changed.is_empty() {
+            return 0;
+        }
+
+        // Readers might be holding the current snapshot, so we merge into a copy.
+        let current = self.snapshot.lock().unwrap().clone();
+        let mut next = SliceEstimatorArray::new(self.logic.clone(), current.len());
+        next.as_mut().copy_from_slice((*current).as_ref());
+
+        let mut helper = self.logic.new_helper();
+        for (&index, backend) in changed.iter().zip(backends.chunks(backend_len)) {
+            self.logic
+                .merge_with_helper(next.get_backend_mut(index), backend, &mut helper);
+        }
+
+        *self.snapshot.lock().unwrap() = Arc::new(next);
+        changed.len()
+    }
+}
+
+/// An array of estimators whose updates are merged periodically by a
+/// background thread into a snapshot.
+///
+/// Producers [add](BackgroundMerger::add) elements to the estimators of the
+/// array, possibly from several threads at the same time. Pending updates are
+/// sharded by estimator index, each shard with its own lock, so producers
+/// contend only when they add to estimators of the same shard. Every `period`, a
+/// background thread [merges](BackgroundMerger::merge_all_changed) the
+/// estimators modified since the last merge into the current snapshot, and
+/// publishes the result as a new snapshot. Consumers can obtain the latest
+/// snapshot at any time using [`snapshot`](BackgroundMerger::snapshot): since
+/// snapshots are never modified after their publication, a consumer always
+/// sees a consistent state.
+///
+/// Since merges can only add elements to an estimator, the state of each
+/// estimator in a snapshot contains the state of the same estimator in all
+/// previous snapshots.
+///
+/// The thread is stopped by [`stop`](BackgroundMerger::stop), or when the
+/// merger is dropped.
+pub struct BackgroundMerger<L, W> {
+    shared: Arc<Shared<L, W>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl<
+        L: SliceEstimationLogic<W> + MergeEstimationLogic + Clone + Send + Sync + 'static,
+        W: Word + 'static,
+    > BackgroundMerger<L, W>
+{
+    /// Creates a new background merger for an array of estimators, and starts
+    /// its background thread.
+    ///
+    /// # Arguments
+    /// * `logic`: the estimator logic to use.
+    /// * `len`: the number of the estimators in the array.
+    /// * `period`: the time between two merges.
+    pub fn new(logic: L, len: usize, period: Duration) -> Self {
+        // A few shards per thread make collisions between producers unlikely
+        let num_shards = std::thread::available_parallelism()
+            .map_or(1, |n| n.get() * 4)
+            .min(len)
+            .max(1);
+        let shards = (0..num_shards)
+            .map(|shard_index| {
+                let shard_len = (len + num_shards - 1 - shard_index) / num_shards;
+                Mutex::new(Shard {
+                    array: SliceEstimatorArray::new(logic.clone(), shard_len),
+                    dirty: vec![false; shard_len],
+                    changed: Vec::new(),
+                })
+            })
+            .collect();
+        let shared = Arc::new(Shared {
+            shards,
+            snapshot: Mutex::new(Arc::new(SliceEstimatorArray::new(logic.clone(), len))),
+            merge_lock: Mutex::new(()),
+            stop: AtomicBool::new(false),
+            logic,
+        });
+
+        let thread_shared = shared.clone();
+        let thread = std::thread::spawn(move || {
+            while !thread_shared.stop.load(Ordering::Acquire) {
+                std::thread::park_timeout(period);
+                thread_shared.merge_all_changed();
+            }
+        });
+
+        Self {
+            shared,
+            thread: Some(thread),
+        }
+    }
+}
+
+impl<L: SliceEstimationLogic<W> + MergeEstimationLogic + Clone, W: Word> BackgroundMerger<L, W> {
+    /// Returns the logic used by the estimators in the array.
+    pub fn logic(&self) -> &L {
+        &self.shared.logic
+    }
+
+    /// Adds an element to the estimator at the specified index.
+    ///
+    /// The element will be visible in snapshots after the next merge.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `index` is out of bounds.
+    pub fn add(&self, index: usize, element: impl Borrow<L::Item>) {
+        let shards = &self.shared.shards;
+        let (shard_index, local_index) = (index % shards.len(), index / shards.len());
+        let mut shard = shards[shard_index].lock().unwrap();
+        let shard = &mut *shard;
+        self.shared
+            .logic
+            .add(shard.array.get_backend_mut(local_index), element);
+        if !shard.dirty[local_index] {
+            shard.dirty[local_index] = true;
+            shard.changed.push(local_index);
+        }
+    }
+
+    /// Merges into a new snapshot all estimators modified since the last merge,
+    /// returning the number of merged estimators.
+    ///
+    /// This method is called periodically by the background thread, but it can
+    /// be called explicitly to make all additions visible immediately. If no
+    /// estimator has been modified, no new snapshot is created.
+    pub fn merge_all_changed(&self) -> usize {
+        self.shared.merge_all_changed()
+    }
+
+    /// Returns the latest snapshot.
+    pub fn snapshot(&self) -> Arc<SliceEstimatorArray<L, W, Box<[W]>>> {
+        self.shared.snapshot.lock().unwrap().clone()
+    }
+
+    /// Stops the background thread, waiting for its termination, and returns
+    /// the final snapshot, which contains all elements added so far.
+    pub fn stop(mut self) -> Arc<SliceEstimatorArray<L, W, Box<[W]>>> {
+        self.join();
+        self.merge_all_changed();
+        self.snapshot()
+    }
+}
+
+impl<L, W> BackgroundMerger<L, W> {
+    /// Stops the background thread, if it is still running, and waits for
+    /// its termination.
+    fn join(&mut self) {
+        if let Some(thread) = self.thread.take() {
+            self.shared.stop.store(true, Ordering::Release);
+            thread.thread().unpark();
+            // A panic in the thread can only come from the logic, and it
+            // would have already been reported.
+            let _ = thread.join();
+        }
+    }
+}
+
+impl<L, W> Drop for BackgroundMerger<L, W> {
+    fn drop(&mut self) {
+        self.join();
+    }
+}
@@ -0,0 +1,63 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::SliceEstimatorArray;
+use crate::traits::SliceEstimationLogic;
+use anyhow::{ensure, Result};
+use common_traits::{FromBytes, ToBytes};
+use sux::traits::Word;
+
+impl<L: SliceEstimationLogic<W>, W: Word + ToBytes, S: AsRef<[W]>> SliceEstimatorArray<L, W, S> {
+    /// Returns the backends of the array as a sequence of bytes.
+    ///
+    /// The result is the concatenation of the little-endian representations
+    /// of the words of the backends, so it does not depend on the platform.
+    /// The logic is not serialized: the array can be rebuilt using
+    /// [`from_bytes`](SliceEstimatorArray::from_bytes) with the same logic.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let backend = self.backend.as_ref();
+        let mut bytes = Vec::with_capacity(backend.len() * W::BYTES);
+        for &word in backend {
+            bytes.extend_from_slice(word.to_le_bytes().as_ref());
+        }
+        bytes
+    }
+}
+
+impl<L: SliceEstimationLogic<W>, W: Word + FromBytes> SliceEstimatorArray<L, W, Box<[W]>> {
+    /// Creates an array with the provided logic from a sequence of bytes, as
+    /// returned by [`to_bytes`](SliceEstimatorArray::to_bytes).
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the length of `bytes` is not a
+    /// multiple of the size in bytes of a backend of `logic`.
+    pub fn from_bytes(logic: L, bytes: &[u8]) -> Result<Self> {
+        let backend_bytes = logic.backend_len() * W::BYTES;
+        ensure!(
+            bytes.len() % backend_bytes == 0,
+            "the number of bytes ({}) is not a multiple of the size of a backend ({} bytes)",
+            bytes.len(),
+            backend_bytes
+        );
+        Ok(Self::from_parts(logic, words_from_le_bytes(bytes)))
+    }
+}
+
+/// Decodes a sequence of little-endian words.
+///
+/// The length of `bytes` must be a multiple of the size of `W`.
+pub(super) fn words_from_le_bytes<W: Word + FromBytes>(bytes: &[u8]) -> Box<[W]> {
+    debug_assert!(bytes.len() % W::BYTES == 0);
+    bytes
+        .chunks_exact(W::BYTES)
+        .map(|chunk| {
+            let mut word_bytes = W::Bytes::default();
+            word_bytes.as_mut().copy_from_slice(chunk);
+            W::from_le_bytes(word_bytes)
+        })
+        .collect()
+}
@@ -0,0 +1,354 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::{ensure, Result};
+use common_traits::{CastableFrom, UpcastableInto};
+use std::borrow::Borrow;
+use std::collections::hash_map::DefaultHasher;
+use std::f64::consts::E;
+use std::hash::*;
+use sux::traits::Word;
+
+use super::ams_f0::trial_hash;
+use super::DefaultEstimator;
+use crate::traits::{EstimationLogic, SliceEstimationLogic};
+
+/// Estimator logic implementing Count-Min sketches.
+///
+/// Differently from the other logics of this crate, a Count-Min sketch
+/// answers frequency queries: items are pairs (*key*, *count*), and
+/// [`add`](EstimationLogic::add) increments by *count* the frequency of
+/// *key*. A backend is a *d* × *w* matrix of counters of type `W`, stored in
+/// row-major order; each key is mapped by a different hash function to a
+/// counter in each row, and adding a pair increments all such counters.
+///
+/// The [frequency](CountMinSketch::frequency) of a key is estimated by the
+/// minimum of its counters. The estimate is never smaller than the true
+/// frequency, and, with probability at least 1 − δ, it exceeds it by at most
+/// ε*N*, where *N* is the sum of all counts, ε = e / *w*, and δ =
+/// e<sup>−*d*</sup>. [`estimate`](EstimationLogic::estimate) returns *N*.
+///
+/// Since backends are slices, arrays of sketches can be stored in a
+/// [`SliceEstimatorArray`](super::SliceEstimatorArray). Counters saturate at
+/// the maximum value of `W`. Instances are created using a
+/// [`CountMinSketchBuilder`].
+///
+/// Count-Min sketches are combined by adding their counters (see
+/// [`add_sketch`](CountMinSketch::add_sketch)), which is not a set union: in
+/// particular, adding a sketch to itself doubles all frequencies. For this
+/// reason, this logic does not implement
+/// [`MergeEstimationLogic`](crate::traits::MergeEstimationLogic).
+#[derive(Debug, PartialEq)]
+pub struct CountMinSketch<K, H, W> {
+    build_hasher: H,
+    width: usize,
+    depth: usize,
+    _marker: std::marker::PhantomData<(K, W)>,
+}
+
+// We implement Clone manually because we do not want to require that K and W
+// are Clone.
+impl<K, H: Clone, W> Clone for CountMinSketch<K, H, W> {
+    fn clone(&self) -> Self {
+        Self {
+            build_hasher: self.build_hasher.clone(),
+            width: self.width,
+            depth: self.depth,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<K, H, W> CountMinSketch<K, H, W> {
+    /// Returns the number of counters per row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the additive error ε = e / *w* of the frequency estimates,
+    /// relative to the sum of all counts.
+    pub fn epsilon(&self) -> f64 {
+        E / self.width as f64
+    }
+
+    /// Returns the probability δ = e<sup>−*d*</sup> that a frequency
+    /// estimate exceeds the error bound.
+    pub fn delta(&self) -> f64 {
+        (-(self.depth as f64)).exp()
+    }
+}
+
+impl<K: Hash, H: BuildHasher, W> CountMinSketch<K, H, W> {
+    /// Returns the indices in a backend of the counters associated with a
+    /// key, one for each row.
+    fn counters(&self, key: &K) -> impl Iterator<Item = usize> + '_ {
+        let hash = self.build_hasher.hash_one(key);
+        (0..self.depth).map(move |row| {
+            // Multiply-shift range reduction
+            let column = ((trial_hash(hash, row) as u128 * self.width as u128) >> 64) as usize;
+            row * self.width + column
+        })
+    }
+}
+
+impl<K: Hash, H: BuildHasher, W: Word + UpcastableInto<u64>> CountMinSketch<K, H, W> {
+    /// Returns an estimate of the frequency of a key in a backend.
+    ///
+    /// The estimate is never smaller than the true frequency (unless counters
+    /// saturated).
+    pub fn frequency(&self, backend: &[W], key: impl Borrow<K>) -> u64 {
+        debug_assert_eq!(backend.len(), self.width * self.depth);
+        self.counters(key.borrow())
+            .map(|index| backend[index].upcast())
+            .min()
+            .unwrap_or(0)
+    }
+}
+
+impl<K, H, W: Word + UpcastableInto<u64> + CastableFrom<u64>> CountMinSketch<K, H, W> {
+    /// Adds element-wise the counters of `src` to those of `dst`, so that
+    /// `dst` represents the sum of the frequencies of the two sketches.
+    ///
+    /// Counters saturate at the maximum value of `W`.
+    pub fn add_sketch(&self, dst: &mut [W], src: &[W]) {
+        debug_assert_eq!(dst.len(), self.width * self.depth);
+        debug_assert_eq!(dst.len(), src.len());
+        for (d, &s) in dst.iter_mut().zip(src) {
+            *d = saturating_add(*d, s.upcast());
+        }
+    }
+}
+
+/// Adds two counters, saturating at the maximum value of `W`.
+#[inline(always)]
+fn saturating_add<W: Word + UpcastableInto<u64> + CastableFrom<u64>>(counter: W, count: u64) -> W {
+    let max: u64 = W::MAX.upcast();
+    W::cast_from(counter.upcast().saturating_add(count).min(max))
+}
+
+impl<K: Hash, H: BuildHasher + Clone, W> SliceEstimationLogic<W> for CountMinSketch<K, H, W>
+where
+    W: Word + UpcastableInto<u64> + CastableFrom<u64>,
+{
+    fn backend_len(&self) -> usize {
+        self.width * self.depth
+    }
+}
+
+impl<K: Hash, H: BuildHasher + Clone, W> EstimationLogic for CountMinSketch<K, H, W>
+where
+    W: Word + UpcastableInto<u64> + CastableFrom<u64>,
+{
+    type Item = (K, u64);
+    type Backend = [W];
+    type Estimator<'a>
+        = DefaultEstimator<Self, &'a Self, Box<[W]>>
+    where
+        K: 'a,
+        H: 'a,
+        W: 'a;
+
+    fn new_estimator(&self) -> Self::Estimator<'_> {
+        DefaultEstimator::new(
+            self,
+            vec![W::ZERO; self.width * self.depth].into_boxed_slice(),
+        )
+    }
+
+    fn add(&self, backend: &mut [W], element: impl Borrow<(K, u64)>) {
+        debug_assert_eq!(backend.len(), self.width * self.depth);
+        let (key, count) = element.borrow();
+        for index in self.counters(key) {
+            backend[index] = saturating_add(backend[index], *count);
+        }
+    }
+
+    /// Returns the sum *N* of all counts added to the backend, which is the
+    /// sum of the counters of any row.
+    fn estimate(&self, backend: &[W]) -> f64 {
+        debug_assert_eq!(backend.len(), self.width * self.depth);
+        backend[..self.width]
+            .iter()
+            .map(|&counter| UpcastableInto::<u64>::upcast(counter))
+            .sum::<u64>() as f64
+    }
+
+    fn clear(&self, backend: &mut [W]) {
+        backend.fill(W::ZERO);
+    }
+
+    fn set(&self, dst: &mut [W], src: &[W]) {
+        debug_assert_eq!(dst.len(), src.len());
+        dst.copy_from_slice(src);
+    }
+}
+
+/// Builds a [`CountMinSketch`] logic.
+///
+/// The dimensions of the sketch can be set either directly, using
+/// [`width`](CountMinSketchBuilder::width) and
+/// [`depth`](CountMinSketchBuilder::depth), or from the desired error
+/// guarantees, using [`epsilon`](CountMinSketchBuilder::epsilon) and
+/// [`delta`](CountMinSketchBuilder::delta).
+#[derive(Debug, Clone)]
+pub struct CountMinSketchBuilder<H, W = u64> {
+    build_hasher: H,
+    width: usize,
+    depth: usize,
+    epsilon: Option<f64>,
+    delta: Option<f64>,
+    _marker: std::marker::PhantomData<W>,
+}
+
+impl CountMinSketchBuilder<BuildHasherDefault<DefaultHasher>, u64> {
+    /// Creates a new builder for a [`CountMinSketch`] logic with a default
+    /// word type of `u64`, ε = 0.01, and δ = 0.01.
+    pub fn new() -> Self {
+        Self {
+            build_hasher: BuildHasherDefault::default(),
+            width: 0,
+            depth: 0,
+            epsilon: None,
+            delta: None,
+            _marker: std::marker::PhantomData,
+        }
+        .epsilon(0.01)
+        .delta(0.01)
+    }
+}
+
+impl Default for CountMinSketchBuilder<BuildHasherDefault<DefaultHasher>, u64> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H, W> CountMinSketchBuilder<H, W> {
+    /// Sets the number of counters per row.
+    ///
+    /// ## Note
+    ///
+    /// This is a low-level alternative to [`Self::epsilon`]. Calling one after
+    /// the other invalidates the work done by the first one.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self.epsilon = None;
+        self
+    }
+
+    /// Sets the number of rows.
+    ///
+    /// ## Note
+    ///
+    /// This is a low-level alternative to [`Self::delta`]. Calling one after
+    /// the other invalidates the work done by the first one.
+    pub fn depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self.delta = None;
+        self
+    }
+
+    /// Sets the width so that the additive error of frequency estimates is at
+    /// most ε times the sum of all counts.
+    ///
+    /// # Arguments
+    /// * `epsilon`: the additive error ε, which must be in (0 . . 1).
+    pub fn epsilon(mut self, epsilon: f64) -> Self {
+        self.epsilon = Some(epsilon);
+        self
+    }
+
+    /// Sets the depth so that the error bound holds with probability at least
+    /// 1 − δ.
+    ///
+    /// # Arguments
+    /// * `delta`: the failure probability δ, which must be in (0 . . 1).
+    pub fn delta(mut self, delta: f64) -> Self {
+        self.delta = Some(delta);
+        self
+    }
+
+    /// Sets the type `W2` of the counters.
+    pub fn word_type<W2>(self) -> CountMinSketchBuilder<H, W2> {
+        CountMinSketchBuilder {
+            build_hasher: self.build_hasher,
+            width: self.width,
+            depth: self.depth,
+            epsilon: self.epsilon,
+            delta: self.delta,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Sets the [`BuildHasher`] to use.
+    ///
+    /// Using this method you can select a specific hashed based on one or more
+    /// seeds.
+    pub fn build_hasher<H2>(self, build_hasher: H2) -> CountMinSketchBuilder<H2, W> {
+        CountMinSketchBuilder {
+            build_hasher,
+            width: self.width,
+            depth: self.depth,
+            epsilon: self.epsilon,
+            delta: self.delta,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Builds the logic.
+    ///
+    /// The type of keys whose frequencies the sketches keep track of is
+    /// defined here by `K`, but it is usually inferred by the compiler.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if ε or δ is not in (0 . . 1), if the
+    /// width or the depth is zero, or if the number of counters overflows a
+    /// `usize`.
+    pub fn build<K>(self) -> Result<CountMinSketch<K, H, W>> {
+        let width = match self.epsilon {
+            Some(epsilon) => {
+                ensure!(
+                    epsilon > 0.0 && epsilon < 1.0,
+                    "ε must be in (0 . . 1); got {}",
+                    epsilon
+                );
+                (E / epsilon).ceil() as usize
+            }
+            None => self.width,
+        };
+        let depth = match self.delta {
+            Some(delta) => {
+                ensure!(
+                    delta > 0.0 && delta < 1.0,
+                    "δ must be in (0 . . 1); got {}",
+                    delta
+                );
+                (1.0 / delta).ln().ceil().max(1.0) as usize
+            }
+            None => self.depth,
+        };
+        ensure!(width > 0, "the width must be positive");
+        ensure!(depth > 0, "the depth must be positive");
+        ensure!(
+            width.checked_mul(depth).is_some(),
+            "the number of counters overflows a usize: width {}, depth {}",
+            width,
+            depth
+        );
+        Ok(CountMinSketch {
+            build_hasher: self.build_hasher,
+            width,
+            depth,
+            _marker: std::marker::PhantomData,
+        })
+    }
+}
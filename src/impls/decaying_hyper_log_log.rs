@@ -0,0 +1,282 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::{ensure, Result};
+use common_traits::{CastableFrom, UpcastableInto};
+use std::borrow::Borrow;
+use std::f64::consts::LN_2;
+use std::hash::*;
+use sux::traits::Word;
+
+use super::{alpha_m, BiasCorrection, DefaultEstimator, EstimationMode, HyperLogLog};
+use crate::traits::{EstimationLogic, MergeEstimationLogic};
+
+/// A variant of [`HyperLogLog`] in which the contribution of elements decays
+/// exponentially with time.
+///
+/// Items are pairs (*element*, *timestamp*), where timestamps are logical
+/// ticks (e.g., seconds) represented by a `u32`. Each register of a backend
+/// is a pair (*value*, *timestamp*) recording the value of the register and
+/// the time of its last update. At time *t*, a register with value *v* last
+/// updated at time *s* has decayed value *v* e<sup>−λ(*t* − *s*)</sup>, where
+/// λ = ln 2 / *h* and *h* is the [half-life](DecayingHyperLogLog::half_life).
+/// [`estimate_at`](DecayingHyperLogLog::estimate_at) applies the HyperLogLog
+/// formula to the decayed values, so elements contribute less to the estimate
+/// the older they are.
+///
+/// When adding an element, and when merging, the register keeps the pair
+/// with the larger decayed value; since all values decay at the same rate,
+/// the result does not depend on the time of comparison, so merges are
+/// commutative and associative.
+///
+/// The wrapped [`HyperLogLog`] logic is used to hash elements and to compute
+/// the candidate values of registers. This structure is specific to
+/// HyperLogLog, and it has some limitations with respect to it:
+///
+/// - registers are not packed, and a backend is a slice of pairs of length
+///   equal to the [number of registers](HyperLogLog::num_registers);
+///
+/// - estimates always use the [harmonic
+///   mean](EstimationMode::HarmonicMean) with the [standard bias
+///   correction](BiasCorrection::Standard), so the
+///   [builder](DecayingHyperLogLogBuilder::build) rejects logics configured
+///   otherwise.
+///
+/// Instances are created using a [`DecayingHyperLogLogBuilder`].
+pub struct DecayingHyperLogLog<T, H, W> {
+    logic: HyperLogLog<T, H, W>,
+    half_life: f64,
+    lambda: f64,
+}
+
+// We implement Debug, Clone, and PartialEq manually because we want the same
+// bounds as the wrapped HyperLogLog logic.
+impl<T, H, W: std::fmt::Debug> std::fmt::Debug for DecayingHyperLogLog<T, H, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DecayingHyperLogLog")
+            .field("logic", &self.logic)
+            .field("half_life", &self.half_life)
+            .finish()
+    }
+}
+
+impl<T, H: Clone, W: Clone> Clone for DecayingHyperLogLog<T, H, W> {
+    fn clone(&self) -> Self {
+        Self {
+            logic: self.logic.clone(),
+            half_life: self.half_life,
+            lambda: self.lambda,
+        }
+    }
+}
+
+impl<T, H: PartialEq, W> PartialEq for DecayingHyperLogLog<T, H, W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.logic == other.logic && self.half_life == other.half_life
+    }
+}
+
+impl<T, H, W> DecayingHyperLogLog<T, H, W> {
+    /// Returns the wrapped logic.
+    pub fn logic(&self) -> &HyperLogLog<T, H, W> {
+        &self.logic
+    }
+
+    /// Returns the half-life of the contribution of an element, in ticks.
+    pub fn half_life(&self) -> f64 {
+        self.half_life
+    }
+
+    /// Returns the decayed value at time `now` of a register with value
+    /// `value` last updated at time `timestamp`.
+    ///
+    /// Timestamps in the future are considered equal to `now`.
+    #[inline(always)]
+    fn decayed(&self, value: f64, timestamp: u32, now: u32) -> f64 {
+        value * (-self.lambda * now.saturating_sub(timestamp) as f64).exp()
+    }
+
+    /// Returns whether the register (`value`, `timestamp`) has a larger
+    /// decayed value than the register (`current_value`,
+    /// `current_timestamp`). Ties are broken in favor of the newer register.
+    #[inline(always)]
+    fn is_larger(
+        &self,
+        (value, timestamp): (f64, u32),
+        (current_value, current_timestamp): (f64, u32),
+    ) -> bool {
+        let now = timestamp.max(current_timestamp);
+        let decayed = self.decayed(value, timestamp, now);
+        let current_decayed = self.decayed(current_value, current_timestamp, now);
+        decayed > current_decayed || (decayed == current_decayed && timestamp > current_timestamp)
+    }
+}
+
+impl<T, H: Clone, W: Word + UpcastableInto<u64>> DecayingHyperLogLog<T, H, W> {
+    /// Returns an estimation at time `now` of the number of distinct elements
+    /// that have been added to an estimator with the given backend, in which
+    /// each element is weighted by its decay.
+    ///
+    /// As in the standard HyperLogLog formula, linear counting is used below
+    /// the [linear-counting threshold](HyperLogLog::linear_counting_threshold)
+    /// if some register has never been updated.
+    pub fn estimate_at(&self, backend: &[(W, u32)], now: u32) -> f64 {
+        debug_assert_eq!(backend.len(), self.logic.num_registers());
+        let m = backend.len() as f64;
+        let mut harmonic_mean = 0.0;
+        let mut zeroes = 0;
+        for &(value, timestamp) in backend {
+            let value: u64 = value.upcast();
+            if value == 0 {
+                zeroes += 1;
+            }
+            harmonic_mean += (-self.decayed(value as f64, timestamp, now)).exp2();
+        }
+
+        let estimate = alpha_m(backend.len()) * m * m / harmonic_mean;
+        if zeroes != 0 && estimate < self.logic.linear_counting_threshold() {
+            m * (m / zeroes as f64).ln()
+        } else {
+            estimate
+        }
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, W: Word + UpcastableInto<u64> + CastableFrom<u64>>
+    EstimationLogic for DecayingHyperLogLog<T, H, W>
+{
+    type Item = (T, u32);
+    type Backend = [(W, u32)];
+    type Estimator<'a>
+        = DefaultEstimator<Self, &'a Self, Box<[(W, u32)]>>
+    where
+        T: 'a,
+        H: 'a,
+        W: 'a;
+
+    fn new_estimator(&self) -> Self::Estimator<'_> {
+        DefaultEstimator::new(
+            self,
+            vec![(W::ZERO, 0); self.logic.num_registers()].into_boxed_slice(),
+        )
+    }
+
+    fn add(&self, backend: &mut [(W, u32)], element: impl Borrow<(T, u32)>) {
+        debug_assert_eq!(backend.len(), self.logic.num_registers());
+        let (element, timestamp) = element.borrow();
+        let (register, value) = self
+            .logic
+            .register_and_value(self.logic.build_hasher().hash_one(element));
+        let (current_value, current_timestamp) = backend[register];
+        if self.is_larger(
+            (value as f64, *timestamp),
+            (current_value.upcast() as f64, current_timestamp),
+        ) {
+            backend[register] = (W::cast_from(value), *timestamp);
+        }
+    }
+
+    /// Returns an estimation at the time of the most recent update of the
+    /// backend (see [`estimate_at`](DecayingHyperLogLog::estimate_at)).
+    fn estimate(&self, backend: &[(W, u32)]) -> f64 {
+        let now = backend
+            .iter()
+            .map(|&(_, timestamp)| timestamp)
+            .max()
+            .unwrap_or(0);
+        self.estimate_at(backend, now)
+    }
+
+    fn clear(&self, backend: &mut [(W, u32)]) {
+        backend.fill((W::ZERO, 0));
+    }
+
+    fn set(&self, dst: &mut [(W, u32)], src: &[(W, u32)]) {
+        debug_assert_eq!(dst.len(), src.len());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, W: Word + UpcastableInto<u64> + CastableFrom<u64>>
+    MergeEstimationLogic for DecayingHyperLogLog<T, H, W>
+{
+    type Helper = ();
+
+    fn new_helper(&self) -> Self::Helper {}
+
+    fn merge_with_helper(
+        &self,
+        dst: &mut [(W, u32)],
+        src: &[(W, u32)],
+        _helper: &mut Self::Helper,
+    ) {
+        debug_assert_eq!(dst.len(), src.len());
+        for (d, &(value, timestamp)) in dst.iter_mut().zip(src) {
+            if self.is_larger(
+                (value.upcast() as f64, timestamp),
+                (d.0.upcast() as f64, d.1),
+            ) {
+                *d = (value, timestamp);
+            }
+        }
+    }
+}
+
+/// Builds a [`DecayingHyperLogLog`] logic.
+#[derive(Debug, Clone)]
+pub struct DecayingHyperLogLogBuilder<T, H, W> {
+    logic: HyperLogLog<T, H, W>,
+    half_life: f64,
+}
+
+impl<T, H: Clone, W: Word> DecayingHyperLogLogBuilder<T, H, W> {
+    /// Creates a new builder wrapping the given logic, with a half-life of
+    /// one tick.
+    pub fn new(logic: HyperLogLog<T, H, W>) -> Self {
+        Self {
+            logic,
+            half_life: 1.0,
+        }
+    }
+
+    /// Sets the half-life of the contribution of an element, in ticks.
+    pub fn half_life(mut self, half_life: f64) -> Self {
+        self.half_life = half_life;
+        self
+    }
+
+    /// Builds the logic.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the half-life is not positive and
+    /// finite, or if the wrapped logic does not use the [harmonic
+    /// mean](EstimationMode::HarmonicMean) with the [standard bias
+    /// correction](BiasCorrection::Standard), as decayed estimates support
+    /// only this configuration.
+    pub fn build(self) -> Result<DecayingHyperLogLog<T, H, W>> {
+        ensure!(
+            self.half_life > 0.0 && self.half_life.is_finite(),
+            "the half-life must be positive and finite; got {}",
+            self.half_life
+        );
+        ensure!(
+            self.logic.estimation_mode() == EstimationMode::HarmonicMean,
+            "decayed estimates support only the harmonic mean; got {:?}",
+            self.logic.estimation_mode()
+        );
+        ensure!(
+            self.logic.bias_correction() == BiasCorrection::Standard,
+            "decayed estimates support only the standard bias correction; got {:?}",
+            self.logic.bias_correction()
+        );
+        Ok(DecayingHyperLogLog {
+            logic: self.logic,
+            half_life: self.half_life,
+            lambda: LN_2 / self.half_life,
+        })
+    }
+}
@@ -12,7 +12,9 @@ use std::borrow::Borrow;
 pub struct DefaultEstimator<L: EstimationLogic, BL: Borrow<L>, B> {
     logic: BL,
     backend: B,
-    _marker: std::marker::PhantomData<L>,
+    // An estimator does not own a logic (unless BL is L), so its
+    // Send and Sync implementations should depend only on BL and B.
+    _marker: std::marker::PhantomData<fn() -> L>,
 }
 
 impl<L: EstimationLogic, BL: Borrow<L>, B> DefaultEstimator<L, BL, B> {
@@ -30,6 +32,30 @@ impl<L: EstimationLogic, BL: Borrow<L>, B> DefaultEstimator<L, BL, B> {
     }
 }
 
+impl<L: EstimationLogic, BL: Borrow<L> + std::fmt::Debug, B: std::fmt::Debug> std::fmt::Debug
+    for DefaultEstimator<L, BL, B>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DefaultEstimator")
+            .field("logic", &self.logic)
+            .field("backend", &self.backend)
+            .finish()
+    }
+}
+
+/// Displays the current estimate, rounded to the nearest integer.
+impl<L: EstimationLogic, BL: Borrow<L>, B: AsRef<L::Backend>> std::fmt::Display
+    for DefaultEstimator<L, BL, B>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.logic.borrow().estimate(self.backend.as_ref()).round()
+        )
+    }
+}
+
 impl<L: EstimationLogic + Clone, BL: Borrow<L>, B: AsRef<L::Backend>> AsRef<L::Backend>
     for DefaultEstimator<L, BL, B>
 {
@@ -59,9 +85,17 @@ impl<L: EstimationLogic + Clone, BL: Borrow<L>, B: AsRef<L::Backend>> Estimator<
     fn estimate(&self) -> f64 {
         self.logic.borrow().estimate(self.backend.as_ref())
     }
-    #[inline(always)]
-    fn into_owned(self) -> Self::OwnedEstimator {
-        todo!()
+
+    /// Returns an estimator owning a clone of the logic and a copy of the
+    /// backend, and thus independent of the original backend.
+    fn into_owned(self) -> Self::OwnedEstimator
+    where
+        for<'a> Box<L::Backend>: From<&'a L::Backend>,
+    {
+        let logic = self.logic.borrow().clone();
+        let mut backend = Box::from(logic.new_estimator().as_ref());
+        logic.set(&mut backend, self.backend.as_ref());
+        DefaultEstimator::new(logic, backend)
     }
 }
 
@@ -73,6 +107,13 @@ impl<L: EstimationLogic + Clone, BL: Borrow<L>, B: AsRef<L::Backend> + AsMut<L::
         self.logic.borrow().add(self.backend.as_mut(), element)
     }
 
+    #[inline(always)]
+    fn add_many(&mut self, elements: &[L::Item]) {
+        self.logic
+            .borrow()
+            .add_many(self.backend.as_mut(), elements)
+    }
+
     #[inline(always)]
     fn clear(&mut self) {
         self.logic.borrow().clear(self.backend.as_mut())
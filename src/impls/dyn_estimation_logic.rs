@@ -0,0 +1,142 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::DefaultEstimator;
+use crate::traits::*;
+use std::any::Any;
+use std::borrow::Borrow;
+use std::sync::Arc;
+use sux::traits::Word;
+
+/// The type-erased [helper](MergeEstimationLogic::Helper) of a
+/// [`DynEstimationLogic`].
+pub type DynHelper = Box<dyn Any + Send>;
+
+/// A type-erased [estimation logic](EstimationLogic) with items of type `T`
+/// and slices of `W` as backends.
+///
+/// [`EstimationLogic`] is not dyn compatible, so it is not possible to use a
+/// `Box<dyn EstimationLogic>` to select at runtime the kind of estimator to
+/// use (e.g., in a plugin-based architecture). This structure wraps any
+/// [mergeable](MergeEstimationLogic) [slice-based](SliceEstimationLogic)
+/// logic, and implements the same traits by dispatching calls through
+/// function pointers, so that, for example, a
+/// `SliceEstimatorArray<DynEstimationLogic<T, W>, W, Box<[W]>>` can use
+/// any such logic chosen at runtime.
+///
+/// Cloning a dynamic logic is cheap, as the wrapped logic is reference
+/// counted.
+pub struct DynEstimationLogic<T, W> {
+    logic: Arc<dyn Any + Send + Sync>,
+    backend_len: usize,
+    add: fn(&dyn Any, &mut [W], &T),
+    estimate: fn(&dyn Any, &[W]) -> f64,
+    clear: fn(&dyn Any, &mut [W]),
+    set: fn(&dyn Any, &mut [W], &[W]),
+    new_helper: fn(&dyn Any) -> DynHelper,
+    merge_with_helper: fn(&dyn Any, &mut [W], &[W], &mut DynHelper),
+}
+
+/// Downcasts a type-erased logic.
+#[inline(always)]
+fn downcast<L: 'static>(logic: &dyn Any) -> &L {
+    logic
+        .downcast_ref::<L>()
+        .expect("a dynamic logic must be used with its own backends and helpers")
+}
+
+impl<T, W: Word> DynEstimationLogic<T, W> {
+    /// Creates a new dynamic logic wrapping the given logic.
+    pub fn new<L>(logic: L) -> Self
+    where
+        L: SliceEstimationLogic<W, Item = T> + MergeEstimationLogic + Send + Sync + 'static,
+        L::Helper: Send + 'static,
+    {
+        Self {
+            backend_len: logic.backend_len(),
+            logic: Arc::new(logic),
+            add: |logic, backend, element| downcast::<L>(logic).add(backend, element),
+            estimate: |logic, backend| downcast::<L>(logic).estimate(backend),
+            clear: |logic, backend| downcast::<L>(logic).clear(backend),
+            set: |logic, dst, src| downcast::<L>(logic).set(dst, src),
+            new_helper: |logic| Box::new(downcast::<L>(logic).new_helper()),
+            merge_with_helper: |logic, dst, src, helper| {
+                let helper = helper
+                    .downcast_mut::<L::Helper>()
+                    .expect("a dynamic logic must be used with its own backends and helpers");
+                downcast::<L>(logic).merge_with_helper(dst, src, helper)
+            },
+        }
+    }
+
+    /// Returns a reference to the wrapped logic, if it has type `L`.
+    pub fn downcast_ref<L: 'static>(&self) -> Option<&L> {
+        self.logic.downcast_ref::<L>()
+    }
+}
+
+impl<T, W> Clone for DynEstimationLogic<T, W> {
+    fn clone(&self) -> Self {
+        Self {
+            logic: self.logic.clone(),
+            backend_len: self.backend_len,
+            add: self.add,
+            estimate: self.estimate,
+            clear: self.clear,
+            set: self.set,
+            new_helper: self.new_helper,
+            merge_with_helper: self.merge_with_helper,
+        }
+    }
+}
+
+impl<T, W: Word> EstimationLogic for DynEstimationLogic<T, W> {
+    type Item = T;
+    type Backend = [W];
+    type Estimator<'a>
+        = DefaultEstimator<Self, &'a Self, Box<[W]>>
+    where
+        T: 'a,
+        W: 'a;
+
+    fn add(&self, backend: &mut [W], element: impl Borrow<T>) {
+        (self.add)(self.logic.as_ref(), backend, element.borrow())
+    }
+
+    fn estimate(&self, backend: &[W]) -> f64 {
+        (self.estimate)(self.logic.as_ref(), backend)
+    }
+
+    fn clear(&self, backend: &mut [W]) {
+        (self.clear)(self.logic.as_ref(), backend)
+    }
+
+    fn set(&self, dst: &mut [W], src: &[W]) {
+        (self.set)(self.logic.as_ref(), dst, src)
+    }
+
+    fn new_estimator(&self) -> Self::Estimator<'_> {
+        DefaultEstimator::new(self, vec![W::ZERO; self.backend_len].into_boxed_slice())
+    }
+}
+
+impl<T, W: Word> SliceEstimationLogic<W> for DynEstimationLogic<T, W> {
+    fn backend_len(&self) -> usize {
+        self.backend_len
+    }
+}
+
+impl<T, W: Word> MergeEstimationLogic for DynEstimationLogic<T, W> {
+    type Helper = DynHelper;
+
+    fn new_helper(&self) -> Self::Helper {
+        (self.new_helper)(self.logic.as_ref())
+    }
+
+    fn merge_with_helper(&self, dst: &mut [W], src: &[W], helper: &mut Self::Helper) {
+        (self.merge_with_helper)(self.logic.as_ref(), dst, src, helper)
+    }
+}
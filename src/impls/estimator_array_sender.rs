@@ -0,0 +1,133 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::SliceEstimatorArray;
+use crate::traits::*;
+use std::sync::mpsc::{channel, SendError, Sender};
+use sux::traits::Word;
+
+/// A message to the background thread of an [`EstimatorArraySender`].
+enum Message<L: SliceEstimationLogic<W>, W> {
+    /// Adds an element to the estimator with the given index.
+    Add(usize, L::Item),
+    /// Sends back a copy of the array.
+    Flush(Sender<SliceEstimatorArray<L, W, Box<[W]>>>),
+}
+
+/// A handle to send updates to an array of estimators owned by a background
+/// thread.
+///
+/// This structure provides a safe alternative to
+/// [synchronized arrays](SyncEstimatorArray) for multithreaded updates:
+/// senders can be cloned and moved to other threads, and each
+/// [`send`](EstimatorArraySender::send) enqueues an `(index, element)` pair
+/// in a channel, which is drained by a background thread applying updates
+/// sequentially to the array. [`flush`](EstimatorArraySender::flush) waits
+/// for the updates sent so far and returns a copy of the array.
+///
+/// The background thread terminates when all senders have been dropped.
+pub struct EstimatorArraySender<L: SliceEstimationLogic<W>, W> {
+    sender: Sender<Message<L, W>>,
+    len: usize,
+}
+
+// We implement Clone manually because we do not want to require that L and W
+// are Clone.
+impl<L: SliceEstimationLogic<W>, W> Clone for EstimatorArraySender<L, W> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<L, W> EstimatorArraySender<L, W>
+where
+    L: SliceEstimationLogic<W> + Clone + Send + 'static,
+    L::Item: Send + 'static,
+    W: Word + Send + 'static,
+{
+    /// Creates a new sender, moving the given array to a new background
+    /// thread.
+    pub fn new(mut array: SliceEstimatorArray<L, W, Box<[W]>>) -> Self {
+        let (sender, receiver) = channel::<Message<L, W>>();
+        let len = array.len();
+        std::thread::spawn(move || {
+            for message in receiver {
+                match message {
+                    Message::Add(index, element) => {
+                        array.get_estimator_mut(index).add(element);
+                    }
+                    Message::Flush(reply) => {
+                        let mut copy = SliceEstimatorArray::new(array.logic().clone(), len);
+                        copy.as_mut().copy_from_slice(array.as_ref());
+                        // The flushing thread might have been interrupted
+                        let _ = reply.send(copy);
+                    }
+                }
+            }
+        });
+        Self { sender, len }
+    }
+
+    /// Sends an element to be added to the estimator with the given index.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error containing the index and the element
+    /// if the background thread has terminated (which can only happen if the
+    /// logic panicked).
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `index` is out of bounds.
+    pub fn send(&self, index: usize, element: L::Item) -> Result<(), SendError<(usize, L::Item)>> {
+        assert!(
+            index < self.len,
+            "index {} out of bounds ({} estimators)",
+            index,
+            self.len
+        );
+        self.sender
+            .send(Message::Add(index, element))
+            .map_err(|SendError(message)| match message {
+                Message::Add(index, element) => SendError((index, element)),
+                Message::Flush(_) => unreachable!(),
+            })
+    }
+
+    /// Waits for all updates sent so far to be applied, and returns a copy of
+    /// the array.
+    ///
+    /// Updates sent by other threads are included if the corresponding calls
+    /// to [`send`](EstimatorArraySender::send) happen before this call (e.g.,
+    /// if the threads have been joined).
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the background thread has terminated (which
+    /// can only happen if the logic panicked).
+    pub fn flush(&self) -> SliceEstimatorArray<L, W, Box<[W]>> {
+        let (reply, receiver) = channel();
+        self.sender
+            .send(Message::Flush(reply))
+            .expect("the background thread has terminated");
+        receiver
+            .recv()
+            .expect("the background thread has terminated")
+    }
+
+    /// Returns the number of estimators in the array.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the array has no estimators.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
@@ -0,0 +1,83 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::{slice_estimator_array::backend_pair_mut, SliceEstimatorArray};
+use crate::traits::*;
+use std::borrow::Borrow;
+use sux::traits::Word;
+
+/// A tracker of the number of distinct sources of the flows through the nodes
+/// of a network.
+///
+/// Each node of the network has an estimator keeping track of the sources
+/// (e.g., addresses) of the traffic flowing through it. Sources are
+/// [injected](FlowCardinalityTracker::inject_source) at the nodes where the
+/// traffic originates, and then [propagated](FlowCardinalityTracker::propagate_flow)
+/// along the arcs of the network: after propagating along all arcs in
+/// topological order, the [estimate at each node](FlowCardinalityTracker::cardinality_at)
+/// is the number of distinct sources of the traffic through the node.
+pub struct FlowCardinalityTracker<L, W> {
+    array: SliceEstimatorArray<L, W, Box<[W]>>,
+}
+
+impl<L: SliceEstimationLogic<W> + MergeEstimationLogic + Clone, W: Word>
+    FlowCardinalityTracker<L, W>
+{
+    /// Creates a new tracker for a network with the given number of nodes.
+    ///
+    /// # Arguments
+    /// * `logic`: the estimator logic to use.
+    /// * `num_nodes`: the number of nodes of the network.
+    pub fn new(logic: L, num_nodes: usize) -> Self {
+        Self {
+            array: SliceEstimatorArray::new(logic, num_nodes),
+        }
+    }
+
+    /// Adds a source to the estimator of a node.
+    pub fn inject_source(&mut self, node: usize, element: impl Borrow<L::Item>) {
+        self.array.get_estimator_mut(node).add(element);
+    }
+
+    /// Propagates the sources of a node to another node, that is, merges the
+    /// estimator of `source` into the estimator of `dest`.
+    ///
+    /// If `source` and `dest` are equal, this method does nothing.
+    pub fn propagate_flow(&mut self, source: usize, dest: usize) {
+        if source == dest {
+            return;
+        }
+        let logic = &self.array.logic;
+        let (dst, src) = backend_pair_mut(
+            self.array.backend.as_mut(),
+            logic.backend_len(),
+            dest,
+            source,
+        );
+        logic.merge(dst, src);
+    }
+
+    /// Returns an estimation of the number of distinct sources of the traffic
+    /// through a node.
+    pub fn cardinality_at(&self, node: usize) -> f64 {
+        self.array.get_estimator(node).estimate()
+    }
+
+    /// Returns the number of nodes.
+    pub fn num_nodes(&self) -> usize {
+        self.array.len()
+    }
+
+    /// Returns the array of estimators of the nodes.
+    pub fn array(&self) -> &SliceEstimatorArray<L, W, Box<[W]>> {
+        &self.array
+    }
+
+    /// Returns the array of estimators of the nodes, consuming the tracker.
+    pub fn into_array(self) -> SliceEstimatorArray<L, W, Box<[W]>> {
+        self.array
+    }
+}
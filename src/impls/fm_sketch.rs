@@ -0,0 +1,157 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use std::borrow::Borrow;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::*;
+
+use super::DefaultEstimator;
+use crate::traits::{EstimationLogic, MergeEstimationLogic, SliceEstimationLogic};
+
+/// The bias-correction constant φ of the Flajolet–Martin algorithm.
+pub const FM_PHI: f64 = 0.77351;
+
+/// Estimator logic implementing the original probabilistic counting algorithm
+/// by Flajolet and Martin.
+///
+/// A backend is a single 64-bit word, the *bitmap*. Adding an element sets the
+/// bit of the bitmap at position ρ(*x*), the number of trailing zeroes of the
+/// hash *x* of the element. If *R* is the position of the lowest zero bit of
+/// the bitmap, the estimate is 2<sup>*R*</sup> / φ, where φ ≈ 0.77351 is
+/// [`FM_PHI`]. Merging is a bitwise OR of bitmaps.
+///
+/// The standard deviation of *R* is about 1.12, so single estimates can be
+/// off by a factor of two or more: this logic is mainly useful as a baseline
+/// for comparisons with more accurate logics, such as
+/// [`HyperLogLog`](super::HyperLogLog).
+///
+/// Instances are created using an [`FMSketchBuilder`].
+#[derive(Debug, PartialEq)]
+pub struct FMSketch<T, H> {
+    build_hasher: H,
+    _marker: std::marker::PhantomData<T>,
+}
+
+// We implement Clone manually because we do not want to require that T is
+// Clone.
+impl<T, H: Clone> Clone for FMSketch<T, H> {
+    fn clone(&self) -> Self {
+        Self {
+            build_hasher: self.build_hasher.clone(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, H> FMSketch<T, H> {
+    /// Returns the position of the lowest zero bit of the bitmap of a backend.
+    pub fn lowest_zero_bit(&self, backend: &[u64]) -> u32 {
+        debug_assert_eq!(backend.len(), 1);
+        backend[0].trailing_ones()
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> SliceEstimationLogic<u64> for FMSketch<T, H> {
+    fn backend_len(&self) -> usize {
+        1
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> EstimationLogic for FMSketch<T, H> {
+    type Item = T;
+    type Backend = [u64];
+    type Estimator<'a>
+        = DefaultEstimator<Self, &'a Self, Box<[u64]>>
+    where
+        T: 'a,
+        H: 'a;
+
+    fn new_estimator(&self) -> Self::Estimator<'_> {
+        DefaultEstimator::new(self, vec![0; 1].into_boxed_slice())
+    }
+
+    fn add(&self, backend: &mut [u64], element: impl Borrow<T>) {
+        debug_assert_eq!(backend.len(), 1);
+        let hash = self.build_hasher.hash_one(element.borrow());
+        // Bit 63 is set when the hash is zero
+        backend[0] |= 1 << hash.trailing_zeros().min(63);
+    }
+
+    fn estimate(&self, backend: &[u64]) -> f64 {
+        if backend[0] == 0 {
+            return 0.0;
+        }
+        (self.lowest_zero_bit(backend) as f64).exp2() / FM_PHI
+    }
+
+    fn clear(&self, backend: &mut [u64]) {
+        backend.fill(0);
+    }
+
+    fn set(&self, dst: &mut [u64], src: &[u64]) {
+        debug_assert_eq!(dst.len(), src.len());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> MergeEstimationLogic for FMSketch<T, H> {
+    type Helper = ();
+
+    fn new_helper(&self) -> Self::Helper {}
+
+    fn merge_with_helper(&self, dst: &mut [u64], src: &[u64], _helper: &mut Self::Helper) {
+        debug_assert_eq!(dst.len(), src.len());
+        dst[0] |= src[0];
+    }
+}
+
+/// Builds an [`FMSketch`] logic.
+#[derive(Debug, Clone)]
+pub struct FMSketchBuilder<H> {
+    build_hasher: H,
+}
+
+impl FMSketchBuilder<BuildHasherDefault<DefaultHasher>> {
+    /// Creates a new builder for an [`FMSketch`] logic.
+    pub fn new() -> Self {
+        Self {
+            build_hasher: BuildHasherDefault::default(),
+        }
+    }
+}
+
+impl Default for FMSketchBuilder<BuildHasherDefault<DefaultHasher>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H> FMSketchBuilder<H> {
+    /// Sets the [`BuildHasher`] to use.
+    ///
+    /// Using this method you can select a specific hashed based on one or more
+    /// seeds.
+    pub fn build_hasher<H2>(self, build_hasher: H2) -> FMSketchBuilder<H2> {
+        FMSketchBuilder { build_hasher }
+    }
+
+    /// Builds the logic.
+    ///
+    /// The type of objects the estimators keep track of is defined here by `T`,
+    /// but it is usually inferred by the compiler.
+    ///
+    /// # Errors
+    ///
+    /// This method currently never fails; it returns a [`Result`] for
+    /// consistency with the other builders.
+    pub fn build<T>(self) -> Result<FMSketch<T, H>> {
+        Ok(FMSketch {
+            build_hasher: self.build_hasher,
+            _marker: std::marker::PhantomData,
+        })
+    }
+}
@@ -0,0 +1,356 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use std::fmt::{Display, Formatter};
+
+/// The aggregator type of HyperLogLog++ sketches.
+const HYPERLOGLOG_PLUS_UNIQUE: u64 = 112;
+/// The encoding version of HyperLogLog++ sketches.
+const ENCODING_VERSION: u64 = 2;
+
+// Field numbers of the aggregator state
+const TYPE_FIELD: u64 = 1;
+const ENCODING_VERSION_FIELD: u64 = 3;
+const HLL_STATE_FIELD: u64 = 112;
+
+// Field numbers of the HyperLogLog++ state
+const PRECISION_FIELD: u64 = 3;
+const SPARSE_PRECISION_FIELD: u64 = 4;
+const DATA_FIELD: u64 = 5;
+const SPARSE_DATA_FIELD: u64 = 6;
+
+/// The maximum sparse precision.
+const MAX_SPARSE_PRECISION: u64 = 30;
+/// The number of bits used to store ρ in rho-encoded sparse values.
+const RHO_BITS: usize = 6;
+
+// Protocol Buffers wire types
+const VARINT: u64 = 0;
+const FIXED_64: u64 = 1;
+const LENGTH_DELIMITED: u64 = 2;
+const FIXED_32: u64 = 5;
+
+/// An error in the parsing of a sketch in HyperLogLog++ format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoogleHllError {
+    /// The input ended in the middle of a field.
+    Truncated,
+    /// A varint is longer than ten bytes.
+    InvalidVarint,
+    /// A field has an unsupported wire type.
+    InvalidWireType(u64),
+    /// The aggregator type is not HyperLogLog++.
+    InvalidType(u64),
+    /// The encoding version is not supported.
+    UnsupportedVersion(u64),
+    /// The HyperLogLog++ state is missing.
+    MissingState,
+    /// The precision is missing or out of range.
+    InvalidPrecision(u64),
+    /// The dense data has not one byte per register.
+    InvalidDataLength(usize),
+    /// A register has a value that cannot be produced by the hashing
+    /// process.
+    InvalidRegisterValue {
+        /// The index of the register.
+        register: usize,
+        /// The value of the register.
+        value: u64,
+    },
+    /// The sparse precision is missing or out of range.
+    InvalidSparsePrecision(u64),
+    /// The sparse data contains a value that does not decode to a register
+    /// and a ρ.
+    InvalidSparseValue(u64),
+}
+
+impl Display for GoogleHllError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Truncated => write!(f, "truncated sketch"),
+            Self::InvalidVarint => write!(f, "invalid varint"),
+            Self::InvalidWireType(wire_type) => write!(f, "invalid wire type {}", wire_type),
+            Self::InvalidType(t) => write!(f, "aggregator type {} is not HyperLogLog++", t),
+            Self::UnsupportedVersion(v) => write!(f, "unsupported encoding version {}", v),
+            Self::MissingState => write!(f, "missing HyperLogLog++ state"),
+            Self::InvalidPrecision(p) => write!(f, "invalid precision {}", p),
+            Self::InvalidDataLength(len) => {
+                write!(
+                    f,
+                    "dense data length {} is not the number of registers",
+                    len
+                )
+            }
+            Self::InvalidRegisterValue { register, value } => {
+                write!(f, "register {} has invalid value {}", register, value)
+            }
+            Self::InvalidSparsePrecision(p) => write!(f, "invalid sparse precision {}", p),
+            Self::InvalidSparseValue(v) => write!(f, "invalid sparse value {}", v),
+        }
+    }
+}
+
+impl std::error::Error for GoogleHllError {}
+
+/// Reads a varint, advancing the input.
+fn read_varint(input: &mut &[u8]) -> Result<u64, GoogleHllError> {
+    let mut value = 0;
+    for shift in (0..70).step_by(7) {
+        let (&byte, rest) = input.split_first().ok_or(GoogleHllError::Truncated)?;
+        *input = rest;
+        if shift == 63 && byte > 1 {
+            return Err(GoogleHllError::InvalidVarint);
+        }
+        value |= ((byte & 0x7F) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(GoogleHllError::InvalidVarint)
+}
+
+/// Writes a varint.
+fn write_varint(output: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        output.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    output.push(value as u8);
+}
+
+/// Reads the bytes of a length-delimited field, advancing the input.
+fn read_bytes<'a>(input: &mut &'a [u8]) -> Result<&'a [u8], GoogleHllError> {
+    let len = read_varint(input)? as usize;
+    if len > input.len() {
+        return Err(GoogleHllError::Truncated);
+    }
+    let (bytes, rest) = input.split_at(len);
+    *input = rest;
+    Ok(bytes)
+}
+
+/// A field of a message; length-delimited fields are returned as slices.
+enum Field<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+    Other,
+}
+
+/// Reads a field, advancing the input, and returns its number and content.
+fn read_field<'a>(input: &mut &'a [u8]) -> Result<(u64, Field<'a>), GoogleHllError> {
+    let key = read_varint(input)?;
+    let field = match key & 7 {
+        VARINT => Field::Varint(read_varint(input)?),
+        LENGTH_DELIMITED => Field::Bytes(read_bytes(input)?),
+        wire_type @ (FIXED_64 | FIXED_32) => {
+            let len = if wire_type == FIXED_64 { 8 } else { 4 };
+            if len > input.len() {
+                return Err(GoogleHllError::Truncated);
+            }
+            *input = &input[len..];
+            Field::Other
+        }
+        wire_type => return Err(GoogleHllError::InvalidWireType(wire_type)),
+    };
+    Ok((key >> 3, field))
+}
+
+/// Decodes the difference-encoded sparse values, and stores in the dense
+/// registers the maximum between their current value and the ρ of each
+/// sparse value.
+///
+/// A sparse value is usually a sparse index, that is, the first
+/// `sparse_precision` bits of a hash, from which the register and ρ are
+/// computed. If the bits of the sparse index after the first `precision`
+/// bits are all zero, the value is instead a flag followed by the register
+/// and by the ρ of the bits of the hash after the sparse index; the ρ of the
+/// register is then obtained by adding `sparse_precision` − `precision`.
+fn decode_sparse(
+    mut input: &[u8],
+    precision: usize,
+    sparse_precision: usize,
+    registers: &mut [u8],
+) -> Result<(), GoogleHllError> {
+    let extra_bits = sparse_precision - precision;
+    let flag = 1 << sparse_precision.max(precision + RHO_BITS);
+    let mut value = 0_u64;
+    while !input.is_empty() {
+        value = value
+            .checked_add(read_varint(&mut input)?)
+            .ok_or(GoogleHllError::InvalidSparseValue(u64::MAX))?;
+        let (register, rho) = if value & flag != 0 {
+            let rest = value ^ flag;
+            (
+                rest >> RHO_BITS,
+                (rest & ((1 << RHO_BITS) - 1)) + extra_bits as u64,
+            )
+        } else {
+            // The bits after the register cannot be all zeroes
+            let suffix = value & ((1 << extra_bits) - 1);
+            if suffix == 0 || value >> sparse_precision != 0 {
+                return Err(GoogleHllError::InvalidSparseValue(value));
+            }
+            (
+                value >> extra_bits,
+                (suffix << (64 - extra_bits)).leading_zeros() as u64 + 1,
+            )
+        };
+        let register_value = registers
+            .get_mut(register as usize)
+            .ok_or(GoogleHllError::InvalidSparseValue(value))?;
+        *register_value = (*register_value).max(rho.min(u8::MAX as u64) as u8);
+    }
+    Ok(())
+}
+
+/// Writes the key of a field.
+fn write_key(output: &mut Vec<u8>, field: u64, wire_type: u64) {
+    write_varint(output, (field << 3) | wire_type);
+}
+
+/// Writes a length-delimited field.
+fn write_bytes_field(output: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+    write_key(output, field, LENGTH_DELIMITED);
+    write_varint(output, bytes.len() as u64);
+    output.extend_from_slice(bytes);
+}
+
+/// Serialization and deserialization of dense HyperLogLog backends in the
+/// wire format of Google's HyperLogLog++ implementation (used, for example,
+/// by BigQuery's `HLL_COUNT` functions).
+///
+/// A sketch is a Protocol Buffers `AggregatorStateProto` message of type
+/// `HYPERLOGLOG_PLUS_UNIQUE` containing a `HyperLogLogPlusUniqueStateProto`
+/// extension, which in turn contains the precision (i.e., the logarithm of
+/// the number of registers) and the registers.
+///
+/// Registers can be stored either in dense form, as one byte per register,
+/// or, for small cardinalities, in sparse form, as a difference-encoded list
+/// of hashes at a higher sparse precision.
+/// [`deserialize`](GoogleHllFormat::deserialize) accepts both forms, and
+/// converts sparse data to dense registers at the precision of the sketch;
+/// [`serialize`](GoogleHllFormat::serialize) always writes dense data.
+///
+/// Backends are slices of bytes containing one register per byte, as in
+/// [`SparkCompatHyperLogLog`](super::SparkCompatHyperLogLog), which uses the
+/// same indexing of registers.
+pub struct GoogleHllFormat;
+
+impl GoogleHllFormat {
+    /// The minimum supported precision.
+    pub const MIN_PRECISION: usize = 4;
+    /// The maximum supported precision.
+    pub const MAX_PRECISION: usize = 24;
+
+    /// Parses a sketch, returning its precision and its dense registers.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the sketch is malformed, if it is
+    /// not a HyperLogLog++ sketch, if its sparse data cannot be decoded, or
+    /// if some register has an impossible value.
+    pub fn deserialize(bytes: &[u8]) -> Result<(usize, Box<[u8]>), GoogleHllError> {
+        let mut input = bytes;
+        let mut state = None;
+        while !input.is_empty() {
+            match read_field(&mut input)? {
+                (TYPE_FIELD, Field::Varint(t)) if t != HYPERLOGLOG_PLUS_UNIQUE => {
+                    return Err(GoogleHllError::InvalidType(t))
+                }
+                (ENCODING_VERSION_FIELD, Field::Varint(v)) if v != ENCODING_VERSION => {
+                    return Err(GoogleHllError::UnsupportedVersion(v))
+                }
+                (HLL_STATE_FIELD, Field::Bytes(bytes)) => state = Some(bytes),
+                _ => {}
+            }
+        }
+
+        let mut input = state.ok_or(GoogleHllError::MissingState)?;
+        let (mut precision, mut sparse_precision, mut data, mut sparse_data) =
+            (None, None, None, None);
+        while !input.is_empty() {
+            match read_field(&mut input)? {
+                (PRECISION_FIELD, Field::Varint(p)) => precision = Some(p),
+                (SPARSE_PRECISION_FIELD, Field::Varint(p)) => sparse_precision = Some(p),
+                (DATA_FIELD, Field::Bytes(bytes)) => data = Some(bytes),
+                (SPARSE_DATA_FIELD, Field::Bytes(bytes)) => sparse_data = Some(bytes),
+                _ => {}
+            }
+        }
+
+        let precision = precision.ok_or(GoogleHllError::InvalidPrecision(0))?;
+        if !(Self::MIN_PRECISION as u64..=Self::MAX_PRECISION as u64).contains(&precision) {
+            return Err(GoogleHllError::InvalidPrecision(precision));
+        }
+        let precision = precision as usize;
+        let num_registers = 1 << precision;
+        let max_value = (64 - precision + 1) as u64;
+
+        let mut registers = vec![0_u8; num_registers].into_boxed_slice();
+        if let Some(data) = data {
+            if data.len() != num_registers {
+                return Err(GoogleHllError::InvalidDataLength(data.len()));
+            }
+            registers.copy_from_slice(data);
+        }
+        // An empty sketch might have empty sparse data
+        if let Some(sparse_data) = sparse_data.filter(|bytes| !bytes.is_empty()) {
+            let sparse_precision = sparse_precision.unwrap_or(0);
+            if !(precision as u64..=MAX_SPARSE_PRECISION).contains(&sparse_precision) {
+                return Err(GoogleHllError::InvalidSparsePrecision(sparse_precision));
+            }
+            decode_sparse(
+                sparse_data,
+                precision,
+                sparse_precision as usize,
+                &mut registers,
+            )?;
+        }
+        if let Some(register) = registers.iter().position(|&v| v as u64 > max_value) {
+            return Err(GoogleHllError::InvalidRegisterValue {
+                register,
+                value: registers[register] as u64,
+            });
+        }
+        Ok((precision, registers))
+    }
+
+    /// Serializes dense registers as a sketch with the given precision.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the precision is not supported or if the
+    /// length of `backend` is not 2<sup>`log2m`</sup>.
+    pub fn serialize(log2m: usize, backend: &[u8]) -> Vec<u8> {
+        assert!(
+            (Self::MIN_PRECISION..=Self::MAX_PRECISION).contains(&log2m),
+            "the precision must be between {} and {}, but it is {}",
+            Self::MIN_PRECISION,
+            Self::MAX_PRECISION,
+            log2m
+        );
+        assert_eq!(
+            backend.len(),
+            1 << log2m,
+            "the backend has length {}, but the precision is {}",
+            backend.len(),
+            log2m
+        );
+
+        let mut state = vec![];
+        write_key(&mut state, PRECISION_FIELD, VARINT);
+        write_varint(&mut state, log2m as u64);
+        write_bytes_field(&mut state, DATA_FIELD, backend);
+
+        let mut output = vec![];
+        write_key(&mut output, TYPE_FIELD, VARINT);
+        write_varint(&mut output, HYPERLOGLOG_PLUS_UNIQUE);
+        write_key(&mut output, ENCODING_VERSION_FIELD, VARINT);
+        write_varint(&mut output, ENCODING_VERSION);
+        write_bytes_field(&mut output, HLL_STATE_FIELD, &state);
+        output
+    }
+}
@@ -0,0 +1,134 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::{slice_estimator_array::backend_pair_mut, SliceEstimatorArray};
+use crate::traits::*;
+use std::borrow::Borrow;
+use sux::traits::Word;
+
+/// An array of estimators associated with the nodes of a graph, whose
+/// estimators can be propagated along the arcs.
+///
+/// A [propagation step](GraphEstimatorArray::propagate_step) merges into the
+/// estimator of each node the estimators of its successors. If each node
+/// initially contains itself, after *t* steps the estimator of each node
+/// contains (at least) the nodes at distance at most *t*, and at
+/// [convergence](GraphEstimatorArray::propagate_until_convergence) the nodes
+/// reachable from the node, as in the computation of the neighborhood
+/// function of a graph.
+///
+/// Nodes are processed in order, and merges happen in place, so estimators
+/// updated during a step are propagated immediately to the following nodes.
+pub struct GraphEstimatorArray<L, W> {
+    array: SliceEstimatorArray<L, W, Box<[W]>>,
+    successors: Vec<Vec<usize>>,
+}
+
+impl<L: SliceEstimationLogic<W> + MergeEstimationLogic + Clone, W: Word> GraphEstimatorArray<L, W> {
+    /// Creates a new array with an empty estimator for each node of a graph.
+    ///
+    /// # Arguments
+    /// * `logic`: the estimator logic to use.
+    /// * `successors`: the adjacency lists of the graph, that is, the list of
+    ///   successors of each node.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if a successor is out of bounds.
+    pub fn new(logic: L, successors: Vec<Vec<usize>>) -> Self {
+        let num_nodes = successors.len();
+        for (node, succ) in successors.iter().enumerate() {
+            for &s in succ {
+                assert!(
+                    s < num_nodes,
+                    "successor {} of node {} is out of bounds ({} nodes)",
+                    s,
+                    node,
+                    num_nodes
+                );
+            }
+        }
+        Self {
+            array: SliceEstimatorArray::new(logic, num_nodes),
+            successors,
+        }
+    }
+
+    /// Adds an element to the estimator of a node.
+    pub fn add(&mut self, node: usize, element: impl Borrow<L::Item>) {
+        self.array.get_estimator_mut(node).add(element);
+    }
+
+    /// Merges into the estimator of each node the estimators of its
+    /// successors.
+    ///
+    /// Returns the number of estimators that changed.
+    pub fn propagate_step(&mut self) -> usize {
+        let logic = &self.array.logic;
+        let backend_len = logic.backend_len();
+        let backend = self.array.backend.as_mut();
+        let mut helper = logic.new_helper();
+        let mut old = vec![W::ZERO; backend_len];
+        let mut changed = 0;
+        for (node, succ) in self.successors.iter().enumerate() {
+            old.copy_from_slice(&backend[node * backend_len..][..backend_len]);
+            for &s in succ {
+                if s == node {
+                    continue;
+                }
+                let (dst, src) = backend_pair_mut(backend, backend_len, node, s);
+                logic.merge_with_helper(dst, src, &mut helper);
+            }
+            if old[..] != backend[node * backend_len..][..backend_len] {
+                changed += 1;
+            }
+        }
+        changed
+    }
+
+    /// Performs [propagation steps](GraphEstimatorArray::propagate_step)
+    /// until no estimator changes, or `max_iter` steps have been performed.
+    ///
+    /// Returns the number of steps performed, including the last one, which
+    /// does not change any estimator if the propagation has converged.
+    pub fn propagate_until_convergence(&mut self, max_iter: usize) -> usize {
+        for iter in 0..max_iter {
+            if self.propagate_step() == 0 {
+                return iter + 1;
+            }
+        }
+        max_iter
+    }
+
+    /// Returns an estimation of the number of distinct elements in the
+    /// estimator of a node.
+    pub fn estimate(&self, node: usize) -> f64 {
+        self.array.get_estimator(node).estimate()
+    }
+}
+
+impl<L, W> GraphEstimatorArray<L, W> {
+    /// Returns the number of nodes.
+    pub fn num_nodes(&self) -> usize {
+        self.successors.len()
+    }
+
+    /// Returns the successors of a node.
+    pub fn successors(&self, node: usize) -> &[usize] {
+        &self.successors[node]
+    }
+
+    /// Returns the array of estimators of the nodes.
+    pub fn array(&self) -> &SliceEstimatorArray<L, W, Box<[W]>> {
+        &self.array
+    }
+
+    /// Returns the array of estimators of the nodes, consuming this
+    /// structure.
+    pub fn into_array(self) -> SliceEstimatorArray<L, W, Box<[W]>> {
+        self.array
+    }
+}
@@ -0,0 +1,97 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::SliceEstimatorArray;
+use crate::traits::*;
+use sux::traits::Word;
+
+/// A two-level array of estimators, that is, an array of
+/// [`SliceEstimatorArray`]s sharing the same logic.
+///
+/// Each inner array corresponds to an estimator of a parent array (e.g.,
+/// each inner array contains the estimators of the cities of a country, and
+/// the parent array contains the estimators of the countries).
+/// [`merge_up_level`](HierarchicalEstimatorArray::merge_up_level) merges each
+/// inner array into the corresponding estimator of the parent array.
+pub struct HierarchicalEstimatorArray<L, W> {
+    arrays: Vec<SliceEstimatorArray<L, W, Box<[W]>>>,
+}
+
+impl<L: SliceEstimationLogic<W> + Clone, W: Word> HierarchicalEstimatorArray<L, W> {
+    /// Creates a new hierarchical array containing `num_arrays` inner arrays
+    /// of `len` estimators.
+    ///
+    /// # Arguments
+    /// * `logic`: the estimator logic to use.
+    /// * `num_arrays`: the number of inner arrays.
+    /// * `len`: the number of estimators in each inner array.
+    pub fn new(logic: L, num_arrays: usize, len: usize) -> Self {
+        Self {
+            arrays: (0..num_arrays)
+                .map(|_| SliceEstimatorArray::new(logic.clone(), len))
+                .collect(),
+        }
+    }
+}
+
+impl<L, W> HierarchicalEstimatorArray<L, W> {
+    /// Creates a new hierarchical array using the given inner arrays.
+    pub fn from_arrays(arrays: Vec<SliceEstimatorArray<L, W, Box<[W]>>>) -> Self {
+        Self { arrays }
+    }
+
+    /// Appends an inner array.
+    pub fn push(&mut self, array: SliceEstimatorArray<L, W, Box<[W]>>) {
+        self.arrays.push(array);
+    }
+
+    /// Returns the number of inner arrays.
+    pub fn len(&self) -> usize {
+        self.arrays.len()
+    }
+
+    /// Returns `true` if there are no inner arrays.
+    pub fn is_empty(&self) -> bool {
+        self.arrays.is_empty()
+    }
+
+    /// Returns a reference to the inner array at the specified index.
+    pub fn get_array(&self, index: usize) -> &SliceEstimatorArray<L, W, Box<[W]>> {
+        &self.arrays[index]
+    }
+
+    /// Returns a mutable reference to the inner array at the specified index.
+    pub fn get_array_mut(&mut self, index: usize) -> &mut SliceEstimatorArray<L, W, Box<[W]>> {
+        &mut self.arrays[index]
+    }
+}
+
+impl<L: SliceEstimationLogic<W> + MergeEstimationLogic + Clone, W: Word>
+    HierarchicalEstimatorArray<L, W>
+{
+    /// Merges all estimators of each inner array into the estimator with the
+    /// same index of `parent`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the length of `parent` is not equal to
+    /// the number of inner arrays.
+    pub fn merge_up_level<S: AsRef<[W]> + AsMut<[W]>>(
+        &self,
+        parent: &mut SliceEstimatorArray<L, W, S>,
+    ) {
+        assert_eq!(
+            parent.len(),
+            self.arrays.len(),
+            "the parent array has {} estimators, but there are {} inner arrays",
+            parent.len(),
+            self.arrays.len()
+        );
+        for (i, array) in self.arrays.iter().enumerate() {
+            array.merge_all(parent.get_backend_mut(i));
+        }
+    }
+}
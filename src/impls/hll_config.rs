@@ -0,0 +1,199 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use common_traits::{CastableFrom, UpcastableInto};
+use std::borrow::Borrow;
+use std::hash::*;
+use sux::traits::Word;
+
+use super::{alpha_m, DefaultEstimator, HyperLogLog};
+use crate::traits::{
+    EstimationGuarantees, EstimationLogic, MergeEstimationLogic, SliceEstimationLogic,
+};
+
+/// Estimator logic implementing the HyperLogLog algorithm with a number of
+/// registers fixed at compile time.
+///
+/// Differently from [`HyperLogLog`](super::HyperLogLog), which computes its
+/// parameters at runtime, this logic has 2<sup>`LOG2M`</sup> registers, each
+/// stored in a separate word of type `W`, so that the [backend
+/// length](SliceEstimationLogic::backend_len) is the compile-time constant
+/// [`BACKEND_LEN`](HllConfig::BACKEND_LEN), and [`add`](EstimationLogic::add)
+/// and [`estimate`](EstimationLogic::estimate) use no runtime parameter
+/// besides the hasher. Since there is no register packing, any unsigned type
+/// (usually `u8`) is large enough for `W`.
+///
+/// Instances are created directly with [`new`](HllConfig::new). Since the
+/// backend length is known at compile time, estimators can use arrays as
+/// backends, avoiding heap allocation:
+///
+/// ```
+/// # use card_est_array::impls::{DefaultEstimator, HllConfig};
+/// # use card_est_array::traits::{Estimator, EstimatorMut};
+/// # use std::hash::BuildHasherDefault;
+/// # use std::collections::hash_map::DefaultHasher;
+/// let logic = HllConfig::<u64, _, 8, u8>::new(BuildHasherDefault::<DefaultHasher>::default());
+/// let mut estimator: DefaultEstimator<HllConfig<u64, _, 8, u8>, _, _> =
+///     DefaultEstimator::new(&logic, [0; 1 << 8]);
+/// for i in 0..1000_u64 {
+///     estimator.add(i);
+/// }
+/// assert!((estimator.estimate() - 1000.0).abs() < 200.0);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct HllConfig<T, H, const LOG2M: usize, W> {
+    build_hasher: H,
+    _marker: std::marker::PhantomData<(T, W)>,
+}
+
+// We implement Clone and Copy manually because we do not want to require that
+// T and W are Clone.
+impl<T, H: Clone, const LOG2M: usize, W> Clone for HllConfig<T, H, LOG2M, W> {
+    fn clone(&self) -> Self {
+        Self {
+            build_hasher: self.build_hasher.clone(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, H: Copy, const LOG2M: usize, W> Copy for HllConfig<T, H, LOG2M, W> {}
+
+impl<T, H, const LOG2M: usize, W> HllConfig<T, H, LOG2M, W> {
+    /// The number of registers per estimator.
+    pub const NUM_REGISTERS: usize = 1 << LOG2M;
+
+    /// The number of words in a backend, which is equal to the number of
+    /// registers.
+    pub const BACKEND_LEN: usize = Self::NUM_REGISTERS;
+
+    /// The bias-correction constant α<sub>*m*</sub> multiplied by *m*².
+    const ALPHA_M_M: f64 = {
+        let m = Self::NUM_REGISTERS as f64;
+        alpha_m(Self::NUM_REGISTERS) * m * m
+    };
+
+    /// A bit set in the hash to bound the number of trailing zeroes, and thus
+    /// the value of the registers.
+    const SENTINEL_MASK: u64 = 1 << (64 - LOG2M);
+
+    /// Creates a new logic using the given [`BuildHasher`].
+    ///
+    /// Calls to this method will not compile if `LOG2M` is smaller than 4 or
+    /// larger than 32.
+    pub const fn new(build_hasher: H) -> Self {
+        const {
+            assert!(
+                LOG2M >= 4 && LOG2M <= 32,
+                "the logarithm of the number of registers must be between 4 and 32"
+            )
+        };
+        Self {
+            build_hasher,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, const LOG2M: usize, W> SliceEstimationLogic<W>
+    for HllConfig<T, H, LOG2M, W>
+where
+    W: Word + UpcastableInto<u64> + CastableFrom<u64>,
+{
+    #[inline(always)]
+    fn backend_len(&self) -> usize {
+        Self::BACKEND_LEN
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, const LOG2M: usize, W> EstimationLogic
+    for HllConfig<T, H, LOG2M, W>
+where
+    W: Word + UpcastableInto<u64> + CastableFrom<u64>,
+{
+    type Item = T;
+    type Backend = [W];
+    type Estimator<'a>
+        = DefaultEstimator<Self, &'a Self, Box<[W]>>
+    where
+        T: 'a,
+        W: 'a,
+        H: 'a;
+
+    fn new_estimator(&self) -> Self::Estimator<'_> {
+        DefaultEstimator::new(self, vec![W::ZERO; Self::BACKEND_LEN].into_boxed_slice())
+    }
+
+    #[inline(always)]
+    fn add(&self, backend: &mut [W], element: impl Borrow<T>) {
+        debug_assert_eq!(backend.len(), Self::BACKEND_LEN);
+        let x = self.build_hasher.hash_one(element.borrow());
+        let register = (x & (Self::NUM_REGISTERS as u64 - 1)) as usize;
+        let r = ((x >> LOG2M) | Self::SENTINEL_MASK).trailing_zeros() as u64;
+        let candidate_value = W::cast_from(r + 1);
+        let current_value = &mut backend[register];
+        if *current_value < candidate_value {
+            *current_value = candidate_value;
+        }
+    }
+
+    fn estimate(&self, backend: &[W]) -> f64 {
+        debug_assert_eq!(backend.len(), Self::BACKEND_LEN);
+        let mut harmonic_mean = 0.0;
+        let mut zeroes = 0;
+
+        for &register in backend {
+            let value: u64 = register.upcast();
+            if value == 0 {
+                zeroes += 1;
+            }
+            harmonic_mean += 1.0 / (1_u64 << value) as f64;
+        }
+
+        let m = Self::NUM_REGISTERS as f64;
+        let mut estimate = Self::ALPHA_M_M / harmonic_mean;
+        if zeroes != 0 && estimate < 2.5 * m {
+            estimate = m * (m / zeroes as f64).ln();
+        }
+        estimate
+    }
+
+    fn clear(&self, backend: &mut [W]) {
+        backend.fill(W::ZERO);
+    }
+
+    fn set(&self, dst: &mut [W], src: &[W]) {
+        debug_assert_eq!(dst.len(), src.len());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, const LOG2M: usize, W> MergeEstimationLogic
+    for HllConfig<T, H, LOG2M, W>
+where
+    W: Word + UpcastableInto<u64> + CastableFrom<u64>,
+{
+    type Helper = ();
+
+    fn new_helper(&self) -> Self::Helper {}
+
+    fn merge_with_helper(&self, dst: &mut [W], src: &[W], _helper: &mut Self::Helper) {
+        debug_assert_eq!(dst.len(), src.len());
+        for (d, &s) in dst.iter_mut().zip(src) {
+            *d = std::cmp::max(*d, s);
+        }
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, const LOG2M: usize, W> EstimationGuarantees
+    for HllConfig<T, H, LOG2M, W>
+where
+    W: Word + UpcastableInto<u64> + CastableFrom<u64>,
+{
+    fn relative_std(&self) -> f64 {
+        HyperLogLog::rel_std(LOG2M)
+    }
+}
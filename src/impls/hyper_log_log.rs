@@ -5,22 +5,279 @@
  * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
  */
 
-use anyhow::{ensure, Result};
-use common_traits::{CastableFrom, CastableInto, Number, UpcastableInto};
+use anyhow::{ensure, Context, Result};
+use common_traits::{Atomic, CastableFrom, CastableInto, IntoAtomic, Number, UpcastableInto};
 use std::hash::*;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::{borrow::Borrow, f64::consts::LN_2};
 use sux::{
     bits::BitFieldVec,
     traits::{BitFieldSliceMut, Word},
 };
 
-use crate::traits::{EstimationLogic, MergeEstimationLogic, SliceEstimationLogic};
+use crate::traits::{
+    EstimationGuarantees, EstimationLogic, MergeEstimationLogic, SliceEstimationLogic,
+};
 
-use super::DefaultEstimator;
+use super::{DefaultEstimator, SliceEstimatorArray};
+#[cfg(not(target_arch = "wasm32"))]
+use super::{OwnedSyncSliceEstimatorArray, SyncSliceEstimatorArray};
+#[cfg(not(target_arch = "wasm32"))]
+use sync_cell_slice::SyncCell;
 
 /// The type returned by the hash function.
 type HashResult = u64;
 
+/// The type returned by [`HyperLogLogBuilder::build_array`].
+type HyperLogLogArray<T, H, W> = SliceEstimatorArray<HyperLogLog<T, H, W>, W, Box<[W]>>;
+
+/// The number of registers processed at a time by the AVX2 implementation of
+/// the estimate.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+const AVX2_REGISTERS: usize = 32;
+
+/// Unpacks [`AVX2_REGISTERS`] registers of `BIT_WIDTH` bits from the start of
+/// a stream of bytes into a buffer.
+///
+/// Registers are read in groups of eight, each group being contained in a
+/// 64-bit window; thus, the stream must contain at least 3 · `BIT_WIDTH` + 8
+/// bytes, and `BIT_WIDTH` must be at most eight.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+#[inline(always)]
+fn unpack_registers<const BIT_WIDTH: usize>(bytes: &[u8], buffer: &mut [u8; AVX2_REGISTERS]) {
+    let mask = (1_u64 << BIT_WIDTH) - 1;
+    let bytes = &bytes[..3 * BIT_WIDTH + 8];
+    for (group, registers) in buffer.chunks_exact_mut(8).enumerate() {
+        let window = u64::from_le_bytes(bytes[group * BIT_WIDTH..][..8].try_into().unwrap());
+        for (i, register) in registers.iter_mut().enumerate() {
+            *register = ((window >> (i * BIT_WIDTH)) & mask) as u8;
+        }
+    }
+}
+
+/// Returns the bias-correction constant α<sub>*m*</sub> of HyperLogLog for
+/// `m` registers.
+///
+/// The values for 16, 32, and 64 registers are those given in the original
+/// HyperLogLog paper, whereas for larger values of `m` the approximation
+/// 0.7213 / (1 + 1.079 / `m`) is used. Being a `const fn`, this function
+/// can be used to compute the constant at compile time.
+///
+/// # Panics
+///
+/// This function will panic if `m` is smaller than 16.
+pub const fn alpha_m(m: usize) -> f64 {
+    assert!(m >= 16, "α is defined for at least 16 registers");
+    match m {
+        16 => 0.673,
+        32 => 0.697,
+        64 => 0.709,
+        _ => 0.7213 / (1.0 + 1.079 / m as f64),
+    }
+}
+
+/// The method used by [`HyperLogLog`] to compute an estimate from the values
+/// of the registers.
+///
+/// In all cases, the estimate is replaced by linear counting when it is below
+/// the [linear-counting threshold](HyperLogLog::linear_counting_threshold) and
+/// some register is zero, unless the harmonic mean is used with the
+/// [LogLog-Beta bias correction](BiasCorrection::LogLogBeta).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EstimationMode {
+    /// The harmonic mean of 2<sup>−*r*</sup>, where *r* ranges over the
+    /// values of the registers, as in the original HyperLogLog paper.
+    #[default]
+    HarmonicMean,
+    /// The geometric mean of 2<sup>−*r*</sup>, as in the original LogLog
+    /// algorithm (see [`HyperLogLog::estimate_geometric`]).
+    GeometricMean,
+    /// Maximum-likelihood estimation (see
+    /// [`HyperLogLog::estimate_max_likelihood`]).
+    MaxLikelihood,
+}
+
+/// The bias correction applied by [`HyperLogLog`] to the estimates computed
+/// using the [harmonic mean](EstimationMode::HarmonicMean).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BiasCorrection {
+    /// The corrections of the original HyperLogLog paper: linear counting is
+    /// used below the [linear-counting
+    /// threshold](HyperLogLog::linear_counting_threshold).
+    #[default]
+    Standard,
+    /// The correction of the LogLog-Beta algorithm by Qin, Kim, and Tung: the
+    /// estimate is α<sub>*m*</sub>*m*(*m* − *z*) / (β(*z*) + Σ
+    /// 2<sup>−*r*</sup>), where *z* is the number of zero registers and β is
+    /// a polynomial in *z* and ln(*z* + 1), with no need for linear counting
+    /// (see [`HyperLogLog::estimate_log_log_beta`]). It is available only for
+    /// 2<sup>4</sup> to 2<sup>18</sup> registers.
+    LogLogBeta,
+}
+
+/// The coefficients β<sub>0</sub>, …, β<sub>7</sub> of the LogLog-Beta
+/// correction β(*z*) = β<sub>0</sub>*z* + β<sub>1</sub>*z*<sub>l</sub> + ⋯ +
+/// β<sub>7</sub>*z*<sub>l</sub><sup>7</sup>, where *z*<sub>l</sub> = ln(*z* +
+/// 1), indexed by the logarithm of the number of registers minus 4.
+///
+/// The coefficients have been fitted by weighted least squares on simulated
+/// estimators, as described in the LogLog-Beta paper, so to minimize the
+/// relative error of the estimate.
+const LOG_LOG_BETA_COEFFICIENTS: [[f64; 8]; 15] = [
+    [
+        -3.37856329127e+02,
+        3.36813434981e+02,
+        1.69976259510e+02,
+        5.45007370854e+01,
+        1.49499155349e+01,
+        3.11156750834e+00,
+        3.10000251655e-02,
+        2.00589895066e-01,
+    ],
+    [
+        2.61391847689e+02,
+        -2.64629145879e+02,
+        -1.19371958728e+02,
+        -6.36908858560e+01,
+        7.52539618486e+00,
+        -1.15181354556e+01,
+        2.17717897836e+00,
+        -3.68067201336e-01,
+    ],
+    [
+        -1.41532479828e+01,
+        1.41493977149e+01,
+        4.63130179837e+00,
+        6.16842869465e+00,
+        -2.43707777055e+00,
+        1.38460714582e+00,
+        -2.63295152835e-01,
+        3.06070339851e-02,
+    ],
+    [
+        5.63465915166e+00,
+        -6.60481911952e+00,
+        -9.45081303399e-01,
+        -3.75569627973e+00,
+        1.79464921167e+00,
+        -8.54204254450e-01,
+        1.57681280224e-01,
+        -1.61771282674e-02,
+    ],
+    [
+        -2.31558263867e+00,
+        2.12890694959e+00,
+        -1.60274865850e-02,
+        1.98675677231e+00,
+        -9.96868637697e-01,
+        3.98208420789e-01,
+        -6.80616939961e-02,
+        6.20995524386e-03,
+    ],
+    [
+        -1.46613998994e+00,
+        1.76004071567e+00,
+        -1.56103840242e+00,
+        2.98821468071e+00,
+        -1.56612703578e+00,
+        5.06888393415e-01,
+        -7.74898693208e-02,
+        5.65551674728e-03,
+    ],
+    [
+        -5.04482223126e-01,
+        3.41709519487e-01,
+        -3.04746591763e-01,
+        6.35856699655e-01,
+        -2.88600175602e-01,
+        8.69476910897e-02,
+        -1.22310013877e-02,
+        8.60740776061e-04,
+    ],
+    [
+        -4.19555156876e-01,
+        5.09379157017e-01,
+        -7.44611502234e-01,
+        8.95811815770e-01,
+        -3.67867714432e-01,
+        9.14094017413e-02,
+        -1.08390996812e-02,
+        6.32008274510e-04,
+    ],
+    [
+        -3.82120808289e-01,
+        3.00775120252e-01,
+        -3.37276100975e-01,
+        4.83879151504e-01,
+        -1.71090976145e-01,
+        4.17456626659e-02,
+        -4.59828621162e-03,
+        3.06582579823e-04,
+    ],
+    [
+        -3.85293894866e-01,
+        -3.75917388526e-01,
+        2.35845213360e-01,
+        3.84374364558e-01,
+        -2.16016646271e-01,
+        6.40289320030e-02,
+        -8.00296675150e-03,
+        5.14060835298e-04,
+    ],
+    [
+        -3.87825222481e-01,
+        2.08633841199e+00,
+        -3.45563600947e+00,
+        2.96190150638e+00,
+        -1.12482838268e+00,
+        2.34781968518e-01,
+        -2.43373342151e-02,
+        1.16985928362e-03,
+    ],
+    [
+        -3.91146382942e-01,
+        9.45533250040e-01,
+        -3.34890541909e+00,
+        3.84230825200e+00,
+        -1.66460053305e+00,
+        3.66278631308e-01,
+        -3.89985842547e-02,
+        1.82201610299e-03,
+    ],
+    [
+        -3.76438586333e-01,
+        1.88215451981e+00,
+        -6.77649886874e+00,
+        6.07235354645e+00,
+        -2.28154407953e+00,
+        4.50656338653e-01,
+        -4.47175570252e-02,
+        1.98909023969e-03,
+    ],
+    [
+        -3.69484186791e-01,
+        -3.43983825384e+00,
+        1.64156392630e+00,
+        2.18077531915e+00,
+        -1.57025071312e+00,
+        4.11233959346e-01,
+        -4.72419416278e-02,
+        2.26550765301e-03,
+    ],
+    [
+        -3.69961154923e-01,
+        2.32163831615e+01,
+        -4.22201918918e+01,
+        2.77091273666e+01,
+        -8.77197670554e+00,
+        1.48148147733e+00,
+        -1.28589762937e-01,
+        4.80443397945e-03,
+    ],
+];
+
 /// Estimator logic implementing the HyperLogLog algorithm.
 ///
 /// Instances are built using [`HyperLogLogBuilder`], which provides convenient
@@ -36,27 +293,78 @@ type HashResult = u64;
 /// and small number of registers it might be necessary to select a smaller
 /// type, resulting in slower merges. For example, using 16 5-bit registers one
 /// needs to use `u16`, whereas for 16 6-bit registers `u32` will be sufficient.
-#[derive(Debug, PartialEq)]
 pub struct HyperLogLog<T, H, W> {
     build_hasher: H,
-    register_size: usize,
+    num_elements: usize,
+    pub(super) register_size: usize,
     num_registers_minus_1: HashResult,
     log_2_num_registers: usize,
     sentinel_mask: HashResult,
     num_registers: usize,
     pub(super) words_per_estimator: usize,
     alpha_m_m: f64,
+    linear_counting_threshold: f64,
+    estimation_mode: EstimationMode,
+    bias_correction: BiasCorrection,
     msb_mask: Box<[W]>,
     lsb_mask: Box<[W]>,
     _marker: std::marker::PhantomData<T>,
 }
 
+// We implement Debug manually because we do not want to require that T and H
+// are Debug: the hasher is represented by its type name.
+impl<T, H, W: std::fmt::Debug> std::fmt::Debug for HyperLogLog<T, H, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HyperLogLog")
+            .field("build_hasher", &std::any::type_name::<H>())
+            .field("num_elements", &self.num_elements)
+            .field("register_size", &self.register_size)
+            .field("num_registers_minus_1", &self.num_registers_minus_1)
+            .field("log_2_num_registers", &self.log_2_num_registers)
+            .field("sentinel_mask", &self.sentinel_mask)
+            .field("num_registers", &self.num_registers)
+            .field("words_per_estimator", &self.words_per_estimator)
+            .field("alpha_m_m", &self.alpha_m_m)
+            .field("linear_counting_threshold", &self.linear_counting_threshold)
+            .field("estimation_mode", &self.estimation_mode)
+            .field("bias_correction", &self.bias_correction)
+            .field("msb_mask", &self.msb_mask)
+            .field("lsb_mask", &self.lsb_mask)
+            .finish()
+    }
+}
+
+/// Two logics are equal if they have the same hasher and the same
+/// configuration, so that they can be used interchangeably on the same
+/// backends.
+///
+/// Only the parameters determining the behavior of the logic are compared: in
+/// particular, logics built with different [upper bounds on the number of
+/// elements](HyperLogLogBuilder::num_elements) are equal if they yield the same
+/// register size. The word type is part of the type, so logics with different
+/// word types cannot be compared.
+// We implement PartialEq manually because we do not want to require that T
+// and W are PartialEq.
+impl<T, H: PartialEq, W> PartialEq for HyperLogLog<T, H, W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.build_hasher == other.build_hasher
+            && self.log_2_num_registers == other.log_2_num_registers
+            && self.register_size == other.register_size
+            && self.linear_counting_threshold == other.linear_counting_threshold
+            && self.estimation_mode == other.estimation_mode
+            && self.bias_correction == other.bias_correction
+    }
+}
+
+impl<T, H: Eq, W> Eq for HyperLogLog<T, H, W> {}
+
 // We implement Clone manually because we do not want to require that T is
 // Clone.
 impl<T, H: Clone, W: Clone> Clone for HyperLogLog<T, H, W> {
     fn clone(&self) -> Self {
         Self {
             build_hasher: self.build_hasher.clone(),
+            num_elements: self.num_elements,
             register_size: self.register_size,
             num_registers_minus_1: self.num_registers_minus_1,
             log_2_num_registers: self.log_2_num_registers,
@@ -64,6 +372,9 @@ impl<T, H: Clone, W: Clone> Clone for HyperLogLog<T, H, W> {
             num_registers: self.num_registers,
             words_per_estimator: self.words_per_estimator,
             alpha_m_m: self.alpha_m_m,
+            linear_counting_threshold: self.linear_counting_threshold,
+            estimation_mode: self.estimation_mode,
+            bias_correction: self.bias_correction,
             msb_mask: self.msb_mask.clone(),
             lsb_mask: self.lsb_mask.clone(),
             _marker: std::marker::PhantomData,
@@ -72,9 +383,575 @@ impl<T, H: Clone, W: Clone> Clone for HyperLogLog<T, H, W> {
 }
 
 impl<T, H: Clone, W: Word> HyperLogLog<T, H, W> {
+    /// Returns the number of registers per estimator.
+    #[inline(always)]
+    pub fn num_registers(&self) -> usize {
+        self.num_registers
+    }
+
+    /// Returns the base-2 logarithm of the number of registers per
+    /// estimator.
+    pub fn log_2_num_registers(&self) -> usize {
+        self.log_2_num_registers
+    }
+
+    /// Returns the number of bytes used by the backend of an estimator, that
+    /// is, the [backend length](SliceEstimationLogic::backend_len) times the
+    /// size of `W`.
+    pub fn memory_usage_bytes(&self) -> usize {
+        self.words_per_estimator * size_of::<W>()
+    }
+
+    /// Returns the upper bound on the number of distinct elements used to
+    /// size the registers.
+    ///
+    /// See [`HyperLogLogBuilder::num_elements`].
+    pub fn num_elements(&self) -> usize {
+        self.num_elements
+    }
+
+    /// Returns the [`BuildHasher`] used to hash elements.
+    pub fn build_hasher(&self) -> &H {
+        &self.build_hasher
+    }
+
+    /// Returns the value contained in a register of a given backend.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `index` is not smaller than the [number of
+    /// registers](HyperLogLog::num_registers) or if the backend is too short.
+    pub fn get_register(&self, backend: &[W], index: usize) -> W {
+        assert!(
+            index < self.num_registers,
+            "register index {} out of bounds ({} registers)",
+            index,
+            self.num_registers
+        );
+        assert_eq!(backend.len(), self.words_per_estimator);
+        self.get_register_unchecked(backend, index)
+    }
+
+    /// Sets the value of a register of a given backend.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `index` is not smaller than the [number of
+    /// registers](HyperLogLog::num_registers), if the backend is too short, or
+    /// if `value` does not fit in a register.
+    pub fn set_register(&self, backend: &mut [W], index: usize, value: W) {
+        assert!(
+            index < self.num_registers,
+            "register index {} out of bounds ({} registers)",
+            index,
+            self.num_registers
+        );
+        assert_eq!(backend.len(), self.words_per_estimator);
+        assert!(
+            value <= W::MAX >> (W::BITS - self.register_size),
+            "value {} does not fit in a register of {} bits",
+            value,
+            self.register_size
+        );
+        self.set_register_unchecked(backend, index, value);
+    }
+
+    /// Returns the histogram of the values of the registers of a given
+    /// backend.
+    ///
+    /// The result has an entry for each possible value of a register, that
+    /// is, 2<sup>*r*</sup> entries, where *r* is the register size in bits,
+    /// and entry *v* is the number of registers with value *v*.
+    pub fn register_histogram(&self, backend: &[W]) -> Vec<u64>
+    where
+        W: UpcastableInto<HashResult>,
+    {
+        assert_eq!(backend.len(), self.words_per_estimator);
+        let mut histogram = vec![0; 1 << self.register_size];
+        for i in 0..self.num_registers {
+            let value: HashResult = self.get_register_unchecked(backend, i).upcast();
+            histogram[value as usize] += 1;
+        }
+        histogram
+    }
+
+    /// Returns the number of registers of a given backend whose value is
+    /// zero.
+    pub fn empty_register_count(&self, backend: &[W]) -> usize {
+        assert_eq!(backend.len(), self.words_per_estimator);
+        (0..self.num_registers)
+            .filter(|&i| self.get_register_unchecked(backend, i) == W::ZERO)
+            .count()
+    }
+
+    /// Returns the fraction of registers of a given backend whose value is
+    /// not zero.
+    pub fn fill_factor(&self, backend: &[W]) -> f64 {
+        1.0 - self.empty_register_count(backend) as f64 / self.num_registers as f64
+    }
+
+    /// Returns the threshold on the raw estimate below which
+    /// [`estimate`](EstimationLogic::estimate) uses linear counting.
+    ///
+    /// See [`HyperLogLogBuilder::linear_counting_threshold`].
+    pub fn linear_counting_threshold(&self) -> f64 {
+        self.linear_counting_threshold
+    }
+
+    /// Returns the method used by [`estimate`](EstimationLogic::estimate).
+    ///
+    /// See [`HyperLogLogBuilder::estimation_mode`].
+    pub fn estimation_mode(&self) -> EstimationMode {
+        self.estimation_mode
+    }
+
+    /// Returns the bias correction used by
+    /// [`estimate`](EstimationLogic::estimate) with the harmonic mean.
+    ///
+    /// See [`HyperLogLogBuilder::bias_correction`].
+    pub fn bias_correction(&self) -> BiasCorrection {
+        self.bias_correction
+    }
+
+    /// Returns an estimation of the number of distinct elements that have
+    /// been added to an estimator with the given backend so far, using the
+    /// geometric mean of the values of the registers.
+    ///
+    /// This is the estimator of the original LogLog algorithm by Durand and
+    /// Flajolet, that is, α<sub>*m*</sub>*m*2<sup>*r̄*</sup>, where *r̄* is
+    /// the arithmetic mean of the values of the registers and α<sub>*m*</sub>
+    /// is a bias-correction constant. It is simpler to compute than the
+    /// harmonic mean, but its relative standard deviation is about 1.30/√*m*
+    /// instead of 1.04/√*m*. Linear counting is used below the
+    /// [linear-counting threshold](HyperLogLog::linear_counting_threshold).
+    pub fn estimate_geometric(&self, backend: &[W]) -> f64
+    where
+        W: UpcastableInto<HashResult>,
+    {
+        let m = self.num_registers as f64;
+        let mut sum = 0;
+        let mut zeroes = 0;
+        for i in 0..self.num_registers {
+            let value: HashResult = self.get_register_unchecked(backend, i).upcast();
+            if value == 0 {
+                zeroes += 1;
+            }
+            sum += value;
+        }
+        // The asymptotic value of α is e^(-γ)√2/2, with a first-order
+        // correction for the number of registers
+        let alpha = 0.39701 - (2.0 * std::f64::consts::PI.powi(2) + LN_2.powi(2)) / (48.0 * m);
+        let estimate = alpha * m * (sum as f64 / m).exp2();
+        if zeroes != 0 && estimate < self.linear_counting_threshold {
+            m * (m / zeroes as f64).ln()
+        } else {
+            estimate
+        }
+    }
+
+    /// Returns an estimation of the number of distinct elements that have
+    /// been added to an estimator with the given backend so far, using
+    /// maximum-likelihood estimation.
+    ///
+    /// The estimate maximizes the likelihood of the histogram of the values
+    /// of the registers under a Poisson model, as proposed by Otmar Ertl in
+    /// “[New cardinality estimation algorithms for HyperLogLog
+    /// sketches](https://arxiv.org/abs/1702.01284)”. It needs no bias
+    /// correction and no linear counting, as it is accurate on the whole range
+    /// of cardinalities, but it is slower than the other methods, as the
+    /// maximum is found by bisection.
+    ///
+    /// If all registers are saturated, the estimate is infinite.
+    pub fn estimate_max_likelihood(&self, backend: &[W]) -> f64
+    where
+        W: UpcastableInto<HashResult>,
+    {
+        // The number of usable bits of the hash after the register index: a
+        // register with value larger than q is saturated
+        let q = std::cmp::min((1 << self.register_size) - 2, 64 - self.log_2_num_registers);
+        let mut histogram = vec![0_usize; q + 2];
+        for i in 0..self.num_registers {
+            let value: HashResult = self.get_register_unchecked(backend, i).upcast();
+            histogram[std::cmp::min(value as usize, q + 1)] += 1;
+        }
+
+        if histogram[0] == self.num_registers {
+            return 0.0;
+        }
+        if histogram[q + 1] == self.num_registers {
+            return f64::INFINITY;
+        }
+
+        // The log-likelihood of a rate x per register is
+        // -a x + Σ_k c_k ln(1 - exp(-x / 2^min(k, q))), where c_k is the
+        // number of registers with value k > 0 and a is the sum of c_k / 2^k
+        // for k ≤ q; multiplying its derivative by x yields a function
+        // decreasing in x whose root we find.
+        let a: f64 = (0..=q)
+            .map(|k| histogram[k] as f64 / (k as f64).exp2())
+            .sum();
+        let derivative = |x: f64| {
+            let mut result = -a * x;
+            for (k, &c) in histogram.iter().enumerate().skip(1) {
+                if c != 0 {
+                    let y = x / (std::cmp::min(k, q) as f64).exp2();
+                    result += c as f64 * y / y.exp_m1();
+                }
+            }
+            result
+        };
+
+        // Bisection on the logarithm of x
+        let (mut lo, mut hi) = (-64.0_f64, q as f64 + 64.0);
+        for _ in 0..100 {
+            let mid = (lo + hi) / 2.0;
+            if derivative(mid.exp2()) > 0.0 {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        self.num_registers as f64 * ((lo + hi) / 2.0).exp2()
+    }
+
+    /// Returns an estimation of the number of distinct elements that have
+    /// been added to an estimator with the given backend so far, using linear
+    /// counting below the given threshold.
+    ///
+    /// If the raw HyperLogLog estimate is below the threshold and there are
+    /// registers equal to zero, the estimate is computed by linear counting
+    /// instead, that is, as *m* ln(*m* / *z*), where *m* is the number of
+    /// registers and *z* the number of zero registers. This correction avoids
+    /// the bias of the raw estimate for small cardinalities.
+    ///
+    /// # Arguments
+    /// * `backend`: the backend of the estimator.
+    /// * `threshold`: the threshold below which linear counting is used; if
+    ///   `None`, five times the number of registers, as suggested in the
+    ///   HyperLogLog++ paper.
+    pub fn estimate_with_linear_counting_threshold(
+        &self,
+        backend: &[W],
+        threshold: Option<f64>,
+    ) -> f64
+    where
+        W: UpcastableInto<HashResult>,
+    {
+        let threshold = threshold.unwrap_or(5.0 * self.num_registers as f64);
+        let (harmonic_mean, zeroes) = self.register_sum(backend);
+        self.harmonic_estimate(harmonic_mean, zeroes, threshold)
+    }
+
+    /// Returns an estimation of the number of distinct elements that have
+    /// been added to an estimator with the given backend so far, using the
+    /// harmonic mean with the LogLog-Beta bias correction.
+    ///
+    /// The estimate is α<sub>*m*</sub>*m*(*m* − *z*) / (β(*z*) + Σ
+    /// 2<sup>−*r*</sup>), where *m* is the number of registers, *z* the
+    /// number of zero registers, *r* ranges over the values of the registers,
+    /// and β is a polynomial in *z* and ln(*z* + 1) depending on *m*. The
+    /// correction makes the estimate accurate on the whole range of
+    /// cardinalities without resorting to linear counting.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the number of registers is larger than
+    /// 2<sup>18</sup>.
+    pub fn estimate_log_log_beta(&self, backend: &[W]) -> f64
+    where
+        W: UpcastableInto<HashResult>,
+    {
+        let (harmonic_mean, zeroes) = self.register_sum(backend);
+        self.log_log_beta_estimate(harmonic_mean, zeroes)
+    }
+
+    /// Returns the same estimate as [`estimate`](EstimationLogic::estimate),
+    /// but never uses SIMD instructions.
+    ///
+    /// This method is useful to test and benchmark the AVX2 implementation.
+    #[cfg(feature = "simd")]
+    pub fn estimate_scalar(&self, backend: &[W]) -> f64
+    where
+        W: UpcastableInto<HashResult>,
+    {
+        match self.estimation_mode {
+            EstimationMode::HarmonicMean => {
+                let (harmonic_mean, zeroes) = self.register_sum_scalar(backend);
+                self.bias_corrected_estimate(harmonic_mean, zeroes)
+            }
+            EstimationMode::GeometricMean => self.estimate_geometric(backend),
+            EstimationMode::MaxLikelihood => self.estimate_max_likelihood(backend),
+        }
+    }
+
+    /// Returns the sum of 2<sup>−*r*</sup> over the values *r* of the
+    /// registers of a backend, and the number of zero registers.
+    ///
+    /// With the `simd` feature, on x86-64 CPUs supporting AVX2 the sum is
+    /// computed by [`register_sum_avx2`](Self::register_sum_avx2). The sum
+    /// is exact, and thus independent of the order of summation, unless some
+    /// register is larger than 35 (which in practice never happens), so the
+    /// two implementations return the same results.
+    #[inline(always)]
+    fn register_sum(&self, backend: &[W]) -> (f64, usize)
+    where
+        W: UpcastableInto<HashResult>,
+    {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        if self.num_registers >= AVX2_REGISTERS && std::is_x86_feature_detected!("avx2") {
+            // SAFETY: we just checked that the CPU supports AVX2
+            return unsafe { self.register_sum_avx2(backend) };
+        }
+        self.register_sum_scalar(backend)
+    }
+
+    #[inline(always)]
+    fn register_sum_scalar(&self, backend: &[W]) -> (f64, usize)
+    where
+        W: UpcastableInto<HashResult>,
+    {
+        let mut harmonic_mean = 0.0;
+        let mut zeroes = 0;
+
+        for i in 0..self.num_registers {
+            let value: u64 = self.get_register_unchecked(backend, i).upcast();
+            if value == 0 {
+                zeroes += 1;
+            }
+            harmonic_mean += 1.0 / (1_u64 << value) as f64;
+        }
+
+        (harmonic_mean, zeroes)
+    }
+
+    /// AVX2 implementation of [`register_sum`](Self::register_sum).
+    ///
+    /// Registers are unpacked into a buffer of [`AVX2_REGISTERS`] bytes
+    /// (eight at a time, if they have five or six bits); zero registers are
+    /// counted with a byte comparison, and 2<sup>−*r*</sup> is built directly
+    /// from its exponent in four 64-bit lanes at a time.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support AVX2, and the number of registers must be a
+    /// multiple of [`AVX2_REGISTERS`].
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    #[target_feature(enable = "avx2")]
+    unsafe fn register_sum_avx2(&self, backend: &[W]) -> (f64, usize)
+    where
+        W: UpcastableInto<HashResult>,
+    {
+        use std::arch::x86_64::*;
+        debug_assert!(self.num_registers % AVX2_REGISTERS == 0);
+
+        let bias = _mm256_set1_epi64x(1023);
+        let zero = _mm256_setzero_si256();
+        let mut acc = [_mm256_setzero_pd(); 2];
+        let mut zeroes = 0;
+        let mut buffer = [0_u8; AVX2_REGISTERS];
+
+        // On x86-64 words are little endian, so the backend is a stream of
+        // registers packed starting from the lowest bit of its first byte
+        let bytes = unsafe {
+            std::slice::from_raw_parts(
+                backend.as_ptr() as *const u8,
+                std::mem::size_of_val(backend),
+            )
+        };
+        let bit_width = self.register_size;
+
+        for start in (0..self.num_registers).step_by(AVX2_REGISTERS) {
+            // A block of registers starts at a byte boundary
+            let block = &bytes[start * bit_width / 8..];
+            match bit_width {
+                // Register sizes are always five or six bits
+                5 if block.len() >= 3 * 5 + 8 => unpack_registers::<5>(block, &mut buffer),
+                6 if block.len() >= 3 * 6 + 8 => unpack_registers::<6>(block, &mut buffer),
+                _ => {
+                    for (i, register) in buffer.iter_mut().enumerate() {
+                        let value: HashResult =
+                            self.get_register_unchecked(backend, start + i).upcast();
+                        *register = value as u8;
+                    }
+                }
+            }
+            let registers = _mm256_loadu_si256(buffer.as_ptr() as *const __m256i);
+            let mask = _mm256_movemask_epi8(_mm256_cmpeq_epi8(registers, zero));
+            zeroes += mask.count_ones() as usize;
+            for (i, chunk) in buffer.chunks_exact(4).enumerate() {
+                let values = _mm256_cvtepu8_epi64(_mm_cvtsi32_si128(i32::from_le_bytes(
+                    chunk.try_into().unwrap(),
+                )));
+                // 2^-value, built directly from its exponent
+                let powers =
+                    _mm256_castsi256_pd(_mm256_slli_epi64::<52>(_mm256_sub_epi64(bias, values)));
+                acc[i % 2] = _mm256_add_pd(acc[i % 2], powers);
+            }
+        }
+
+        let mut lanes = [0.0; 4];
+        _mm256_storeu_pd(lanes.as_mut_ptr(), _mm256_add_pd(acc[0], acc[1]));
+        (lanes.iter().sum(), zeroes)
+    }
+
+    /// Returns the LogLog-Beta estimate given the sum of 2<sup>−*r*</sup>
+    /// over the values *r* of the registers and the number of zero registers.
+    #[inline(always)]
+    fn log_log_beta_estimate(&self, harmonic_mean: f64, zeroes: usize) -> f64 {
+        let coefficients = &LOG_LOG_BETA_COEFFICIENTS[self.log_2_num_registers - 4];
+        let z = zeroes as f64;
+        let z_l = (z + 1.0).ln();
+        let mut beta = 0.0;
+        // Horner's scheme on the powers of z_l
+        for &coefficient in coefficients[1..].iter().rev() {
+            beta = (beta + coefficient) * z_l;
+        }
+        beta += coefficients[0] * z;
+        let m = self.num_registers as f64;
+        self.alpha_m_m * (m - z) / (m * (beta + harmonic_mean))
+    }
+
+    /// Returns the estimate given the sum of 2<sup>−*r*</sup> over the values
+    /// *r* of the registers and the number of zero registers, using the
+    /// [bias correction](HyperLogLog::bias_correction) of the logic.
+    #[inline(always)]
+    fn bias_corrected_estimate(&self, harmonic_mean: f64, zeroes: usize) -> f64 {
+        match self.bias_correction {
+            BiasCorrection::Standard => {
+                self.harmonic_estimate(harmonic_mean, zeroes, self.linear_counting_threshold)
+            }
+            BiasCorrection::LogLogBeta => self.log_log_beta_estimate(harmonic_mean, zeroes),
+        }
+    }
+
+    /// Returns the estimate given the sum of 2<sup>−*r*</sup> over the values
+    /// *r* of the registers, the number of zero registers, and the
+    /// linear-counting threshold.
+    #[inline(always)]
+    fn harmonic_estimate(&self, harmonic_mean: f64, zeroes: usize, threshold: f64) -> f64 {
+        let estimate = self.alpha_m_m / harmonic_mean;
+        if zeroes != 0 && estimate < threshold {
+            self.num_registers as f64 * (self.num_registers as f64 / zeroes as f64).ln()
+        } else {
+            estimate
+        }
+    }
+
+    /// Computes the estimates of a sequence of backends, processing `K`
+    /// backends at a time.
+    ///
+    /// The backends are concatenated in `backends`, as in a
+    /// [`SliceEstimatorArray`], and the estimate of the *i*-th backend is
+    /// stored in `output[i]`. The results are the same as those of
+    /// [`estimate`](EstimationLogic::estimate), but when using the harmonic
+    /// mean (the [default estimation mode](EstimationMode::HarmonicMean)) the
+    /// registers with the same index of `K` backends are processed together
+    /// in a branchless loop over `K` lanes, which the compiler vectorizes:
+    /// thus, `K` should be a multiple of the number of `f64` lanes of the
+    /// target (e.g., 4 for AVX2). Remaining backends are processed one at a
+    /// time.
+    ///
+    /// Calls to this method will not compile if `K` is zero.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the length of `backends` is not the length
+    /// of `output` times the backend length.
+    pub fn estimate_batch_simd<const K: usize>(&self, backends: &[W], output: &mut [f64])
+    where
+        W: UpcastableInto<HashResult>,
+    {
+        const { assert!(K > 0, "the number of lanes must be positive") };
+        let backend_len = self.words_per_estimator;
+        assert_eq!(
+            backends.len(),
+            output.len() * backend_len,
+            "the backends contain {} words, but {} estimates of {} words each are required",
+            backends.len(),
+            output.len(),
+            backend_len
+        );
+
+        if self.estimation_mode != EstimationMode::HarmonicMean {
+            for (backend, estimate) in backends.chunks_exact(backend_len).zip(output) {
+                *estimate = match self.estimation_mode {
+                    EstimationMode::GeometricMean => self.estimate_geometric(backend),
+                    _ => self.estimate_max_likelihood(backend),
+                };
+            }
+            return;
+        }
+
+        let threshold = self.linear_counting_threshold;
+        let mut blocks = backends.chunks_exact(K * backend_len);
+        let mut outputs = output.chunks_exact_mut(K);
+        for (block, output) in (&mut blocks).zip(&mut outputs) {
+            let lanes: [&[W]; K] =
+                std::array::from_fn(|lane| &block[lane * backend_len..][..backend_len]);
+            let mut harmonic_means = [0.0; K];
+            let mut zeroes = [0; K];
+            for i in 0..self.num_registers {
+                for lane in 0..K {
+                    let value: HashResult = self.get_register_unchecked(lanes[lane], i).upcast();
+                    // 2^-value, built directly from its exponent
+                    harmonic_means[lane] += f64::from_bits((1023 - value) << 52);
+                    zeroes[lane] += (value == 0) as usize;
+                }
+            }
+            for lane in 0..K {
+                output[lane] = self.bias_corrected_estimate(harmonic_means[lane], zeroes[lane]);
+            }
+        }
+        for (backend, estimate) in blocks
+            .remainder()
+            .chunks_exact(backend_len)
+            .zip(outputs.into_remainder())
+        {
+            *estimate = match self.bias_correction {
+                BiasCorrection::Standard => {
+                    self.estimate_with_linear_counting_threshold(backend, Some(threshold))
+                }
+                BiasCorrection::LogLogBeta => self.estimate_log_log_beta(backend),
+            };
+        }
+    }
+
+    /// Returns a similarity score between two backends based on the absolute
+    /// differences of their registers.
+    ///
+    /// The score is one minus the L1 distance between the registers of the
+    /// two backends, normalized by the maximum possible distance (i.e., the
+    /// number of registers times the maximum register value). Thus, identical
+    /// backends have similarity one, and an empty backend has similarity zero
+    /// with a backend whose registers all have the maximum value.
+    ///
+    /// This score does not require a merge, and it is thus faster to compute
+    /// than estimates of the Jaccard similarity; it can be used as a quick
+    /// filter for similar estimators.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the backends have the wrong length.
+    pub fn register_l1_similarity(&self, a: &[W], b: &[W]) -> f64
+    where
+        W: UpcastableInto<HashResult>,
+    {
+        assert_eq!(a.len(), self.words_per_estimator);
+        assert_eq!(b.len(), self.words_per_estimator);
+        let distance: HashResult = (0..self.num_registers)
+            .map(|i| {
+                let x: HashResult = self.get_register_unchecked(a, i).upcast();
+                let y: HashResult = self.get_register_unchecked(b, i).upcast();
+                x.abs_diff(y)
+            })
+            .sum();
+        let max_register_value = (1 << self.register_size) - 1;
+        1.0 - distance as f64 / (self.num_registers as HashResult * max_register_value) as f64
+    }
+
     /// Returns the value contained in a register of a given backend.
     #[inline(always)]
-    fn get_register_unchecked(&self, backend: impl AsRef<[W]>, index: usize) -> W {
+    pub(super) fn get_register_unchecked(&self, backend: impl AsRef<[W]>, index: usize) -> W {
         let backend = backend.as_ref();
         let bit_width = self.register_size;
         let mask = W::MAX >> (W::BITS - bit_width);
@@ -93,7 +970,12 @@ impl<T, H: Clone, W: Word> HyperLogLog<T, H, W> {
 
     /// Sets the value contained in a register of a given backend.
     #[inline(always)]
-    fn set_register_unchecked(&self, mut backend: impl AsMut<[W]>, index: usize, new_value: W) {
+    pub(super) fn set_register_unchecked(
+        &self,
+        mut backend: impl AsMut<[W]>,
+        index: usize,
+        new_value: W,
+    ) {
         let backend = backend.as_mut();
         let bit_width = self.register_size;
         let mask = W::MAX >> (W::BITS - bit_width);
@@ -120,6 +1002,262 @@ impl<T, H: Clone, W: Word> HyperLogLog<T, H, W> {
     }
 }
 
+impl<T, H: Clone, W: Word + CastableFrom<HashResult>> HyperLogLog<T, H, W> {
+    /// Adds to an estimator with the given backend an element with the given
+    /// hash.
+    #[inline(always)]
+    fn add_hash(&self, mut backend: &mut [W], x: HashResult) {
+        let (register, candidate_value) = self.register_and_value(x);
+        let current_value = self.get_register_unchecked(&mut backend, register);
+        let new_value = std::cmp::max(current_value, candidate_value.cast());
+        if current_value != new_value {
+            self.set_register_unchecked(backend, register, new_value);
+        }
+    }
+}
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+impl<T: Hash + Sync, H: BuildHasher + Clone + Sync, W: Word + CastableFrom<HashResult>>
+    HyperLogLog<T, H, W>
+{
+    /// Adds in parallel all elements of a slice to an estimator with the
+    /// given backend.
+    ///
+    /// Elements are hashed in parallel, and each thread keeps track of the
+    /// maximum candidate value for each register; the values are then
+    /// combined and the backend is updated sequentially register by
+    /// register. The result is the same as that of
+    /// [`add_many`](EstimationLogic::add_many), but since each thread needs
+    /// a byte per register, this method is convenient only for large slices.
+    pub fn add_all_par(&self, backend: &mut [W], elements: &[T]) {
+        use rayon::prelude::*;
+        let Some(maxima) = elements
+            .par_iter()
+            .fold(
+                || vec![0_u8; self.num_registers],
+                |mut maxima, element| {
+                    let (register, value) =
+                        self.register_and_value(self.build_hasher.hash_one(element));
+                    maxima[register] = std::cmp::max(maxima[register], value as u8);
+                    maxima
+                },
+            )
+            .reduce_with(|mut a, b| {
+                for (x, y) in a.iter_mut().zip(b) {
+                    *x = std::cmp::max(*x, y);
+                }
+                a
+            })
+        else {
+            return;
+        };
+
+        for (register, value) in maxima.into_iter().enumerate() {
+            let candidate_value = W::cast_from(value as HashResult);
+            if candidate_value > self.get_register_unchecked(&*backend, register) {
+                self.set_register_unchecked(&mut *backend, register, candidate_value);
+            }
+        }
+    }
+}
+
+impl<T, H, W> HyperLogLog<T, H, W> {
+    /// Returns the register associated with a hash, and the candidate value
+    /// for the register.
+    #[inline(always)]
+    pub(super) fn register_and_value(&self, x: HashResult) -> (usize, HashResult) {
+        let j = x & self.num_registers_minus_1;
+        let r =
+            ((x >> self.log_2_num_registers) | self.sentinel_mask).trailing_zeros() as HashResult;
+        let register = j as usize;
+
+        debug_assert!(r < (1 << self.register_size) - 1);
+        debug_assert!(register < self.num_registers);
+
+        (register, r + 1)
+    }
+}
+
+/// The number of locks protecting registers that straddle two words in
+/// [`HyperLogLog::update_register_atomic`] and
+/// [`HyperLogLog::load_atomic`].
+const STRADDLING_LOCKS: usize = 64;
+
+/// Spin locks protecting registers that straddle two words, indexed by the
+/// address of the second word modulo [`STRADDLING_LOCKS`].
+static STRADDLING_LOCK: [AtomicBool; STRADDLING_LOCKS] =
+    [const { AtomicBool::new(false) }; STRADDLING_LOCKS];
+
+/// Runs `f` holding the spin lock associated with `high`, the second word
+/// of a straddling register.
+#[inline(always)]
+fn with_straddling_lock<A, R>(high: &A, f: impl FnOnce() -> R) -> R {
+    let lock = &STRADDLING_LOCK[(high as *const A as usize / size_of::<A>()) % STRADDLING_LOCKS];
+    while lock
+        .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+        .is_err()
+    {
+        std::hint::spin_loop();
+    }
+    let result = f();
+    lock.store(false, Ordering::Release);
+    result
+}
+
+/// Replaces the value of an atomic word with the result of `f`, unless `f`
+/// returns `None`, using a compare-and-swap loop.
+#[inline(always)]
+fn update_atomic_word<W: Word + IntoAtomic>(word: &W::AtomicType, f: impl Fn(W) -> Option<W>) {
+    let mut current = word.load(Ordering::Relaxed);
+    while let Some(new) = f(current) {
+        match word.compare_exchange_weak(current, new, Ordering::Relaxed, Ordering::Relaxed) {
+            Ok(_) => return,
+            Err(actual) => current = actual,
+        }
+    }
+}
+
+impl<T: Hash, H: BuildHasher, W: Word + IntoAtomic + CastableFrom<HashResult>>
+    HyperLogLog<T, H, W>
+{
+    /// Adds an element to an estimator whose backend is a slice of atomic
+    /// words.
+    ///
+    /// Registers are packed as in a standard backend, and only the register
+    /// associated with the element is updated, with a compare-and-swap loop
+    /// that stores the maximum between its current value and the candidate
+    /// value, so this method can be safely called concurrently on the same
+    /// backend. Registers straddling two words cannot be updated by a single
+    /// compare-and-swap: they are updated under one of a fixed set of spin
+    /// locks, chosen by address, while the other registers in the same words
+    /// are still updated lock-free. Backends updated concurrently should be
+    /// read using [`load_atomic`](HyperLogLog::load_atomic), which takes the
+    /// same locks, as otherwise a straddling register might be read between
+    /// the updates of its two words. It is the basis of
+    /// [`SyncEstimatorArrayAtomic`](super::SyncEstimatorArrayAtomic).
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `backend` is shorter than the [backend
+    /// length](SliceEstimationLogic::backend_len).
+    pub fn update_register_atomic(&self, backend: &[W::AtomicType], element: impl Borrow<T>) {
+        let (register, value) =
+            self.register_and_value(self.build_hasher.hash_one(element.borrow()));
+        let value = W::cast_from(value);
+        let bit_width = self.register_size;
+        let mask = W::MAX >> (W::BITS - bit_width);
+        let pos = register * bit_width;
+        let word_index = pos / W::BITS;
+        let bit_index = pos % W::BITS;
+
+        if bit_index + bit_width <= W::BITS {
+            update_atomic_word::<W>(&backend[word_index], |word| {
+                ((word >> bit_index) & mask < value)
+                    .then(|| (word & !(mask << bit_index)) | (value << bit_index))
+            });
+            return;
+        }
+
+        let (low, high) = (&backend[word_index], &backend[word_index + 1]);
+        with_straddling_lock(high, || {
+            // Under the lock, no other thread can modify the bits of the
+            // register, but the other bits of both words can still change
+            let current = ((low.load(Ordering::Relaxed) >> bit_index)
+                | (high.load(Ordering::Relaxed) << (W::BITS - bit_index)))
+                & mask;
+            if current < value {
+                update_atomic_word::<W>(low, |word| {
+                    Some((word & ((W::ONE << bit_index) - W::ONE)) | (value << bit_index))
+                });
+                update_atomic_word::<W>(high, |word| {
+                    Some(
+                        (word & !(mask >> (W::BITS - bit_index)))
+                            | (value >> (W::BITS - bit_index)),
+                    )
+                });
+            }
+        });
+    }
+}
+
+impl<T, H, W: Word + IntoAtomic> HyperLogLog<T, H, W> {
+    /// Copies a backend of atomic words, possibly updated concurrently by
+    /// [`update_register_atomic`](HyperLogLog::update_register_atomic), into
+    /// a standard backend.
+    ///
+    /// Words are loaded atomically, and registers straddling two words are
+    /// loaded under the same spin locks used for updates, so every register
+    /// of `dst` contains a value that the register actually had. The copy is
+    /// not a snapshot of the whole backend, as registers can be updated while
+    /// it is in progress.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `backend` or `dst` is shorter than the
+    /// [backend length](SliceEstimationLogic::backend_len).
+    pub fn load_atomic(&self, backend: &[W::AtomicType], dst: &mut [W]) {
+        let num_words = self.words_per_estimator;
+        for (word, atomic) in dst[..num_words].iter_mut().zip(&backend[..num_words]) {
+            *word = atomic.load(Ordering::Relaxed);
+        }
+
+        let bit_width = self.register_size;
+        let mask = W::MAX >> (W::BITS - bit_width);
+        for word_index in 0..num_words - 1 {
+            // The register containing the first bit of the next word
+            let boundary = (word_index + 1) * W::BITS;
+            if boundary % bit_width == 0 {
+                continue;
+            }
+            let bit_index = (boundary - boundary % bit_width) % W::BITS;
+            let (low, high) = (&backend[word_index], &backend[word_index + 1]);
+            let value = with_straddling_lock(high, || {
+                ((low.load(Ordering::Relaxed) >> bit_index)
+                    | (high.load(Ordering::Relaxed) << (W::BITS - bit_index)))
+                    & mask
+            });
+            dst[word_index] =
+                (dst[word_index] & ((W::ONE << bit_index) - W::ONE)) | (value << bit_index);
+            dst[word_index + 1] = (dst[word_index + 1] & !(mask >> (W::BITS - bit_index)))
+                | (value >> (W::BITS - bit_index));
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Hash, H: BuildHasher, W: Word + IntoAtomic + CastableFrom<HashResult>, S>
+    SyncSliceEstimatorArray<HyperLogLog<T, H, W>, W, S>
+where
+    S: AsRef<[SyncCell<W>]>,
+{
+    /// Adds an element to the estimator at `index` using
+    /// [`update_register_atomic`](HyperLogLog::update_register_atomic).
+    ///
+    /// Differently from the methods of
+    /// [`SyncEstimatorArray`](crate::traits::SyncEstimatorArray), this method
+    /// is safe, and it can be called concurrently on the same estimator.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `index` is out of bounds, or if the words of
+    /// the backend are not suitably aligned for atomic access (which can happen
+    /// only on platforms where the alignment of `W` is smaller than its size).
+    pub fn scatter_add_atomic(&self, index: usize, element: impl Borrow<T>) {
+        let backend_len = self.logic.words_per_estimator;
+        let backend = &self.backend.as_ref()[index * backend_len..][..backend_len];
+        let ptr = backend.as_ptr() as *const W::AtomicType;
+        assert!(
+            ptr.is_aligned(),
+            "the backend is not aligned for atomic access"
+        );
+        // SAFETY: SyncCell<W> is a transparent wrapper of W, which has the
+        // same size of its atomic type, the pointer is aligned, and all access
+        // to the words through this view are atomic
+        let backend = unsafe { std::slice::from_raw_parts(ptr, backend_len) };
+        self.logic.update_register_atomic(backend, element);
+    }
+}
+
 impl<
         T: Hash,
         H: BuildHasher + Clone,
@@ -153,41 +1291,41 @@ impl<
         )
     }
 
-    fn add(&self, mut backend: &mut Self::Backend, element: impl Borrow<T>) {
-        let x = self.build_hasher.hash_one(element.borrow());
-        let j = x & self.num_registers_minus_1;
-        let r =
-            ((x >> self.log_2_num_registers) | self.sentinel_mask).trailing_zeros() as HashResult;
-        let register = j as usize;
-
-        debug_assert!(r < (1 << self.register_size) - 1);
-        debug_assert!(register < self.num_registers);
-
-        let current_value = self.get_register_unchecked(&mut backend, register);
-        let candidate_value = r + 1;
-        let new_value = std::cmp::max(current_value, candidate_value.cast());
-        if current_value != new_value {
-            self.set_register_unchecked(backend, register, new_value);
-        }
+    fn add(&self, backend: &mut Self::Backend, element: impl Borrow<T>) {
+        self.add_hash(backend, self.build_hasher.hash_one(element.borrow()));
     }
 
-    fn estimate(&self, backend: &[W]) -> f64 {
-        let mut harmonic_mean = 0.0;
-        let mut zeroes = 0;
-
-        for i in 0..self.num_registers {
-            let value: u64 = self.get_register_unchecked(backend, i).upcast();
-            if value == 0 {
-                zeroes += 1;
+    fn add_many(&self, backend: &mut Self::Backend, elements: &[T]) {
+        // Hashes are computed in blocks, separately from register updates,
+        // so that the compiler can unroll and interleave their computation
+        const BLOCK_SIZE: usize = 8;
+        let mut hashes = [0; BLOCK_SIZE];
+        let mut blocks = elements.chunks_exact(BLOCK_SIZE);
+        for block in &mut blocks {
+            for (hash, element) in hashes.iter_mut().zip(block) {
+                *hash = self.build_hasher.hash_one(element);
+            }
+            for &hash in &hashes {
+                self.add_hash(backend, hash);
             }
-            harmonic_mean += 1.0 / (1_u64 << value) as f64;
         }
+        for element in blocks.remainder() {
+            self.add(backend, element);
+        }
+    }
 
-        let mut estimate = self.alpha_m_m / harmonic_mean;
-        if zeroes != 0 && estimate < 2.5 * self.num_registers as f64 {
-            estimate = self.num_registers as f64 * (self.num_registers as f64 / zeroes as f64).ln();
+    fn estimate(&self, backend: &[W]) -> f64 {
+        match self.estimation_mode {
+            EstimationMode::HarmonicMean => match self.bias_correction {
+                BiasCorrection::Standard => self.estimate_with_linear_counting_threshold(
+                    backend,
+                    Some(self.linear_counting_threshold),
+                ),
+                BiasCorrection::LogLogBeta => self.estimate_log_log_beta(backend),
+            },
+            EstimationMode::GeometricMean => self.estimate_geometric(backend),
+            EstimationMode::MaxLikelihood => self.estimate_max_likelihood(backend),
         }
-        estimate
     }
 
     fn clear(&self, backend: &mut [W]) {
@@ -206,6 +1344,17 @@ pub struct HyperLogLogHelper<W> {
     mask: Vec<W>,
 }
 
+impl<
+        T: Hash,
+        H: BuildHasher + Clone,
+        W: Word + UpcastableInto<HashResult> + CastableFrom<HashResult>,
+    > EstimationGuarantees for HyperLogLog<T, H, W>
+{
+    fn relative_std(&self) -> f64 {
+        HyperLogLog::rel_std(self.log_2_num_registers)
+    }
+}
+
 impl<
         T: Hash,
         H: BuildHasher + Clone,
@@ -234,15 +1383,220 @@ impl<
     }
 }
 
+impl<
+        T: Hash,
+        H: BuildHasher + Clone,
+        W: Word + UpcastableInto<HashResult> + CastableFrom<HashResult>,
+    > HyperLogLog<T, H, W>
+{
+    /// Returns the largest value a register can attain.
+    ///
+    /// The value is limited both by the register size and by the number of
+    /// bits of the hash that are not used to select a register.
+    pub fn max_register_value(&self) -> usize {
+        let max_trailing_zeros: usize = (1 << self.register_size) - 2;
+        std::cmp::min(
+            max_trailing_zeros,
+            HashResult::BITS as usize - self.log_2_num_registers,
+        ) + 1
+    }
+
+    /// Returns the cardinality above which estimates become unreliable
+    /// because of saturation.
+    ///
+    /// The result is 2<sup>*v*</sup>*m* / 30, where *v* is the [largest
+    /// value a register can attain](Self::max_register_value) and *m* is the
+    /// number of registers, which is a commonly cited heuristic limit for
+    /// HyperLogLog.
+    pub fn max_estimable_cardinality(&self) -> f64 {
+        (self.max_register_value() as f64).exp2() * self.num_registers as f64 / 30.0
+    }
+
+    /// Returns the fraction of registers of a given backend holding the
+    /// [largest value a register can attain](Self::max_register_value).
+    pub fn saturation_fraction(&self, backend: &[W]) -> f64 {
+        assert_eq!(backend.len(), self.words_per_estimator);
+        let max: HashResult = self.max_register_value() as HashResult;
+        (0..self.num_registers)
+            .filter(|&i| self.get_register_unchecked(backend, i).upcast() == max)
+            .count() as f64
+            / self.num_registers as f64
+    }
+
+    /// Returns whether a backend is saturated, that is, whether its estimate
+    /// is above the [maximum estimable
+    /// cardinality](Self::max_estimable_cardinality), or at least 99% of its
+    /// registers hold the [largest value a register can
+    /// attain](Self::max_register_value).
+    pub fn is_saturated(&self, backend: &[W]) -> bool {
+        self.estimate(backend) > self.max_estimable_cardinality()
+            || self.saturation_fraction(backend) >= 0.99
+    }
+
+    /// Returns the number of registers of `src` that are larger than the
+    /// corresponding registers of `dst`, that is, the number of registers
+    /// that a merge of `src` into `dst` would change.
+    pub fn count_new_registers(&self, dst: &[W], src: &[W]) -> usize {
+        debug_assert_eq!(dst.len(), self.words_per_estimator);
+        debug_assert_eq!(src.len(), self.words_per_estimator);
+        (0..self.num_registers)
+            .filter(|&i| self.get_register_unchecked(src, i) > self.get_register_unchecked(dst, i))
+            .count()
+    }
+
+    /// Merges `src` into `dst` only if the merge would change at least
+    /// `min_new_registers` registers.
+    ///
+    /// In iterative algorithms that are close to convergence most merges do
+    /// not change the destination; this method makes it possible to skip them,
+    /// or to skip merges bringing little information.
+    ///
+    /// Returns whether the merge was performed.
+    pub fn merge_if_significant(&self, dst: &mut [W], src: &[W], min_new_registers: usize) -> bool {
+        let mut helper = self.new_helper();
+        self.merge_if_significant_with_helper(dst, src, min_new_registers, &mut helper)
+    }
+
+    /// Merges `src` into `dst` only if the merge would change at least
+    /// `min_new_registers` registers, using the provided helper to avoid
+    /// allocations.
+    ///
+    /// Returns whether the merge was performed.
+    pub fn merge_if_significant_with_helper(
+        &self,
+        dst: &mut [W],
+        src: &[W],
+        min_new_registers: usize,
+        helper: &mut HyperLogLogHelper<W>,
+    ) -> bool {
+        let new_registers = self.count_new_registers(dst, src);
+        if new_registers == 0 || new_registers < min_new_registers {
+            return false;
+        }
+        self.merge_with_helper(dst, src, helper);
+        true
+    }
+
+    /// Merges `src` into `dst`, assuming that the registers of `src` are
+    /// nonincreasing, stopping as soon as the remaining registers of `src`
+    /// cannot change `dst`.
+    ///
+    /// Since the registers of `src` are nonincreasing, the maximum of the
+    /// registers of `src` from index *i* onwards is the register of index
+    /// *i*; if it is not larger than the minimum of the registers of `dst`
+    /// from index *i* onwards, no remaining register of `dst` would be
+    /// changed by the merge, and the method returns. Note that merging
+    /// backends with nonincreasing registers yields a backend with
+    /// nonincreasing registers.
+    ///
+    /// Returns `true` if the merge stopped before examining all registers,
+    /// that is, if at least one register was skipped.
+    ///
+    /// This method is slower than a [standard merge](MergeEstimationLogic::merge)
+    /// when it cannot stop early, as it needs a preliminary pass on `dst`.
+    pub fn merge_sorted(&self, dst: &mut [W], src: &[W]) -> bool {
+        debug_assert_eq!(dst.len(), self.words_per_estimator);
+        debug_assert_eq!(src.len(), self.words_per_estimator);
+        debug_assert!((1..self.num_registers).all(|i| {
+            self.get_register_unchecked(src, i - 1) >= self.get_register_unchecked(src, i)
+        }));
+
+        // Suffix minima of the registers of dst
+        let mut min_suffix = vec![W::ZERO; self.num_registers];
+        let mut min = W::MAX;
+        for i in (0..self.num_registers).rev() {
+            min = std::cmp::min(min, self.get_register_unchecked(&*dst, i));
+            min_suffix[i] = min;
+        }
+
+        for (i, &min) in min_suffix.iter().enumerate() {
+            let src_value = self.get_register_unchecked(src, i);
+            if src_value <= min {
+                return true;
+            }
+            if src_value > self.get_register_unchecked(&*dst, i) {
+                self.set_register_unchecked(&mut *dst, i, src_value);
+            }
+        }
+        false
+    }
+}
+
+impl<
+        T: Hash,
+        H: BuildHasher + Clone,
+        W: Word + UpcastableInto<HashResult> + CastableFrom<HashResult>,
+        S: AsMut<[W]>,
+    > SliceEstimatorArray<HyperLogLog<T, H, W>, W, S>
+{
+    /// Merges each estimator of `src` into the estimator with the same index
+    /// of this array, but only if the merge would change at least
+    /// `min_new_registers` registers.
+    ///
+    /// Returns the number of merges performed.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the two arrays have different lengths.
+    pub fn merge_array_if_significant<S2: AsRef<[W]>>(
+        &mut self,
+        src: &SliceEstimatorArray<HyperLogLog<T, H, W>, W, S2>,
+        min_new_registers: usize,
+    ) -> usize {
+        let backend_len = self.logic.backend_len();
+        let dst = self.backend.as_mut();
+        let src = src.backend.as_ref();
+        assert_eq!(
+            dst.len(),
+            src.len(),
+            "the arrays have different lengths ({} and {} estimators)",
+            dst.len() / backend_len,
+            src.len() / backend_len
+        );
+        let mut helper = self.logic.new_helper();
+        let mut merges = 0;
+        for (dst, src) in dst.chunks_mut(backend_len).zip(src.chunks(backend_len)) {
+            if self
+                .logic
+                .merge_if_significant_with_helper(dst, src, min_new_registers, &mut helper)
+            {
+                merges += 1;
+            }
+        }
+        merges
+    }
+}
+
 /// Builds a [`HyperLogLog`] cardinality-estimator logic.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct HyperLogLogBuilder<H, W = usize> {
     build_hasher: H,
     log_2_num_registers: usize,
     n: usize,
+    linear_counting_threshold: Option<f64>,
+    hyperloglog_plus_plus: bool,
+    estimation_mode: EstimationMode,
+    bias_correction: BiasCorrection,
     _marker: std::marker::PhantomData<(H, W)>,
 }
 
+// We implement Debug manually because we do not want to require that H is
+// Debug: the hasher is represented by its type name.
+impl<H, W> std::fmt::Debug for HyperLogLogBuilder<H, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HyperLogLogBuilder")
+            .field("build_hasher", &std::any::type_name::<H>())
+            .field("word_type", &std::any::type_name::<W>())
+            .field("log_2_num_registers", &self.log_2_num_registers)
+            .field("n", &self.n)
+            .field("linear_counting_threshold", &self.linear_counting_threshold)
+            .field("hyperloglog_plus_plus", &self.hyperloglog_plus_plus)
+            .field("estimation_mode", &self.estimation_mode)
+            .field("bias_correction", &self.bias_correction)
+            .finish()
+    }
+}
+
 impl HyperLogLogBuilder<BuildHasherDefault<DefaultHasher>, usize> {
     /// Creates a new builder for a [`HyperLogLog`] logic with a default word
     /// type of `usize`.
@@ -251,11 +1605,72 @@ impl HyperLogLogBuilder<BuildHasherDefault<DefaultHasher>, usize> {
             build_hasher: BuildHasherDefault::default(),
             log_2_num_registers: 4,
             n,
+            linear_counting_threshold: None,
+            hyperloglog_plus_plus: false,
+            estimation_mode: EstimationMode::HarmonicMean,
+            bias_correction: BiasCorrection::Standard,
             _marker: std::marker::PhantomData,
         }
     }
 }
 
+impl HyperLogLogBuilder<BuildHasherDefault<DefaultHasher>, usize> {
+    /// The largest logarithm of the number of registers per estimator
+    /// selected by [`from_rsd`](Self::from_rsd).
+    pub const MAX_LOG_2_NUM_REG_FOR_RSD: usize = 18;
+
+    /// Creates a new builder for a [`HyperLogLog`] logic whose number of
+    /// registers per estimator is the smallest one attaining a given
+    /// relative standard deviation.
+    ///
+    /// The logarithm of the number of registers is the smallest value *k* in
+    /// the range [4 . . [`MAX_LOG_2_NUM_REG_FOR_RSD`](Self::MAX_LOG_2_NUM_REG_FOR_RSD)]
+    /// such that [`HyperLogLog::achievable_rsd(k)`](HyperLogLog::achievable_rsd)
+    /// does not exceed `target_rsd`, that is, approximately, ⌈log₂((1.04 /
+    /// `target_rsd`)²)⌉. Since no upper bound on the number of elements is
+    /// given, it is set to `usize::MAX`; it can be changed with
+    /// [`num_elements`](Self::num_elements).
+    ///
+    /// # Arguments
+    /// * `target_rsd`: the relative standard deviation to be attained.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if `target_rsd` is not positive, or
+    /// if it is too small to be attained with at most
+    /// 2<sup>[`MAX_LOG_2_NUM_REG_FOR_RSD`](Self::MAX_LOG_2_NUM_REG_FOR_RSD)</sup>
+    /// registers.
+    pub fn from_rsd(target_rsd: f64) -> Result<Self> {
+        ensure!(
+            target_rsd > 0.0,
+            "the target relative standard deviation must be positive; got {}",
+            target_rsd
+        );
+        let log_2_num_reg = (4..=Self::MAX_LOG_2_NUM_REG_FOR_RSD)
+            .find(|&k| HyperLogLog::achievable_rsd(k) <= target_rsd)
+            .with_context(|| {
+                format!(
+                    "the target relative standard deviation {} is smaller than {}, which is the smallest supported",
+                    target_rsd,
+                    HyperLogLog::achievable_rsd(Self::MAX_LOG_2_NUM_REG_FOR_RSD)
+                )
+            })?;
+        Ok(Self::new(usize::MAX).log_2_num_reg(log_2_num_reg))
+    }
+
+    /// Creates a new builder for a [`HyperLogLog`] logic attaining a given
+    /// error rate.
+    ///
+    /// This is an alias for [`from_rsd`](Self::from_rsd).
+    ///
+    /// # Errors
+    ///
+    /// See [`from_rsd`](Self::from_rsd).
+    pub fn from_error_rate(error_rate: f64) -> Result<Self> {
+        Self::from_rsd(error_rate)
+    }
+}
+
 fn min_alignment(bits: usize) -> String {
     if bits % 128 == 0 {
         "u128"
@@ -272,6 +1687,11 @@ fn min_alignment(bits: usize) -> String {
 }
 
 impl HyperLogLog<(), (), ()> {
+    /// The z-score of a two-sided 95% confidence interval, to be used with
+    /// [`confidence_interval`](EstimationGuarantees::confidence_interval) and
+    /// [`estimate_with_ci`](crate::traits::Estimator::estimate_with_ci).
+    pub const NINETY_FIVE_PERCENT_Z: f64 = 1.96;
+
     /// Returns the logarithm of the number of registers per estimator that are
     /// necessary to attain a given relative standard deviation.
     ///
@@ -289,14 +1709,62 @@ impl HyperLogLog<(), (), ()> {
     /// * `log_2_num_registers`: the logarithm of the number of registers per
     ///   estimator.
     pub fn rel_std(log_2_num_registers: usize) -> f64 {
-        let tmp = match log_2_num_registers {
+        Self::achievable_rsd(log_2_num_registers)
+    }
+
+    /// Returns the relative standard deviation that can be achieved with a
+    /// given number of registers per estimator.
+    ///
+    /// This is the same as [`rel_std`](Self::rel_std), but being a `const
+    /// fn`, it can be used to compute the value at compile time.
+    ///
+    /// # Arguments
+    ///
+    /// * `log_2_num_reg`: the logarithm of the number of registers per
+    ///   estimator.
+    pub const fn achievable_rsd(log_2_num_reg: usize) -> f64 {
+        let tmp = match log_2_num_reg {
             4 => 1.106,
             5 => 1.070,
             6 => 1.054,
             7 => 1.046,
             _ => 1.04,
         };
-        tmp / ((1 << log_2_num_registers) as f64).sqrt()
+        // 1 / √(2^k) = 2^-⌊k/2⌋ (1 / √2)^(k mod 2)
+        let inv_sqrt = 1.0 / (1_u128 << (log_2_num_reg / 2)) as f64;
+        if log_2_num_reg % 2 == 0 {
+            tmp * inv_sqrt
+        } else {
+            tmp * inv_sqrt * std::f64::consts::FRAC_1_SQRT_2
+        }
+    }
+
+    /// Returns the smallest number of registers per estimator, which is a
+    /// power of two, whose [relative standard deviation](Self::rel_std) does
+    /// not exceed a given target.
+    ///
+    /// # Arguments
+    /// * `target_rsd`: the relative standard deviation to be attained.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `target_rsd` is not positive or cannot be
+    /// attained with less than 2⁶⁴ registers.
+    pub fn num_registers_for_rsd(target_rsd: f64) -> usize {
+        assert!(
+            target_rsd > 0.0,
+            "the target relative standard deviation must be positive"
+        );
+        let mut log_2_num_registers = 4;
+        while Self::rel_std(log_2_num_registers) > target_rsd {
+            log_2_num_registers += 1;
+            assert!(
+                log_2_num_registers < usize::BITS as usize,
+                "the target relative standard deviation {} cannot be attained",
+                target_rsd
+            );
+        }
+        1 << log_2_num_registers
     }
 
     /// Returns the register size in bits, given an upper bound on the number of
@@ -309,6 +1777,32 @@ impl HyperLogLog<(), (), ()> {
     }
 }
 
+/// Returns the number of bytes necessary to store the backends of
+/// `num_estimators` [`HyperLogLog`] estimators with a given relative standard
+/// deviation.
+///
+/// The number of registers is given by
+/// [`num_registers_for_rsd`](HyperLogLog::num_registers_for_rsd), and the
+/// registers are assumed to be large enough for any number of distinct
+/// elements representable by a `usize`. The result is thus an upper bound
+/// for arrays built with a [`HyperLogLogBuilder`] with the same relative
+/// standard deviation.
+///
+/// # Arguments
+///
+/// * `rsd`: the relative standard deviation to be attained.
+///
+/// * `num_estimators`: the number of estimators.
+///
+/// # Panics
+///
+/// This function will panic if `rsd` is not positive or cannot be attained
+/// with less than 2⁶⁴ registers.
+pub fn hll_memory_for_rsd(rsd: f64, num_estimators: usize) -> usize {
+    let bits = HyperLogLog::num_registers_for_rsd(rsd) * HyperLogLog::register_size(usize::MAX);
+    bits.div_ceil(8) * num_estimators
+}
+
 impl<H, W: Word> HyperLogLogBuilder<H, W> {
     /// Sets the desired relative standard deviation.
     ///
@@ -337,6 +1831,17 @@ impl<H, W: Word> HyperLogLogBuilder<H, W> {
         self
     }
 
+    /// Returns the number of bytes that will be used by the backends of an
+    /// array of `len` estimators using the logic built by this builder.
+    ///
+    /// The result does not depend on the hasher, and it is correct only if
+    /// the parameters of the builder are valid (i.e., if
+    /// [`build`](Self::build) does not fail).
+    pub fn estimated_array_memory(&self, len: usize) -> usize {
+        let bits = (1 << self.log_2_num_registers) * HyperLogLog::register_size(self.n);
+        bits.div_ceil(W::BITS) * size_of::<W>() * len
+    }
+
     /// Sets the type `W` to use to represent backends.
     ///
     /// See the [`logic documentation`](HyperLogLog) for the limitations on the
@@ -346,6 +1851,10 @@ impl<H, W: Word> HyperLogLogBuilder<H, W> {
             n: self.n,
             build_hasher: self.build_hasher,
             log_2_num_registers: self.log_2_num_registers,
+            linear_counting_threshold: self.linear_counting_threshold,
+            hyperloglog_plus_plus: self.hyperloglog_plus_plus,
+            estimation_mode: self.estimation_mode,
+            bias_correction: self.bias_correction,
             _marker: std::marker::PhantomData,
         }
     }
@@ -364,11 +1873,70 @@ impl<H, W: Word> HyperLogLogBuilder<H, W> {
         HyperLogLogBuilder {
             n: self.n,
             log_2_num_registers: self.log_2_num_registers,
+            linear_counting_threshold: self.linear_counting_threshold,
+            hyperloglog_plus_plus: self.hyperloglog_plus_plus,
+            estimation_mode: self.estimation_mode,
+            bias_correction: self.bias_correction,
             build_hasher,
             _marker: std::marker::PhantomData,
         }
     }
 
+    /// Sets the threshold on the raw estimate below which linear counting is
+    /// used.
+    ///
+    /// By default, the threshold is 2.5 times the number of registers, as in
+    /// the original HyperLogLog paper.
+    ///
+    /// ## Note
+    ///
+    /// This is a low-level alternative to [`Self::hyperloglog_plus_plus`].
+    /// Calling one after the other invalidates the work done by the first one.
+    ///
+    /// # Arguments
+    /// * `threshold`: the threshold below which linear counting is used.
+    pub fn linear_counting_threshold(mut self, threshold: f64) -> Self {
+        self.linear_counting_threshold = Some(threshold);
+        self.hyperloglog_plus_plus = false;
+        self
+    }
+
+    /// Sets whether to use the linear-counting threshold of HyperLogLog++,
+    /// that is, five times the number of registers.
+    ///
+    /// ## Note
+    ///
+    /// This is a high-level alternative to [`Self::linear_counting_threshold`].
+    /// Calling one after the other invalidates the work done by the first one.
+    pub fn hyperloglog_plus_plus(mut self, enable: bool) -> Self {
+        self.hyperloglog_plus_plus = enable;
+        self.linear_counting_threshold = None;
+        self
+    }
+
+    /// Sets the method used to compute estimates from the values of the
+    /// registers.
+    ///
+    /// The default is [`EstimationMode::HarmonicMean`]. The mode is used by
+    /// [`estimate`](EstimationLogic::estimate), but each method is also
+    /// available directly on the logic.
+    pub fn estimation_mode(mut self, mode: EstimationMode) -> Self {
+        self.estimation_mode = mode;
+        self
+    }
+
+    /// Sets the bias correction used with the [harmonic
+    /// mean](EstimationMode::HarmonicMean).
+    ///
+    /// The default is [`BiasCorrection::Standard`].
+    /// [`BiasCorrection::LogLogBeta`] is available only for 2<sup>4</sup> to
+    /// 2<sup>18</sup> registers; when it is selected, the [linear-counting
+    /// threshold](Self::linear_counting_threshold) is ignored.
+    pub fn bias_correction(mut self, bias_correction: BiasCorrection) -> Self {
+        self.bias_correction = bias_correction;
+        self
+    }
+
     /// Builds the logic.
     ///
     /// The type of objects the estimators keep track of is defined here by `T`,
@@ -377,7 +1945,8 @@ impl<H, W: Word> HyperLogLogBuilder<H, W> {
     /// # Errors
     ///
     /// Errors will be caused by consistency checks (at least 16 registers per
-    /// estimator, backend bits divisible exactly `W::BITS`)
+    /// estimator, at most 2<sup>18</sup> registers per estimator with the
+    /// LogLog-Beta bias correction, backend bits divisible exactly `W::BITS`)
     pub fn build<T>(self) -> Result<HyperLogLog<T, H, W>> {
         let log_2_num_registers = self.log_2_num_registers;
         let num_elements = self.n;
@@ -388,16 +1957,18 @@ impl<H, W: Word> HyperLogLogBuilder<H, W> {
             "the logarithm of the number of registers per estimator should be at least 4; got {}",
             log_2_num_registers
         );
+        ensure!(
+            self.bias_correction != BiasCorrection::LogLogBeta
+                || log_2_num_registers < 4 + LOG_LOG_BETA_COEFFICIENTS.len(),
+            "the LogLog-Beta bias correction requires at most 2^{} registers per estimator; got 2^{}",
+            3 + LOG_LOG_BETA_COEFFICIENTS.len(),
+            log_2_num_registers
+        );
 
         let number_of_registers = 1 << log_2_num_registers;
         let register_size = HyperLogLog::register_size(num_elements);
         let sentinel_mask = 1 << ((1 << register_size) - 2);
-        let alpha = match log_2_num_registers {
-            4 => 0.673,
-            5 => 0.697,
-            6 => 0.709,
-            _ => 0.7213 / (1.0 + 1.079 / number_of_registers as f64),
-        };
+        let alpha = alpha_m(number_of_registers);
         let num_registers_minus_1 = (number_of_registers - 1) as HashResult;
 
         let est_size_in_bits = number_of_registers * register_size;
@@ -420,11 +1991,17 @@ impl<H, W: Word> HyperLogLogBuilder<H, W> {
         }
 
         Ok(HyperLogLog {
+            num_elements,
             num_registers: number_of_registers,
             num_registers_minus_1,
             log_2_num_registers,
             register_size,
             alpha_m_m: alpha * (number_of_registers as f64).powi(2),
+            linear_counting_threshold: self.linear_counting_threshold.unwrap_or(
+                if self.hyperloglog_plus_plus { 5.0 } else { 2.5 } * number_of_registers as f64,
+            ),
+            estimation_mode: self.estimation_mode,
+            bias_correction: self.bias_correction,
             sentinel_mask,
             build_hasher: self.build_hasher,
             msb_mask: msb.as_slice().into(),
@@ -435,15 +2012,79 @@ impl<H, W: Word> HyperLogLogBuilder<H, W> {
     }
 }
 
+impl<H: BuildHasher + Clone, W: Word + UpcastableInto<HashResult> + CastableFrom<HashResult>>
+    HyperLogLogBuilder<H, W>
+{
+    /// Builds the logic and an array of empty estimators using it.
+    ///
+    /// This is equivalent to calling [`build`](Self::build) and passing the
+    /// result to [`SliceEstimatorArray::new`].
+    ///
+    /// # Arguments
+    /// * `len`: the number of the estimators in the array.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`build`](Self::build).
+    pub fn build_array<T: Hash>(self, len: usize) -> Result<HyperLogLogArray<T, H, W>> {
+        Ok(SliceEstimatorArray::new(self.build()?, len))
+    }
+
+    /// Builds the logic and an array of empty estimators using it that can
+    /// be shared between threads.
+    ///
+    /// This is equivalent to calling [`build`](Self::build) and passing the
+    /// result to [`OwnedSyncSliceEstimatorArray::new`].
+    ///
+    /// # Arguments
+    /// * `len`: the number of the estimators in the array.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`build`](Self::build).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn build_sync_array<T: Hash>(
+        self,
+        len: usize,
+    ) -> Result<OwnedSyncSliceEstimatorArray<HyperLogLog<T, H, W>, W>> {
+        Ok(OwnedSyncSliceEstimatorArray::new(self.build()?, len))
+    }
+}
+
 impl<T, H, W> std::fmt::Display for HyperLogLog<T, H, W> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "HyperLogLog with relative standard deviation: {}% ({} registers/estimator, {} bits/register, {} bytes/estimator)",
+            "HyperLogLog with relative standard deviation: {}% (2^{} registers/estimator, {} bits/register, {} bytes/estimator, words of type {}, hasher of type {})",
             100.0 * HyperLogLog::rel_std(self.log_2_num_registers),
-            self.num_registers,
+            self.log_2_num_registers,
             self.register_size,
-            (self.num_registers * self.register_size) / 8
+            (self.num_registers * self.register_size) / 8,
+            std::any::type_name::<W>(),
+            std::any::type_name::<H>()
+        )
+    }
+}
+
+impl<H, W> std::fmt::Display for HyperLogLogBuilder<H, W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "HyperLogLogBuilder with 2^{} registers/estimator, at most {} elements, ",
+            self.log_2_num_registers, self.n
+        )?;
+        match (self.linear_counting_threshold, self.hyperloglog_plus_plus) {
+            (Some(threshold), _) => write!(f, "linear-counting threshold {}, ", threshold)?,
+            (None, true) => write!(f, "HyperLogLog++ linear-counting threshold, ")?,
+            (None, false) => write!(f, "default linear-counting threshold, ")?,
+        }
+        write!(
+            f,
+            "estimation mode {:?}, bias correction {:?}, words of type {}, hasher of type {}",
+            self.estimation_mode,
+            self.bias_correction,
+            std::any::type_name::<W>(),
+            std::any::type_name::<H>()
         )
     }
 }
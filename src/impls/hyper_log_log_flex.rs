@@ -0,0 +1,200 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::{ensure, Result};
+use common_traits::{CastableFrom, UpcastableInto};
+use std::borrow::Borrow;
+use std::hash::*;
+use sux::traits::Word;
+
+use super::{alpha_m, DefaultEstimator};
+use crate::traits::{
+    EstimationGuarantees, EstimationLogic, MergeEstimationLogic, SliceEstimationLogic,
+};
+
+/// Returns the bias-correction constant α<sub>*m*</sub>.
+///
+/// For *m* ≥ 128 we use [`alpha_m`]; below, we interpolate linearly its
+/// values for 16, 32, 64, and 128.
+fn alpha(num_registers: usize) -> f64 {
+    const ANCHORS: [usize; 4] = [16, 32, 64, 128];
+    if num_registers >= 128 {
+        return alpha_m(num_registers);
+    }
+    if num_registers <= 16 {
+        return alpha_m(16);
+    }
+    let i = ANCHORS.iter().position(|&x| x > num_registers).unwrap();
+    let (x0, x1) = (ANCHORS[i - 1], ANCHORS[i]);
+    let (y0, y1) = (alpha_m(x0), alpha_m(x1));
+    y0 + (y1 - y0) * (num_registers - x0) as f64 / (x1 - x0) as f64
+}
+
+/// Estimator logic implementing the HyperLogLog algorithm with an arbitrary
+/// number of registers.
+///
+/// Differently from [`HyperLogLog`](super::HyperLogLog), the number of
+/// registers does not need to be a power of two, so that the size of the
+/// estimators can be adapted to a memory budget. The register associated
+/// with an element is the upper half of its hash modulo the number of
+/// registers, and the value is computed from the lower half, so
+/// registers contain at most 33, and any unsigned type (usually `u8`) is
+/// large enough for `W`. Each register is stored in a separate word, so the
+/// [backend length](SliceEstimationLogic::backend_len) is equal to the number
+/// of registers.
+///
+/// The estimate uses the usual harmonic mean of the registers, with linear
+/// counting for small cardinalities, and the relative standard deviation is
+/// 1.04 / √*m*, where *m* is the number of registers. For fewer than 16
+/// registers, the bias correction is less accurate.
+#[derive(Debug, PartialEq)]
+pub struct HyperLogLogFlex<T, H, W> {
+    build_hasher: H,
+    num_registers: usize,
+    alpha_m_m: f64,
+    _marker: std::marker::PhantomData<(T, W)>,
+}
+
+// We implement Clone manually because we do not want to require that T and W
+// are Clone.
+impl<T, H: Clone, W> Clone for HyperLogLogFlex<T, H, W> {
+    fn clone(&self) -> Self {
+        Self {
+            build_hasher: self.build_hasher.clone(),
+            num_registers: self.num_registers,
+            alpha_m_m: self.alpha_m_m,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, H, W> HyperLogLogFlex<T, H, W> {
+    /// Creates a new logic with the given number of registers.
+    ///
+    /// # Arguments
+    /// * `num_registers`: the number of registers per estimator.
+    /// * `build_hasher`: the [`BuildHasher`] used to hash elements.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if `num_registers` is zero or larger
+    /// than 2³².
+    pub fn new(num_registers: usize, build_hasher: H) -> Result<Self> {
+        ensure!(
+            num_registers > 0,
+            "the number of registers must be positive"
+        );
+        ensure!(
+            num_registers as u64 <= 1 << 32,
+            "the number of registers cannot be larger than 2^32"
+        );
+        let m = num_registers as f64;
+        Ok(Self {
+            build_hasher,
+            num_registers,
+            alpha_m_m: alpha(num_registers) * m * m,
+            _marker: std::marker::PhantomData,
+        })
+    }
+
+    /// Returns the number of registers per estimator.
+    pub fn num_registers(&self) -> usize {
+        self.num_registers
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, W> SliceEstimationLogic<W> for HyperLogLogFlex<T, H, W>
+where
+    W: Word + UpcastableInto<u64> + CastableFrom<u64>,
+{
+    fn backend_len(&self) -> usize {
+        self.num_registers
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, W> EstimationLogic for HyperLogLogFlex<T, H, W>
+where
+    W: Word + UpcastableInto<u64> + CastableFrom<u64>,
+{
+    type Item = T;
+    type Backend = [W];
+    type Estimator<'a>
+        = DefaultEstimator<Self, &'a Self, Box<[W]>>
+    where
+        T: 'a,
+        W: 'a,
+        H: 'a;
+
+    fn new_estimator(&self) -> Self::Estimator<'_> {
+        DefaultEstimator::new(self, vec![W::ZERO; self.num_registers].into_boxed_slice())
+    }
+
+    fn add(&self, backend: &mut [W], element: impl Borrow<T>) {
+        debug_assert_eq!(backend.len(), self.num_registers);
+        let x = self.build_hasher.hash_one(element.borrow());
+        let register = ((x >> 32) % self.num_registers as u64) as usize;
+        let r = ((x & 0xFFFF_FFFF) | (1 << 32)).trailing_zeros() as u64;
+        let candidate_value = W::cast_from(r + 1);
+        let current_value = &mut backend[register];
+        if *current_value < candidate_value {
+            *current_value = candidate_value;
+        }
+    }
+
+    fn estimate(&self, backend: &[W]) -> f64 {
+        debug_assert_eq!(backend.len(), self.num_registers);
+        let mut harmonic_mean = 0.0;
+        let mut zeroes = 0;
+        for &register in backend {
+            let value: u64 = register.upcast();
+            if value == 0 {
+                zeroes += 1;
+            }
+            harmonic_mean += 1.0 / (1_u64 << value) as f64;
+        }
+
+        let m = self.num_registers as f64;
+        let mut estimate = self.alpha_m_m / harmonic_mean;
+        if zeroes != 0 && estimate < 2.5 * m {
+            estimate = m * (m / zeroes as f64).ln();
+        }
+        estimate
+    }
+
+    fn clear(&self, backend: &mut [W]) {
+        backend.fill(W::ZERO);
+    }
+
+    fn set(&self, dst: &mut [W], src: &[W]) {
+        debug_assert_eq!(dst.len(), src.len());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, W> MergeEstimationLogic for HyperLogLogFlex<T, H, W>
+where
+    W: Word + UpcastableInto<u64> + CastableFrom<u64>,
+{
+    type Helper = ();
+
+    fn new_helper(&self) -> Self::Helper {}
+
+    fn merge_with_helper(&self, dst: &mut [W], src: &[W], _helper: &mut Self::Helper) {
+        debug_assert_eq!(dst.len(), src.len());
+        for (d, &s) in dst.iter_mut().zip(src) {
+            *d = std::cmp::max(*d, s);
+        }
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, W> EstimationGuarantees for HyperLogLogFlex<T, H, W>
+where
+    W: Word + UpcastableInto<u64> + CastableFrom<u64>,
+{
+    fn relative_std(&self) -> f64 {
+        1.04 / (self.num_registers as f64).sqrt()
+    }
+}
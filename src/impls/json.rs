@@ -0,0 +1,69 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::SliceEstimatorArray;
+use crate::traits::SliceEstimationLogic;
+use std::io::{Result, Write};
+
+/// Writes an estimate as a JSON value.
+///
+/// The [`Display`](std::fmt::Display) implementation of `f64` writes the
+/// shortest representation that parses back to the same value, but non-finite
+/// values have no JSON representation, so they are written as `null`.
+fn write_estimate(writer: &mut impl Write, estimate: f64) -> Result<()> {
+    if estimate.is_finite() {
+        write!(writer, "{}", estimate)
+    } else {
+        write!(writer, "null")
+    }
+}
+
+impl<L: SliceEstimationLogic<W>, W, S: AsRef<[W]>> SliceEstimatorArray<L, W, S> {
+    /// Writes the estimates of the array as a JSON array.
+    ///
+    /// Estimates are computed and written one at a time, so no memory is
+    /// allocated. Non-finite estimates are written as `null`.
+    pub fn write_estimates_json(&self, writer: &mut impl Write) -> Result<()> {
+        write!(writer, "[")?;
+        for (i, backend) in self
+            .backend
+            .as_ref()
+            .chunks(self.logic.backend_len())
+            .enumerate()
+        {
+            if i != 0 {
+                write!(writer, ",")?;
+            }
+            write_estimate(writer, self.logic.estimate(backend))?;
+        }
+        write!(writer, "]")
+    }
+
+    /// Writes the estimates of the array as a JSON array, with an estimate
+    /// per line indented by two spaces.
+    ///
+    /// This is a human-readable version of
+    /// [`write_estimates_json`](SliceEstimatorArray::write_estimates_json).
+    pub fn write_estimates_json_pretty(&self, writer: &mut impl Write) -> Result<()> {
+        if self.is_empty() {
+            return write!(writer, "[]");
+        }
+        writeln!(writer, "[")?;
+        for (i, backend) in self
+            .backend
+            .as_ref()
+            .chunks(self.logic.backend_len())
+            .enumerate()
+        {
+            if i != 0 {
+                writeln!(writer, ",")?;
+            }
+            write!(writer, "  ")?;
+            write_estimate(writer, self.logic.estimate(backend))?;
+        }
+        write!(writer, "\n]")
+    }
+}
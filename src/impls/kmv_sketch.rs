@@ -0,0 +1,269 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::{ensure, Result};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::*;
+
+use super::DefaultEstimator;
+use crate::traits::{
+    EstimationGuarantees, EstimationLogic, MergeEstimationLogic, SliceEstimationLogic,
+};
+
+/// Estimator logic implementing *k*-minimum-values sketches (also known as
+/// bottom-*k* sketches or MinCount).
+///
+/// A backend keeps track of the *k* smallest distinct hashes of the elements
+/// added so far. If fewer than *k* distinct hashes have been seen, the
+/// estimate is their number, which is exact up to hash collisions; otherwise,
+/// it is (*k* − 1) / *u*, where *u* is the largest retained hash mapped to
+/// (0..1], whose relative standard deviation is 1 / √(*k* − 2). For *k* = 1
+/// the estimate is 1 / *u* − 1, as for [`MinHash`](super::MinHash).
+///
+/// Differently from [`MinHash`](super::MinHash), a single hash function is
+/// used, so adding an element costs O(log *k*) comparisons plus a shift in the
+/// worst case, and O(1) once the backend is full and the hash is too large.
+/// The retained hashes also provide an estimate of the [Jaccard
+/// similarity](KMVSketch::jaccard) of two sets.
+///
+/// To make empty backends all-zero, as required, for example, by
+/// [`SliceEstimatorArray`](super::SliceEstimatorArray), words contain the
+/// bitwise complement of the hashes, sorted in decreasing order, followed by
+/// zeroes for unused slots. The hash `u64::MAX`, whose complement would be
+/// zero, is replaced by `u64::MAX` − 1.
+///
+/// Instances are created using a [`KMVSketchBuilder`].
+#[derive(Debug, PartialEq)]
+pub struct KMVSketch<T, H> {
+    build_hasher: H,
+    k: usize,
+    _marker: std::marker::PhantomData<T>,
+}
+
+// We implement Clone manually because we do not want to require that T is
+// Clone.
+impl<T, H: Clone> Clone for KMVSketch<T, H> {
+    fn clone(&self) -> Self {
+        Self {
+            build_hasher: self.build_hasher.clone(),
+            k: self.k,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Merges the nonzero prefixes of two decreasing sequences of words into `dst`,
+/// removing duplicates and stopping after `dst.len()` words. Unused words
+/// of `dst` are set to zero.
+pub(super) fn merge_sorted(dst: &mut [u64], a: &[u64], b: &[u64]) {
+    let (mut i, mut j) = (0, 0);
+    for word in dst.iter_mut() {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(j).copied().unwrap_or(0);
+        *word = match x.cmp(&y) {
+            Ordering::Greater => {
+                i += 1;
+                x
+            }
+            Ordering::Less => {
+                j += 1;
+                y
+            }
+            Ordering::Equal => {
+                i += 1;
+                j += 1;
+                x
+            }
+        };
+    }
+}
+
+impl<T, H> KMVSketch<T, H> {
+    /// Returns the number of hashes kept by a backend, which is also its
+    /// length.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Returns the number of hashes currently retained by a backend.
+    pub fn num_retained(&self, backend: &[u64]) -> usize {
+        debug_assert_eq!(backend.len(), self.k);
+        backend.partition_point(|&word| word != 0)
+    }
+
+    /// Returns an estimate of the Jaccard similarity of the sets represented
+    /// by two backends.
+    ///
+    /// The estimate is the fraction of the *k* smallest hashes of the union of
+    /// the two sets that appear in both backends. Two empty backends have
+    /// similarity one.
+    pub fn jaccard(&self, a: &[u64], b: &[u64]) -> f64 {
+        debug_assert_eq!(a.len(), self.k);
+        debug_assert_eq!(b.len(), self.k);
+        let mut union = vec![0; self.k];
+        merge_sorted(&mut union, a, b);
+        let union = &union[..self.num_retained(&union)];
+        if union.is_empty() {
+            return 1.0;
+        }
+        // Both sequences are decreasing, so we can search with reversed order
+        let contains = |s: &[u64], x: &u64| s.binary_search_by(|y| x.cmp(y)).is_ok();
+        let common = union
+            .iter()
+            .filter(|x| contains(a, x) && contains(b, x))
+            .count();
+        common as f64 / union.len() as f64
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> SliceEstimationLogic<u64> for KMVSketch<T, H> {
+    fn backend_len(&self) -> usize {
+        self.k
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> EstimationLogic for KMVSketch<T, H> {
+    type Item = T;
+    type Backend = [u64];
+    type Estimator<'a>
+        = DefaultEstimator<Self, &'a Self, Box<[u64]>>
+    where
+        T: 'a,
+        H: 'a;
+
+    fn new_estimator(&self) -> Self::Estimator<'_> {
+        DefaultEstimator::new(self, vec![0; self.k].into_boxed_slice())
+    }
+
+    fn add(&self, backend: &mut [u64], element: impl Borrow<T>) {
+        debug_assert_eq!(backend.len(), self.k);
+        let hash = self.build_hasher.hash_one(element.borrow());
+        // Words contain the complement of the hash
+        let word = !hash.min(u64::MAX - 1);
+        if word <= backend[self.k - 1] {
+            // The backend is full and the hash is too large (or already there)
+            return;
+        }
+        // Words are in decreasing order, so we search with reversed order
+        if let Err(pos) = backend.binary_search_by(|x| word.cmp(x)) {
+            backend[pos..].rotate_right(1);
+            backend[pos] = word;
+        }
+    }
+
+    fn estimate(&self, backend: &[u64]) -> f64 {
+        let retained = self.num_retained(backend);
+        if retained < self.k {
+            return retained as f64;
+        }
+        // The largest retained hash, mapped to (0..1]
+        let u = ((!backend[self.k - 1]) as f64 + 1.0) / 2.0_f64.powi(64);
+        if self.k == 1 {
+            1.0 / u - 1.0
+        } else {
+            (self.k - 1) as f64 / u
+        }
+    }
+
+    fn clear(&self, backend: &mut [u64]) {
+        backend.fill(0);
+    }
+
+    fn set(&self, dst: &mut [u64], src: &[u64]) {
+        debug_assert_eq!(dst.len(), src.len());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> MergeEstimationLogic for KMVSketch<T, H> {
+    type Helper = Vec<u64>;
+
+    fn new_helper(&self) -> Self::Helper {
+        vec![0; self.k]
+    }
+
+    fn merge_with_helper(&self, dst: &mut [u64], src: &[u64], helper: &mut Self::Helper) {
+        debug_assert_eq!(dst.len(), src.len());
+        debug_assert_eq!(helper.len(), self.k);
+        merge_sorted(helper, dst, src);
+        dst.copy_from_slice(helper);
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> EstimationGuarantees for KMVSketch<T, H> {
+    fn relative_std(&self) -> f64 {
+        if self.k > 2 {
+            1.0 / ((self.k - 2) as f64).sqrt()
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+/// Builds a [`KMVSketch`] logic.
+#[derive(Debug, Clone)]
+pub struct KMVSketchBuilder<H> {
+    build_hasher: H,
+    k: usize,
+}
+
+impl KMVSketchBuilder<BuildHasherDefault<DefaultHasher>> {
+    /// Creates a new builder for a [`KMVSketch`] logic keeping 128 hashes.
+    pub fn new() -> Self {
+        Self {
+            build_hasher: BuildHasherDefault::default(),
+            k: 128,
+        }
+    }
+}
+
+impl Default for KMVSketchBuilder<BuildHasherDefault<DefaultHasher>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H> KMVSketchBuilder<H> {
+    /// Sets the number of hashes to keep.
+    ///
+    /// # Arguments
+    /// * `k`: the number of smallest hashes to keep, which is also the length
+    ///   of a backend.
+    pub fn k(mut self, k: usize) -> Self {
+        self.k = k;
+        self
+    }
+
+    /// Sets the [`BuildHasher`] to use.
+    ///
+    /// Using this method you can select a specific hashed based on one or more
+    /// seeds.
+    pub fn build_hasher<H2>(self, build_hasher: H2) -> KMVSketchBuilder<H2> {
+        KMVSketchBuilder {
+            build_hasher,
+            k: self.k,
+        }
+    }
+
+    /// Builds the logic.
+    ///
+    /// The type of objects the estimators keep track of is defined here by `T`,
+    /// but it is usually inferred by the compiler.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the number of hashes is zero.
+    pub fn build<T>(self) -> Result<KMVSketch<T, H>> {
+        ensure!(self.k > 0, "the number of hashes must be positive");
+        Ok(KMVSketch {
+            build_hasher: self.build_hasher,
+            k: self.k,
+            _marker: std::marker::PhantomData,
+        })
+    }
+}
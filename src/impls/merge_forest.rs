@@ -0,0 +1,169 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::SliceEstimatorArray;
+use crate::traits::*;
+use std::borrow::Borrow;
+use sux::traits::Word;
+
+/// A structure answering union queries on ranges of estimators using
+/// precomputed merges, in the spirit of a segment tree.
+///
+/// The forest contains a copy of the estimators of an array (the *leaves*)
+/// and, for each *k* > 0, the merge of each aligned interval of 2<sup>*k*</sup>
+/// estimators. A [query on a range](MergeForest::query_union) merges
+/// O(log *n*) precomputed backends.
+///
+/// [Updates](MergeForest::update) modify a leaf and mark as invalid the
+/// intervals containing it, which are recomputed lazily when a query needs
+/// them.
+pub struct MergeForest<L, W> {
+    logic: L,
+    /// The backends of level k, concatenated; level 0 contains the leaves.
+    levels: Vec<Box<[W]>>,
+    /// Whether the backends of level k must be recomputed.
+    dirty: Vec<Vec<bool>>,
+    len: usize,
+}
+
+impl<L: SliceEstimationLogic<W> + MergeEstimationLogic + Clone, W: Word> MergeForest<L, W> {
+    /// Creates a new merge forest containing the estimators of an array.
+    pub fn new<S: AsRef<[W]>>(array: &SliceEstimatorArray<L, W, S>) -> Self {
+        let logic = array.logic().clone();
+        let backend_len = logic.backend_len();
+        let len = array.len();
+        let mut helper = logic.new_helper();
+        let mut levels: Vec<Box<[W]>> = vec![array.as_ref().into()];
+        let mut dirty = vec![vec![false; len]];
+        let mut level_len = len / 2;
+        while level_len > 0 {
+            let prev = levels.last().unwrap();
+            let mut level = vec![W::ZERO; level_len * backend_len].into_boxed_slice();
+            for (i, backend) in level.chunks_mut(backend_len).enumerate() {
+                backend.copy_from_slice(&prev[2 * i * backend_len..][..backend_len]);
+                logic.merge_with_helper(
+                    backend,
+                    &prev[(2 * i + 1) * backend_len..][..backend_len],
+                    &mut helper,
+                );
+            }
+            levels.push(level);
+            dirty.push(vec![false; level_len]);
+            level_len /= 2;
+        }
+
+        Self {
+            logic,
+            levels,
+            dirty,
+            len,
+        }
+    }
+
+    /// Returns the number of leaves.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no leaves.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the logic of the estimators.
+    pub fn logic(&self) -> &L {
+        &self.logic
+    }
+
+    /// Adds an element to the leaf at the given index.
+    pub fn update(&mut self, index: usize, element: impl Borrow<L::Item>) {
+        let backend_len = self.logic.backend_len();
+        self.logic.add(
+            &mut self.levels[0][index * backend_len..][..backend_len],
+            element,
+        );
+        for (k, dirty) in self.dirty.iter_mut().enumerate().skip(1) {
+            if let Some(flag) = dirty.get_mut(index >> k) {
+                *flag = true;
+            }
+        }
+    }
+
+    /// Recomputes, if necessary, the backend of index `i` at level `k`.
+    fn refresh(&mut self, k: usize, i: usize, helper: &mut L::Helper) {
+        if !self.dirty[k][i] {
+            return;
+        }
+        self.refresh(k - 1, 2 * i, helper);
+        self.refresh(k - 1, 2 * i + 1, helper);
+        let backend_len = self.logic.backend_len();
+        let (lower, upper) = self.levels.split_at_mut(k);
+        let children = &lower[k - 1][2 * i * backend_len..][..2 * backend_len];
+        let backend = &mut upper[0][i * backend_len..][..backend_len];
+        backend.copy_from_slice(&children[..backend_len]);
+        self.logic
+            .merge_with_helper(backend, &children[backend_len..], helper);
+        self.dirty[k][i] = false;
+    }
+
+    /// Merges into `dst` the leaves with index in the range `from..to`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `from > to` or `to` is larger than the
+    /// number of leaves.
+    pub fn merge_range(&mut self, from: usize, to: usize, dst: &mut [W]) {
+        assert!(
+            from <= to && to <= self.len,
+            "invalid range {}..{} ({} leaves)",
+            from,
+            to,
+            self.len
+        );
+        let backend_len = self.logic.backend_len();
+        let mut helper = self.logic.new_helper();
+        let (mut l, mut r) = (from, to);
+        let mut k = 0;
+        while l < r {
+            if l % 2 == 1 {
+                self.refresh(k, l, &mut helper);
+                self.logic.merge_with_helper(
+                    dst,
+                    &self.levels[k][l * backend_len..][..backend_len],
+                    &mut helper,
+                );
+                l += 1;
+            }
+            if r % 2 == 1 {
+                r -= 1;
+                self.refresh(k, r, &mut helper);
+                self.logic.merge_with_helper(
+                    dst,
+                    &self.levels[k][r * backend_len..][..backend_len],
+                    &mut helper,
+                );
+            }
+            l /= 2;
+            r /= 2;
+            k += 1;
+        }
+    }
+
+    /// Returns an estimation of the number of distinct elements in the union
+    /// of the leaves with index in the range `from..to`.
+    ///
+    /// If the range is empty, this method returns zero.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `from > to` or `to` is larger than the
+    /// number of leaves.
+    pub fn query_union(&mut self, from: usize, to: usize) -> f64 {
+        let mut union = vec![W::ZERO; self.logic.backend_len()];
+        self.merge_range(from, to, &mut union);
+        self.logic.estimate(&union)
+    }
+}
@@ -0,0 +1,180 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::SliceEstimatorArray;
+use crate::traits::*;
+use anyhow::{bail, ensure, Result};
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+use sux::traits::Word;
+
+/// A precomputed schedule of merges along a directed acyclic graph.
+///
+/// Given a DAG, executing the plan on an array of estimators (one per node)
+/// merges into the estimator of each node the estimators of its
+/// predecessors, after they have been themselves updated. At the end, the
+/// estimator of each node contains the union of the estimators of all the
+/// nodes from which it is reachable (including itself).
+///
+/// The nodes are divided into *layers*: the first layer contains the nodes
+/// without predecessors, and each subsequent layer contains the nodes whose
+/// predecessors are all in previous layers. Nodes in the same layer are
+/// independent, and can thus be [processed in
+/// parallel](MergePlan::execute_par).
+#[derive(Debug, Clone)]
+pub struct MergePlan {
+    num_nodes: usize,
+    /// The layers, in order.
+    layers: Vec<Vec<usize>>,
+    /// The predecessors of each node.
+    preds: Vec<Vec<usize>>,
+}
+
+impl MergePlan {
+    /// Creates a merge plan for a DAG.
+    ///
+    /// # Arguments
+    /// * `edges`: the arcs of the DAG; an arc `(u, v)` means that the
+    ///   estimator of `u` will be merged into the estimator of `v`.
+    /// * `num_nodes`: the number of nodes of the DAG.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if an arc has an endpoint not
+    /// smaller than `num_nodes`, or if the graph contains a cycle.
+    pub fn from_dag(edges: &[(usize, usize)], num_nodes: usize) -> Result<Self> {
+        let mut preds = vec![vec![]; num_nodes];
+        let mut succs = vec![vec![]; num_nodes];
+        let mut indegree = vec![0_usize; num_nodes];
+        for &(u, v) in edges {
+            ensure!(
+                u < num_nodes && v < num_nodes,
+                "arc ({}, {}) out of bounds ({} nodes)",
+                u,
+                v,
+                num_nodes
+            );
+            preds[v].push(u);
+            succs[u].push(v);
+            indegree[v] += 1;
+        }
+
+        // Kahn's algorithm, one layer at a time
+        let mut layers = vec![];
+        let mut layer = (0..num_nodes)
+            .filter(|&v| indegree[v] == 0)
+            .collect::<Vec<_>>();
+        let mut visited = 0;
+        while !layer.is_empty() {
+            visited += layer.len();
+            let mut next = vec![];
+            for &u in &layer {
+                for &v in &succs[u] {
+                    indegree[v] -= 1;
+                    if indegree[v] == 0 {
+                        next.push(v);
+                    }
+                }
+            }
+            layers.push(layer);
+            layer = next;
+        }
+        if visited != num_nodes {
+            bail!("the graph contains a cycle");
+        }
+
+        Ok(Self {
+            num_nodes,
+            layers,
+            preds,
+        })
+    }
+
+    /// Returns the number of nodes of the DAG.
+    pub fn num_nodes(&self) -> usize {
+        self.num_nodes
+    }
+
+    /// Returns the layers of the plan.
+    pub fn layers(&self) -> &[Vec<usize>] {
+        &self.layers
+    }
+
+    /// Executes the plan on an array of estimators.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the length of the array is not equal to the
+    /// number of nodes of the DAG.
+    pub fn execute<L: SliceEstimationLogic<W> + MergeEstimationLogic, W, S: AsMut<[W]>>(
+        &self,
+        array: &mut SliceEstimatorArray<L, W, S>,
+    ) {
+        self.check_len(array.as_mut().len() / array.logic.backend_len());
+        for layer in &self.layers {
+            for &v in layer {
+                array.merge_range_into(v, self.preds[v].iter().copied());
+            }
+        }
+    }
+
+    fn check_len(&self, len: usize) {
+        assert_eq!(
+            len, self.num_nodes,
+            "the array has {} estimators, but the DAG has {} nodes",
+            len, self.num_nodes
+        );
+    }
+}
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+impl MergePlan {
+    /// Executes the plan on an array of estimators, processing in parallel
+    /// the nodes of each layer.
+    ///
+    /// This is a parallel version of [`execute`](MergePlan::execute). The
+    /// new backends of the nodes of a layer are computed in parallel in a
+    /// temporary buffer, and then copied into the array.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the length of the array is not equal to the
+    /// number of nodes of the DAG.
+    pub fn execute_par<
+        L: SliceEstimationLogic<W> + MergeEstimationLogic + Clone + Sync,
+        W: Word,
+        S: AsRef<[W]> + AsMut<[W]> + Sync,
+    >(
+        &self,
+        array: &mut SliceEstimatorArray<L, W, S>,
+    ) {
+        use rayon::prelude::*;
+        self.check_len(array.len());
+        let backend_len = array.logic().backend_len();
+        let mut buffer = vec![];
+        for layer in &self.layers {
+            buffer.resize(layer.len() * backend_len, W::ZERO);
+            {
+                let array = &*array;
+                let logic = array.logic();
+                buffer
+                    .par_chunks_mut(backend_len)
+                    .zip(layer.par_iter())
+                    .for_each_init(
+                        || logic.new_helper(),
+                        |helper, (backend, &v)| {
+                            backend.copy_from_slice(array.get_backend(v));
+                            for &u in &self.preds[v] {
+                                logic.merge_with_helper(backend, array.get_backend(u), helper);
+                            }
+                        },
+                    );
+            }
+            for (backend, &v) in buffer.chunks(backend_len).zip(layer) {
+                array.get_backend_mut(v).copy_from_slice(backend);
+            }
+        }
+    }
+}
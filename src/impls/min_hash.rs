@@ -0,0 +1,220 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::{ensure, Result};
+use std::borrow::Borrow;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::*;
+
+use super::ams_f0::trial_hash;
+use super::DefaultEstimator;
+use crate::traits::{
+    EstimationGuarantees, EstimationLogic, MergeEstimationLogic, SliceEstimationLogic,
+};
+
+/// Estimator logic implementing MinHash sketches.
+///
+/// Each of the *k* words of a backend keeps track of the minimum value of one
+/// of *k* independent hash functions over the elements added so far. Hashes
+/// are obtained by mixing a single hash of the element with different
+/// constants. The fraction of equal minima in two backends is an unbiased
+/// estimate of the [Jaccard similarity](MinHash::jaccard) of the two sets,
+/// with standard error at most 1 / (2√*k*).
+///
+/// The estimate of the number of distinct elements is (*k* − 1) / ∑ *u*ᵢ,
+/// where *u*ᵢ is the *i*-th minimum hash mapped to (0..1], whose relative
+/// standard deviation is 1 / √(*k* − 2).
+///
+/// To make empty backends all-zero, as required, for example, by
+/// [`SliceEstimatorArray`](super::SliceEstimatorArray), words contain the
+/// bitwise complement of the minimum hash: thus, [`add`](EstimationLogic::add)
+/// and [merges](MergeEstimationLogic::merge) compute element-wise maxima of
+/// the stored words, which correspond to element-wise minima of the hashes.
+///
+/// Instances are created using a [`MinHashBuilder`].
+#[derive(Debug, PartialEq)]
+pub struct MinHash<T, H> {
+    build_hasher: H,
+    num_hashes: usize,
+    _marker: std::marker::PhantomData<T>,
+}
+
+// We implement Clone manually because we do not want to require that T is
+// Clone.
+impl<T, H: Clone> Clone for MinHash<T, H> {
+    fn clone(&self) -> Self {
+        Self {
+            build_hasher: self.build_hasher.clone(),
+            num_hashes: self.num_hashes,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, H> MinHash<T, H> {
+    /// Returns the number of hash functions, which is also the length of a
+    /// backend.
+    pub fn num_hashes(&self) -> usize {
+        self.num_hashes
+    }
+
+    /// Returns an estimate of the Jaccard similarity of the sets represented
+    /// by two backends.
+    ///
+    /// The estimate is the fraction of hash functions having the same minimum
+    /// in the two backends. Two empty backends have similarity one.
+    pub fn jaccard(&self, a: &[u64], b: &[u64]) -> f64 {
+        debug_assert_eq!(a.len(), self.num_hashes);
+        debug_assert_eq!(b.len(), self.num_hashes);
+        let equal = a.iter().zip(b).filter(|(a, b)| a == b).count();
+        equal as f64 / self.num_hashes as f64
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> SliceEstimationLogic<u64> for MinHash<T, H> {
+    fn backend_len(&self) -> usize {
+        self.num_hashes
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> EstimationLogic for MinHash<T, H> {
+    type Item = T;
+    type Backend = [u64];
+    type Estimator<'a>
+        = DefaultEstimator<Self, &'a Self, Box<[u64]>>
+    where
+        T: 'a,
+        H: 'a;
+
+    fn new_estimator(&self) -> Self::Estimator<'_> {
+        DefaultEstimator::new(self, vec![0; self.num_hashes].into_boxed_slice())
+    }
+
+    fn add(&self, backend: &mut [u64], element: impl Borrow<T>) {
+        debug_assert_eq!(backend.len(), self.num_hashes);
+        let hash = self.build_hasher.hash_one(element.borrow());
+        for (i, word) in backend.iter_mut().enumerate() {
+            // Words contain the complement of the minimum hash
+            *word = (*word).max(!trial_hash(hash, i));
+        }
+    }
+
+    fn estimate(&self, backend: &[u64]) -> f64 {
+        debug_assert_eq!(backend.len(), self.num_hashes);
+        if backend.iter().all(|&word| word == 0) {
+            return 0.0;
+        }
+        // The sum of the minimum hashes, mapped to (0..1]
+        let sum = backend
+            .iter()
+            .map(|&word| ((!word) as f64 + 1.0) / 2.0_f64.powi(64))
+            .sum::<f64>();
+        if self.num_hashes == 1 {
+            1.0 / sum - 1.0
+        } else {
+            (self.num_hashes - 1) as f64 / sum
+        }
+    }
+
+    fn clear(&self, backend: &mut [u64]) {
+        backend.fill(0);
+    }
+
+    fn set(&self, dst: &mut [u64], src: &[u64]) {
+        debug_assert_eq!(dst.len(), src.len());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> MergeEstimationLogic for MinHash<T, H> {
+    type Helper = ();
+
+    fn new_helper(&self) -> Self::Helper {}
+
+    fn merge_with_helper(&self, dst: &mut [u64], src: &[u64], _helper: &mut Self::Helper) {
+        debug_assert_eq!(dst.len(), src.len());
+        for (d, &s) in dst.iter_mut().zip(src) {
+            *d = (*d).max(s);
+        }
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> EstimationGuarantees for MinHash<T, H> {
+    fn relative_std(&self) -> f64 {
+        if self.num_hashes > 2 {
+            1.0 / ((self.num_hashes - 2) as f64).sqrt()
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+/// Builds a [`MinHash`] logic.
+#[derive(Debug, Clone)]
+pub struct MinHashBuilder<H> {
+    build_hasher: H,
+    num_hashes: usize,
+}
+
+impl MinHashBuilder<BuildHasherDefault<DefaultHasher>> {
+    /// Creates a new builder for a [`MinHash`] logic with 128 hash functions.
+    pub fn new() -> Self {
+        Self {
+            build_hasher: BuildHasherDefault::default(),
+            num_hashes: 128,
+        }
+    }
+}
+
+impl Default for MinHashBuilder<BuildHasherDefault<DefaultHasher>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H> MinHashBuilder<H> {
+    /// Sets the number of hash functions.
+    ///
+    /// # Arguments
+    /// * `num_hashes`: the number of hash functions, which is also the length
+    ///   of a backend.
+    pub fn num_hashes(mut self, num_hashes: usize) -> Self {
+        self.num_hashes = num_hashes;
+        self
+    }
+
+    /// Sets the [`BuildHasher`] to use.
+    ///
+    /// Using this method you can select a specific hashed based on one or more
+    /// seeds.
+    pub fn build_hasher<H2>(self, build_hasher: H2) -> MinHashBuilder<H2> {
+        MinHashBuilder {
+            build_hasher,
+            num_hashes: self.num_hashes,
+        }
+    }
+
+    /// Builds the logic.
+    ///
+    /// The type of objects the estimators keep track of is defined here by `T`,
+    /// but it is usually inferred by the compiler.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the number of hash functions is
+    /// zero.
+    pub fn build<T>(self) -> Result<MinHash<T, H>> {
+        ensure!(
+            self.num_hashes > 0,
+            "the number of hash functions must be positive"
+        );
+        Ok(MinHash {
+            build_hasher: self.build_hasher,
+            num_hashes: self.num_hashes,
+            _marker: std::marker::PhantomData,
+        })
+    }
+}
@@ -0,0 +1,161 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::SliceEstimatorArray;
+use crate::traits::SliceEstimationLogic;
+use memmap2::MmapMut;
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::path::Path;
+use sux::traits::Word;
+
+/// A backend storage for a [`SliceEstimatorArray`] given by a memory-mapped
+/// file.
+///
+/// The file contains the words of the backends in native byte order, with
+/// no header. Modifications are written back to the file by the operating
+/// system, or explicitly by [`flush`](MmapBackend::flush).
+pub struct MmapBackend<W> {
+    mmap: MmapMut,
+    len: usize,
+    _marker: std::marker::PhantomData<W>,
+}
+
+impl<W: Word> MmapBackend<W> {
+    /// Maps the given file, which must contain exactly `len` words.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be mapped or modified elsewhere while the mapping
+    /// exists.
+    unsafe fn map(file: &File, len: usize) -> io::Result<Self> {
+        // SAFETY: the caller guarantees that the file is accessed only
+        // through this mapping
+        let mmap = unsafe { MmapMut::map_mut(file)? };
+        Ok(Self {
+            mmap,
+            len,
+            _marker: std::marker::PhantomData,
+        })
+    }
+
+    /// Flushes to the file outstanding modifications.
+    pub fn flush(&self) -> io::Result<()> {
+        self.mmap.flush()
+    }
+}
+
+impl<W: Word> AsRef<[W]> for MmapBackend<W> {
+    fn as_ref(&self) -> &[W] {
+        if self.len == 0 {
+            return &[];
+        }
+        // SAFETY: the mapping is page-aligned, it contains len words, and
+        // all bit patterns are valid words
+        unsafe { std::slice::from_raw_parts(self.mmap.as_ptr() as *const W, self.len) }
+    }
+}
+
+impl<W: Word> AsMut<[W]> for MmapBackend<W> {
+    fn as_mut(&mut self) -> &mut [W] {
+        if self.len == 0 {
+            return &mut [];
+        }
+        // SAFETY: see as_ref
+        unsafe { std::slice::from_raw_parts_mut(self.mmap.as_mut_ptr() as *mut W, self.len) }
+    }
+}
+
+/// A [`SliceEstimatorArray`] whose backends are stored in a memory-mapped
+/// file.
+///
+/// Arrays of this type make it possible to handle arrays larger than the
+/// available memory. They are created with
+/// [`create`](SliceEstimatorArray::create) and reopened with
+/// [`open`](SliceEstimatorArray::open); in both cases, the logic must be
+/// provided by the caller, as the file contains just the backends.
+///
+/// Both methods are unsafe for the same reason [`MmapMut::map_mut`] is: the
+/// content of the file is accessed as a slice of words, so modifying the file
+/// while it is mapped (through another mapping, or by any other process)
+/// is undefined behavior.
+pub type MmapSliceEstimatorArray<L, W> = SliceEstimatorArray<L, W, MmapBackend<W>>;
+
+impl<L: SliceEstimationLogic<W>, W: Word> MmapSliceEstimatorArray<L, W> {
+    /// Creates a new file containing `len` empty estimators with the given
+    /// logic and maps it into memory.
+    ///
+    /// An existing file is truncated.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be mapped or modified, by this or any other
+    /// process, until the returned array is dropped.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error in case of I/O errors.
+    pub unsafe fn create(path: impl AsRef<Path>, logic: L, len: usize) -> io::Result<Self> {
+        let num_words = len * logic.backend_len();
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        // As in SliceEstimatorArray::new, backends are zero-filled
+        file.set_len((num_words * size_of::<W>()) as u64)?;
+        // SAFETY: the caller guarantees exclusive access to the file
+        let backend = unsafe { MmapBackend::map(&file, num_words)? };
+        Ok(SliceEstimatorArray::from_parts(logic, backend))
+    }
+
+    /// Maps into memory an existing file containing the backends of an array
+    /// with the given logic, as created by
+    /// [`create`](SliceEstimatorArray::create).
+    ///
+    /// The length of the array is deduced from the length of the file.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be mapped or modified, by this or any other
+    /// process, until the returned array is dropped.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the length of the file is not a
+    /// multiple of the size of a backend of `logic`, or in case of I/O
+    /// errors.
+    pub unsafe fn open(path: impl AsRef<Path>, logic: L) -> io::Result<Self> {
+        let path = path.as_ref();
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        let file_len = file.metadata()?.len() as usize;
+        let backend_bytes = logic.backend_len() * size_of::<W>();
+        if file_len % backend_bytes != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "the length of {} ({} bytes) is not a multiple of the size of a backend ({} bytes)",
+                    path.display(),
+                    file_len,
+                    backend_bytes
+                ),
+            ));
+        }
+        // SAFETY: the caller guarantees exclusive access to the file
+        let backend = unsafe { MmapBackend::map(&file, file_len / size_of::<W>())? };
+        Ok(SliceEstimatorArray::from_parts(logic, backend))
+    }
+
+    /// Flushes to the file outstanding modifications.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error in case of I/O errors.
+    pub fn flush(&self) -> io::Result<()> {
+        self.backend.flush()
+    }
+}
@@ -13,3 +13,147 @@ pub use slice_estimator_array::*;
 
 mod default_estimator;
 pub use default_estimator::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod background_merger;
+#[cfg(not(target_arch = "wasm32"))]
+pub use background_merger::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod shared_estimator_array;
+#[cfg(not(target_arch = "wasm32"))]
+pub use shared_estimator_array::*;
+
+mod npy;
+
+mod json;
+
+mod bytes;
+
+mod save_load;
+
+#[cfg(feature = "tokio")]
+mod async_io;
+
+mod subset_detector;
+pub use subset_detector::*;
+
+mod hierarchical_estimator_array;
+pub use hierarchical_estimator_array::*;
+
+mod dyn_estimation_logic;
+pub use dyn_estimation_logic::*;
+
+mod hll_config;
+pub use hll_config::*;
+
+mod observable_estimator;
+pub use observable_estimator::*;
+
+mod flow_cardinality_tracker;
+pub use flow_cardinality_tracker::*;
+
+mod merge_plan;
+pub use merge_plan::*;
+
+mod reservoir_estimator;
+pub use reservoir_estimator::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod sync_estimator_array_atomic;
+#[cfg(not(target_arch = "wasm32"))]
+pub use sync_estimator_array_atomic::*;
+
+mod merge_forest;
+pub use merge_forest::*;
+
+mod normalized_estimator_array;
+pub use normalized_estimator_array::*;
+
+mod ams_f0;
+pub use ams_f0::*;
+
+mod progress_estimator;
+pub use progress_estimator::*;
+
+mod typed_estimator_array;
+pub use typed_estimator_array::*;
+
+mod multi_backend;
+pub use multi_backend::*;
+
+mod accuracy_monitor;
+pub use accuracy_monitor::*;
+
+mod graph_estimator_array;
+pub use graph_estimator_array::*;
+
+mod hll_plus_plus_data;
+mod spark_compat;
+pub use spark_compat::*;
+
+mod google_hll;
+pub use google_hll::*;
+
+mod hyper_log_log_flex;
+pub use hyper_log_log_flex::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod estimator_array_sender;
+#[cfg(not(target_arch = "wasm32"))]
+pub use estimator_array_sender::*;
+
+mod top_k_estimator_array;
+pub use top_k_estimator_array::*;
+
+mod ngram_estimator;
+pub use ngram_estimator::*;
+
+mod union_query;
+pub use union_query::*;
+
+#[cfg(feature = "wasm-bindgen")]
+mod wasm;
+#[cfg(feature = "wasm-bindgen")]
+pub use wasm::*;
+
+mod small_slice_estimator_array;
+pub use small_slice_estimator_array::*;
+
+mod truncated_hyper_log_log;
+pub use truncated_hyper_log_log::*;
+
+mod summary;
+pub use summary::*;
+
+mod min_hash;
+pub use min_hash::*;
+
+#[cfg(feature = "serde")]
+mod serialization;
+
+#[cfg(feature = "memmap2")]
+mod mmap;
+#[cfg(feature = "memmap2")]
+pub use mmap::*;
+
+mod count_min_sketch;
+pub use count_min_sketch::*;
+
+mod decaying_hyper_log_log;
+pub use decaying_hyper_log_log::*;
+
+mod fm_sketch;
+pub use fm_sketch::*;
+
+mod kmv_sketch;
+pub use kmv_sketch::*;
+
+mod theta_sketch;
+pub use theta_sketch::*;
+
+#[cfg(feature = "arrow2")]
+mod arrow;
+
+mod hyper_bit_bit;
+pub use hyper_bit_bit::*;
@@ -0,0 +1,67 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::*;
+use std::borrow::Borrow;
+use sux::traits::Word;
+
+/// A set of `N` estimators with different logics of the same type fed with
+/// the same elements.
+///
+/// This structure is useful when the same stream of elements must be tracked
+/// by estimators with different configurations (e.g., HyperLogLog logics with
+/// a different number of registers, for different precision and memory
+/// requirements): every [addition](MultiBackend::add) is delegated to all
+/// backends, and [`estimates`](MultiBackend::estimates) returns the
+/// estimates of all of them.
+pub struct MultiBackend<L, W, const N: usize> {
+    logics: [L; N],
+    backends: [Box<[W]>; N],
+}
+
+impl<L: SliceEstimationLogic<W>, W: Word, const N: usize> MultiBackend<L, W, N> {
+    /// Creates a new set of empty estimators with the given logics.
+    pub fn new(logics: [L; N]) -> Self {
+        let backends =
+            std::array::from_fn(|i| vec![W::ZERO; logics[i].backend_len()].into_boxed_slice());
+        Self { logics, backends }
+    }
+
+    /// Adds an element to all estimators.
+    pub fn add(&mut self, element: impl Borrow<L::Item>) {
+        let element = element.borrow();
+        for (logic, backend) in self.logics.iter().zip(self.backends.iter_mut()) {
+            logic.add(backend, element);
+        }
+    }
+
+    /// Returns the estimates of all estimators.
+    pub fn estimates(&self) -> [f64; N] {
+        std::array::from_fn(|i| self.estimate(i))
+    }
+
+    /// Returns the estimate of the estimator of given index.
+    pub fn estimate(&self, index: usize) -> f64 {
+        self.logics[index].estimate(&self.backends[index])
+    }
+
+    /// Clears all estimators.
+    pub fn clear(&mut self) {
+        for (logic, backend) in self.logics.iter().zip(self.backends.iter_mut()) {
+            logic.clear(backend);
+        }
+    }
+
+    /// Returns the logic of the estimator of given index.
+    pub fn logic(&self, index: usize) -> &L {
+        &self.logics[index]
+    }
+
+    /// Returns the backend of the estimator of given index.
+    pub fn backend(&self, index: usize) -> &[W] {
+        &self.backends[index]
+    }
+}
@@ -0,0 +1,176 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::*;
+use std::hash::{BuildHasher, Hash};
+
+/// The base of the polynomial hash of [`SequenceHasher`].
+const BASE: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// A rolling hasher for the windows of `N` consecutive elements of a
+/// sequence.
+///
+/// Each element is hashed once using the provided [`BuildHasher`], and the
+/// hash of a window is the polynomial hash (modulo 2⁶⁴) of the hashes of its
+/// elements, that is, for element hashes *h*₀, *h*₁, …, *h*<sub>*N*-1</sub>,
+/// the sum of *h*<sub>*i*</sub>*B*<sup>*N*-1-*i*</sup>, where *B* is a fixed
+/// odd base. The hash of a window is thus sensitive to the order of the
+/// elements, and it is updated in constant time when the window slides.
+#[derive(Debug, Clone)]
+pub struct SequenceHasher<H, const N: usize> {
+    build_hasher: H,
+    /// The hashes of the elements in the window, as a circular buffer.
+    window: [u64; N],
+    /// The position in `window` of the next element.
+    pos: usize,
+    /// The number of elements in the window.
+    len: usize,
+    /// The polynomial hash of the window.
+    hash: u64,
+    /// `BASE` raised to the power `N` - 1.
+    top_power: u64,
+}
+
+impl<H: BuildHasher, const N: usize> SequenceHasher<H, N> {
+    /// Creates a new sequence hasher with an empty window.
+    ///
+    /// Calls to this method will not compile if `N` is zero.
+    pub fn new(build_hasher: H) -> Self {
+        const { assert!(N > 0, "the window size must be positive") };
+        Self {
+            build_hasher,
+            window: [0; N],
+            pos: 0,
+            len: 0,
+            hash: 0,
+            top_power: (1..N).fold(1, |power, _| power.wrapping_mul(BASE)),
+        }
+    }
+
+    /// Appends an element to the sequence, sliding the window.
+    ///
+    /// Returns the hash of the window ending with the element, or `None` if
+    /// fewer than `N` elements have been appended since the last
+    /// [reset](SequenceHasher::reset).
+    pub fn push(&mut self, element: impl Hash) -> Option<u64> {
+        let element_hash = self.build_hasher.hash_one(element);
+        if self.len == N {
+            let oldest = self.window[self.pos];
+            self.hash = self.hash.wrapping_sub(oldest.wrapping_mul(self.top_power));
+        } else {
+            self.len += 1;
+        }
+        self.hash = self.hash.wrapping_mul(BASE).wrapping_add(element_hash);
+        self.window[self.pos] = element_hash;
+        self.pos = (self.pos + 1) % N;
+        (self.len == N).then_some(self.hash)
+    }
+
+    /// Empties the window, starting a new sequence.
+    pub fn reset(&mut self) {
+        self.pos = 0;
+        self.len = 0;
+        self.hash = 0;
+    }
+
+    /// Returns the hash of a full window, computed from scratch.
+    ///
+    /// This method returns the same value returned by
+    /// [`push`](SequenceHasher::push) on the last element of the window.
+    pub fn hash_window(&self, window: &[impl Hash; N]) -> u64 {
+        window.iter().fold(0, |hash: u64, element| {
+            hash.wrapping_mul(BASE)
+                .wrapping_add(self.build_hasher.hash_one(element))
+        })
+    }
+}
+
+/// A wrapper around a [mutable estimator](EstimatorMut) counting the distinct
+/// *n*-grams of a sequence, that is, the distinct windows of `N` consecutive
+/// elements.
+///
+/// The elements of the sequence are passed to a [`SequenceHasher`], and the
+/// hash of each full window is added to the wrapped estimator, whose logic must
+/// accept `u64` items. Thus, the estimate of the wrapper is an estimate of the
+/// number of distinct *n*-grams, up to collisions of 64-bit hashes.
+///
+/// Multiple sequences can be added to the same wrapper by calling
+/// [`end_sequence`](NGramEstimator::end_sequence) between them, so that no
+/// *n*-gram spans two sequences.
+pub struct NGramEstimator<L: ?Sized, E, H, const N: usize> {
+    estimator: E,
+    hasher: SequenceHasher<H, N>,
+    _marker: std::marker::PhantomData<L>,
+}
+
+impl<
+        L: EstimationLogic<Item = u64> + ?Sized,
+        E: EstimatorMut<L>,
+        H: BuildHasher,
+        const N: usize,
+    > NGramEstimator<L, E, H, N>
+{
+    /// Creates a new *n*-gram estimator.
+    ///
+    /// Calls to this method will not compile if `N` is zero.
+    ///
+    /// # Arguments
+    /// * `estimator`: the estimator to wrap.
+    /// * `build_hasher`: the builder of the hasher for the elements of the
+    ///   sequence.
+    pub fn new(estimator: E, build_hasher: H) -> Self {
+        Self {
+            estimator,
+            hasher: SequenceHasher::new(build_hasher),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Appends an element to the current sequence, adding to the wrapped
+    /// estimator the *n*-gram it completes, if any.
+    pub fn add(&mut self, element: impl Hash) {
+        if let Some(hash) = self.hasher.push(element) {
+            self.estimator.add(hash);
+        }
+    }
+
+    /// Appends all elements of an iterator to the current sequence.
+    pub fn add_sequence<I: IntoIterator>(&mut self, elements: I)
+    where
+        I::Item: Hash,
+    {
+        for element in elements {
+            self.add(element);
+        }
+    }
+
+    /// Ends the current sequence: the next element will start a new one.
+    pub fn end_sequence(&mut self) {
+        self.hasher.reset();
+    }
+
+    /// Returns an estimation of the number of distinct *n*-grams added so
+    /// far.
+    pub fn estimate(&self) -> f64 {
+        self.estimator.estimate()
+    }
+
+    /// Clears the wrapped estimator and ends the current sequence.
+    pub fn clear(&mut self) {
+        self.estimator.clear();
+        self.hasher.reset();
+    }
+
+    /// Returns a reference to the wrapped estimator.
+    pub fn estimator(&self) -> &E {
+        &self.estimator
+    }
+
+    /// Returns the wrapped estimator.
+    pub fn into_inner(self) -> E {
+        self.estimator
+    }
+}
@@ -0,0 +1,95 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::SliceEstimatorArray;
+use crate::traits::*;
+
+/// A wrapper around a [`SliceEstimatorArray`] whose estimates are multiplied
+/// by a correction factor.
+///
+/// The state of many estimators (e.g., HyperLogLog registers) cannot be scaled
+/// so to scale their estimates. This wrapper makes it possible to rescale the
+/// estimates of an array anyway by storing a correction factor that is
+/// applied by [`estimate`](NormalizedEstimatorArray::estimate). In particular,
+/// [`normalize_sum`](NormalizedEstimatorArray::normalize_sum) sets the factor
+/// so that the estimates sum to a given value.
+pub struct NormalizedEstimatorArray<L, W, S> {
+    array: SliceEstimatorArray<L, W, S>,
+    correction_factor: f64,
+}
+
+impl<L, W, S> NormalizedEstimatorArray<L, W, S> {
+    /// Wraps an array with correction factor one.
+    pub fn new(array: SliceEstimatorArray<L, W, S>) -> Self {
+        Self {
+            array,
+            correction_factor: 1.0,
+        }
+    }
+
+    /// Returns the current correction factor.
+    pub fn correction_factor(&self) -> f64 {
+        self.correction_factor
+    }
+
+    /// Sets the correction factor.
+    pub fn set_correction_factor(&mut self, correction_factor: f64) {
+        self.correction_factor = correction_factor;
+    }
+
+    /// Returns a reference to the wrapped array.
+    pub fn array(&self) -> &SliceEstimatorArray<L, W, S> {
+        &self.array
+    }
+
+    /// Returns a mutable reference to the wrapped array.
+    ///
+    /// Note that modifying the array does not update the correction factor.
+    pub fn array_mut(&mut self) -> &mut SliceEstimatorArray<L, W, S> {
+        &mut self.array
+    }
+
+    /// Returns the wrapped array, discarding the correction factor.
+    pub fn into_inner(self) -> SliceEstimatorArray<L, W, S> {
+        self.array
+    }
+}
+
+impl<L: SliceEstimationLogic<W>, W, S: AsRef<[W]>> NormalizedEstimatorArray<L, W, S> {
+    /// Returns the estimate of the estimator at the given index multiplied by
+    /// the correction factor.
+    pub fn estimate(&self, index: usize) -> f64 {
+        let backend_len = self.array.logic.backend_len();
+        self.correction_factor
+            * self
+                .array
+                .logic
+                .estimate(&self.array.backend.as_ref()[index * backend_len..][..backend_len])
+    }
+
+    /// Returns the estimates of all estimators multiplied by the correction
+    /// factor.
+    pub fn estimates(&self) -> Vec<f64> {
+        (0..self.array.len()).map(|i| self.estimate(i)).collect()
+    }
+
+    /// Sets the correction factor so that the sum of the corrected estimates
+    /// is equal to `target_sum`.
+    ///
+    /// If the sum of the uncorrected estimates is zero, the correction factor
+    /// is set to one.
+    pub fn normalize_sum(&mut self, target_sum: f64) {
+        let backend_len = self.array.logic.backend_len();
+        let sum: f64 = self
+            .array
+            .backend
+            .as_ref()
+            .chunks(backend_len)
+            .map(|backend| self.array.logic.estimate(backend))
+            .sum();
+        self.correction_factor = if sum == 0.0 { 1.0 } else { target_sum / sum };
+    }
+}
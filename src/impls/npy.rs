@@ -0,0 +1,200 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::{bytes::words_from_le_bytes, SliceEstimatorArray};
+use crate::traits::SliceEstimationLogic;
+use anyhow::{bail, ensure, Context, Result};
+use common_traits::{FromBytes, ToBytes};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, Write};
+use std::path::Path;
+use sux::traits::Word;
+
+/// The magic string starting every `.npy` file.
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Returns the NumPy type descriptor of `W`.
+fn descr<W: Word>() -> Result<String> {
+    match W::BYTES {
+        1 => Ok("|u1".to_string()),
+        2 | 4 | 8 => Ok(format!("<u{}", W::BYTES)),
+        bytes => bail!("NumPy has no unsigned type of {} bytes", bytes),
+    }
+}
+
+/// Returns the (unquoted) value associated with `key` in the Python
+/// dictionary literal `header`.
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str> {
+    let quoted_key = format!("'{}':", key);
+    let start = header
+        .find(&quoted_key)
+        .with_context(|| format!("missing key '{}' in .npy header", key))?
+        + quoted_key.len();
+    let value = header[start..].trim_start();
+    let end = if value.starts_with('(') {
+        value.find(')').map(|end| end + 1)
+    } else {
+        value.find(',')
+    }
+    .with_context(|| format!("malformed value for key '{}' in .npy header", key))?;
+    Ok(value[..end].trim().trim_matches('\''))
+}
+
+impl<L: SliceEstimationLogic<W>, W: Word + ToBytes, S: AsRef<[W]>> SliceEstimatorArray<L, W, S> {
+    /// Writes the backends of the array to a file in [NumPy `.npy`
+    /// format](https://numpy.org/doc/stable/reference/generated/numpy.lib.format.html).
+    ///
+    /// The file will contain a two-dimensional array of unsigned integers of
+    /// the size of `W`, with one row per estimator and one column per word of
+    /// backend. The array can be loaded in Python using `numpy.load`, and
+    /// read back using [`from_npy`](SliceEstimatorArray::from_npy).
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if NumPy has no unsigned type of the
+    /// size of `W` (e.g., `u128`), or in case of I/O errors.
+    pub fn to_npy(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': False, 'shape': ({}, {}), }}",
+            descr::<W>()?,
+            self.len(),
+            self.logic.backend_len()
+        );
+        // The header is padded with spaces and terminated by a newline so that
+        // the data is 64-byte aligned.
+        let prefix_len = MAGIC.len() + 2 + 2;
+        let padded_len = (prefix_len + header.len() + 1).next_multiple_of(64);
+        header.extend(std::iter::repeat_n(
+            ' ',
+            padded_len - prefix_len - header.len() - 1,
+        ));
+        header.push('\n');
+        ensure!(
+            header.len() <= u16::MAX as usize,
+            ".npy header too long ({} bytes)",
+            header.len()
+        );
+
+        let mut writer = BufWriter::new(
+            File::create(path).with_context(|| format!("Could not create {}", path.display()))?,
+        );
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&(header.len() as u16).to_le_bytes())?;
+        writer.write_all(header.as_bytes())?;
+        for &word in self.backend.as_ref() {
+            writer.write_all(word.to_le_bytes().as_ref())?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<L: SliceEstimationLogic<W>, W: Word + FromBytes> SliceEstimatorArray<L, W, Box<[W]>> {
+    /// Reads from a file in NumPy `.npy` format an array with the provided
+    /// logic.
+    ///
+    /// The file must contain a two-dimensional array in C order of unsigned
+    /// integers of the size of `W`, whose rows have the
+    /// [length](SliceEstimationLogic::backend_len) of the backends of `logic`,
+    /// as written by [`to_npy`](SliceEstimatorArray::to_npy).
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the file is not a valid `.npy`
+    /// file, if its type or shape are not compatible with `W` and `logic`, or
+    /// in case of I/O errors.
+    pub fn from_npy(path: impl AsRef<Path>, logic: L) -> Result<Self> {
+        let path = path.as_ref();
+        let file =
+            File::open(path).with_context(|| format!("Could not open {}", path.display()))?;
+        let file_len = file.metadata()?.len();
+        let mut reader = BufReader::new(file);
+
+        let mut magic = [0; MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        ensure!(magic == MAGIC, "{} is not a .npy file", path.display());
+        let mut version = [0; 2];
+        reader.read_exact(&mut version)?;
+        let header_len = match version[0] {
+            1 => {
+                let mut len = [0; 2];
+                reader.read_exact(&mut len)?;
+                u16::from_le_bytes(len) as usize
+            }
+            2 | 3 => {
+                let mut len = [0; 4];
+                reader.read_exact(&mut len)?;
+                u32::from_le_bytes(len) as usize
+            }
+            major => bail!("unsupported .npy format version {}.{}", major, version[1]),
+        };
+        // We do not preallocate, as the length might be corrupted
+        let mut header = Vec::new();
+        (&mut reader)
+            .take(header_len as u64)
+            .read_to_end(&mut header)?;
+        ensure!(
+            header.len() == header_len,
+            "the .npy header is truncated ({} bytes instead of {})",
+            header.len(),
+            header_len
+        );
+        let header = String::from_utf8(header).context("the .npy header is not valid UTF-8")?;
+
+        let file_descr = header_value(&header, "descr")?;
+        let expected_descr = descr::<W>()?;
+        ensure!(
+            file_descr == expected_descr
+                || (W::BYTES == 1 && file_descr.ends_with(&expected_descr[1..])),
+            "the .npy file has type '{}', but '{}' was expected",
+            file_descr,
+            expected_descr
+        );
+        ensure!(
+            header_value(&header, "fortran_order")? == "False",
+            "Fortran-order .npy files are not supported"
+        );
+        let shape = header_value(&header, "shape")?
+            .trim_matches(|c| c == '(' || c == ')')
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse::<usize>())
+            .collect::<Result<Vec<_>, _>>()
+            .context("malformed shape in .npy header")?;
+        ensure!(
+            shape.len() == 2 && shape[1] == logic.backend_len(),
+            "the .npy file has shape {:?}, but (_, {}) was expected",
+            shape,
+            logic.backend_len()
+        );
+
+        let num_bytes = shape[0]
+            .checked_mul(shape[1])
+            .and_then(|num_words| num_words.checked_mul(W::BYTES))
+            .with_context(|| format!("the .npy shape {:?} is too large", shape))?;
+        let remaining = file_len.saturating_sub(reader.stream_position()?);
+        ensure!(
+            remaining == num_bytes as u64,
+            "the .npy file contains {} bytes of data, but its shape {:?} requires {}",
+            remaining,
+            shape,
+            num_bytes
+        );
+        let mut bytes = Vec::new();
+        reader.take(num_bytes as u64).read_to_end(&mut bytes)?;
+        ensure!(
+            bytes.len() == num_bytes,
+            "the .npy file contains {} bytes of data instead of {}",
+            bytes.len(),
+            num_bytes
+        );
+
+        Ok(Self::from_parts(logic, words_from_le_bytes(&bytes)))
+    }
+}
@@ -0,0 +1,161 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::*;
+use std::borrow::Borrow;
+use std::hash::{BuildHasher, BuildHasherDefault, DefaultHasher, Hash};
+
+/// An event notified by an [`ObservableEstimator`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EstimatorEvent {
+    /// An element has been added.
+    Added {
+        /// The hash of the element, computed with a [`DefaultHasher`].
+        element_hash: u64,
+    },
+    /// A backend has been merged into the estimator.
+    Merged {
+        /// The estimate of the estimator before the merge.
+        dst_estimate: f64,
+        /// The estimate of the merged backend.
+        src_estimate: f64,
+        /// The estimate of the estimator after the merge.
+        new_estimate: f64,
+    },
+    /// The estimator has been cleared.
+    Cleared,
+    /// The content of the estimator has been set.
+    Set,
+}
+
+/// A wrapper around a [mutable estimator](EstimatorMut) that notifies a
+/// callback of every update.
+///
+/// Every call to [`add`](EstimatorMut::add), [`clear`](EstimatorMut::clear),
+/// [`set`](EstimatorMut::set), and [`merge`](MergeEstimator::merge) (or
+/// [`merge_with_helper`](MergeEstimator::merge_with_helper)) is delegated to
+/// the wrapped estimator, and then the callback is called with the
+/// corresponding [`EstimatorEvent`]. This makes it possible, for example, to
+/// log updates or collect metrics.
+///
+/// The callback is a type parameter, so using a function pointer or a closure
+/// does not require dynamic dispatch. Note, however, that a
+/// [`Merged`](EstimatorEvent::Merged) event requires computing three
+/// estimates, and an [`Added`](EstimatorEvent::Added) event requires hashing
+/// the element.
+pub struct ObservableEstimator<L: ?Sized, E, CB> {
+    estimator: E,
+    callback: CB,
+    _marker: std::marker::PhantomData<L>,
+}
+
+impl<L: EstimationLogic + ?Sized, E: EstimatorMut<L>, CB: Fn(EstimatorEvent)>
+    ObservableEstimator<L, E, CB>
+{
+    /// Creates a new observable estimator.
+    ///
+    /// # Arguments
+    /// * `estimator`: the estimator to wrap.
+    /// * `callback`: the callback that will be notified of updates.
+    pub fn new(estimator: E, callback: CB) -> Self {
+        Self {
+            estimator,
+            callback,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the wrapped estimator.
+    pub fn into_inner(self) -> E {
+        self.estimator
+    }
+}
+
+impl<L: EstimationLogic + ?Sized, E: AsRef<L::Backend>, CB> AsRef<L::Backend>
+    for ObservableEstimator<L, E, CB>
+{
+    fn as_ref(&self) -> &L::Backend {
+        self.estimator.as_ref()
+    }
+}
+
+impl<L: EstimationLogic + ?Sized, E: AsMut<L::Backend>, CB> AsMut<L::Backend>
+    for ObservableEstimator<L, E, CB>
+{
+    fn as_mut(&mut self) -> &mut L::Backend {
+        self.estimator.as_mut()
+    }
+}
+
+impl<L: EstimationLogic + ?Sized, E: Estimator<L>, CB> Estimator<L>
+    for ObservableEstimator<L, E, CB>
+{
+    type OwnedEstimator = E::OwnedEstimator;
+
+    fn logic(&self) -> &L {
+        self.estimator.logic()
+    }
+
+    #[inline(always)]
+    fn estimate(&self) -> f64 {
+        self.estimator.estimate()
+    }
+
+    /// Converts the wrapped estimator into an owned version.
+    ///
+    /// The result is no longer observed.
+    fn into_owned(self) -> Self::OwnedEstimator
+    where
+        for<'a> Box<L::Backend>: From<&'a L::Backend>,
+    {
+        self.estimator.into_owned()
+    }
+}
+
+impl<L: EstimationLogic + ?Sized, E: EstimatorMut<L>, CB: Fn(EstimatorEvent)> EstimatorMut<L>
+    for ObservableEstimator<L, E, CB>
+where
+    L::Item: Hash,
+{
+    fn add(&mut self, element: impl Borrow<L::Item>) {
+        let element_hash =
+            BuildHasherDefault::<DefaultHasher>::default().hash_one(element.borrow());
+        self.estimator.add(element);
+        (self.callback)(EstimatorEvent::Added { element_hash });
+    }
+
+    fn clear(&mut self) {
+        self.estimator.clear();
+        (self.callback)(EstimatorEvent::Cleared);
+    }
+
+    fn set(&mut self, backend: &L::Backend) {
+        self.estimator.set(backend);
+        (self.callback)(EstimatorEvent::Set);
+    }
+}
+
+impl<L: MergeEstimationLogic + ?Sized, E: MergeEstimator<L>, CB: Fn(EstimatorEvent)>
+    MergeEstimator<L> for ObservableEstimator<L, E, CB>
+where
+    L::Item: Hash,
+{
+    fn merge(&mut self, backend: &L::Backend) {
+        let mut helper = self.logic().new_helper();
+        self.merge_with_helper(backend, &mut helper);
+    }
+
+    fn merge_with_helper(&mut self, backend: &L::Backend, helper: &mut L::Helper) {
+        let dst_estimate = self.estimator.estimate();
+        let src_estimate = self.logic().estimate(backend);
+        self.estimator.merge_with_helper(backend, helper);
+        (self.callback)(EstimatorEvent::Merged {
+            dst_estimate,
+            src_estimate,
+            new_estimate: self.estimator.estimate(),
+        });
+    }
+}
@@ -0,0 +1,207 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::SliceEstimatorArray;
+use crate::traits::*;
+use std::borrow::Borrow;
+
+/// A wrapper around a [mutable estimator](EstimatorMut) that reports progress
+/// to a callback.
+///
+/// Every `interval` calls to [`add`](EstimatorMut::add), the callback is
+/// called with the number of elements added so far to the wrapper and the
+/// current estimate.
+pub struct ProgressEstimator<L: ?Sized, E, CB> {
+    estimator: E,
+    callback: CB,
+    interval: usize,
+    elements_added: usize,
+    _marker: std::marker::PhantomData<L>,
+}
+
+impl<L: EstimationLogic + ?Sized, E: EstimatorMut<L>, CB: FnMut(usize, f64)>
+    ProgressEstimator<L, E, CB>
+{
+    /// Creates a new progress estimator.
+    ///
+    /// # Arguments
+    /// * `estimator`: the estimator to wrap.
+    /// * `interval`: the number of additions between calls to the callback.
+    /// * `callback`: the callback, which will receive the number of elements
+    ///   added so far and the current estimate.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `interval` is zero.
+    pub fn new(estimator: E, interval: usize, callback: CB) -> Self {
+        assert!(interval > 0, "the reporting interval must be positive");
+        Self {
+            estimator,
+            callback,
+            interval,
+            elements_added: 0,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of elements added so far.
+    pub fn elements_added(&self) -> usize {
+        self.elements_added
+    }
+
+    /// Returns the wrapped estimator.
+    pub fn into_inner(self) -> E {
+        self.estimator
+    }
+}
+
+impl<L: EstimationLogic + ?Sized, E: AsRef<L::Backend>, CB> AsRef<L::Backend>
+    for ProgressEstimator<L, E, CB>
+{
+    fn as_ref(&self) -> &L::Backend {
+        self.estimator.as_ref()
+    }
+}
+
+impl<L: EstimationLogic + ?Sized, E: AsMut<L::Backend>, CB> AsMut<L::Backend>
+    for ProgressEstimator<L, E, CB>
+{
+    fn as_mut(&mut self) -> &mut L::Backend {
+        self.estimator.as_mut()
+    }
+}
+
+impl<L: EstimationLogic + ?Sized, E: Estimator<L>, CB> Estimator<L>
+    for ProgressEstimator<L, E, CB>
+{
+    type OwnedEstimator = E::OwnedEstimator;
+
+    fn logic(&self) -> &L {
+        self.estimator.logic()
+    }
+
+    #[inline(always)]
+    fn estimate(&self) -> f64 {
+        self.estimator.estimate()
+    }
+
+    /// Converts the wrapped estimator into an owned version.
+    ///
+    /// The result no longer reports progress.
+    fn into_owned(self) -> Self::OwnedEstimator
+    where
+        for<'a> Box<L::Backend>: From<&'a L::Backend>,
+    {
+        self.estimator.into_owned()
+    }
+}
+
+impl<L: EstimationLogic + ?Sized, E: EstimatorMut<L>, CB: FnMut(usize, f64)> EstimatorMut<L>
+    for ProgressEstimator<L, E, CB>
+{
+    fn add(&mut self, element: impl Borrow<L::Item>) {
+        self.estimator.add(element);
+        self.elements_added += 1;
+        if self.elements_added % self.interval == 0 {
+            (self.callback)(self.elements_added, self.estimator.estimate());
+        }
+    }
+
+    fn clear(&mut self) {
+        self.estimator.clear();
+    }
+
+    fn set(&mut self, backend: &L::Backend) {
+        self.estimator.set(backend);
+    }
+}
+
+impl<L: MergeEstimationLogic + ?Sized, E: MergeEstimator<L>, CB: FnMut(usize, f64)>
+    MergeEstimator<L> for ProgressEstimator<L, E, CB>
+{
+    fn merge(&mut self, backend: &L::Backend) {
+        self.estimator.merge(backend);
+    }
+
+    fn merge_with_helper(&mut self, backend: &L::Backend, helper: &mut L::Helper) {
+        self.estimator.merge_with_helper(backend, helper);
+    }
+}
+
+/// A wrapper around a [`SliceEstimatorArray`] that reports progress to a
+/// callback.
+///
+/// Every `interval` calls to [`add`](ProgressEstimatorArray::add), the
+/// callback is called with the number of elements added so far to all
+/// estimators of the array and the sum of the current estimates. Note that
+/// computing the sum requires computing the estimates of all estimators, so
+/// the interval should be large enough to amortize the cost.
+pub struct ProgressEstimatorArray<L, W, S, CB> {
+    array: SliceEstimatorArray<L, W, S>,
+    callback: CB,
+    interval: usize,
+    elements_added: usize,
+}
+
+impl<L: SliceEstimationLogic<W> + Clone, W, S: AsRef<[W]> + AsMut<[W]>, CB: FnMut(usize, f64)>
+    ProgressEstimatorArray<L, W, S, CB>
+{
+    /// Creates a new progress estimator array.
+    ///
+    /// # Arguments
+    /// * `array`: the array to wrap.
+    /// * `interval`: the number of additions between calls to the callback.
+    /// * `callback`: the callback, which will receive the number of elements
+    ///   added so far and the sum of the current estimates.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `interval` is zero.
+    pub fn new(array: SliceEstimatorArray<L, W, S>, interval: usize, callback: CB) -> Self {
+        assert!(interval > 0, "the reporting interval must be positive");
+        Self {
+            array,
+            callback,
+            interval,
+            elements_added: 0,
+        }
+    }
+
+    /// Adds an element to the estimator at the given index.
+    pub fn add(&mut self, index: usize, element: impl Borrow<L::Item>) {
+        let backend_len = self.array.logic.backend_len();
+        self.array.logic.add(
+            &mut self.array.backend.as_mut()[index * backend_len..][..backend_len],
+            element,
+        );
+        self.elements_added += 1;
+        if self.elements_added % self.interval == 0 {
+            let sum = self
+                .array
+                .backend
+                .as_ref()
+                .chunks(backend_len)
+                .map(|backend| self.array.logic.estimate(backend))
+                .sum();
+            (self.callback)(self.elements_added, sum);
+        }
+    }
+
+    /// Returns the number of elements added so far.
+    pub fn elements_added(&self) -> usize {
+        self.elements_added
+    }
+
+    /// Returns a reference to the wrapped array.
+    pub fn array(&self) -> &SliceEstimatorArray<L, W, S> {
+        &self.array
+    }
+
+    /// Returns the wrapped array.
+    pub fn into_inner(self) -> SliceEstimatorArray<L, W, S> {
+        self.array
+    }
+}
@@ -0,0 +1,123 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::*;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, BuildHasherDefault, DefaultHasher, Hash};
+use sux::traits::Word;
+
+/// An estimator keeping exact track of the `K` most frequent elements, and
+/// using an estimator with a given logic for the remaining ones.
+///
+/// The most frequent elements are kept in a *reservoir* of size `K` managed
+/// with the Space-Saving algorithm: when a new element arrives and the
+/// reservoir is full, the least frequent element of the reservoir is evicted
+/// and added to the *tail* estimator, and the new element replaces it,
+/// inheriting its frequency plus one. Frequencies are thus upper bounds, and
+/// they are exact for elements that entered the reservoir when it was not
+/// full and never left it. Every element whose frequency is larger than 1/`K`
+/// of the total number of additions is guaranteed to be in the reservoir.
+///
+/// As long as no element has been evicted, the [estimate](Self::estimate) is
+/// exact; afterwards, it is the estimate of the union of the tail and of the
+/// reservoir, so elements moving between the two are never counted twice.
+///
+/// Elements are identified in the reservoir by their hash, computed with a
+/// [`DefaultHasher`].
+pub struct ReservoirEstimator<L: EstimationLogic, W, const K: usize> {
+    logic: L,
+    /// Maps hashes to elements and their frequencies.
+    reservoir: HashMap<u64, (L::Item, u64)>,
+    tail: Box<[W]>,
+    evictions: usize,
+}
+
+impl<L: SliceEstimationLogic<W>, W: Word, const K: usize> ReservoirEstimator<L, W, K>
+where
+    L::Item: Hash + Clone,
+{
+    /// Creates a new empty estimator using the given logic for the tail.
+    ///
+    /// Calls to this method will not compile if `K` is zero.
+    pub fn new(logic: L) -> Self {
+        const { assert!(K > 0, "the size of the reservoir must be positive") };
+        let tail = vec![W::ZERO; logic.backend_len()].into_boxed_slice();
+        Self {
+            logic,
+            reservoir: HashMap::with_capacity(K),
+            tail,
+            evictions: 0,
+        }
+    }
+
+    /// Adds an element to the estimator.
+    pub fn add(&mut self, element: impl Borrow<L::Item>) {
+        let element = element.borrow();
+        let hash = BuildHasherDefault::<DefaultHasher>::default().hash_one(element);
+        if let Some((_, frequency)) = self.reservoir.get_mut(&hash) {
+            *frequency += 1;
+            return;
+        }
+
+        let mut frequency = 1;
+        if self.reservoir.len() == K {
+            // Ties are broken by hash so that the behavior is deterministic
+            let (&min_hash, &(_, min_frequency)) = self
+                .reservoir
+                .iter()
+                .min_by_key(|(&hash, &(_, frequency))| (frequency, hash))
+                .unwrap();
+            let (evicted, _) = self.reservoir.remove(&min_hash).unwrap();
+            self.logic.add(&mut self.tail, evicted);
+            self.evictions += 1;
+            frequency += min_frequency;
+        }
+        self.reservoir.insert(hash, (element.clone(), frequency));
+    }
+
+    /// Returns an estimation of the number of distinct elements that have been
+    /// added to the estimator so far.
+    pub fn estimate(&self) -> f64 {
+        if self.evictions == 0 {
+            return self.reservoir.len() as f64;
+        }
+        let mut union = self.tail.clone();
+        for (element, _) in self.reservoir.values() {
+            self.logic.add(&mut union, element);
+        }
+        self.logic.estimate(&union)
+    }
+
+    /// Returns the hashes of the elements in the reservoir and their
+    /// frequencies, sorted by decreasing frequency and then by increasing
+    /// hash.
+    pub fn top_k(&self) -> Vec<(u64, u64)> {
+        let mut top_k = self
+            .reservoir
+            .iter()
+            .map(|(&hash, &(_, frequency))| (hash, frequency))
+            .collect::<Vec<_>>();
+        top_k.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        top_k
+    }
+
+    /// Returns the logic of the tail estimator.
+    pub fn logic(&self) -> &L {
+        &self.logic
+    }
+
+    /// Returns the backend of the tail estimator, which contains the elements
+    /// evicted from the reservoir.
+    pub fn tail(&self) -> &[W] {
+        &self.tail
+    }
+
+    /// Returns the number of elements evicted from the reservoir so far.
+    pub fn evictions(&self) -> usize {
+        self.evictions
+    }
+}
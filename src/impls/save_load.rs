@@ -0,0 +1,228 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! Portable binary format for [`HyperLogLog`] logics and arrays.
+//!
+//! All integers are stored in little-endian order.
+
+use super::{
+    bytes::words_from_le_bytes, BiasCorrection, EstimationMode, HyperLogLog, HyperLogLogBuilder,
+    SliceEstimatorArray,
+};
+use crate::traits::SliceEstimationLogic;
+use common_traits::{FromBytes, ToBytes};
+use std::io::{self, Read, Write};
+use sux::traits::Word;
+
+/// The magic bytes starting a saved array.
+const ARRAY_MAGIC: [u8; 8] = *b"HLLARRAY";
+/// The magic bytes starting a saved logic.
+const LOGIC_MAGIC: [u8; 8] = *b"HLLLOGIC";
+/// The current version of the format.
+const VERSION: u32 = 1;
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn read_u8(reader: &mut impl Read) -> io::Result<u8> {
+    let mut bytes = [0; 1];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes[0])
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Reads and checks the magic bytes and the version of a header.
+fn read_preamble(reader: &mut impl Read, magic: &[u8; 8], what: &str) -> io::Result<()> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    if &bytes != magic {
+        return Err(invalid_data(format!(
+            "wrong magic bytes {:?} for a saved {} (expected {:?})",
+            bytes, what, magic
+        )));
+    }
+    let version = read_u32(reader)?;
+    if version != VERSION {
+        return Err(invalid_data(format!(
+            "unsupported version {} for a saved {} (expected {})",
+            version, what, VERSION
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that a value of a header matches the expected value.
+fn check_field(name: &str, value: u64, expected: usize) -> io::Result<()> {
+    if value != expected as u64 {
+        return Err(invalid_data(format!(
+            "the {} of the saved array ({}) does not match that of the logic ({})",
+            name, value, expected
+        )));
+    }
+    Ok(())
+}
+
+impl<T, H: Clone, W: Word> HyperLogLog<T, H, W> {
+    /// Writes the parameters of the logic to a writer in a portable binary
+    /// format.
+    ///
+    /// The hasher is not saved: it must be passed to
+    /// [`load_logic`](HyperLogLog::load_logic) when reading back the logic.
+    pub fn save_logic(&self, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(&LOGIC_MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&(self.log_2_num_registers() as u32).to_le_bytes())?;
+        writer.write_all(&(W::BYTES as u32).to_le_bytes())?;
+        writer.write_all(&(self.num_elements() as u64).to_le_bytes())?;
+        writer.write_all(&self.linear_counting_threshold().to_le_bytes())?;
+        let estimation_mode: u8 = match self.estimation_mode() {
+            EstimationMode::HarmonicMean => 0,
+            EstimationMode::GeometricMean => 1,
+            EstimationMode::MaxLikelihood => 2,
+        };
+        let bias_correction: u8 = match self.bias_correction() {
+            BiasCorrection::Standard => 0,
+            BiasCorrection::LogLogBeta => 1,
+        };
+        writer.write_all(&[estimation_mode, bias_correction])
+    }
+
+    /// Reads from a reader a logic written by
+    /// [`save_logic`](HyperLogLog::save_logic), using the given hasher.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error of kind
+    /// [`InvalidData`](io::ErrorKind::InvalidData) if the magic bytes, the
+    /// version, or the word size are wrong, or if the parameters are not
+    /// valid, and an error of kind
+    /// [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) if the data is
+    /// truncated.
+    pub fn load_logic(mut reader: impl Read, build_hasher: H) -> io::Result<Self> {
+        read_preamble(&mut reader, &LOGIC_MAGIC, "logic")?;
+        let log_2_num_reg = read_u32(&mut reader)?;
+        let word_size = read_u32(&mut reader)?;
+        if word_size as usize != W::BYTES {
+            return Err(invalid_data(format!(
+                "the word size of the saved logic ({} bytes) does not match the word type ({} bytes)",
+                word_size,
+                W::BYTES
+            )));
+        }
+        let num_elements = read_u64(&mut reader)?;
+        let linear_counting_threshold = f64::from_bits(read_u64(&mut reader)?);
+        let estimation_mode = match read_u8(&mut reader)? {
+            0 => EstimationMode::HarmonicMean,
+            1 => EstimationMode::GeometricMean,
+            2 => EstimationMode::MaxLikelihood,
+            mode => return Err(invalid_data(format!("unknown estimation mode {}", mode))),
+        };
+        let bias_correction = match read_u8(&mut reader)? {
+            0 => BiasCorrection::Standard,
+            1 => BiasCorrection::LogLogBeta,
+            correction => {
+                return Err(invalid_data(format!(
+                    "unknown bias correction {}",
+                    correction
+                )))
+            }
+        };
+        HyperLogLogBuilder::new(num_elements as usize)
+            .log_2_num_reg(log_2_num_reg as usize)
+            .word_type::<W>()
+            .build_hasher(build_hasher)
+            .linear_counting_threshold(linear_counting_threshold)
+            .estimation_mode(estimation_mode)
+            .bias_correction(bias_correction)
+            .build()
+            .map_err(|e| invalid_data(e.to_string()))
+    }
+}
+
+impl<T, H: Clone, W: Word + ToBytes, S: AsRef<[W]>> SliceEstimatorArray<HyperLogLog<T, H, W>, W, S>
+where
+    HyperLogLog<T, H, W>: SliceEstimationLogic<W>,
+{
+    /// Writes the array to a writer in a portable binary format.
+    ///
+    /// The format is made of a header containing magic bytes, a version
+    /// number, the logarithm of the number of registers, the register size,
+    /// the word size, and the number of estimators, followed by the
+    /// backends in the format of [`to_bytes`](SliceEstimatorArray::to_bytes).
+    /// The logic is not saved: use [`save_logic`](HyperLogLog::save_logic)
+    /// to save it, and [`load`](SliceEstimatorArray::load) to read back the
+    /// array.
+    pub fn save(&self, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(&ARRAY_MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&(self.logic.log_2_num_registers() as u32).to_le_bytes())?;
+        writer.write_all(&(self.logic.register_size as u32).to_le_bytes())?;
+        writer.write_all(&(W::BYTES as u32).to_le_bytes())?;
+        writer.write_all(&(self.len() as u64).to_le_bytes())?;
+        writer.write_all(&self.to_bytes())
+    }
+}
+
+impl<T, H: Clone, W: Word + FromBytes> SliceEstimatorArray<HyperLogLog<T, H, W>, W, Box<[W]>>
+where
+    HyperLogLog<T, H, W>: SliceEstimationLogic<W>,
+{
+    /// Reads from a reader an array with the provided logic, as written by
+    /// [`save`](SliceEstimatorArray::save).
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error of kind
+    /// [`InvalidData`](io::ErrorKind::InvalidData) if the magic bytes or the
+    /// version are wrong, or if the header does not match the logic, and an
+    /// error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) if the
+    /// data is truncated.
+    pub fn load(logic: HyperLogLog<T, H, W>, mut reader: impl Read) -> io::Result<Self> {
+        read_preamble(&mut reader, &ARRAY_MAGIC, "array")?;
+        check_field(
+            "logarithm of the number of registers",
+            read_u32(&mut reader)? as u64,
+            logic.log_2_num_registers(),
+        )?;
+        check_field(
+            "register size",
+            read_u32(&mut reader)? as u64,
+            logic.register_size,
+        )?;
+        check_field("word size", read_u32(&mut reader)? as u64, W::BYTES)?;
+        let len = read_u64(&mut reader)?;
+        let num_bytes = usize::try_from(len)
+            .ok()
+            .and_then(|len| len.checked_mul(logic.backend_len() * W::BYTES))
+            .ok_or_else(|| invalid_data(format!("the array length ({}) is too large", len)))?;
+        // We do not preallocate, as the length might be corrupted
+        let mut bytes = Vec::new();
+        reader.take(num_bytes as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != num_bytes {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!(
+                    "the saved array contains {} bytes of backends instead of {}",
+                    bytes.len(),
+                    num_bytes
+                ),
+            ));
+        }
+        Ok(Self::from_parts(logic, words_from_le_bytes(&bytes)))
+    }
+}
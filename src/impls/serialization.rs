@@ -0,0 +1,95 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! [`serde`] support for logics and arrays.
+
+use super::{BiasCorrection, EstimationMode, HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray};
+use crate::traits::SliceEstimationLogic;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sux::traits::Word;
+
+/// The serialized form of a [`HyperLogLog`] logic.
+///
+/// The logic is rebuilt from these parameters using a
+/// [`HyperLogLogBuilder`], so the hasher must be serializable and contain
+/// its seeds, if any.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "HyperLogLog")]
+struct HyperLogLogParams<H> {
+    log_2_num_reg: usize,
+    num_elements: usize,
+    linear_counting_threshold: f64,
+    estimation_mode: EstimationMode,
+    bias_correction: BiasCorrection,
+    build_hasher: H,
+}
+
+impl<T, H: Serialize + Clone, W: Word> Serialize for HyperLogLog<T, H, W> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        HyperLogLogParams {
+            log_2_num_reg: self.log_2_num_registers(),
+            num_elements: self.num_elements(),
+            linear_counting_threshold: self.linear_counting_threshold(),
+            estimation_mode: self.estimation_mode(),
+            bias_correction: self.bias_correction(),
+            build_hasher: self.build_hasher(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T, H: Deserialize<'de>, W: Word> Deserialize<'de> for HyperLogLog<T, H, W> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let params = HyperLogLogParams::<H>::deserialize(deserializer)?;
+        HyperLogLogBuilder::new(params.num_elements)
+            .log_2_num_reg(params.log_2_num_reg)
+            .word_type::<W>()
+            .build_hasher(params.build_hasher)
+            .linear_counting_threshold(params.linear_counting_threshold)
+            .estimation_mode(params.estimation_mode)
+            .bias_correction(params.bias_correction)
+            .build()
+            .map_err(D::Error::custom)
+    }
+}
+
+/// The serialized form of a [`SliceEstimatorArray`].
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "SliceEstimatorArray")]
+struct SliceEstimatorArrayParts<L, B> {
+    logic: L,
+    backend: B,
+}
+
+impl<L: Serialize, W: Serialize, S: AsRef<[W]>> Serialize for SliceEstimatorArray<L, W, S> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        SliceEstimatorArrayParts {
+            logic: &self.logic,
+            backend: self.backend.as_ref(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, L, W> Deserialize<'de> for SliceEstimatorArray<L, W, Box<[W]>>
+where
+    L: SliceEstimationLogic<W> + Deserialize<'de>,
+    W: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let parts = SliceEstimatorArrayParts::<L, Box<[W]>>::deserialize(deserializer)?;
+        let backend_len = parts.logic.backend_len();
+        if parts.backend.len() % backend_len != 0 {
+            return Err(D::Error::custom(format!(
+                "the number of words ({}) is not a multiple of the backend length ({})",
+                parts.backend.len(),
+                backend_len
+            )));
+        }
+        Ok(Self::from_parts(parts.logic, parts.backend))
+    }
+}
@@ -0,0 +1,132 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::SliceEstimatorArray;
+use crate::traits::*;
+use std::borrow::Borrow;
+use std::sync::{Arc, Mutex, MutexGuard};
+use sux::traits::Word;
+
+/// The array shared by the clones of a [`SharedEstimatorArray`].
+type Shared<L, W> = Arc<Mutex<SliceEstimatorArray<L, W, Box<[W]>>>>;
+
+/// A [`SliceEstimatorArray`] that can be shared between threads, using a
+/// [`Mutex`] for synchronization.
+///
+/// Differently from [`SyncSliceEstimatorArray`](super::SyncSliceEstimatorArray),
+/// which requires the caller to avoid data races, all accesses to the
+/// underlying array happen while holding a lock, so [`add`](Self::add) and
+/// the other inherent methods are safe, and the methods of
+/// [`SyncEstimatorArray`] are sound even if they are called concurrently on the
+/// same estimator. The price to pay is that threads are serialized on the
+/// lock: for highly concurrent additions to HyperLogLog estimators, see
+/// [`SyncEstimatorArrayAtomic`](super::SyncEstimatorArrayAtomic).
+///
+/// Cloning this structure is cheap, and the clones share the same array.
+pub struct SharedEstimatorArray<L, W> {
+    logic: L,
+    array: Shared<L, W>,
+}
+
+// We implement Clone manually because we do not want to require that W is
+// Clone.
+impl<L: Clone, W> Clone for SharedEstimatorArray<L, W> {
+    fn clone(&self) -> Self {
+        Self {
+            logic: self.logic.clone(),
+            array: self.array.clone(),
+        }
+    }
+}
+
+impl<L: SliceEstimationLogic<W> + Clone, W: Word> SharedEstimatorArray<L, W> {
+    /// Creates a new shared array of empty estimators.
+    ///
+    /// # Arguments
+    /// * `logic`: the estimator logic to use.
+    /// * `len`: the number of the estimators in the array.
+    pub fn new(logic: L, len: usize) -> Self {
+        SliceEstimatorArray::new(logic, len).into()
+    }
+
+    /// Adds an element to the estimator at the given index.
+    pub fn add(&self, index: usize, element: impl Borrow<L::Item>) {
+        self.lock().get_estimator_mut(index).add(element);
+    }
+
+    /// Returns an estimation of the number of distinct elements that have been
+    /// added to the estimator at the given index.
+    pub fn estimate(&self, index: usize) -> f64 {
+        self.lock().get_estimator(index).estimate()
+    }
+}
+
+impl<L, W> SharedEstimatorArray<L, W> {
+    /// Locks the underlying array, blocking until the lock is available.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if another thread panicked while holding the
+    /// lock.
+    pub fn lock(&self) -> MutexGuard<'_, SliceEstimatorArray<L, W, Box<[W]>>> {
+        self.array.lock().unwrap()
+    }
+
+    /// Returns the underlying array, if this is the only reference to it.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if another thread panicked while holding the
+    /// lock.
+    pub fn into_inner(self) -> Option<SliceEstimatorArray<L, W, Box<[W]>>> {
+        Arc::into_inner(self.array).map(|array| array.into_inner().unwrap())
+    }
+}
+
+impl<L: Clone, W> From<SliceEstimatorArray<L, W, Box<[W]>>> for SharedEstimatorArray<L, W> {
+    fn from(array: SliceEstimatorArray<L, W, Box<[W]>>) -> Self {
+        Self {
+            logic: array.logic.clone(),
+            array: Arc::new(Mutex::new(array)),
+        }
+    }
+}
+
+impl<L, W> SyncEstimatorArray<L> for SharedEstimatorArray<L, W>
+where
+    L: SliceEstimationLogic<W> + Clone + Send + Sync,
+    W: Word,
+{
+    fn logic(&self) -> &L {
+        &self.logic
+    }
+
+    // These methods are unsafe only because of the trait signature: the lock
+    // rules out data races.
+
+    unsafe fn set(&self, index: usize, content: &[W]) {
+        self.lock().get_backend_mut(index).copy_from_slice(content);
+    }
+
+    unsafe fn get(&self, index: usize, content: &mut [W]) {
+        content.copy_from_slice(self.lock().get_backend(index));
+    }
+
+    unsafe fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Adds an element to the estimator at `index` while holding the lock,
+    /// so differently from the default implementation this method can be
+    /// called concurrently on the same estimator.
+    unsafe fn scatter_add(&self, index: usize, element: impl Borrow<L::Item>) {
+        self.lock().get_estimator_mut(index).add(element);
+    }
+
+    fn len(&self) -> usize {
+        self.lock().len()
+    }
+}
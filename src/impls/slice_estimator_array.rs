@@ -8,6 +8,7 @@
 use super::DefaultEstimator;
 use crate::traits::*;
 use sux::traits::Word;
+#[cfg(not(target_arch = "wasm32"))]
 use sync_cell_slice::{SyncCell, SyncSlice};
 
 /// An array for estimators implementing a shared [`EstimationLogic`], and whose
@@ -15,6 +16,13 @@ use sync_cell_slice::{SyncCell, SyncSlice};
 ///
 /// Note that we need a specific type for arrays of slice backends as one cannot
 /// create a slice of slices.
+///
+/// The array is [`Send`] and [`Sync`] whenever its logic, its words, and its
+/// storage are. Since all methods modifying the array take an exclusive
+/// reference, concurrent modifications require a
+/// [`SyncSliceEstimatorArray`] or a
+/// [`SharedEstimatorArray`](super::SharedEstimatorArray).
+#[derive(Debug)]
 pub struct SliceEstimatorArray<L, W, S> {
     pub(super) logic: L,
     pub(super) backend: S,
@@ -22,12 +30,50 @@ pub struct SliceEstimatorArray<L, W, S> {
 }
 
 /// A view of a [`SliceEstimatorArray`] as a [`SyncEstimatorArray`].
+#[cfg(not(target_arch = "wasm32"))]
 pub struct SyncSliceEstimatorArray<L, W, S> {
     pub(super) logic: L,
     pub(super) backend: S,
     _marker: std::marker::PhantomData<W>,
 }
 
+/// A [`SyncSliceEstimatorArray`] owning its backend.
+#[cfg(not(target_arch = "wasm32"))]
+pub type OwnedSyncSliceEstimatorArray<L, W> = SyncSliceEstimatorArray<L, W, Box<[SyncCell<W>]>>;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<L: SliceEstimationLogic<W>, W: Word> OwnedSyncSliceEstimatorArray<L, W> {
+    /// Creates a new array of empty estimators owning its backend.
+    ///
+    /// Arrays returned by [`as_sync_array`](AsSyncArray::as_sync_array)
+    /// borrow the backend of a [`SliceEstimatorArray`]; this constructor is
+    /// useful when the array is shared between threads from the start.
+    ///
+    /// # Arguments
+    /// * `logic`: the estimator logic to use.
+    /// * `len`: the number of the estimators in the array.
+    pub fn new(logic: L, len: usize) -> Self {
+        let backend = (0..len * logic.backend_len())
+            .map(|_| SyncCell::new(W::ZERO))
+            .collect();
+        Self {
+            logic,
+            backend,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Converts this array into a [`SliceEstimatorArray`].
+    pub fn into_array(self) -> SliceEstimatorArray<L, W, Box<[W]>> {
+        let backend = Vec::from(self.backend)
+            .into_iter()
+            .map(SyncCell::into_inner)
+            .collect();
+        SliceEstimatorArray::from_parts(self.logic, backend)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
 unsafe impl<L, W, S> Sync for SyncSliceEstimatorArray<L, W, S>
 where
     L: Sync,
@@ -36,6 +82,7 @@ where
 {
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl<L: SliceEstimationLogic<W> + Sync, W: Word, S: AsRef<[SyncCell<W>]> + Sync>
     SyncEstimatorArray<L> for SyncSliceEstimatorArray<L, W, S>
 {
@@ -71,6 +118,52 @@ impl<L: SliceEstimationLogic<W> + Sync, W: Word, S: AsRef<[SyncCell<W>]> + Sync>
     }
 }
 
+impl<L, W, S> SliceEstimatorArray<L, W, S> {
+    /// Creates an estimator array from a logic and a backend storage.
+    pub(super) fn from_parts(logic: L, backend: S) -> Self {
+        Self {
+            logic,
+            backend,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the logic and the backend storage, consuming the array.
+    ///
+    /// This is the inverse of [`from_raw_parts`](Self::from_raw_parts).
+    pub fn into_raw_parts(self) -> (L, S) {
+        (self.logic, self.backend)
+    }
+}
+
+impl<L: SliceEstimationLogic<W>, W, S: AsRef<[W]>> SliceEstimatorArray<L, W, S> {
+    /// Creates an estimator array with the provided logic wrapping an
+    /// existing backend storage, without copying it.
+    ///
+    /// The storage is the concatenation of the backends of the estimators, as
+    /// returned by [`into_raw_parts`](Self::into_raw_parts), and might be,
+    /// for example, a slice of a memory-mapped file. Its length must be a
+    /// multiple of the [backend length](SliceEstimationLogic::backend_len) of
+    /// the logic, which is checked only in debug mode.
+    pub fn from_raw_parts(logic: L, storage: S) -> Self {
+        debug_assert!(
+            storage.as_ref().len() % logic.backend_len() == 0,
+            "the length of the storage ({}) is not a multiple of the backend length ({})",
+            storage.as_ref().len(),
+            logic.backend_len()
+        );
+        Self::from_parts(logic, storage)
+    }
+}
+
+// We implement Clone manually because we do not want to require that W is
+// Clone.
+impl<L: Clone, W, S: Clone> Clone for SliceEstimatorArray<L, W, S> {
+    fn clone(&self) -> Self {
+        Self::from_parts(self.logic.clone(), self.backend.clone())
+    }
+}
+
 impl<L: SliceEstimationLogic<W>, W, S: AsRef<[W]>> SliceEstimatorArray<L, W, S> {
     /// Returns the number of estimators in the array.
     #[inline(always)]
@@ -85,8 +178,381 @@ impl<L: SliceEstimationLogic<W>, W, S: AsRef<[W]>> SliceEstimatorArray<L, W, S>
     pub fn is_empty(&self) -> bool {
         self.backend.as_ref().is_empty()
     }
+
+    /// Returns whether the estimators at indices `i` and `j` have the same
+    /// backend, using [`EstimationLogic::backends_equal`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if one of the indices is out of bounds.
+    pub fn estimator_backends_equal(&self, i: usize, j: usize) -> bool
+    where
+        W: PartialEq,
+    {
+        let len = self.len();
+        assert!(
+            i < len && j < len,
+            "index out of bounds ({} and {} with {} estimators)",
+            i,
+            j,
+            len
+        );
+        let backend_len = self.logic.backend_len();
+        let backend = self.backend.as_ref();
+        self.logic.backends_equal(
+            &backend[i * backend_len..][..backend_len],
+            &backend[j * backend_len..][..backend_len],
+        )
+    }
+}
+
+impl<L: SliceEstimationLogic<W>, W, S: AsRef<[W]>> SliceEstimatorArray<L, W, S> {
+    /// Returns the number of bytes used by the array, that is, the size of
+    /// the backends plus the size of the logic.
+    ///
+    /// Memory allocated on the heap by the logic, if any, is not included.
+    pub fn total_memory_bytes(&self) -> usize {
+        size_of_val(self.backend.as_ref()) + size_of::<L>()
+    }
+
+    /// Returns the estimates of all the estimators of the array.
+    pub(super) fn estimates(&self) -> Vec<f64> {
+        self.backend
+            .as_ref()
+            .chunks(self.logic.backend_len())
+            .map(|backend| self.logic.estimate(backend))
+            .collect()
+    }
+
+    /// Returns the [histogram-equalized](crate::utils::histogram_equalize)
+    /// estimates of the estimators of the array, that is, their percentile
+    /// ranks.
+    pub fn equalized_estimates(&self) -> Vec<f64> {
+        crate::utils::histogram_equalize(&self.estimates())
+    }
+
+    /// Returns the estimates of the estimators of the array in logarithmic
+    /// scale, that is, ln(1 + *e*) for each estimate *e*.
+    ///
+    /// Results are nonnegative, and zero for empty estimators.
+    pub fn log_estimates(&self) -> Vec<f64> {
+        self.estimates().into_iter().map(f64::ln_1p).collect()
+    }
+
+    /// Returns the indices of the estimators of the array sorted by
+    /// increasing estimate.
+    ///
+    /// Ties are broken by increasing index.
+    pub fn argsort_by_estimate(&self) -> Vec<usize> {
+        let estimates = self.estimates();
+        let mut indices = (0..estimates.len()).collect::<Vec<_>>();
+        // The sort is stable, so ties are broken by index
+        indices.sort_by(|&a, &b| estimates[a].total_cmp(&estimates[b]));
+        indices
+    }
+
+    /// Returns the indices of the estimators of the array sorted by
+    /// decreasing estimate.
+    ///
+    /// Ties are broken by increasing index.
+    pub fn argsort_by_estimate_desc(&self) -> Vec<usize> {
+        let estimates = self.estimates();
+        let mut indices = (0..estimates.len()).collect::<Vec<_>>();
+        indices.sort_by(|&a, &b| estimates[b].total_cmp(&estimates[a]));
+        indices
+    }
+
+    /// Returns the indices of the `k` estimators of the array with the
+    /// largest estimates, sorted by decreasing estimate.
+    ///
+    /// Ties are broken by increasing index, so the result is the prefix of
+    /// length `k` of [`argsort_by_estimate_desc`](Self::argsort_by_estimate_desc).
+    /// If `k` is larger than the length of the array, all indices are
+    /// returned.
+    ///
+    /// This method uses a heap of size `k`, so it is faster than a full
+    /// sort when `k` is small.
+    pub fn top_k_indices(&self, k: usize) -> Vec<usize> {
+        top_k(
+            self.backend
+                .as_ref()
+                .chunks(self.logic.backend_len())
+                .map(|backend| self.logic.estimate(backend)),
+            k,
+        )
+    }
+}
+
+/// Returns a mutable reference to the backend at index `dst` and an immutable
+/// reference to the backend at index `src`, which must be different, of a
+/// concatenation of backends of length `backend_len`.
+pub(super) fn backend_pair_mut<W>(
+    backend: &mut [W],
+    backend_len: usize,
+    dst: usize,
+    src: usize,
+) -> (&mut [W], &[W]) {
+    debug_assert_ne!(dst, src);
+    if dst < src {
+        let (head, tail) = backend.split_at_mut(src * backend_len);
+        (
+            &mut head[dst * backend_len..][..backend_len],
+            &tail[..backend_len],
+        )
+    } else {
+        let (head, tail) = backend.split_at_mut(dst * backend_len);
+        (
+            &mut tail[..backend_len],
+            &head[src * backend_len..][..backend_len],
+        )
+    }
+}
+
+impl<L: SliceEstimationLogic<W> + Clone, W: Word, S: AsRef<[W]>> SliceEstimatorArray<L, W, S> {
+    /// Returns an owned estimator with a clone of the logic and a copy of the
+    /// backend of the estimator at the given index.
+    ///
+    /// The result is independent of the array, so it can be used to take a
+    /// snapshot of an estimator before further modifications.
+    pub fn duplicate_estimator(&self, index: usize) -> DefaultEstimator<L, L, Box<[W]>> {
+        DefaultEstimator::new(self.logic.clone(), Box::from(self.get_backend(index)))
+    }
+}
+
+impl<L: SliceEstimationLogic<W>, W, S: AsMut<[W]>> SliceEstimatorArray<L, W, S> {
+    /// Copies the backend of the estimator at index `src` into the backend of
+    /// the estimator at index `dst` using [`EstimationLogic::set`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if one of the indices is out of bounds.
+    pub fn copy_estimator_to(&mut self, src: usize, dst: usize) {
+        let backend_len = self.logic.backend_len();
+        let len = self.backend.as_mut().len() / backend_len;
+        assert!(
+            src < len && dst < len,
+            "index out of bounds ({} and {} with {} estimators)",
+            src,
+            dst,
+            len
+        );
+        if src == dst {
+            return;
+        }
+        let (dst, src) = backend_pair_mut(self.backend.as_mut(), backend_len, dst, src);
+        self.logic.set(dst, src);
+    }
+
+    /// Swaps in place the backends of the estimators at indices `i` and `j`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if one of the indices is out of bounds.
+    pub fn swap_estimators(&mut self, i: usize, j: usize) {
+        let backend_len = self.logic.backend_len();
+        let len = self.backend.as_mut().len() / backend_len;
+        assert!(
+            i < len && j < len,
+            "index out of bounds ({} and {} with {} estimators)",
+            i,
+            j,
+            len
+        );
+        if i == j {
+            return;
+        }
+        let (i, j) = (i.min(j), i.max(j));
+        let (head, tail) = self.backend.as_mut().split_at_mut(j * backend_len);
+        head[i * backend_len..][..backend_len].swap_with_slice(&mut tail[..backend_len]);
+    }
+}
+
+impl<L: SliceEstimationLogic<W> + MergeEstimationLogic, W, S: AsMut<[W]>>
+    SliceEstimatorArray<L, W, S>
+{
+    /// Merges into the estimator at index `target` the estimators at the
+    /// indices returned by `sources`.
+    ///
+    /// The same [helper](MergeEstimationLogic::Helper) is used for all merges.
+    /// `target` may appear among the sources: since merging an estimator with
+    /// itself does not change it, it will be simply skipped.
+    pub fn merge_range_into(&mut self, target: usize, sources: impl IntoIterator<Item = usize>) {
+        let mut helper = self.logic.new_helper();
+        for source in sources {
+            if source == target {
+                continue;
+            }
+            let (dst, src) = backend_pair_mut(
+                self.backend.as_mut(),
+                self.logic.backend_len(),
+                target,
+                source,
+            );
+            self.logic.merge_with_helper(dst, src, &mut helper);
+        }
+    }
+
+    /// Merges into the estimator at index `target` all other estimators of
+    /// the array.
+    pub fn reduce_into(&mut self, target: usize) {
+        let len = self.backend.as_mut().len() / self.logic.backend_len();
+        self.merge_range_into(target, 0..len);
+    }
+
+    /// Merges each estimator of `other` into the estimator with the same
+    /// index of this array.
+    ///
+    /// After the call, the estimator at index *i* of this array represents
+    /// the union of the sets represented by the estimators at index *i* of
+    /// the two arrays. The same [helper](MergeEstimationLogic::Helper) is used
+    /// for all merges.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the two arrays have different lengths.
+    pub fn zip_merge<S2: AsRef<[W]>>(&mut self, other: &SliceEstimatorArray<L, W, S2>) {
+        let backend_len = self.logic.backend_len();
+        let dst = self.backend.as_mut();
+        let src = other.backend.as_ref();
+        assert_eq!(
+            dst.len(),
+            src.len(),
+            "the arrays have different lengths ({} and {} estimators)",
+            dst.len() / backend_len,
+            src.len() / backend_len
+        );
+        let mut helper = self.logic.new_helper();
+        for (dst, src) in dst.chunks_mut(backend_len).zip(src.chunks(backend_len)) {
+            self.logic.merge_with_helper(dst, src, &mut helper);
+        }
+    }
+}
+
+impl<L: SliceEstimationLogic<W> + MergeEstimationLogic + Clone, W: Word, S: AsRef<[W]>>
+    SliceEstimatorArray<L, W, S>
+{
+    /// Returns an estimation of the number of distinct elements in the union
+    /// of the estimators at the given indices.
+    ///
+    /// The estimators are merged into a temporary backend, so the array is not
+    /// modified. If there are no indices, this method returns zero.
+    pub fn aggregate_filtered(&self, indices: impl IntoIterator<Item = usize>) -> f64 {
+        let mut indices = indices.into_iter().peekable();
+        if indices.peek().is_none() {
+            return 0.0;
+        }
+        let mut acc = vec![W::ZERO; self.logic.backend_len()];
+        let mut helper = self.logic.new_helper();
+        for index in indices {
+            self.logic
+                .merge_with_helper(&mut acc, self.get_backend(index), &mut helper);
+        }
+        self.logic.estimate(&acc)
+    }
+
+    /// Merges all the estimators of the array into the given backend.
+    pub fn merge_all(&self, dst: &mut L::Backend) {
+        let mut helper = self.logic.new_helper();
+        for backend in self.backend.as_ref().chunks(self.logic.backend_len()) {
+            self.logic.merge_with_helper(dst, backend, &mut helper);
+        }
+    }
+
+    /// Returns an estimation of the number of distinct elements in the union
+    /// of all the estimators of the array.
+    ///
+    /// This is equivalent to calling
+    /// [`aggregate_filtered`](SliceEstimatorArray::aggregate_filtered) on all
+    /// indices.
+    pub fn aggregate_all(&self) -> f64 {
+        self.aggregate_filtered(0..self.len())
+    }
+
+    /// Returns a new array whose estimator at each index is the merge of the
+    /// estimators with the same index of `a` and `b`.
+    ///
+    /// This is the non-mutating version of
+    /// [`zip_merge`](SliceEstimatorArray::zip_merge); the result uses a clone
+    /// of the logic of `a`.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the two arrays have different lengths.
+    pub fn zip_merge_into<S2: AsRef<[W]>>(
+        a: &Self,
+        b: &SliceEstimatorArray<L, W, S2>,
+    ) -> SliceEstimatorArray<L, W, Box<[W]>> {
+        let mut result =
+            SliceEstimatorArray::from_parts(a.logic.clone(), Box::from(a.backend.as_ref()));
+        result.zip_merge(b);
+        result
+    }
+}
+
+impl<L: SliceEstimationLogic<W>, W: Copy, S: AsMut<[W]>> SliceEstimatorArray<L, W, S> {
+    /// Sets the backends at the given indices.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if an index is out of bounds or a backend has
+    /// the wrong length.
+    pub fn batch_set<'a>(&mut self, updates: impl IntoIterator<Item = (usize, &'a [W])>)
+    where
+        W: 'a,
+    {
+        let backend_len = self.logic.backend_len();
+        let backend = self.backend.as_mut();
+        let len = backend.len() / backend_len;
+        for (index, content) in updates {
+            assert!(
+                index < len,
+                "index {} out of bounds ({} estimators)",
+                index,
+                len
+            );
+            assert_eq!(
+                content.len(),
+                backend_len,
+                "backend at index {} has length {}, but the backend length is {}",
+                index,
+                content.len(),
+                backend_len
+            );
+            backend[index * backend_len..][..backend_len].copy_from_slice(content);
+        }
+    }
+
+    /// Sets the backends at the given indices without checking indices and
+    /// backend lengths.
+    ///
+    /// # Safety
+    ///
+    /// All indices must be smaller than the length of the array, and all
+    /// backends must have the [backend length](SliceEstimationLogic::backend_len)
+    /// of the logic.
+    pub unsafe fn batch_set_unchecked<'a>(
+        &mut self,
+        updates: impl IntoIterator<Item = (usize, &'a [W])>,
+    ) where
+        W: 'a,
+    {
+        let backend_len = self.logic.backend_len();
+        let backend = self.backend.as_mut();
+        for (index, content) in updates {
+            debug_assert!((index + 1) * backend_len <= backend.len());
+            debug_assert_eq!(content.len(), backend_len);
+            unsafe {
+                std::ptr::copy_nonoverlapping(
+                    content.as_ptr(),
+                    backend.as_mut_ptr().add(index * backend_len),
+                    backend_len,
+                )
+            };
+        }
+    }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl<L: SliceEstimationLogic<W> + Clone + Sync, W: Word, S: AsMut<[W]>> AsSyncArray<L>
     for SliceEstimatorArray<L, W, S>
 {
@@ -104,6 +570,19 @@ impl<L: SliceEstimationLogic<W> + Clone + Sync, W: Word, S: AsMut<[W]>> AsSyncAr
     }
 }
 
+impl<L: SliceEstimationLogic<W>, W, S: AsRef<[W]>> std::fmt::Display
+    for SliceEstimatorArray<L, W, S>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SliceEstimatorArray with {} estimators ({} bytes/estimator)",
+            self.len(),
+            self.logic.backend_len() * size_of::<W>()
+        )
+    }
+}
+
 impl<L, W, S: AsRef<[W]>> AsRef<[W]> for SliceEstimatorArray<L, W, S> {
     fn as_ref(&self) -> &[W] {
         self.backend.as_ref()
@@ -131,6 +610,41 @@ impl<L: SliceEstimationLogic<W>, W: Word> SliceEstimatorArray<L, W, Box<[W]>> {
             _marker: std::marker::PhantomData,
         }
     }
+
+    /// Creates a new estimator slice with the provided logic, and adds to its
+    /// estimators a stream of elements.
+    ///
+    /// # Arguments
+    /// * `logic`: the estimator logic to use.
+    /// * `len`: the number of the estimators in the array.
+    /// * `pairs`: pairs made of the index of an estimator and an element to
+    ///   add to it, as in [`batch_add`](EstimatorArrayMut::batch_add).
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if an index is out of bounds.
+    pub fn from_pairs(
+        logic: L,
+        len: usize,
+        pairs: impl IntoIterator<Item = (usize, L::Item)>,
+    ) -> Self
+    where
+        L: Clone,
+    {
+        let mut array = Self::new(logic, len);
+        array.extend(pairs);
+        array
+    }
+}
+
+/// Adds each element to the estimator with the given index using
+/// [`batch_add`](EstimatorArrayMut::batch_add).
+impl<L: SliceEstimationLogic<W> + Clone, W: Word, S: AsRef<[W]> + AsMut<[W]>>
+    Extend<(usize, L::Item)> for SliceEstimatorArray<L, W, S>
+{
+    fn extend<I: IntoIterator<Item = (usize, L::Item)>>(&mut self, pairs: I) {
+        self.batch_add(pairs);
+    }
 }
 
 impl<L: SliceEstimationLogic<W> + Clone, W: Word, S: AsRef<[W]>> EstimatorArray<L>
@@ -161,6 +675,31 @@ impl<L: SliceEstimationLogic<W> + Clone, W: Word, S: AsRef<[W]>> EstimatorArray<
     fn len(&self) -> usize {
         self.len()
     }
+
+    fn iter(&self) -> impl ExactSizeIterator<Item = Self::Estimator<'_>> + DoubleEndedIterator {
+        let logic = &self.logic;
+        self.backend
+            .as_ref()
+            .chunks(logic.backend_len())
+            .map(move |backend| DefaultEstimator::new(logic, backend))
+    }
+
+    fn iter_backends<'a>(
+        &'a self,
+    ) -> impl ExactSizeIterator<Item = &'a L::Backend> + DoubleEndedIterator
+    where
+        L: 'a,
+    {
+        self.backend.as_ref().chunks(self.logic.backend_len())
+    }
+
+    fn estimate_all(&self) -> Vec<f64> {
+        self.estimates()
+    }
+
+    fn top_k_by_estimate(&self, k: usize) -> Vec<usize> {
+        self.top_k_indices(k)
+    }
 }
 
 impl<L: SliceEstimationLogic<W> + Clone, W: Word, S: AsRef<[W]> + AsMut<[W]>> EstimatorArrayMut<L>
@@ -188,8 +727,241 @@ impl<L: SliceEstimationLogic<W> + Clone, W: Word, S: AsRef<[W]> + AsMut<[W]>> Es
         DefaultEstimator::new(logic, backend)
     }
 
+    fn iter_mut(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = Self::EstimatorMut<'_>> + DoubleEndedIterator {
+        let logic = &self.logic;
+        self.backend
+            .as_mut()
+            .chunks_mut(logic.backend_len())
+            .map(move |backend| DefaultEstimator::new(logic, backend))
+    }
+
+    fn iter_backends_mut<'a>(
+        &'a mut self,
+    ) -> impl ExactSizeIterator<Item = &'a mut L::Backend> + DoubleEndedIterator
+    where
+        L: 'a,
+    {
+        self.backend.as_mut().chunks_mut(self.logic.backend_len())
+    }
+
     #[inline(always)]
     fn clear(&mut self) {
         self.backend.as_mut().iter_mut().for_each(|v| *v = W::ZERO)
     }
+
+    fn clear_range(&mut self, start: usize, end: usize) {
+        assert!(
+            start <= end && end <= self.len(),
+            "invalid range [{} . . {}) for an array of length {}",
+            start,
+            end,
+            self.len()
+        );
+        let backend_len = self.logic.backend_len();
+        self.backend.as_mut()[start * backend_len..end * backend_len].fill(W::ZERO);
+    }
+
+    fn batch_add(&mut self, pairs: impl IntoIterator<Item = (usize, L::Item)>) {
+        let backend_len = self.logic.backend_len();
+        let backend = self.backend.as_mut();
+        let mut current = None;
+        let mut slot: &mut [W] = &mut [];
+        for (index, element) in pairs {
+            // Runs of the same index reuse the same slice
+            if current != Some(index) {
+                current = Some(index);
+                slot = &mut backend[index * backend_len..][..backend_len];
+            }
+            self.logic.add(slot, element);
+        }
+    }
+
+    /// Adds a stream of elements, sorted by index, to the estimators of the
+    /// array.
+    ///
+    /// Backends are visited in order, so that each backend is located at most
+    /// once.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the pairs are not sorted by index or if an
+    /// index is out of bounds.
+    fn batch_add_sorted(&mut self, pairs: impl IntoIterator<Item = (usize, L::Item)>) {
+        let backend_len = self.logic.backend_len();
+        let mut backends = self.backend.as_mut().chunks_mut(backend_len);
+        let mut current = None;
+        let mut slot: &mut [W] = &mut [];
+        for (index, element) in pairs {
+            if current != Some(index) {
+                let skip = match current {
+                    None => index,
+                    Some(current) => {
+                        assert!(
+                            index > current,
+                            "pairs are not sorted by index ({} follows {})",
+                            index,
+                            current
+                        );
+                        index - current - 1
+                    }
+                };
+                current = Some(index);
+                slot = backends
+                    .nth(skip)
+                    .unwrap_or_else(|| panic!("index {} out of bounds", index));
+            }
+            self.logic.add(slot, element);
+        }
+    }
+
+    fn apply(&mut self, f: impl FnMut(&mut L::Backend)) {
+        let backend_len = self.logic.backend_len();
+        self.backend.as_mut().chunks_mut(backend_len).for_each(f)
+    }
+
+    fn apply_indexed(&mut self, mut f: impl FnMut(usize, &mut L::Backend)) {
+        let backend_len = self.logic.backend_len();
+        self.backend
+            .as_mut()
+            .chunks_mut(backend_len)
+            .enumerate()
+            .for_each(|(i, backend)| f(i, backend))
+    }
+}
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+impl<L: SliceEstimationLogic<W>, W: Word, S: AsMut<[W]>> SliceEstimatorArray<L, W, S> {
+    /// Applies in parallel a function to the backend of every estimator in the
+    /// array.
+    ///
+    /// This is a parallel version of [`apply`](EstimatorArrayMut::apply).
+    pub fn apply_par(&mut self, f: impl Fn(&mut L::Backend) + Sync + Send) {
+        use rayon::prelude::*;
+        let backend_len = self.logic.backend_len();
+        self.backend
+            .as_mut()
+            .par_chunks_mut(backend_len)
+            .for_each(f)
+    }
+
+    /// Applies in parallel a function to the backend of every estimator in the
+    /// array, passing also the index of the estimator.
+    ///
+    /// This is a parallel version of
+    /// [`apply_indexed`](EstimatorArrayMut::apply_indexed).
+    pub fn apply_indexed_par(&mut self, f: impl Fn(usize, &mut L::Backend) + Sync + Send) {
+        use rayon::prelude::*;
+        let backend_len = self.logic.backend_len();
+        self.backend
+            .as_mut()
+            .par_chunks_mut(backend_len)
+            .enumerate()
+            .for_each(|(i, backend)| f(i, backend))
+    }
+}
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+impl<L: SliceEstimationLogic<W>, W: Sync, S: AsRef<[W]>> SliceEstimatorArray<L, W, S> {
+    /// Returns a parallel iterator over the backends of the estimators in the
+    /// array, in index order.
+    ///
+    /// This is a parallel version of
+    /// [`iter_backends`](EstimatorArray::iter_backends).
+    pub fn par_iter_backends(
+        &self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = &L::Backend> {
+        use rayon::prelude::*;
+        self.backend.as_ref().par_chunks(self.logic.backend_len())
+    }
+}
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+impl<L: SliceEstimationLogic<W>, W: Send, S: AsMut<[W]>> SliceEstimatorArray<L, W, S> {
+    /// Returns a parallel iterator over the mutable backends of the
+    /// estimators in the array, in index order.
+    ///
+    /// This is a parallel version of
+    /// [`iter_backends_mut`](EstimatorArrayMut::iter_backends_mut).
+    pub fn par_iter_backends_mut(
+        &mut self,
+    ) -> impl rayon::iter::IndexedParallelIterator<Item = &mut L::Backend> {
+        use rayon::prelude::*;
+        let backend_len = self.logic.backend_len();
+        self.backend.as_mut().par_chunks_mut(backend_len)
+    }
+}
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+impl<L: SliceEstimationLogic<W> + Sync, W: Sync, S: AsRef<[W]>> SliceEstimatorArray<L, W, S> {
+    /// Returns the indices of the estimators of the array sorted by
+    /// increasing estimate, computing estimates and sorting in parallel.
+    ///
+    /// This is a parallel version of
+    /// [`argsort_by_estimate`](SliceEstimatorArray::argsort_by_estimate).
+    pub fn argsort_by_estimate_par(&self) -> Vec<usize> {
+        use rayon::prelude::*;
+        let logic = &self.logic;
+        let estimates = self
+            .backend
+            .as_ref()
+            .par_chunks(logic.backend_len())
+            .map(|backend| logic.estimate(backend))
+            .collect::<Vec<_>>();
+        let mut indices = (0..estimates.len()).collect::<Vec<_>>();
+        indices.par_sort_by(|&a, &b| estimates[a].total_cmp(&estimates[b]));
+        indices
+    }
+}
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+impl<L: SliceEstimationLogic<W> + Clone + Sync, W: Word, S: AsMut<[W]>>
+    SliceEstimatorArray<L, W, S>
+{
+    /// Sets in parallel the backends at the given indices.
+    ///
+    /// This is a parallel version of
+    /// [`batch_set`](SliceEstimatorArray::batch_set), using a
+    /// [`SyncSliceEstimatorArray`] to set backends concurrently.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if an index is out of bounds or appears more
+    /// than once, or if a backend has the wrong length.
+    pub fn batch_set_par<'a>(&mut self, updates: impl IntoIterator<Item = (usize, &'a [W])>)
+    where
+        W: 'a,
+    {
+        use rayon::prelude::*;
+        let backend_len = self.logic.backend_len();
+        let len = self.backend.as_mut().len() / backend_len;
+        let updates = updates.into_iter().collect::<Vec<_>>();
+        // Checking that indices are distinct guarantees that there are no
+        // data races
+        let mut seen = vec![false; len];
+        for &(index, content) in &updates {
+            assert!(
+                index < len,
+                "index {} out of bounds ({} estimators)",
+                index,
+                len
+            );
+            assert!(!seen[index], "index {} appears more than once", index);
+            seen[index] = true;
+            assert_eq!(
+                content.len(),
+                backend_len,
+                "backend at index {} has length {}, but the backend length is {}",
+                index,
+                content.len(),
+                backend_len
+            );
+        }
+
+        let sync_array = self.as_sync_array();
+        updates
+            .into_par_iter()
+            .for_each(|(index, content)| unsafe { sync_array.set(index, content) });
+    }
 }
@@ -0,0 +1,158 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::DefaultEstimator;
+use crate::traits::*;
+use sux::traits::Word;
+
+/// An array of `N` estimators with slice backends stored inline, without heap
+/// allocation.
+///
+/// The backends are stored in an array of type `[[W; REGISTERS]; N]`, so the
+/// whole structure lives on the stack (or wherever it is embedded), which is
+/// useful when creating many small groups of estimators (e.g., a few per node
+/// of a graph). `REGISTERS` is the capacity in words of each backend, and it
+/// must be at least the [backend length](SliceEstimationLogic::backend_len)
+/// of the logic: for logics storing one register per word, such as
+/// [`HllConfig`](super::HllConfig) with `u8` words, it is `1 << LOG2M`;
+/// with packed registers a smaller value is sufficient, and the words in excess
+/// are unused.
+///
+/// For example, `SmallSliceEstimatorArray::<HyperLogLog<T, H, u8>, u8, 4,
+/// 4096>::new(logic)` creates an array of four estimators with 2¹²
+/// registers.
+pub struct SmallSliceEstimatorArray<L, W, const N: usize, const REGISTERS: usize> {
+    logic: L,
+    backends: [[W; REGISTERS]; N],
+}
+
+impl<L: SliceEstimationLogic<W>, W: Word, const N: usize, const REGISTERS: usize>
+    SmallSliceEstimatorArray<L, W, N, REGISTERS>
+{
+    /// Creates a new array of `N` empty estimators.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the backend length of the logic is larger
+    /// than `REGISTERS`.
+    pub fn new(logic: L) -> Self {
+        assert!(
+            logic.backend_len() <= REGISTERS,
+            "the backend length of the logic ({}) is larger than the capacity of a backend ({})",
+            logic.backend_len(),
+            REGISTERS
+        );
+        Self {
+            logic,
+            backends: [[W::ZERO; REGISTERS]; N],
+        }
+    }
+
+    /// Returns the number of estimators in the array, that is, `N`.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if `N` is zero.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns the logic and the backends, consuming the array.
+    pub fn into_inner(self) -> (L, [[W; REGISTERS]; N]) {
+        (self.logic, self.backends)
+    }
+}
+
+impl<L: SliceEstimationLogic<W> + Clone, W: Word, const N: usize, const REGISTERS: usize>
+    EstimatorArray<L> for SmallSliceEstimatorArray<L, W, N, REGISTERS>
+{
+    type Estimator<'a>
+        = DefaultEstimator<L, &'a L, &'a [W]>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_backend(&self, index: usize) -> &L::Backend {
+        &self.backends[index][..self.logic.backend_len()]
+    }
+
+    #[inline(always)]
+    fn logic(&self) -> &L {
+        &self.logic
+    }
+
+    #[inline(always)]
+    fn get_estimator(&self, index: usize) -> Self::Estimator<'_> {
+        DefaultEstimator::new(&self.logic, self.get_backend(index))
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn iter_backends<'a>(
+        &'a self,
+    ) -> impl ExactSizeIterator<Item = &'a L::Backend> + DoubleEndedIterator
+    where
+        L: 'a,
+    {
+        let backend_len = self.logic.backend_len();
+        self.backends
+            .iter()
+            .map(move |backend| &backend[..backend_len])
+    }
+}
+
+impl<L: SliceEstimationLogic<W> + Clone, W: Word, const N: usize, const REGISTERS: usize>
+    EstimatorArrayMut<L> for SmallSliceEstimatorArray<L, W, N, REGISTERS>
+{
+    type EstimatorMut<'a>
+        = DefaultEstimator<L, &'a L, &'a mut [W]>
+    where
+        Self: 'a;
+
+    #[inline(always)]
+    fn get_backend_mut(&mut self, index: usize) -> &mut L::Backend {
+        &mut self.backends[index][..self.logic.backend_len()]
+    }
+
+    #[inline(always)]
+    fn get_estimator_mut(&mut self, index: usize) -> Self::EstimatorMut<'_> {
+        let backend_len = self.logic.backend_len();
+        DefaultEstimator::new(&self.logic, &mut self.backends[index][..backend_len])
+    }
+
+    fn iter_mut(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = Self::EstimatorMut<'_>> + DoubleEndedIterator {
+        let logic = &self.logic;
+        let backend_len = logic.backend_len();
+        self.backends
+            .iter_mut()
+            .map(move |backend| DefaultEstimator::new(logic, &mut backend[..backend_len]))
+    }
+
+    fn iter_backends_mut<'a>(
+        &'a mut self,
+    ) -> impl ExactSizeIterator<Item = &'a mut L::Backend> + DoubleEndedIterator
+    where
+        L: 'a,
+    {
+        let backend_len = self.logic.backend_len();
+        self.backends
+            .iter_mut()
+            .map(move |backend| &mut backend[..backend_len])
+    }
+
+    #[inline(always)]
+    fn clear(&mut self) {
+        self.backends
+            .iter_mut()
+            .for_each(|backend| backend.fill(W::ZERO));
+    }
+}
@@ -0,0 +1,381 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::{ensure, Result};
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::*;
+
+use super::hll_plus_plus_data::{BIAS_DATA, RAW_ESTIMATE_DATA};
+use super::{alpha_m, DefaultEstimator};
+use crate::traits::{EstimationLogic, MergeEstimationLogic, SliceEstimationLogic};
+
+/// The number of bits of a register in Spark's representation.
+const SPARK_REGISTER_SIZE: usize = 6;
+
+/// The number of registers in a 64-bit word in Spark's representation.
+const SPARK_REGISTERS_PER_WORD: usize = 64 / SPARK_REGISTER_SIZE;
+
+/// The thresholds below which Spark uses linear counting, indexed by the
+/// logarithm of the number of registers minus 4.
+const SPARK_THRESHOLDS: [f64; 15] = [
+    10.0, 20.0, 40.0, 80.0, 220.0, 400.0, 900.0, 1800.0, 3100.0, 6500.0, 11500.0, 20000.0, 50000.0,
+    120000.0, 350000.0,
+];
+
+/// The number of raw estimates used to interpolate the bias.
+const BIAS_NEIGHBORS: usize = 6;
+
+/// Returns the number of 64-bit words of Spark's representation of
+/// `num_registers` registers.
+const fn spark_num_words(num_registers: usize) -> usize {
+    num_registers / SPARK_REGISTERS_PER_WORD + 1
+}
+
+/// Estimator logic following the HyperLogLog++ implementation used by Apache
+/// Spark's `approx_count_distinct`.
+///
+/// Registers are stored one per byte, so the [backend
+/// length](SliceEstimationLogic::backend_len) is equal to the number of
+/// registers. Register indices and values are computed from hashes as in
+/// Spark: the index is given by the highest bits of the hash, and the value
+/// by the number of leading zeroes of the remaining bits plus one. The number
+/// of registers is derived from the relative standard deviation using the
+/// same formula of Spark.
+///
+/// Spark hashes elements using XXH64 with seed 42; for 64-bit integers, the
+/// same hashes are obtained using as [`BuildHasher`] an XXH64 builder with
+/// seed 42 (e.g., `xxhash_rust::xxh64::Xxh64Builder::new(42)`), as hashing a
+/// `u64` in Rust feeds the hasher with its eight bytes. For other types, the
+/// hashes will in general be different.
+///
+/// The estimate is computed as in Spark, using linear counting below Spark's
+/// thresholds and correcting raw estimates smaller than five times the number
+/// of registers with the empirical bias of HyperLogLog++, which is
+/// interpolated by averaging the biases of the six nearest raw estimates of
+/// the tables of Heule, Nunkesser, and Hall; the result is rounded to the
+/// nearest integer.
+///
+/// Backends can be converted from and to Spark's representation using
+/// [`import_spark_sketch`] and [`export_spark_sketch`].
+#[derive(Debug, PartialEq)]
+pub struct SparkCompatHyperLogLog<T, H> {
+    build_hasher: H,
+    log_2_num_registers: usize,
+    _marker: std::marker::PhantomData<T>,
+}
+
+// We implement Clone manually because we do not want to require that T is
+// Clone.
+impl<T, H: Clone> Clone for SparkCompatHyperLogLog<T, H> {
+    fn clone(&self) -> Self {
+        Self {
+            build_hasher: self.build_hasher.clone(),
+            log_2_num_registers: self.log_2_num_registers,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, H> SparkCompatHyperLogLog<T, H> {
+    /// The maximum supported logarithm of the number of registers, which is
+    /// the largest one for which Spark has thresholds and bias data.
+    pub const MAX_LOG_2_NUM_REGISTERS: usize = 18;
+
+    /// Creates a new logic with the number of registers that Spark uses for
+    /// a given relative standard deviation.
+    ///
+    /// # Arguments
+    /// * `relative_sd`: the relative standard deviation (the `rsd` argument
+    ///   of `approx_count_distinct`, whose default is 0.05).
+    /// * `build_hasher`: the [`BuildHasher`] used to hash elements.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the resulting logarithm of the
+    /// number of registers is not between 4 and
+    /// [`MAX_LOG_2_NUM_REGISTERS`](Self::MAX_LOG_2_NUM_REGISTERS).
+    pub fn new(relative_sd: f64, build_hasher: H) -> Result<Self> {
+        ensure!(
+            relative_sd > 0.0,
+            "the relative standard deviation must be positive"
+        );
+        let log_2_num_registers = (2.0 * (1.106 / relative_sd).ln() / 2.0_f64.ln()).ceil();
+        ensure!(
+            log_2_num_registers >= 4.0,
+            "the relative standard deviation {} is too large",
+            relative_sd
+        );
+        Self::with_log_2_num_registers(log_2_num_registers as usize, build_hasher)
+    }
+
+    /// Creates a new logic with a given number of registers.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if `log_2_num_registers` is not
+    /// between 4 and [`MAX_LOG_2_NUM_REGISTERS`](Self::MAX_LOG_2_NUM_REGISTERS).
+    pub fn with_log_2_num_registers(log_2_num_registers: usize, build_hasher: H) -> Result<Self> {
+        ensure!(
+            (4..=Self::MAX_LOG_2_NUM_REGISTERS).contains(&log_2_num_registers),
+            "the logarithm of the number of registers must be between 4 and {}, but it is {}",
+            Self::MAX_LOG_2_NUM_REGISTERS,
+            log_2_num_registers
+        );
+        Ok(Self {
+            build_hasher,
+            log_2_num_registers,
+            _marker: std::marker::PhantomData,
+        })
+    }
+
+    /// Returns the logarithm of the number of registers.
+    pub fn log_2_num_registers(&self) -> usize {
+        self.log_2_num_registers
+    }
+
+    /// Returns the number of registers.
+    pub fn num_registers(&self) -> usize {
+        1 << self.log_2_num_registers
+    }
+}
+
+impl<T, H> SparkCompatHyperLogLog<T, H> {
+    /// Returns the empirical bias of a raw estimate, computed exactly as in
+    /// Spark.
+    fn estimate_bias(&self, e: f64) -> f64 {
+        let estimates = RAW_ESTIMATE_DATA[self.log_2_num_registers - 4];
+        let biases = BIAS_DATA[self.log_2_num_registers - 4];
+        let distance = |i: usize| (e - estimates[i]) * (e - estimates[i]);
+
+        // The raw estimates are not strictly sorted, so to select the same
+        // neighbors as Spark we replicate the binary search of
+        // java.util.Arrays, returning the insertion point if e is not found
+        let mut low = 0_isize;
+        let mut high = estimates.len() as isize - 1;
+        let nearest = loop {
+            if low > high {
+                break low as usize;
+            }
+            let mid = (low + high) / 2;
+            if estimates[mid as usize] < e {
+                low = mid + 1;
+            } else if estimates[mid as usize] > e {
+                high = mid - 1;
+            } else {
+                break mid as usize;
+            }
+        };
+
+        // Move the window as long as the (exclusive) upper bound is closer to
+        // e than the (inclusive) lower bound
+        let mut low = nearest.saturating_sub(BIAS_NEIGHBORS - 1);
+        let mut high = (low + BIAS_NEIGHBORS).min(estimates.len());
+        while high < estimates.len() && distance(high) < distance(low) {
+            low += 1;
+            high += 1;
+        }
+
+        biases[low..high].iter().sum::<f64>() / (high - low) as f64
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> SliceEstimationLogic<u8> for SparkCompatHyperLogLog<T, H> {
+    fn backend_len(&self) -> usize {
+        self.num_registers()
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> EstimationLogic for SparkCompatHyperLogLog<T, H> {
+    type Item = T;
+    type Backend = [u8];
+    type Estimator<'a>
+        = DefaultEstimator<Self, &'a Self, Box<[u8]>>
+    where
+        T: 'a,
+        H: 'a;
+
+    fn new_estimator(&self) -> Self::Estimator<'_> {
+        DefaultEstimator::new(self, vec![0; self.num_registers()].into_boxed_slice())
+    }
+
+    fn add(&self, backend: &mut [u8], element: impl Borrow<T>) {
+        debug_assert_eq!(backend.len(), self.num_registers());
+        let p = self.log_2_num_registers;
+        let x = self.build_hasher.hash_one(element.borrow());
+        let register = (x >> (64 - p)) as usize;
+        let value = ((x << p) | (1 << (p - 1))).leading_zeros() as u8 + 1;
+        backend[register] = backend[register].max(value);
+    }
+
+    fn estimate(&self, backend: &[u8]) -> f64 {
+        debug_assert_eq!(backend.len(), self.num_registers());
+        let p = self.log_2_num_registers;
+        let m = self.num_registers() as f64;
+        let alpha = alpha_m(self.num_registers());
+
+        let mut z_inverse = 0.0;
+        let mut zeroes = 0;
+        for &register in backend {
+            z_inverse += 1.0 / (1_u64 << register) as f64;
+            if register == 0 {
+                zeroes += 1;
+            }
+        }
+
+        let e = alpha * m * m / z_inverse;
+        let bias_corrected = || {
+            if e < 5.0 * m {
+                e - self.estimate_bias(e)
+            } else {
+                e
+            }
+        };
+        let estimate = if zeroes > 0 {
+            let h = m * (m / zeroes as f64).ln();
+            if h <= SPARK_THRESHOLDS[p - 4] {
+                h
+            } else {
+                bias_corrected()
+            }
+        } else {
+            bias_corrected()
+        };
+        estimate.round()
+    }
+
+    fn clear(&self, backend: &mut [u8]) {
+        backend.fill(0);
+    }
+
+    fn set(&self, dst: &mut [u8], src: &[u8]) {
+        debug_assert_eq!(dst.len(), src.len());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> MergeEstimationLogic for SparkCompatHyperLogLog<T, H> {
+    type Helper = ();
+
+    fn new_helper(&self) -> Self::Helper {}
+
+    fn merge_with_helper(&self, dst: &mut [u8], src: &[u8], _helper: &mut Self::Helper) {
+        debug_assert_eq!(dst.len(), src.len());
+        for (d, &s) in dst.iter_mut().zip(src) {
+            *d = (*d).max(s);
+        }
+    }
+}
+
+/// An error in the conversion of a sketch in Spark's representation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SparkCompatError {
+    /// The number of bytes is not a multiple of eight.
+    InvalidLength(usize),
+    /// The number of 64-bit words does not correspond to a supported number
+    /// of registers.
+    InvalidNumWords(usize),
+    /// A register has a value that cannot be produced by the hashing
+    /// process.
+    InvalidRegisterValue {
+        /// The index of the register.
+        register: usize,
+        /// The value of the register.
+        value: u8,
+    },
+}
+
+impl Display for SparkCompatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidLength(len) => {
+                write!(
+                    f,
+                    "the sketch length ({} bytes) is not a multiple of 8",
+                    len
+                )
+            }
+            Self::InvalidNumWords(num_words) => write!(
+                f,
+                "no supported number of registers corresponds to {} words",
+                num_words
+            ),
+            Self::InvalidRegisterValue { register, value } => {
+                write!(f, "register {} has invalid value {}", register, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SparkCompatError {}
+
+/// Converts a sketch in Spark's representation into a backend for a
+/// [`SparkCompatHyperLogLog`] logic with the same number of registers.
+///
+/// In Spark's aggregation buffer, registers are six bits wide, and they are
+/// packed ten per 64-bit word, starting from the lowest bits, in *m* / 10 + 1
+/// words, where *m* is the number of registers. The sketch is the sequence of
+/// the little-endian representations of the words, and the number of
+/// registers is deduced from its length.
+///
+/// # Errors
+///
+/// This function will return an error if the length of `bytes` does not
+/// correspond to a supported number of registers, or if a register contains
+/// an impossible value.
+pub fn import_spark_sketch(bytes: &[u8]) -> Result<Box<[u8]>, SparkCompatError> {
+    if bytes.len() % 8 != 0 {
+        return Err(SparkCompatError::InvalidLength(bytes.len()));
+    }
+    let num_words = bytes.len() / 8;
+    let log_2_num_registers = (4..=SparkCompatHyperLogLog::<(), ()>::MAX_LOG_2_NUM_REGISTERS)
+        .find(|&p| spark_num_words(1 << p) == num_words)
+        .ok_or(SparkCompatError::InvalidNumWords(num_words))?;
+    let num_registers = 1 << log_2_num_registers;
+    let max_value = (64 - log_2_num_registers + 1) as u8;
+
+    let words = bytes
+        .chunks_exact(8)
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+        .collect::<Vec<_>>();
+    let mut backend = vec![0; num_registers].into_boxed_slice();
+    for (register, value) in backend.iter_mut().enumerate() {
+        let word = words[register / SPARK_REGISTERS_PER_WORD];
+        let shift = SPARK_REGISTER_SIZE * (register % SPARK_REGISTERS_PER_WORD);
+        *value = ((word >> shift) & ((1 << SPARK_REGISTER_SIZE) - 1)) as u8;
+        if *value > max_value {
+            return Err(SparkCompatError::InvalidRegisterValue {
+                register,
+                value: *value,
+            });
+        }
+    }
+    Ok(backend)
+}
+
+/// Converts a backend of a [`SparkCompatHyperLogLog`] logic into Spark's
+/// representation, as described in [`import_spark_sketch`].
+///
+/// # Panics
+///
+/// This function will panic if the length of `backend` is not the number of
+/// registers of `logic`.
+pub fn export_spark_sketch<T, H>(logic: &SparkCompatHyperLogLog<T, H>, backend: &[u8]) -> Vec<u8> {
+    let num_registers = logic.num_registers();
+    assert_eq!(
+        backend.len(),
+        num_registers,
+        "the backend has length {}, but the logic has {} registers",
+        backend.len(),
+        num_registers
+    );
+    let mut words = vec![0_u64; spark_num_words(num_registers)];
+    for (register, &value) in backend.iter().enumerate() {
+        let shift = SPARK_REGISTER_SIZE * (register % SPARK_REGISTERS_PER_WORD);
+        words[register / SPARK_REGISTERS_PER_WORD] |= (value as u64) << shift;
+    }
+    words.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
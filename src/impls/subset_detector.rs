@@ -0,0 +1,66 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::{HyperLogLog, SliceEstimatorArray};
+use crate::traits::*;
+use common_traits::{CastableFrom, UpcastableInto};
+use std::hash::{BuildHasher, Hash};
+use sux::traits::Word;
+
+/// Probabilistic detection of subset relationships between the estimators of
+/// an array of [`HyperLogLog`] estimators.
+///
+/// If an estimator contains a subset of the elements of another estimator,
+/// every register of the first estimator is smaller than or equal to the
+/// corresponding register of the second estimator. The converse is not true,
+/// but the fraction of registers satisfying this property is a good
+/// indication of how much the first set is contained in the second: it is
+/// always one for a subset, and it is well below one for sets with
+/// comparable sizes and a small intersection.
+pub struct SubsetDetector<'a, T, H, W, S> {
+    array: &'a SliceEstimatorArray<HyperLogLog<T, H, W>, W, S>,
+}
+
+impl<
+        'a,
+        T: Hash,
+        H: BuildHasher + Clone,
+        W: Word + UpcastableInto<u64> + CastableFrom<u64>,
+        S: AsRef<[W]>,
+    > SubsetDetector<'a, T, H, W, S>
+{
+    /// Creates a new subset detector for the given array.
+    pub fn new(array: &'a SliceEstimatorArray<HyperLogLog<T, H, W>, W, S>) -> Self {
+        Self { array }
+    }
+
+    /// Returns the fraction of registers of the estimator at index `i` that
+    /// are smaller than or equal to the corresponding register of the
+    /// estimator at index `j`.
+    ///
+    /// A value of one means that the estimator at index `j` dominates the
+    /// estimator at index `i`, which is a strong signal that the set of
+    /// elements of the former contains the set of elements of the latter.
+    pub fn is_probable_subset(&self, i: usize, j: usize) -> f64 {
+        let logic = self.array.logic();
+        let backend_i = self.array.get_backend(i);
+        let backend_j = self.array.get_backend(j);
+        let num_registers = logic.num_registers();
+        let dominated = (0..num_registers)
+            .filter(|&r| logic.get_register(backend_i, r) <= logic.get_register(backend_j, r))
+            .count();
+        dominated as f64 / num_registers as f64
+    }
+
+    /// Returns the indices of the estimators (other than `i`) for which
+    /// [`is_probable_subset(i, _)`](SubsetDetector::is_probable_subset) is at
+    /// least `threshold`.
+    pub fn find_supersets(&self, i: usize, threshold: f64) -> Vec<usize> {
+        (0..self.array.len())
+            .filter(|&j| j != i && self.is_probable_subset(i, j) >= threshold)
+            .collect()
+    }
+}
@@ -0,0 +1,148 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::SliceEstimatorArray;
+use crate::traits::SliceEstimationLogic;
+use std::fmt::{Display, Formatter, Write};
+
+/// The number of buckets of the histogram of
+/// [`summary_verbose`](SliceEstimatorArray::summary_verbose).
+const HISTOGRAM_BUCKETS: usize = 10;
+
+/// The maximum width of the bars of the histogram of
+/// [`summary_verbose`](SliceEstimatorArray::summary_verbose).
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// Summary statistics of the estimates of an array, as returned by
+/// [`SliceEstimatorArray::summary`].
+///
+/// The [`Display`] implementation produces a multi-line report. For an empty
+/// array, all statistics about estimates are zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArraySummary {
+    /// The number of estimators.
+    pub len: usize,
+    /// The number of estimators with a positive estimate.
+    pub non_empty_count: usize,
+    /// The minimum estimate.
+    pub min_estimate: f64,
+    /// The maximum estimate.
+    pub max_estimate: f64,
+    /// The mean of the estimates.
+    pub mean_estimate: f64,
+    /// The median of the estimates (the mean of the two central estimates if
+    /// the number of estimators is even).
+    pub median_estimate: f64,
+    /// The (population) standard deviation of the estimates.
+    pub std_dev_estimate: f64,
+    /// The number of bytes used by the backends.
+    pub backend_memory_bytes: usize,
+}
+
+impl Display for ArraySummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Estimators:      {}", self.len)?;
+        writeln!(f, "Non-empty:       {}", self.non_empty_count)?;
+        writeln!(f, "Min estimate:    {:.2}", self.min_estimate)?;
+        writeln!(f, "Max estimate:    {:.2}", self.max_estimate)?;
+        writeln!(f, "Mean estimate:   {:.2}", self.mean_estimate)?;
+        writeln!(f, "Median estimate: {:.2}", self.median_estimate)?;
+        writeln!(f, "Std dev:         {:.2}", self.std_dev_estimate)?;
+        write!(f, "Backend memory:  {} bytes", self.backend_memory_bytes)
+    }
+}
+
+impl<L: SliceEstimationLogic<W>, W, S: AsRef<[W]>> SliceEstimatorArray<L, W, S> {
+    /// Returns summary statistics of the estimates of the array.
+    ///
+    /// Estimates are computed calling the logic directly on the backends,
+    /// without creating an estimator for each index.
+    pub fn summary(&self) -> ArraySummary {
+        summarize(
+            &self.estimates(),
+            std::mem::size_of_val(self.backend.as_ref()),
+        )
+    }
+
+    /// Returns a report containing the [summary](SliceEstimatorArray::summary)
+    /// of the array followed by a histogram of the estimates in ten buckets
+    /// of equal width between the minimum and the maximum estimate.
+    pub fn summary_verbose(&self) -> String {
+        let estimates = self.estimates();
+        let summary = summarize(&estimates, std::mem::size_of_val(self.backend.as_ref()));
+        let mut report = summary.to_string();
+        if estimates.is_empty() {
+            return report;
+        }
+
+        let (min, max) = (summary.min_estimate, summary.max_estimate);
+        let width = (max - min) / HISTOGRAM_BUCKETS as f64;
+        let mut counts = [0_usize; HISTOGRAM_BUCKETS];
+        for &estimate in &estimates {
+            let bucket = if width > 0.0 {
+                (((estimate - min) / width) as usize).min(HISTOGRAM_BUCKETS - 1)
+            } else {
+                0
+            };
+            counts[bucket] += 1;
+        }
+
+        let max_count = *counts.iter().max().unwrap();
+        report.push_str("\nHistogram:");
+        for (i, &count) in counts.iter().enumerate() {
+            let bar = "#".repeat(count * HISTOGRAM_WIDTH / max_count);
+            write!(
+                report,
+                "\n[{:>12.2}, {:>12.2}{} {:>8} {}",
+                min + width * i as f64,
+                min + width * (i + 1) as f64,
+                if i == HISTOGRAM_BUCKETS - 1 { ']' } else { ')' },
+                count,
+                bar
+            )
+            .unwrap();
+        }
+        report
+    }
+}
+
+/// Computes the summary statistics of a list of estimates.
+fn summarize(estimates: &[f64], backend_memory_bytes: usize) -> ArraySummary {
+    let len = estimates.len();
+    if len == 0 {
+        return ArraySummary {
+            len,
+            non_empty_count: 0,
+            min_estimate: 0.0,
+            max_estimate: 0.0,
+            mean_estimate: 0.0,
+            median_estimate: 0.0,
+            std_dev_estimate: 0.0,
+            backend_memory_bytes,
+        };
+    }
+
+    let mut sorted = estimates.to_vec();
+    sorted.sort_unstable_by(f64::total_cmp);
+    let mean = sorted.iter().sum::<f64>() / len as f64;
+    let variance = sorted.iter().map(|&e| (e - mean) * (e - mean)).sum::<f64>() / len as f64;
+    let median = if len % 2 == 1 {
+        sorted[len / 2]
+    } else {
+        (sorted[len / 2 - 1] + sorted[len / 2]) / 2.0
+    };
+
+    ArraySummary {
+        len,
+        non_empty_count: sorted.iter().filter(|&&e| e > 0.0).count(),
+        min_estimate: sorted[0],
+        max_estimate: sorted[len - 1],
+        mean_estimate: mean,
+        median_estimate: median,
+        std_dev_estimate: variance.sqrt(),
+        backend_memory_bytes,
+    }
+}
@@ -0,0 +1,149 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::{HyperLogLog, SliceEstimatorArray};
+use crate::traits::*;
+use common_traits::{Atomic, CastableFrom, IntoAtomic, UpcastableInto};
+use std::borrow::Borrow;
+use std::hash::{BuildHasher, Hash};
+use std::sync::atomic::Ordering;
+use sux::traits::Word;
+
+/// An array of [`HyperLogLog`] estimators supporting concurrent additions.
+///
+/// Differently from [`SyncSliceEstimatorArray`](super::SyncSliceEstimatorArray),
+/// which needs external synchronization, this array stores the words of the
+/// backends as [atomic words](IntoAtomic::AtomicType), and [adds
+/// elements](SyncEstimatorArrayAtomic::add) by updating atomically the only
+/// register involved using [`HyperLogLog::update_register_atomic`]. Thus,
+/// [`add`](Self::add) is safe, and several threads can add elements to the
+/// same estimators concurrently.
+///
+/// Registers are packed exactly as in a standard backend, so the array
+/// occupies the same space as a [`SliceEstimatorArray`] with the same logic.
+/// [`estimate`](Self::estimate) and [`get`](SyncEstimatorArray::get) read
+/// backends using [`HyperLogLog::load_atomic`], so they can be called while
+/// other threads add elements: every register read has a value it actually
+/// had, even if it straddles two words. The other methods from
+/// [`SyncEstimatorArray`] copy whole backends word by word; they are atomic
+/// at the word level, but not at the estimator level.
+pub struct SyncEstimatorArrayAtomic<T, H, W: IntoAtomic> {
+    logic: HyperLogLog<T, H, W>,
+    backend: Box<[W::AtomicType]>,
+}
+
+impl<T, H: Clone, W: Word + IntoAtomic + UpcastableInto<u64> + CastableFrom<u64>>
+    SyncEstimatorArrayAtomic<T, H, W>
+{
+    /// Creates a new array of empty estimators.
+    ///
+    /// # Arguments
+    /// * `logic`: the estimator logic to use.
+    /// * `len`: the number of the estimators in the array.
+    pub fn new(logic: HyperLogLog<T, H, W>, len: usize) -> Self {
+        let backend = (0..len * logic.words_per_estimator)
+            .map(|_| W::AtomicType::new(W::ZERO))
+            .collect();
+        Self { logic, backend }
+    }
+
+    /// Returns the atomic backend of the estimator at the given index.
+    fn backend(&self, index: usize) -> &[W::AtomicType] {
+        let backend_len = self.logic.words_per_estimator;
+        &self.backend[index * backend_len..][..backend_len]
+    }
+
+    /// Converts this array into a [`SliceEstimatorArray`] with the same
+    /// estimators.
+    pub fn into_array(self) -> SliceEstimatorArray<HyperLogLog<T, H, W>, W, Box<[W]>> {
+        let backend = Vec::from(self.backend)
+            .into_iter()
+            .map(Atomic::into_inner)
+            .collect();
+        SliceEstimatorArray::from_parts(self.logic, backend)
+    }
+
+    /// Copies the backend of an estimator into a standard backend using
+    /// [`HyperLogLog::load_atomic`].
+    fn load(&self, index: usize, backend: &mut [W]) {
+        self.logic.load_atomic(self.backend(index), backend);
+    }
+
+    /// Returns the number of estimators in the array.
+    pub fn len(&self) -> usize {
+        self.backend.len() / self.logic.words_per_estimator
+    }
+
+    /// Returns `true` if the array contains no estimators.
+    pub fn is_empty(&self) -> bool {
+        self.backend.is_empty()
+    }
+}
+
+impl<
+        T: Hash,
+        H: BuildHasher + Clone,
+        W: Word + IntoAtomic + UpcastableInto<u64> + CastableFrom<u64>,
+    > SyncEstimatorArrayAtomic<T, H, W>
+{
+    /// Adds an element to the estimator at the given index.
+    ///
+    /// This method can be called concurrently by several threads.
+    pub fn add(&self, index: usize, element: impl Borrow<T>) {
+        self.logic
+            .update_register_atomic(self.backend(index), element);
+    }
+
+    /// Returns an estimation of the number of distinct elements that have been
+    /// added to the estimator at the given index.
+    pub fn estimate(&self, index: usize) -> f64 {
+        let mut backend = vec![W::ZERO; self.logic.words_per_estimator];
+        self.load(index, &mut backend);
+        self.logic.estimate(&backend)
+    }
+}
+
+impl<T, H, W> SyncEstimatorArray<HyperLogLog<T, H, W>> for SyncEstimatorArrayAtomic<T, H, W>
+where
+    T: Hash + Sync,
+    H: BuildHasher + Clone + Sync,
+    W: Word + IntoAtomic + UpcastableInto<u64> + CastableFrom<u64>,
+{
+    fn logic(&self) -> &HyperLogLog<T, H, W> {
+        &self.logic
+    }
+
+    unsafe fn set(&self, index: usize, content: &[W]) {
+        debug_assert_eq!(content.len(), self.logic.words_per_estimator);
+        for (atomic, &word) in self.backend(index).iter().zip(content) {
+            atomic.store(word, Ordering::Relaxed);
+        }
+    }
+
+    unsafe fn get(&self, index: usize, content: &mut [W]) {
+        debug_assert_eq!(content.len(), self.logic.words_per_estimator);
+        self.load(index, content);
+    }
+
+    unsafe fn clear(&self) {
+        self.backend
+            .iter()
+            .for_each(|word| word.store(W::ZERO, Ordering::Relaxed));
+    }
+
+    /// Adds an element to the estimator at `index` using
+    /// [`add`](SyncEstimatorArrayAtomic::add).
+    ///
+    /// Differently from the default implementation, this method can be
+    /// called concurrently on the same estimator.
+    unsafe fn scatter_add(&self, index: usize, element: impl Borrow<T>) {
+        self.add(index, element);
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
@@ -0,0 +1,329 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::{ensure, Result};
+use std::borrow::Borrow;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::*;
+
+use super::kmv_sketch::merge_sorted;
+use super::DefaultEstimator;
+use crate::traits::{
+    EstimationGuarantees, EstimationLogic, MergeEstimationLogic, SliceEstimationLogic,
+};
+
+/// Estimator logic implementing theta sketches, as in [Apache
+/// DataSketches](https://datasketches.apache.org/).
+///
+/// A backend keeps track of a threshold θ and of the (at most *k*) distinct
+/// hashes of the elements added so far that are smaller than θ. Initially θ
+/// is the maximum hash, so hashes are kept exactly; when a hash would be the
+/// (*k* + 1)-th, the largest one becomes the new θ and it is discarded. If *r*
+/// hashes are kept, the estimate is *r* / θ, with θ mapped to (0..1]; its
+/// relative standard deviation is at most 1 / √(*k* − 1).
+///
+/// Differently from a [`KMVSketch`](super::KMVSketch), all hashes of a set
+/// smaller than θ are kept: thus, restricting two backends to the smaller of
+/// their thresholds yields uniform samples of the hashes of the two sets,
+/// which make it possible to estimate [unions](ThetaSketch::estimate_union_exact),
+/// [intersections](ThetaSketch::estimate_intersection_exact) and [Jaccard
+/// similarities](ThetaSketch::estimate_jaccard_exact) directly, rather than
+/// by inclusion–exclusion.
+///
+/// To make empty backends all-zero, as required, for example, by
+/// [`SliceEstimatorArray`](super::SliceEstimatorArray), the first word of a
+/// backend contains the bitwise complement of θ, and the following *k* words
+/// the bitwise complement of the hashes, sorted in decreasing order, followed
+/// by zeroes for unused slots.
+///
+/// Instances are created using a [`ThetaSketchBuilder`].
+#[derive(Debug, PartialEq)]
+pub struct ThetaSketch<T, H> {
+    build_hasher: H,
+    k: usize,
+    _marker: std::marker::PhantomData<T>,
+}
+
+// We implement Clone manually because we do not want to require that T is
+// Clone.
+impl<T, H: Clone> Clone for ThetaSketch<T, H> {
+    fn clone(&self) -> Self {
+        Self {
+            build_hasher: self.build_hasher.clone(),
+            k: self.k,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Maps the complement of a threshold to (0..1].
+fn theta_fraction(theta_word: u64) -> f64 {
+    ((!theta_word) as f64 + 1.0) / 2.0_f64.powi(64)
+}
+
+/// Returns the hashes of a backend smaller than the threshold with the given
+/// complement, that is, the words larger than `theta_word`.
+fn below(backend: &[u64], theta_word: u64) -> &[u64] {
+    let values = &backend[1..];
+    &values[..values.partition_point(|&word| word > theta_word)]
+}
+
+/// Returns whether a decreasing sequence of words contains a word.
+fn contains(values: &[u64], word: u64) -> bool {
+    values.binary_search_by(|x| word.cmp(x)).is_ok()
+}
+
+impl<T, H> ThetaSketch<T, H> {
+    /// Returns the maximum number of hashes kept by a backend, which is also
+    /// its length minus one.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    /// Returns the threshold θ of a backend, mapped to (0..1].
+    pub fn theta(&self, backend: &[u64]) -> f64 {
+        debug_assert_eq!(backend.len(), self.k + 1);
+        theta_fraction(backend[0])
+    }
+
+    /// Returns the number of hashes currently retained by a backend.
+    pub fn num_retained(&self, backend: &[u64]) -> usize {
+        debug_assert_eq!(backend.len(), self.k + 1);
+        below(backend, 0).len()
+    }
+
+    /// Computes into `dst` the union of the hashes of `a` and `b` smaller
+    /// than the smaller of their thresholds, keeping at most *k* hashes.
+    ///
+    /// `dst` must have length *k* + 2: the result occupies the first *k* + 1
+    /// words, in the format of a backend, and the last word is used as
+    /// scratch space.
+    fn union_into(&self, dst: &mut [u64], a: &[u64], b: &[u64]) {
+        debug_assert_eq!(dst.len(), self.k + 2);
+        let theta_word = a[0].max(b[0]);
+        let (a, b) = (below(a, theta_word), below(b, theta_word));
+        // We merge k + 1 hashes to find out whether the threshold must change
+        merge_sorted(&mut dst[1..], a, b);
+        if dst[self.k + 1] != 0 {
+            dst[0] = dst[self.k + 1];
+            dst[self.k + 1] = 0;
+        } else {
+            dst[0] = theta_word;
+        }
+    }
+
+    /// Returns an estimate of the number of distinct elements in the union of
+    /// the sets represented by two backends.
+    ///
+    /// The estimate is the estimate of the [merge](MergeEstimationLogic::merge)
+    /// of the two backends, which is computed without modifying them.
+    pub fn estimate_union_exact(&self, a: &[u64], b: &[u64]) -> f64 {
+        let mut union = vec![0; self.k + 2];
+        self.union_into(&mut union, a, b);
+        self.num_retained(&union[..=self.k]) as f64 / theta_fraction(union[0])
+    }
+
+    /// Returns an estimate of the number of distinct elements in the
+    /// intersection of the sets represented by two backends.
+    ///
+    /// The estimate is the number of hashes smaller than the smaller of the
+    /// two thresholds appearing in both backends, divided by the threshold.
+    /// Differently from [inclusion–exclusion
+    /// estimates](MergeEstimationLogic::estimate_intersection), its error is
+    /// proportional to the cardinality of the intersection.
+    pub fn estimate_intersection_exact(&self, a: &[u64], b: &[u64]) -> f64 {
+        debug_assert_eq!(a.len(), self.k + 1);
+        debug_assert_eq!(b.len(), self.k + 1);
+        let theta_word = a[0].max(b[0]);
+        let b = below(b, theta_word);
+        let common = below(a, theta_word)
+            .iter()
+            .filter(|&&word| contains(b, word))
+            .count();
+        common as f64 / theta_fraction(theta_word)
+    }
+
+    /// Returns an estimate of the Jaccard similarity of the sets represented
+    /// by two backends.
+    ///
+    /// The estimate is the fraction of the hashes of the
+    /// [union](ThetaSketch::estimate_union_exact) of the two backends that
+    /// appear in both backends. Two empty backends have similarity one.
+    pub fn estimate_jaccard_exact(&self, a: &[u64], b: &[u64]) -> f64 {
+        let mut union = vec![0; self.k + 2];
+        self.union_into(&mut union, a, b);
+        let union = below(&union[..=self.k], 0);
+        if union.is_empty() {
+            return 1.0;
+        }
+        let common = union
+            .iter()
+            .filter(|&&word| contains(&a[1..], word) && contains(&b[1..], word))
+            .count();
+        common as f64 / union.len() as f64
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> SliceEstimationLogic<u64> for ThetaSketch<T, H> {
+    fn backend_len(&self) -> usize {
+        self.k + 1
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> EstimationLogic for ThetaSketch<T, H> {
+    type Item = T;
+    type Backend = [u64];
+    type Estimator<'a>
+        = DefaultEstimator<Self, &'a Self, Box<[u64]>>
+    where
+        T: 'a,
+        H: 'a;
+
+    fn new_estimator(&self) -> Self::Estimator<'_> {
+        DefaultEstimator::new(self, vec![0; self.k + 1].into_boxed_slice())
+    }
+
+    fn add(&self, backend: &mut [u64], element: impl Borrow<T>) {
+        debug_assert_eq!(backend.len(), self.k + 1);
+        let hash = self.build_hasher.hash_one(element.borrow());
+        // Words contain the complement of the hash
+        let word = !hash;
+        if word <= backend[0] {
+            // The hash is not smaller than the threshold
+            return;
+        }
+        let (theta_word, values) = backend.split_first_mut().unwrap();
+        // Words are in decreasing order, so we search with reversed order
+        let Err(pos) = values.binary_search_by(|x| word.cmp(x)) else {
+            return;
+        };
+        if pos == self.k {
+            // The hash is the (k + 1)-th: it becomes the new threshold
+            *theta_word = word;
+            return;
+        }
+        let evicted = values[self.k - 1];
+        values[pos..].rotate_right(1);
+        values[pos] = word;
+        if evicted != 0 {
+            *theta_word = evicted;
+        }
+    }
+
+    fn estimate(&self, backend: &[u64]) -> f64 {
+        self.num_retained(backend) as f64 / self.theta(backend)
+    }
+
+    fn clear(&self, backend: &mut [u64]) {
+        backend.fill(0);
+    }
+
+    fn set(&self, dst: &mut [u64], src: &[u64]) {
+        debug_assert_eq!(dst.len(), src.len());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> MergeEstimationLogic for ThetaSketch<T, H> {
+    type Helper = Vec<u64>;
+
+    fn new_helper(&self) -> Self::Helper {
+        vec![0; self.k + 2]
+    }
+
+    fn merge_with_helper(&self, dst: &mut [u64], src: &[u64], helper: &mut Self::Helper) {
+        debug_assert_eq!(dst.len(), src.len());
+        debug_assert_eq!(helper.len(), self.k + 2);
+        self.union_into(helper, dst, src);
+        dst.copy_from_slice(&helper[..=self.k]);
+    }
+
+    fn estimate_union(&self, a: &[u64], b: &[u64]) -> f64 {
+        self.estimate_union_exact(a, b)
+    }
+
+    fn estimate_intersection(&self, a: &[u64], b: &[u64]) -> f64 {
+        self.estimate_intersection_exact(a, b)
+    }
+
+    fn estimate_jaccard(&self, a: &[u64], b: &[u64]) -> f64 {
+        self.estimate_jaccard_exact(a, b)
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone> EstimationGuarantees for ThetaSketch<T, H> {
+    fn relative_std(&self) -> f64 {
+        if self.k > 1 {
+            1.0 / ((self.k - 1) as f64).sqrt()
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+/// Builds a [`ThetaSketch`] logic.
+#[derive(Debug, Clone)]
+pub struct ThetaSketchBuilder<H> {
+    build_hasher: H,
+    k: usize,
+}
+
+impl ThetaSketchBuilder<BuildHasherDefault<DefaultHasher>> {
+    /// Creates a new builder for a [`ThetaSketch`] logic keeping at most 4096
+    /// hashes, the default of Apache DataSketches.
+    pub fn new() -> Self {
+        Self {
+            build_hasher: BuildHasherDefault::default(),
+            k: 4096,
+        }
+    }
+}
+
+impl Default for ThetaSketchBuilder<BuildHasherDefault<DefaultHasher>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H> ThetaSketchBuilder<H> {
+    /// Sets the maximum number of hashes to keep.
+    ///
+    /// # Arguments
+    /// * `k`: the maximum number of hashes to keep; the length of a backend
+    ///   is `k` + 1.
+    pub fn k(mut self, k: usize) -> Self {
+        self.k = k;
+        self
+    }
+
+    /// Sets the [`BuildHasher`] to use.
+    ///
+    /// Using this method you can select a specific hashed based on one or more
+    /// seeds.
+    pub fn build_hasher<H2>(self, build_hasher: H2) -> ThetaSketchBuilder<H2> {
+        ThetaSketchBuilder {
+            build_hasher,
+            k: self.k,
+        }
+    }
+
+    /// Builds the logic.
+    ///
+    /// The type of objects the estimators keep track of is defined here by `T`,
+    /// but it is usually inferred by the compiler.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the number of hashes is zero.
+    pub fn build<T>(self) -> Result<ThetaSketch<T, H>> {
+        ensure!(self.k > 0, "the number of hashes must be positive");
+        Ok(ThetaSketch {
+            build_hasher: self.build_hasher,
+            k: self.k,
+            _marker: std::marker::PhantomData,
+        })
+    }
+}
@@ -0,0 +1,157 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::SliceEstimatorArray;
+use crate::traits::*;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use sux::traits::Word;
+
+/// Compares two (estimate, index) entries: an entry ranks below another if it
+/// has a smaller estimate, or the same estimate and a larger index.
+#[inline(always)]
+fn cmp_entries(a: &(f64, usize), b: &(f64, usize)) -> Ordering {
+    a.0.total_cmp(&b.0).then_with(|| b.1.cmp(&a.1))
+}
+
+/// Returns whether the entry `a` ranks below the entry `b`.
+#[inline(always)]
+fn ranks_below(a: (f64, usize), b: (f64, usize)) -> bool {
+    cmp_entries(&a, &b).is_lt()
+}
+
+/// An array of estimators keeping track of the `K` estimators with the
+/// highest estimates.
+///
+/// The indices and estimates of the top `K` estimators are kept in an
+/// indexed min-heap, which is updated at each [addition](TopKEstimatorArray::add):
+/// if the addition changes the backend of an estimator in the heap, its
+/// position is updated; otherwise, if its new estimate exceeds the minimum
+/// of the heap, the estimator is promoted, replacing the minimum. Thus,
+/// [`top_k`](TopKEstimatorArray::top_k) takes time O(`K` log `K`)
+/// instead of requiring the computation of all estimates.
+///
+/// Since an estimate is computed only when an addition changes the backend,
+/// the cost of an addition is usually the cost of a copy of the backend.
+/// The heap is initialized with the first `K` estimators, which are empty;
+/// ties are broken in favor of smaller indices. Estimates are assumed not to
+/// decrease as elements are added (see the [invariants of
+/// estimators](crate::traits::EstimationLogic)): if they do, results might be
+/// approximate.
+pub struct TopKEstimatorArray<L, W, const K: usize> {
+    array: SliceEstimatorArray<L, W, Box<[W]>>,
+    /// The (estimate, index) pairs of the top estimators, as a min-heap.
+    heap: Vec<(f64, usize)>,
+    /// Maps indices in the heap to their positions.
+    position: HashMap<usize, usize>,
+    /// A buffer to detect changes in a backend.
+    scratch: Box<[W]>,
+}
+
+impl<L: SliceEstimationLogic<W> + Clone, W: Word, const K: usize> TopKEstimatorArray<L, W, K> {
+    /// Creates a new array of `len` empty estimators.
+    ///
+    /// Calls to this method will not compile if `K` is zero.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `len` is smaller than `K`.
+    pub fn new(logic: L, len: usize) -> Self {
+        const { assert!(K > 0, "the number of top estimators must be positive") };
+        assert!(
+            len >= K,
+            "the array has {} estimators, but {} top estimators are required",
+            len,
+            K
+        );
+        let scratch = vec![W::ZERO; logic.backend_len()].into_boxed_slice();
+        Self {
+            array: SliceEstimatorArray::new(logic, len),
+            // Sorted in increasing rank, hence a valid min-heap
+            heap: (0..K).rev().map(|i| (0.0, i)).collect(),
+            position: (0..K).map(|i| (i, K - 1 - i)).collect(),
+            scratch,
+        }
+    }
+
+    /// Adds an element to the estimator at the specified index, updating the
+    /// top estimators.
+    pub fn add(&mut self, index: usize, element: impl Borrow<L::Item>) {
+        self.scratch.copy_from_slice(self.array.get_backend(index));
+        self.array.get_estimator_mut(index).add(element);
+        if self.array.get_backend(index) == &self.scratch[..] {
+            return;
+        }
+
+        let estimate = self.array.get_estimator(index).estimate();
+        if let Some(&pos) = self.position.get(&index) {
+            self.heap[pos].0 = estimate;
+            let pos = self.sift_up(pos);
+            self.sift_down(pos);
+        } else if ranks_below(self.heap[0], (estimate, index)) {
+            self.position.remove(&self.heap[0].1);
+            self.heap[0] = (estimate, index);
+            self.position.insert(index, 0);
+            self.sift_down(0);
+        }
+    }
+
+    /// Returns the indices and the estimates of the top `K` estimators, in
+    /// decreasing order of estimate, with ties broken by increasing index.
+    pub fn top_k(&self) -> [(usize, f64); K] {
+        let mut top = self.heap.clone();
+        top.sort_unstable_by(|a, b| cmp_entries(b, a));
+        std::array::from_fn(|i| (top[i].1, top[i].0))
+    }
+
+    /// Returns the array of estimators.
+    pub fn array(&self) -> &SliceEstimatorArray<L, W, Box<[W]>> {
+        &self.array
+    }
+
+    /// Returns the array of estimators, consuming this structure.
+    pub fn into_inner(self) -> SliceEstimatorArray<L, W, Box<[W]>> {
+        self.array
+    }
+
+    /// Swaps two entries of the heap, updating their positions.
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.position.insert(self.heap[a].1, a);
+        self.position.insert(self.heap[b].1, b);
+    }
+
+    /// Moves up an entry of the heap, returning its new position.
+    fn sift_up(&mut self, mut pos: usize) -> usize {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if !ranks_below(self.heap[pos], self.heap[parent]) {
+                break;
+            }
+            self.swap(pos, parent);
+            pos = parent;
+        }
+        pos
+    }
+
+    /// Moves down an entry of the heap.
+    fn sift_down(&mut self, mut pos: usize) {
+        loop {
+            let mut min = pos;
+            for child in [2 * pos + 1, 2 * pos + 2] {
+                if child < K && ranks_below(self.heap[child], self.heap[min]) {
+                    min = child;
+                }
+            }
+            if min == pos {
+                break;
+            }
+            self.swap(pos, min);
+            pos = min;
+        }
+    }
+}
@@ -0,0 +1,299 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::{ensure, Result};
+use common_traits::{CastableFrom, UpcastableInto};
+use std::borrow::Borrow;
+use std::hash::*;
+use sux::traits::Word;
+
+use super::{DefaultEstimator, HyperLogLog};
+use crate::traits::{
+    EstimationGuarantees, EstimationLogic, MergeEstimationLogic, SliceEstimationLogic,
+};
+
+/// Computes the function σ(*x*) = *x* + ∑<sub>*k* ≥ 1</sub>
+/// *x*<sup>2<sup>*k*</sup></sup>2<sup>*k* − 1</sup> used to correct the
+/// contribution of empty registers.
+fn sigma(mut x: f64) -> f64 {
+    if x == 1.0 {
+        return f64::INFINITY;
+    }
+    let mut y = 1.0;
+    let mut z = x;
+    loop {
+        x *= x;
+        let z_old = z;
+        z += x * y;
+        y += y;
+        if z == z_old {
+            return z;
+        }
+    }
+}
+
+/// Computes the function τ(*x*) = (1 − *x* − ∑<sub>*k* ≥ 1</sub> (1 −
+/// *x*<sup>2<sup>−*k*</sup></sup>)² 2<sup>−*k*</sup>) / 3 used to correct the
+/// contribution of saturated registers.
+fn tau(mut x: f64) -> f64 {
+    if x == 0.0 || x == 1.0 {
+        return 0.0;
+    }
+    let mut y = 1.0;
+    let mut z = 1.0 - x;
+    loop {
+        x = x.sqrt();
+        let z_old = z;
+        y *= 0.5;
+        z -= (1.0 - x) * (1.0 - x) * y;
+        if z == z_old {
+            return z / 3.0;
+        }
+    }
+}
+
+/// Estimator logic implementing the HyperLogLog algorithm with registers of
+/// `BITS` bits.
+///
+/// Registers are packed in words of type `W` as in
+/// [`HyperLogLog`](super::HyperLogLog), but their size is fixed at compile
+/// time and can be smaller than the size needed to represent all possible
+/// values: a register saturates at 2<sup>`BITS`</sup> − 1, and larger values
+/// are truncated. The [backend length](SliceEstimationLogic::backend_len) is
+/// ⌈*m* · `BITS` / `W::BITS`⌉, where *m* is the number of registers, so
+/// using, say, four-bit registers saves a fifth of the space of the five-bit
+/// registers of [`HyperLogLog`](super::HyperLogLog), and much more if the
+/// latter stores registers in separate words.
+///
+/// The estimate uses the corrected estimator described by Otmar Ertl in
+/// “[New cardinality estimation algorithms for HyperLogLog
+/// sketches](https://arxiv.org/abs/1702.01284)”, which takes into account
+/// both empty and saturated registers. As long as the cardinality is
+/// significantly smaller than *m* · 2<sup>2<sup>`BITS`</sup> − 2</sup>, the
+/// relative standard deviation is essentially that of the standard
+/// algorithm; beyond that point, saturated registers carry less and less
+/// information, and the error grows.
+///
+/// `BITS` must be between 2 and 6.
+#[derive(Debug, PartialEq)]
+pub struct TruncatedHyperLogLog<T, H, W, const BITS: usize> {
+    build_hasher: H,
+    log_2_num_registers: usize,
+    num_registers: usize,
+    backend_len: usize,
+    _marker: std::marker::PhantomData<(T, W)>,
+}
+
+// We implement Clone manually because we do not want to require that T and W
+// are Clone.
+impl<T, H: Clone, W, const BITS: usize> Clone for TruncatedHyperLogLog<T, H, W, BITS> {
+    fn clone(&self) -> Self {
+        Self {
+            build_hasher: self.build_hasher.clone(),
+            log_2_num_registers: self.log_2_num_registers,
+            num_registers: self.num_registers,
+            backend_len: self.backend_len,
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, H, W: Word, const BITS: usize> TruncatedHyperLogLog<T, H, W, BITS> {
+    /// The maximum value of a register.
+    pub const MAX_REGISTER_VALUE: u64 = (1 << BITS) - 1;
+
+    /// Creates a new logic with the given number of registers.
+    ///
+    /// # Arguments
+    /// * `log_2_num_registers`: the base-2 logarithm of the number of
+    ///   registers per estimator.
+    /// * `build_hasher`: the [`BuildHasher`] used to hash elements.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if `BITS` is not between 2 and 6 or
+    /// larger than the number of bits of `W`, or if `log_2_num_registers` is
+    /// not between 4 and 32.
+    pub fn new(log_2_num_registers: usize, build_hasher: H) -> Result<Self> {
+        ensure!(
+            (2..=6).contains(&BITS),
+            "the number of bits per register must be between 2 and 6, got {}",
+            BITS
+        );
+        ensure!(
+            BITS <= W::BITS,
+            "the number of bits per register ({}) is larger than the number of bits of a word ({})",
+            BITS,
+            W::BITS
+        );
+        ensure!(
+            (4..=32).contains(&log_2_num_registers),
+            "the logarithm of the number of registers must be between 4 and 32, got {}",
+            log_2_num_registers
+        );
+        let num_registers = 1 << log_2_num_registers;
+        Ok(Self {
+            build_hasher,
+            log_2_num_registers,
+            num_registers,
+            backend_len: (num_registers * BITS).div_ceil(W::BITS),
+            _marker: std::marker::PhantomData,
+        })
+    }
+
+    /// Returns the number of registers per estimator.
+    pub fn num_registers(&self) -> usize {
+        self.num_registers
+    }
+
+    /// Returns the base-2 logarithm of the number of registers per
+    /// estimator.
+    pub fn log_2_num_registers(&self) -> usize {
+        self.log_2_num_registers
+    }
+
+    /// Returns the value contained in a register of a given backend.
+    #[inline(always)]
+    fn get_register(&self, backend: &[W], index: usize) -> W {
+        let mask = W::MAX >> (W::BITS - BITS);
+        let pos = index * BITS;
+        let word_index = pos / W::BITS;
+        let bit_index = pos % W::BITS;
+
+        if bit_index + BITS <= W::BITS {
+            (backend[word_index] >> bit_index) & mask
+        } else {
+            ((backend[word_index] >> bit_index)
+                | (backend[word_index + 1] << (W::BITS - bit_index)))
+                & mask
+        }
+    }
+
+    /// Sets the value contained in a register of a given backend.
+    #[inline(always)]
+    fn set_register(&self, backend: &mut [W], index: usize, new_value: W) {
+        let mask = W::MAX >> (W::BITS - BITS);
+        let pos = index * BITS;
+        let word_index = pos / W::BITS;
+        let bit_index = pos % W::BITS;
+
+        if bit_index + BITS <= W::BITS {
+            let word = &mut backend[word_index];
+            *word &= !(mask << bit_index);
+            *word |= new_value << bit_index;
+        } else {
+            let word = &mut backend[word_index];
+            *word &= (W::ONE << bit_index) - W::ONE;
+            *word |= new_value << bit_index;
+
+            let word = &mut backend[word_index + 1];
+            *word &= !(mask >> (W::BITS - bit_index));
+            *word |= new_value >> (W::BITS - bit_index);
+        }
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, W, const BITS: usize> SliceEstimationLogic<W>
+    for TruncatedHyperLogLog<T, H, W, BITS>
+where
+    W: Word + UpcastableInto<u64> + CastableFrom<u64>,
+{
+    fn backend_len(&self) -> usize {
+        self.backend_len
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, W, const BITS: usize> EstimationLogic
+    for TruncatedHyperLogLog<T, H, W, BITS>
+where
+    W: Word + UpcastableInto<u64> + CastableFrom<u64>,
+{
+    type Item = T;
+    type Backend = [W];
+    type Estimator<'a>
+        = DefaultEstimator<Self, &'a Self, Box<[W]>>
+    where
+        T: 'a,
+        W: 'a,
+        H: 'a;
+
+    fn new_estimator(&self) -> Self::Estimator<'_> {
+        DefaultEstimator::new(self, vec![W::ZERO; self.backend_len].into_boxed_slice())
+    }
+
+    fn add(&self, backend: &mut [W], element: impl Borrow<T>) {
+        debug_assert_eq!(backend.len(), self.backend_len);
+        let x = self.build_hasher.hash_one(element.borrow());
+        let register = (x & (self.num_registers as u64 - 1)) as usize;
+        let r = (x >> self.log_2_num_registers).trailing_zeros() as u64;
+        let candidate_value = W::cast_from(std::cmp::min(r + 1, Self::MAX_REGISTER_VALUE));
+        if self.get_register(backend, register) < candidate_value {
+            self.set_register(backend, register, candidate_value);
+        }
+    }
+
+    fn estimate(&self, backend: &[W]) -> f64 {
+        debug_assert_eq!(backend.len(), self.backend_len);
+        // Registers larger than q are saturated, either because of truncation
+        // or because the hash has no more bits
+        let q = std::cmp::min(
+            Self::MAX_REGISTER_VALUE as usize - 1,
+            64 - self.log_2_num_registers,
+        );
+        let mut counts = [0_usize; 64];
+        for i in 0..self.num_registers {
+            let value: u64 = self.get_register(backend, i).upcast();
+            counts[std::cmp::min(value as usize, q + 1)] += 1;
+        }
+
+        let m = self.num_registers as f64;
+        let mut z = m * tau(1.0 - counts[q + 1] as f64 / m);
+        for &count in counts[1..=q].iter().rev() {
+            z = 0.5 * (z + count as f64);
+        }
+        z += m * sigma(counts[0] as f64 / m);
+        m * m / (2.0 * std::f64::consts::LN_2 * z)
+    }
+
+    fn clear(&self, backend: &mut [W]) {
+        backend.fill(W::ZERO);
+    }
+
+    fn set(&self, dst: &mut [W], src: &[W]) {
+        debug_assert_eq!(dst.len(), src.len());
+        dst.copy_from_slice(src);
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, W, const BITS: usize> MergeEstimationLogic
+    for TruncatedHyperLogLog<T, H, W, BITS>
+where
+    W: Word + UpcastableInto<u64> + CastableFrom<u64>,
+{
+    type Helper = ();
+
+    fn new_helper(&self) -> Self::Helper {}
+
+    fn merge_with_helper(&self, dst: &mut [W], src: &[W], _helper: &mut Self::Helper) {
+        debug_assert_eq!(dst.len(), src.len());
+        for i in 0..self.num_registers {
+            let value = self.get_register(src, i);
+            if self.get_register(dst, i) < value {
+                self.set_register(dst, i, value);
+            }
+        }
+    }
+}
+
+impl<T: Hash, H: BuildHasher + Clone, W, const BITS: usize> EstimationGuarantees
+    for TruncatedHyperLogLog<T, H, W, BITS>
+where
+    W: Word + UpcastableInto<u64> + CastableFrom<u64>,
+{
+    fn relative_std(&self) -> f64 {
+        HyperLogLog::rel_std(self.log_2_num_registers)
+    }
+}
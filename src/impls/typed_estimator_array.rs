@@ -0,0 +1,159 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::SliceEstimatorArray;
+use crate::traits::*;
+use sux::traits::Word;
+
+/// Defines a newtype wrapping a `usize` that can be used as index type of a
+/// [`TypedEstimatorArray`](crate::impls::TypedEstimatorArray).
+///
+/// The macro accepts an optional visibility and optional attributes, which
+/// are applied to the newtype. The newtype derives the standard comparison
+/// and hashing traits, and implements conversions from and to `usize`.
+///
+/// ```
+/// card_est_array::newtype_index!(
+///     /// The index of a node.
+///     pub NodeId
+/// );
+///
+/// assert_eq!(usize::from(NodeId(5)), 5);
+/// assert_eq!(NodeId::from(5), NodeId(5));
+/// ```
+#[macro_export]
+macro_rules! newtype_index {
+    ($(#[$meta:meta])* $vis:vis $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name(pub usize);
+
+        impl ::core::convert::From<usize> for $name {
+            #[inline(always)]
+            fn from(index: usize) -> Self {
+                Self(index)
+            }
+        }
+
+        impl ::core::convert::From<$name> for usize {
+            #[inline(always)]
+            fn from(index: $name) -> Self {
+                index.0
+            }
+        }
+    };
+}
+
+/// A wrapper around a [`SliceEstimatorArray`] whose methods accept indices of
+/// type `I` instead of `usize`.
+///
+/// When an application uses several arrays indexed by different entities
+/// (e.g., nodes and arcs), using distinct index types (e.g., defined by
+/// [`newtype_index!`](crate::newtype_index)) turns the use of an index with
+/// the wrong array into a compile-time error:
+///
+/// ```compile_fail
+/// # use card_est_array::impls::{HyperLogLogBuilder, SliceEstimatorArray, TypedEstimatorArray};
+/// card_est_array::newtype_index!(NodeId);
+/// card_est_array::newtype_index!(ArcId);
+///
+/// let logic = HyperLogLogBuilder::new(1000).build::<usize>().unwrap();
+/// let nodes = TypedEstimatorArray::<_, _, _, NodeId>::new(SliceEstimatorArray::new(logic, 10));
+/// nodes.get_backend(ArcId(5)); // Does not compile
+/// ```
+pub struct TypedEstimatorArray<L, W, S, I> {
+    array: SliceEstimatorArray<L, W, S>,
+    _marker: std::marker::PhantomData<fn(I)>,
+}
+
+impl<L, W, S, I> TypedEstimatorArray<L, W, S, I> {
+    /// Wraps an array.
+    pub fn new(array: SliceEstimatorArray<L, W, S>) -> Self {
+        Self {
+            array,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns a reference to the wrapped array.
+    pub fn array(&self) -> &SliceEstimatorArray<L, W, S> {
+        &self.array
+    }
+
+    /// Returns a mutable reference to the wrapped array.
+    pub fn array_mut(&mut self) -> &mut SliceEstimatorArray<L, W, S> {
+        &mut self.array
+    }
+
+    /// Returns the wrapped array.
+    pub fn into_inner(self) -> SliceEstimatorArray<L, W, S> {
+        self.array
+    }
+}
+
+impl<L: SliceEstimationLogic<W> + Clone, W: Word, S: AsRef<[W]>, I: Into<usize>>
+    TypedEstimatorArray<L, W, S, I>
+{
+    /// Returns the logic used by the estimators in the array.
+    pub fn logic(&self) -> &L {
+        self.array.logic()
+    }
+
+    /// Returns the number of estimators in the array.
+    pub fn len(&self) -> usize {
+        self.array.len()
+    }
+
+    /// Returns `true` if the array contains no estimators.
+    pub fn is_empty(&self) -> bool {
+        self.array.is_empty()
+    }
+
+    /// Returns the estimator at the specified index as an immutable estimator.
+    pub fn get_estimator(
+        &self,
+        index: I,
+    ) -> <SliceEstimatorArray<L, W, S> as EstimatorArray<L>>::Estimator<'_> {
+        self.array.get_estimator(index.into())
+    }
+
+    /// Returns an immutable reference to the backend of the estimator at the
+    /// specified index.
+    pub fn get_backend(&self, index: I) -> &[W] {
+        self.array.get_backend(index.into())
+    }
+
+    /// Returns an iterator over the indices of the array.
+    pub fn indices(&self) -> impl Iterator<Item = I>
+    where
+        I: From<usize>,
+    {
+        (0..self.array.len()).map(I::from)
+    }
+}
+
+impl<L: SliceEstimationLogic<W> + Clone, W: Word, S: AsRef<[W]> + AsMut<[W]>, I: Into<usize>>
+    TypedEstimatorArray<L, W, S, I>
+{
+    /// Returns the estimator at the specified index as a mutable estimator.
+    pub fn get_estimator_mut(
+        &mut self,
+        index: I,
+    ) -> <SliceEstimatorArray<L, W, S> as EstimatorArrayMut<L>>::EstimatorMut<'_> {
+        self.array.get_estimator_mut(index.into())
+    }
+
+    /// Returns a mutable reference to the backend of the estimator at the
+    /// specified index.
+    pub fn get_backend_mut(&mut self, index: I) -> &mut [W] {
+        self.array.get_backend_mut(index.into())
+    }
+
+    /// Resets all estimators in the array.
+    pub fn clear(&mut self) {
+        self.array.clear()
+    }
+}
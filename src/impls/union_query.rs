@@ -0,0 +1,426 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::SliceEstimatorArray;
+use crate::traits::*;
+use std::cell::OnceCell;
+use sux::traits::Word;
+
+/// The maximum number of sets that can be intersected by an
+/// [`IntersectionQuery`] or a [`QueryBuilder`].
+///
+/// Intersections are estimated by inclusion–exclusion, whose cost is
+/// exponential in the number of sets, and whose error grows quickly with the
+/// number of terms.
+pub const MAX_INTERSECTED_SETS: usize = 4;
+
+/// Merges the estimators at the given indices into a new backend.
+fn merge_indices<L: SliceEstimationLogic<W> + MergeEstimationLogic, W: Word, S: AsRef<[W]>>(
+    array: &SliceEstimatorArray<L, W, S>,
+    indices: impl IntoIterator<Item = usize>,
+) -> Box<[W]> {
+    let logic = &array.logic;
+    let backend_len = logic.backend_len();
+    let backend = array.backend.as_ref();
+    let mut acc = vec![W::ZERO; backend_len].into_boxed_slice();
+    let mut helper = logic.new_helper();
+    for index in indices {
+        logic.merge_with_helper(
+            &mut acc,
+            &backend[index * backend_len..][..backend_len],
+            &mut helper,
+        );
+    }
+    acc
+}
+
+/// Returns an estimation of the number of distinct elements in the union of
+/// the estimators at the given indices.
+fn union_estimate<L: SliceEstimationLogic<W> + MergeEstimationLogic, W: Word, S: AsRef<[W]>>(
+    array: &SliceEstimatorArray<L, W, S>,
+    indices: impl IntoIterator<Item = usize>,
+) -> f64 {
+    array.logic.estimate(&merge_indices(array, indices))
+}
+
+/// A query estimating the number of distinct elements in the union of some of
+/// the estimators of a [`SliceEstimatorArray`].
+///
+/// Indices are added with a builder-like interface:
+/// ```
+/// # use card_est_array::impls::{HyperLogLogBuilder, SliceEstimatorArray, UnionQuery};
+/// # use card_est_array::traits::{EstimatorArrayMut, EstimatorMut};
+/// # use std::hash::BuildHasherDefault;
+/// # use std::collections::hash_map::DefaultHasher;
+/// # let logic = HyperLogLogBuilder::new(1000)
+/// #     .log_2_num_reg(8)
+/// #     .build_hasher(BuildHasherDefault::<DefaultHasher>::default())
+/// #     .build::<usize>()
+/// #     .unwrap();
+/// let mut array = SliceEstimatorArray::new(logic, 100);
+/// for i in 0..100 {
+///     array.get_estimator_mut(i).add(i);
+/// }
+/// let estimate = UnionQuery::new(&array).indices(0..100).estimate();
+/// assert!((estimate - 100.0).abs() < 20.0);
+/// ```
+/// The merge of the estimators happens lazily at the first call to
+/// [`estimate`](UnionQuery::estimate) or [`merged`](UnionQuery::merged), and
+/// its result is cached, so repeated queries are answered without merging
+/// again. Adding indices invalidates the cache.
+pub struct UnionQuery<'a, L, W, S> {
+    array: &'a SliceEstimatorArray<L, W, S>,
+    indices: Vec<usize>,
+    cache: OnceCell<Box<[W]>>,
+}
+
+impl<'a, L: SliceEstimationLogic<W> + MergeEstimationLogic, W: Word, S: AsRef<[W]>>
+    UnionQuery<'a, L, W, S>
+{
+    /// Creates a new query on an array, initially with no indices.
+    pub fn new(array: &'a SliceEstimatorArray<L, W, S>) -> Self {
+        Self {
+            array,
+            indices: Vec::new(),
+            cache: OnceCell::new(),
+        }
+    }
+
+    /// Adds the given indices to the query.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if an index is out of bounds.
+    pub fn indices(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
+        let len = self.array.len();
+        self.indices.extend(indices.into_iter().inspect(|&index| {
+            assert!(
+                index < len,
+                "index {} out of bounds for an array of length {}",
+                index,
+                len
+            )
+        }));
+        self.cache.take();
+        self
+    }
+
+    /// Adds an index to the query.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the index is out of bounds.
+    pub fn index(self, index: usize) -> Self {
+        self.indices(std::iter::once(index))
+    }
+
+    /// Returns the indices of the query, in insertion order.
+    pub fn get_indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns the backend obtained by merging the estimators of the query.
+    ///
+    /// If there are no indices, the result is the backend of an empty
+    /// estimator.
+    pub fn merged(&self) -> &[W] {
+        self.cache
+            .get_or_init(|| merge_indices(self.array, self.indices.iter().copied()))
+    }
+
+    /// Returns an estimation of the number of distinct elements in the union
+    /// of the estimators of the query.
+    pub fn estimate(&self) -> f64 {
+        self.array.logic.estimate(self.merged())
+    }
+}
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+impl<L, W, S> UnionQuery<'_, L, W, S>
+where
+    L: SliceEstimationLogic<W> + MergeEstimationLogic + Sync,
+    L::Helper: Send,
+    W: Word,
+    S: AsRef<[W]> + Sync,
+{
+    /// Returns the backend obtained by merging the estimators of the query,
+    /// merging in parallel.
+    ///
+    /// This is a parallel version of [`merged`](UnionQuery::merged), sharing
+    /// its cache.
+    pub fn merged_par(&self) -> &[W] {
+        use rayon::prelude::*;
+        self.cache.get_or_init(|| {
+            let logic = &self.array.logic;
+            let backend_len = logic.backend_len();
+            let backend = self.array.backend.as_ref();
+            self.indices
+                .par_iter()
+                .fold(
+                    || (vec![W::ZERO; backend_len], logic.new_helper()),
+                    |(mut acc, mut helper), &index| {
+                        logic.merge_with_helper(
+                            &mut acc,
+                            &backend[index * backend_len..][..backend_len],
+                            &mut helper,
+                        );
+                        (acc, helper)
+                    },
+                )
+                .map(|(acc, _)| acc)
+                .reduce_with(|mut acc, other| {
+                    logic.merge(&mut acc, &other);
+                    acc
+                })
+                .unwrap_or_else(|| vec![W::ZERO; backend_len])
+                .into_boxed_slice()
+        })
+    }
+
+    /// Returns an estimation of the number of distinct elements in the union
+    /// of the estimators of the query, merging in parallel.
+    ///
+    /// This is a parallel version of [`estimate`](UnionQuery::estimate),
+    /// sharing its cache.
+    pub fn estimate_par(&self) -> f64 {
+        self.array.logic.estimate(self.merged_par())
+    }
+}
+
+/// A query estimating the number of distinct elements in the intersection of
+/// at most [`MAX_INTERSECTED_SETS`] sets, each of which is the union of some
+/// of the estimators of a [`SliceEstimatorArray`].
+///
+/// The estimate is computed by inclusion–exclusion from the estimates of the
+/// unions of all nonempty subsets of the sets: thus, its absolute error is of
+/// the order of the error on the union of all sets, and it is meaningful only
+/// when the intersection is not too small with respect to the union. Negative
+/// results are clamped to zero.
+pub struct IntersectionQuery<'a, L, W, S> {
+    array: &'a SliceEstimatorArray<L, W, S>,
+    sets: Vec<Vec<usize>>,
+}
+
+impl<'a, L: SliceEstimationLogic<W> + MergeEstimationLogic, W: Word, S: AsRef<[W]>>
+    IntersectionQuery<'a, L, W, S>
+{
+    /// Creates a new query on an array, initially with no sets.
+    pub fn new(array: &'a SliceEstimatorArray<L, W, S>) -> Self {
+        Self {
+            array,
+            sets: Vec::new(),
+        }
+    }
+
+    /// Adds to the query a set given by the union of the estimators at the
+    /// given indices.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the query already contains
+    /// [`MAX_INTERSECTED_SETS`] sets, or if an index is out of bounds.
+    pub fn set(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
+        assert!(
+            self.sets.len() < MAX_INTERSECTED_SETS,
+            "at most {} sets can be intersected",
+            MAX_INTERSECTED_SETS
+        );
+        let len = self.array.len();
+        let set = indices.into_iter().collect::<Vec<_>>();
+        if let Some(&index) = set.iter().find(|&&index| index >= len) {
+            panic!(
+                "index {} out of bounds for an array of length {}",
+                index, len
+            );
+        }
+        self.sets.push(set);
+        self
+    }
+
+    /// Returns the number of sets of the query.
+    pub fn num_sets(&self) -> usize {
+        self.sets.len()
+    }
+
+    /// Returns an estimation of the number of distinct elements in the
+    /// intersection of the sets of the query.
+    ///
+    /// If there are no sets, this method returns zero.
+    pub fn estimate(&self) -> f64 {
+        let mut estimate = 0.0;
+        for subset in 1_usize..1 << self.sets.len() {
+            let union = union_estimate(
+                self.array,
+                self.sets
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| subset & (1 << i) != 0)
+                    .flat_map(|(_, set)| set.iter().copied()),
+            );
+            if subset.count_ones() % 2 == 1 {
+                estimate += union;
+            } else {
+                estimate -= union;
+            }
+        }
+        estimate.max(0.0)
+    }
+}
+
+/// An operation of a [`QueryBuilder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Union,
+    Intersection,
+}
+
+/// A builder for composite queries combining unions and intersections of the
+/// estimators of a [`SliceEstimatorArray`].
+///
+/// A query starts from the union of the estimators at some indices, and it is
+/// extended by [`union`](QueryBuilder::union) and
+/// [`intersect`](QueryBuilder::intersect), which combine the current query
+/// with the union of the estimators at some other indices. Operations are
+/// applied left to right, so
+/// ```
+/// # use card_est_array::impls::{HyperLogLogBuilder, SliceEstimatorArray, QueryBuilder};
+/// # use card_est_array::traits::{EstimatorArrayMut, EstimatorMut};
+/// # use std::hash::BuildHasherDefault;
+/// # use std::collections::hash_map::DefaultHasher;
+/// # let logic = HyperLogLogBuilder::new(1000)
+/// #     .log_2_num_reg(8)
+/// #     .build_hasher(BuildHasherDefault::<DefaultHasher>::default())
+/// #     .build::<usize>()
+/// #     .unwrap();
+/// let mut array = SliceEstimatorArray::new(logic, 4);
+/// for i in 0..4 {
+///     array.get_estimator_mut(i).add(i);
+/// }
+/// let query = QueryBuilder::new(&array, [0, 1]).intersect([2]).union([3]);
+/// assert_eq!(query.estimate().round(), 1.0);
+/// ```
+/// estimates |((*A*₀ ∪ *A*₁) ∩ *A*₂) ∪ *A*₃|.
+///
+/// The estimate is computed by inclusion–exclusion: if *E* is a query and *U*,
+/// *V* are unions of estimators, |(*E* ∩ *V*) ∪ *U*| = |*E* ∪ *U*| + |*V* ∪
+/// *U*| − |*E* ∪ *U* ∪ *V*|, whereas unions are merged directly. Thus, the
+/// number of union estimates is exponential in the number of intersections,
+/// which is limited to [`MAX_INTERSECTED_SETS`] − 1, and the same caveats of
+/// [`IntersectionQuery`] apply.
+pub struct QueryBuilder<'a, L, W, S> {
+    array: &'a SliceEstimatorArray<L, W, S>,
+    first: Vec<usize>,
+    operations: Vec<(Operation, Vec<usize>)>,
+}
+
+impl<'a, L: SliceEstimationLogic<W> + MergeEstimationLogic, W: Word, S: AsRef<[W]>>
+    QueryBuilder<'a, L, W, S>
+{
+    /// Creates a new query given by the union of the estimators at the given
+    /// indices.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if an index is out of bounds.
+    pub fn new(
+        array: &'a SliceEstimatorArray<L, W, S>,
+        indices: impl IntoIterator<Item = usize>,
+    ) -> Self {
+        let mut builder = Self {
+            array,
+            first: Vec::new(),
+            operations: Vec::new(),
+        };
+        builder.first = builder.checked(indices);
+        builder
+    }
+
+    /// Collects indices, checking that they are within bounds.
+    fn checked(&self, indices: impl IntoIterator<Item = usize>) -> Vec<usize> {
+        let len = self.array.len();
+        indices
+            .into_iter()
+            .inspect(|&index| {
+                assert!(
+                    index < len,
+                    "index {} out of bounds for an array of length {}",
+                    index,
+                    len
+                )
+            })
+            .collect()
+    }
+
+    /// Combines the query with the union of the estimators at the given
+    /// indices using a union.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if an index is out of bounds.
+    pub fn union(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
+        let indices = self.checked(indices);
+        self.operations.push((Operation::Union, indices));
+        self
+    }
+
+    /// Combines the query with the union of the estimators at the given
+    /// indices using an intersection.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the query already contains
+    /// [`MAX_INTERSECTED_SETS`] − 1 intersections, or if an index is out of
+    /// bounds.
+    pub fn intersect(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
+        let num_intersections = self
+            .operations
+            .iter()
+            .filter(|(operation, _)| *operation == Operation::Intersection)
+            .count();
+        assert!(
+            num_intersections < MAX_INTERSECTED_SETS - 1,
+            "at most {} sets can be intersected",
+            MAX_INTERSECTED_SETS
+        );
+        let indices = self.checked(indices);
+        self.operations.push((Operation::Intersection, indices));
+        self
+    }
+
+    /// Returns an estimation of |*E* ∪ *U*|, where *E* is the query given by
+    /// the first `len` operations and *U* the union of the estimators at the
+    /// indices in `extra`.
+    fn estimate_with(&self, len: usize, extra: &mut Vec<usize>) -> f64 {
+        let Some(((operation, indices), _)) = self.operations[..len].split_last() else {
+            return union_estimate(self.array, self.first.iter().chain(extra.iter()).copied());
+        };
+        let extra_len = extra.len();
+        match operation {
+            Operation::Union => {
+                extra.extend_from_slice(indices);
+                let estimate = self.estimate_with(len - 1, extra);
+                extra.truncate(extra_len);
+                estimate
+            }
+            Operation::Intersection => {
+                let query_or_extra = self.estimate_with(len - 1, extra);
+                let indices_or_extra =
+                    union_estimate(self.array, indices.iter().chain(extra.iter()).copied());
+                extra.extend_from_slice(indices);
+                let all = self.estimate_with(len - 1, extra);
+                extra.truncate(extra_len);
+                query_or_extra + indices_or_extra - all
+            }
+        }
+    }
+
+    /// Returns an estimation of the number of distinct elements satisfying
+    /// the query.
+    pub fn estimate(&self) -> f64 {
+        self.estimate_with(self.operations.len(), &mut Vec::new())
+            .max(0.0)
+    }
+}
@@ -0,0 +1,102 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use super::{bytes::words_from_le_bytes, HyperLogLog, HyperLogLogBuilder};
+use crate::traits::*;
+use wasm_bindgen::prelude::*;
+use xxhash_rust::xxh3::Xxh3Builder;
+
+/// A HyperLogLog counter of 64-bit integers exposed to JavaScript.
+///
+/// Registers are packed in 32-bit words, and elements are hashed with XXH3,
+/// so the [serialized form](WasmHyperLogLog::to_bytes) of a counter does not
+/// depend on the platform: counters with the same parameters can be merged
+/// using [`merge_bytes`](WasmHyperLogLog::merge_bytes) no matter where they
+/// have been built.
+#[wasm_bindgen]
+pub struct WasmHyperLogLog {
+    logic: HyperLogLog<u64, Xxh3Builder, u32>,
+    backend: Box<[u32]>,
+}
+
+#[wasm_bindgen]
+impl WasmHyperLogLog {
+    /// Creates a new empty counter.
+    ///
+    /// # Arguments
+    /// * `num_elements`: an upper bound on the number of distinct elements.
+    /// * `log_2_num_registers`: the base-2 logarithm of the number of
+    ///   registers.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the parameters are not valid.
+    #[wasm_bindgen(constructor)]
+    pub fn new(num_elements: usize, log_2_num_registers: usize) -> Result<Self, JsError> {
+        let logic = HyperLogLogBuilder::new(num_elements)
+            .log_2_num_reg(log_2_num_registers)
+            .word_type::<u32>()
+            .build_hasher(Xxh3Builder::new())
+            .build::<u64>()
+            .map_err(|e| JsError::new(&e.to_string()))?;
+        let backend = vec![0; logic.backend_len()].into_boxed_slice();
+        Ok(Self { logic, backend })
+    }
+
+    /// Adds an integer to the counter.
+    #[wasm_bindgen(js_name = addInteger)]
+    pub fn add_integer(&mut self, n: u64) {
+        self.logic.add(&mut self.backend, n);
+    }
+
+    /// Returns an estimation of the number of distinct integers added.
+    pub fn estimate(&self) -> f64 {
+        self.logic.estimate(&self.backend)
+    }
+
+    /// Merges into this counter a counter with the same parameters
+    /// serialized by [`to_bytes`](WasmHyperLogLog::to_bytes).
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the length of `data` is not the
+    /// length of a serialized counter with the same parameters.
+    #[wasm_bindgen(js_name = mergeBytes)]
+    pub fn merge_bytes(&mut self, data: &[u8]) -> Result<(), JsError> {
+        let expected = self.backend.len() * size_of::<u32>();
+        if data.len() != expected {
+            return Err(JsError::new(&format!(
+                "the serialized counter has {} bytes, but {} bytes were expected",
+                data.len(),
+                expected
+            )));
+        }
+        let other = words_from_le_bytes::<u32>(data);
+        self.logic.merge(&mut self.backend, &other);
+        Ok(())
+    }
+
+    /// Returns the counter as a sequence of bytes, which is the concatenation
+    /// of the little-endian representations of its words.
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.backend
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect()
+    }
+
+    /// Empties the counter.
+    pub fn clear(&mut self) {
+        self.logic.clear(&mut self.backend);
+    }
+
+    /// Returns the number of registers of the counter.
+    #[wasm_bindgen(js_name = numRegisters)]
+    pub fn num_registers(&self) -> usize {
+        self.logic.num_registers()
+    }
+}
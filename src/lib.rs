@@ -9,3 +9,4 @@
 
 pub mod impls;
 pub mod traits;
+pub mod utils;
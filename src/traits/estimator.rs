@@ -51,6 +51,16 @@ pub trait EstimationLogic {
     /// Adds an element to an estimator with the given backend.
     fn add(&self, backend: &mut Self::Backend, element: impl Borrow<Self::Item>);
 
+    /// Adds all elements of a slice to an estimator with the given backend.
+    ///
+    /// The default implementation calls [`add`](EstimationLogic::add) on each
+    /// element, but implementations can provide faster alternatives.
+    fn add_many(&self, backend: &mut Self::Backend, elements: &[Self::Item]) {
+        for element in elements {
+            self.add(backend, element);
+        }
+    }
+
     /// Returns an estimation of the number of distinct elements that have been
     /// added to an estimator with the given backend so far.
     fn estimate(&self, backend: &Self::Backend) -> f64;
@@ -61,8 +71,49 @@ pub trait EstimationLogic {
     /// Sets the contents of `dst` to the contents of `src`.
     fn set(&self, dst: &mut Self::Backend, src: &Self::Backend);
 
+    /// Returns whether two backends have the same contents.
+    ///
+    /// The default implementation compares the backends using [`PartialEq`];
+    /// for slice backends, this means comparing them word by word.
+    fn backends_equal(&self, a: &Self::Backend, b: &Self::Backend) -> bool
+    where
+        Self::Backend: PartialEq,
+    {
+        a == b
+    }
+
     /// Creates a new empty estimator using this logic.
     fn new_estimator(&self) -> Self::Estimator<'_>;
+
+    /// Serializes a backend.
+    ///
+    /// The logic is not serialized: the backend can be deserialized using
+    /// [`deserialize_backend`](EstimationLogic::deserialize_backend) on an
+    /// equivalent logic.
+    #[cfg(feature = "serde")]
+    fn serialize_backend<S: serde::Serializer>(
+        &self,
+        backend: &Self::Backend,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        Self::Backend: serde::Serialize,
+    {
+        serde::Serialize::serialize(backend, serializer)
+    }
+
+    /// Deserializes a backend serialized by
+    /// [`serialize_backend`](EstimationLogic::serialize_backend).
+    #[cfg(feature = "serde")]
+    fn deserialize_backend<'de, D: serde::Deserializer<'de>>(
+        &self,
+        deserializer: D,
+    ) -> Result<Box<Self::Backend>, D::Error>
+    where
+        Box<Self::Backend>: serde::Deserialize<'de>,
+    {
+        serde::Deserialize::deserialize(deserializer)
+    }
 }
 
 /// An extension of [`EstimationLogic`] providing methods to merge backends.
@@ -96,6 +147,112 @@ pub trait MergeEstimationLogic: EstimationLogic {
         src: &Self::Backend,
         helper: &mut Self::Helper,
     );
+
+    /// Returns an estimation of the number of distinct elements in the union
+    /// of two backends, without modifying them.
+    ///
+    /// The default implementation creates a [new
+    /// estimator](EstimationLogic::new_estimator), sets it to `a`, and merges
+    /// `b` into it.
+    fn estimate_union(&self, a: &Self::Backend, b: &Self::Backend) -> f64 {
+        let mut helper = self.new_helper();
+        self.estimate_union_with_helper(a, b, &mut helper)
+    }
+
+    /// Returns an estimation of the number of distinct elements in the union
+    /// of two backends, without modifying them, using the provided helper to
+    /// avoid allocations.
+    ///
+    /// Note that the default implementation allocates anyway a temporary
+    /// estimator (see [`estimate_union`](MergeEstimationLogic::estimate_union)).
+    fn estimate_union_with_helper(
+        &self,
+        a: &Self::Backend,
+        b: &Self::Backend,
+        helper: &mut Self::Helper,
+    ) -> f64 {
+        let mut union = self.new_estimator();
+        union.set(a);
+        self.merge_with_helper(union.as_mut(), b, helper);
+        union.estimate()
+    }
+
+    /// Returns an estimation of the number of distinct elements in the
+    /// intersection of two backends.
+    ///
+    /// The default implementation uses the inclusion-exclusion principle,
+    /// that is, |*A*| + |*B*| − |*A* ∪ *B*|, where the cardinality of the
+    /// union is computed by [`estimate_union`](MergeEstimationLogic::estimate_union).
+    /// The result is clamped between zero and the smaller of the two
+    /// estimates. Note that the error of the estimate is proportional to the
+    /// cardinality of the union, rather than to that of the intersection.
+    fn estimate_intersection(&self, a: &Self::Backend, b: &Self::Backend) -> f64 {
+        let estimate_a = self.estimate(a);
+        let estimate_b = self.estimate(b);
+        (estimate_a + estimate_b - self.estimate_union(a, b))
+            .min(estimate_a.min(estimate_b))
+            .max(0.0)
+    }
+
+    /// Returns an estimation of the number of distinct elements of the first
+    /// backend that are not in the second one.
+    ///
+    /// The default implementation returns |*A*| − |*A* ∩ *B*|, where the
+    /// cardinality of the intersection is computed by
+    /// [`estimate_intersection`](MergeEstimationLogic::estimate_intersection).
+    fn estimate_difference(&self, a: &Self::Backend, b: &Self::Backend) -> f64 {
+        (self.estimate(a) - self.estimate_intersection(a, b)).max(0.0)
+    }
+
+    /// Returns an estimation of the Jaccard similarity |*A* ∩ *B*| / |*A* ∪
+    /// *B*| of two backends.
+    ///
+    /// The result is in [0 . . 1]. Two empty backends have similarity one.
+    fn estimate_jaccard(&self, a: &Self::Backend, b: &Self::Backend) -> f64 {
+        let estimate_a = self.estimate(a);
+        let estimate_b = self.estimate(b);
+        let union = self.estimate_union(a, b);
+        if union == 0.0 {
+            return 1.0;
+        }
+        let intersection = (estimate_a + estimate_b - union)
+            .min(estimate_a.min(estimate_b))
+            .max(0.0);
+        (intersection / union).min(1.0)
+    }
+}
+
+/// An extension of [`EstimationLogic`] providing the theoretical accuracy of
+/// the estimates.
+pub trait EstimationGuarantees: EstimationLogic {
+    /// Returns the theoretical relative standard deviation of the estimates
+    /// for the current configuration.
+    fn relative_std(&self) -> f64;
+
+    /// Returns a confidence interval for the estimate of a backend.
+    ///
+    /// The default implementation returns the interval of radius `z` times
+    /// the [relative standard deviation](EstimationGuarantees::relative_std)
+    /// times the estimate centered at the estimate, with the lower bound
+    /// clamped at zero.
+    ///
+    /// # Arguments
+    /// * `backend`: the backend of the estimator.
+    /// * `z`: the z-score of the interval (e.g., 1.96 for a 95% confidence
+    ///   interval).
+    fn confidence_interval(&self, backend: &Self::Backend, z: f64) -> (f64, f64) {
+        let estimate = self.estimate(backend);
+        let radius = z * self.relative_std() * estimate;
+        ((estimate - radius).max(0.0), estimate + radius)
+    }
+
+    /// Returns the standard error of the estimate of a backend.
+    ///
+    /// The default implementation returns the [relative standard
+    /// deviation](EstimationGuarantees::relative_std) times the estimate.
+    fn standard_error(&self, backend: &Self::Backend) -> f64 {
+        self.relative_std() * self.estimate(backend)
+    }
 }
 
 /// Trait implemented by [estimation logics](EstimationLogic) whose backend is a
@@ -123,7 +280,27 @@ pub trait Estimator<L: EstimationLogic + ?Sized>: AsRef<L::Backend> {
     fn estimate(&self) -> f64;
 
     /// Converts this estimator into an owned version capable of mutation.
-    fn into_owned(self) -> Self::OwnedEstimator;
+    ///
+    /// Since backends are unsized, owned estimators are available only if a
+    /// backend can be copied into a [`Box`], which is true of slices of
+    /// [`Clone`] words.
+    fn into_owned(self) -> Self::OwnedEstimator
+    where
+        for<'a> Box<L::Backend>: From<&'a L::Backend>;
+
+    /// Returns a confidence interval for the estimate.
+    ///
+    /// See [`EstimationGuarantees::confidence_interval`].
+    ///
+    /// # Arguments
+    /// * `z`: the z-score of the interval (e.g., 1.96 for a 95% confidence
+    ///   interval).
+    fn estimate_with_ci(&self, z: f64) -> (f64, f64)
+    where
+        L: EstimationGuarantees,
+    {
+        self.logic().confidence_interval(self.as_ref(), z)
+    }
 }
 
 /// A mutable estimator.
@@ -134,6 +311,17 @@ pub trait EstimatorMut<L: EstimationLogic + ?Sized>: Estimator<L> + AsMut<L::Bac
     /// Adds an element to the estimator.
     fn add(&mut self, element: impl Borrow<L::Item>);
 
+    /// Adds all elements of a slice to the estimator.
+    ///
+    /// The default implementation calls [`add`](EstimatorMut::add) on each
+    /// element, but implementations can provide faster alternatives (e.g., by
+    /// delegating to [`EstimationLogic::add_many`]).
+    fn add_many(&mut self, elements: &[L::Item]) {
+        for element in elements {
+            self.add(element);
+        }
+    }
+
     /// Clears the estimator, making it empty.
     fn clear(&mut self);
 
@@ -164,4 +352,36 @@ pub trait MergeEstimator<L: MergeEstimationLogic + ?Sized>: EstimatorMut<L> {
     /// [`as_ref`](AsRef) on the estimator. This approach makes it
     /// possible to merge both owned and non-owned estimators.
     fn merge_with_helper(&mut self, backend: &L::Backend, helper: &mut L::Helper);
+
+    /// Returns an estimation of the number of distinct elements in the union
+    /// of `self` and a backend, without modifying `self`.
+    ///
+    /// See [`MergeEstimationLogic::estimate_union`].
+    fn estimate_union_with(&self, backend: &L::Backend) -> f64 {
+        self.logic().estimate_union(self.as_ref(), backend)
+    }
+
+    /// Returns an estimation of the number of distinct elements in the
+    /// intersection of `self` and a backend.
+    ///
+    /// See [`MergeEstimationLogic::estimate_intersection`].
+    fn estimate_intersection_with(&self, backend: &L::Backend) -> f64 {
+        self.logic().estimate_intersection(self.as_ref(), backend)
+    }
+
+    /// Returns an estimation of the number of distinct elements of `self`
+    /// that are not in a backend.
+    ///
+    /// See [`MergeEstimationLogic::estimate_difference`].
+    fn estimate_difference_with(&self, backend: &L::Backend) -> f64 {
+        self.logic().estimate_difference(self.as_ref(), backend)
+    }
+
+    /// Returns an estimation of the Jaccard similarity of `self` and a
+    /// backend.
+    ///
+    /// See [`MergeEstimationLogic::estimate_jaccard`].
+    fn estimate_jaccard_with(&self, backend: &L::Backend) -> f64 {
+        self.logic().estimate_jaccard(self.as_ref(), backend)
+    }
 }
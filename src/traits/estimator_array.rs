@@ -5,7 +5,10 @@
  * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
  */
 
-use super::estimator::{EstimationLogic, Estimator, EstimatorMut};
+use super::estimator::{EstimationLogic, Estimator, EstimatorMut, MergeEstimationLogic};
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::ops::Range;
 
 /// An array of immutable estimators sharing a [`EstimationLogic`].
 ///
@@ -43,6 +46,244 @@ pub trait EstimatorArray<L: EstimationLogic + ?Sized> {
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Returns an iterator over the estimators in the array, in index order.
+    ///
+    /// The default implementation calls
+    /// [`get_estimator`](EstimatorArray::get_estimator) on each index.
+    fn iter(&self) -> impl ExactSizeIterator<Item = Self::Estimator<'_>> + DoubleEndedIterator {
+        (0..self.len()).map(move |i| self.get_estimator(i))
+    }
+
+    /// Returns an iterator over the backends of the estimators in the array,
+    /// in index order.
+    ///
+    /// The default implementation calls
+    /// [`get_backend`](EstimatorArray::get_backend) on each index.
+    fn iter_backends<'a>(
+        &'a self,
+    ) -> impl ExactSizeIterator<Item = &'a L::Backend> + DoubleEndedIterator
+    where
+        L: 'a,
+    {
+        (0..self.len()).map(move |i| self.get_backend(i))
+    }
+
+    /// Returns the estimates of all the estimators in the array, in index
+    /// order.
+    ///
+    /// The default implementation calls
+    /// [`estimate`](EstimationLogic::estimate) on the
+    /// [backends](EstimatorArray::iter_backends) of the array.
+    fn estimate_all(&self) -> Vec<f64> {
+        self.iter_backends()
+            .map(|backend| self.logic().estimate(backend))
+            .collect()
+    }
+
+    /// Returns the pairs (index, estimate) of the estimators in the array
+    /// whose estimate is greater than `threshold`, in index order.
+    fn estimates_above(&self, threshold: f64) -> Vec<(usize, f64)> {
+        self.estimate_all()
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, estimate)| estimate > threshold)
+            .collect()
+    }
+
+    /// Returns the arithmetic mean of the estimates of the estimators in the
+    /// array, or zero if the array is empty.
+    fn arithmetic_mean_estimate(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        let sum: f64 = (0..self.len())
+            .map(|i| self.logic().estimate(self.get_backend(i)))
+            .sum();
+        sum / self.len() as f64
+    }
+
+    /// Returns the harmonic mean of the nonzero estimates of the estimators in
+    /// the array, or zero if there are no nonzero estimates.
+    fn harmonic_mean_estimate(&self) -> f64 {
+        let (count, sum) = (0..self.len())
+            .map(|i| self.logic().estimate(self.get_backend(i)))
+            .filter(|&estimate| estimate != 0.0)
+            .fold((0, 0.0), |(count, sum), estimate| {
+                (count + 1, sum + 1.0 / estimate)
+            });
+        if count == 0 {
+            0.0
+        } else {
+            count as f64 / sum
+        }
+    }
+
+    /// Returns the geometric mean of the nonzero estimates of the estimators
+    /// in the array, or zero if there are no nonzero estimates.
+    fn geometric_mean_estimate(&self) -> f64 {
+        let (count, sum) = (0..self.len())
+            .map(|i| self.logic().estimate(self.get_backend(i)))
+            .filter(|&estimate| estimate != 0.0)
+            .fold((0, 0.0), |(count, sum), estimate| {
+                (count + 1, sum + estimate.ln())
+            });
+        if count == 0 {
+            0.0
+        } else {
+            (sum / count as f64).exp()
+        }
+    }
+
+    /// Returns the trimmed mean of the estimates of the estimators in the
+    /// array, or zero if the array is empty.
+    ///
+    /// The estimates are sorted, and the ⌊`trim_fraction` · *n*⌋ smallest and
+    /// largest estimates are discarded before computing the arithmetic mean,
+    /// where *n* is the length of the array.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `trim_fraction` is not in the interval
+    /// [0 . . 0.5).
+    fn trimmed_mean_estimate(&self, trim_fraction: f64) -> f64 {
+        assert!(
+            (0.0..0.5).contains(&trim_fraction),
+            "the trim fraction must be in [0 . . 0.5); got {}",
+            trim_fraction
+        );
+        if self.is_empty() {
+            return 0.0;
+        }
+        let mut estimates = (0..self.len())
+            .map(|i| self.logic().estimate(self.get_backend(i)))
+            .collect::<Vec<_>>();
+        estimates.sort_by(f64::total_cmp);
+        let trim = (trim_fraction * estimates.len() as f64) as usize;
+        let kept = &estimates[trim..estimates.len() - trim];
+        kept.iter().sum::<f64>() / kept.len() as f64
+    }
+
+    /// Returns the indices of the `k` estimators of the array with the
+    /// largest estimates, sorted by decreasing estimate.
+    ///
+    /// Ties are broken by increasing index. If `k` is larger than the length
+    /// of the array, all indices are returned.
+    ///
+    /// The default implementation computes the estimates of the
+    /// [estimators](EstimatorArray::iter) of the array, keeping the best `k`
+    /// in a heap of size `k`.
+    fn top_k_by_estimate(&self, k: usize) -> Vec<usize> {
+        top_k(self.iter().map(|estimator| estimator.estimate()), k)
+    }
+
+    /// Returns the index of the estimator of the array with the largest
+    /// estimate, or `None` if the array is empty.
+    ///
+    /// Ties are broken by increasing index.
+    fn max_estimate_index(&self) -> Option<usize> {
+        (0..self.len())
+            .map(|i| (i, self.logic().estimate(self.get_backend(i))))
+            .reduce(|best, current| {
+                if current.1.total_cmp(&best.1) == Ordering::Greater {
+                    current
+                } else {
+                    best
+                }
+            })
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the index of the estimator of the array with the smallest
+    /// estimate, or `None` if the array is empty.
+    ///
+    /// Ties are broken by increasing index.
+    fn min_estimate_index(&self) -> Option<usize> {
+        (0..self.len())
+            .map(|i| (i, self.logic().estimate(self.get_backend(i))))
+            .reduce(|best, current| {
+                if current.1.total_cmp(&best.1) == Ordering::Less {
+                    current
+                } else {
+                    best
+                }
+            })
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the estimates of the unions of the prefixes of the array.
+    ///
+    /// The *i*-th element of the result is an estimate of the number of
+    /// distinct elements in the union of the estimators of index 0, 1, …, *i*.
+    /// The estimates are computed in a single pass using a growing
+    /// accumulator.
+    fn prefix_merge_estimates(&self) -> Vec<f64>
+    where
+        L: MergeEstimationLogic,
+    {
+        let logic = self.logic();
+        let mut union = logic.new_estimator();
+        let mut helper = logic.new_helper();
+        (0..self.len())
+            .map(|i| {
+                logic.merge_with_helper(union.as_mut(), self.get_backend(i), &mut helper);
+                union.estimate()
+            })
+            .collect()
+    }
+
+    /// Returns the estimates of the unions of the suffixes of the array.
+    ///
+    /// The *i*-th element of the result is an estimate of the number of
+    /// distinct elements in the union of the estimators of index *i*, *i* + 1,
+    /// …, *n* − 1, where *n* is the length of the array. The estimates are
+    /// computed in a single pass using a growing accumulator.
+    fn suffix_merge_estimates(&self) -> Vec<f64>
+    where
+        L: MergeEstimationLogic,
+    {
+        let logic = self.logic();
+        let mut union = logic.new_estimator();
+        let mut helper = logic.new_helper();
+        let mut estimates = (0..self.len())
+            .rev()
+            .map(|i| {
+                logic.merge_with_helper(union.as_mut(), self.get_backend(i), &mut helper);
+                union.estimate()
+            })
+            .collect::<Vec<_>>();
+        estimates.reverse();
+        estimates
+    }
+
+    /// Returns an estimate of the number of distinct elements in the union of
+    /// the estimators with index in [`start` . . `end`).
+    ///
+    /// The estimate of an empty range is zero.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `start` is greater than `end` or if `end` is
+    /// greater than the length of the array.
+    fn range_merge_estimate(&self, start: usize, end: usize) -> f64
+    where
+        L: MergeEstimationLogic,
+    {
+        assert!(
+            start <= end && end <= self.len(),
+            "invalid range [{} . . {}) for an array of length {}",
+            start,
+            end,
+            self.len()
+        );
+        let logic = self.logic();
+        let mut union = logic.new_estimator();
+        let mut helper = logic.new_helper();
+        for i in start..end {
+            logic.merge_with_helper(union.as_mut(), self.get_backend(i), &mut helper);
+        }
+        union.estimate()
+    }
 }
 
 /// An array of mutable estimators sharing a [`EstimationLogic`].
@@ -65,11 +306,135 @@ pub trait EstimatorArrayMut<L: EstimationLogic + ?Sized>: EstimatorArray<L> {
     /// This method will usually require no allocation.
     fn get_backend_mut(&mut self, index: usize) -> &mut L::Backend;
 
+    /// Returns an iterator over the estimators in the array, in index order,
+    /// as mutable estimators.
+    fn iter_mut(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = Self::EstimatorMut<'_>> + DoubleEndedIterator;
+
+    /// Returns an iterator over mutable references to the backends of the
+    /// estimators in the array, in index order.
+    fn iter_backends_mut<'a>(
+        &'a mut self,
+    ) -> impl ExactSizeIterator<Item = &'a mut L::Backend> + DoubleEndedIterator
+    where
+        L: 'a;
+
     /// Resets all estimators in the array.
     fn clear(&mut self);
+
+    /// Resets the estimators with index in [`start` . . `end`).
+    ///
+    /// The default implementation calls
+    /// [`get_estimator_mut`](EstimatorArrayMut::get_estimator_mut) for each
+    /// index.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `start` is greater than `end` or if `end` is
+    /// greater than the length of the array.
+    fn clear_range(&mut self, start: usize, end: usize) {
+        assert!(
+            start <= end && end <= self.len(),
+            "invalid range [{} . . {}) for an array of length {}",
+            start,
+            end,
+            self.len()
+        );
+        for i in start..end {
+            self.get_estimator_mut(i).clear();
+        }
+    }
+
+    /// Copies the backends of the estimators of `src` with index in
+    /// `src_range` into the estimators of this array starting at index
+    /// `dst_start`.
+    ///
+    /// # Arguments
+    /// * `src`: the array to copy from, which must use a compatible logic.
+    /// * `src_range`: the range of indices of `src` to copy.
+    /// * `dst_start`: the index of this array receiving the first estimator.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `src_range` is not within the bounds of
+    /// `src`, or if the destination range is not within the bounds of this
+    /// array.
+    fn copy_from_array(
+        &mut self,
+        src: &impl EstimatorArray<L>,
+        src_range: Range<usize>,
+        dst_start: usize,
+    ) {
+        assert!(
+            src_range.start <= src_range.end && src_range.end <= src.len(),
+            "invalid source range [{} . . {}) for an array of length {}",
+            src_range.start,
+            src_range.end,
+            src.len()
+        );
+        assert!(
+            dst_start + src_range.len() <= self.len(),
+            "invalid destination range [{} . . {}) for an array of length {}",
+            dst_start,
+            dst_start + src_range.len(),
+            self.len()
+        );
+        for (offset, i) in src_range.enumerate() {
+            self.get_estimator_mut(dst_start + offset)
+                .set(src.get_backend(i));
+        }
+    }
+
+    /// Adds a stream of elements to the estimators of the array.
+    ///
+    /// Each pair contains the index of an estimator and an element to add to
+    /// it. The default implementation calls
+    /// [`get_estimator_mut`](EstimatorArrayMut::get_estimator_mut) for each
+    /// pair.
+    fn batch_add(&mut self, pairs: impl IntoIterator<Item = (usize, L::Item)>) {
+        for (index, element) in pairs {
+            self.get_estimator_mut(index).add(element);
+        }
+    }
+
+    /// Adds a stream of elements, sorted by index, to the estimators of the
+    /// array.
+    ///
+    /// This method is equivalent to [`batch_add`](EstimatorArrayMut::batch_add),
+    /// but implementations can exploit the order to group the updates of each
+    /// estimator. The default implementation calls
+    /// [`batch_add`](EstimatorArrayMut::batch_add).
+    ///
+    /// # Panics
+    ///
+    /// Implementations may panic if the pairs are not sorted by index.
+    fn batch_add_sorted(&mut self, pairs: impl IntoIterator<Item = (usize, L::Item)>) {
+        self.batch_add(pairs);
+    }
+
+    /// Applies a function to the backend of every estimator in the array.
+    ///
+    /// The function can modify the backends in place.
+    fn apply(&mut self, mut f: impl FnMut(&mut L::Backend)) {
+        for i in 0..self.len() {
+            f(self.get_backend_mut(i));
+        }
+    }
+
+    /// Applies a function to the backend of every estimator in the array,
+    /// passing also the index of the estimator.
+    ///
+    /// The function can modify the backends in place.
+    fn apply_indexed(&mut self, mut f: impl FnMut(usize, &mut L::Backend)) {
+        for i in 0..self.len() {
+            f(i, self.get_backend_mut(i));
+        }
+    }
 }
 
 /// A trait for estimator arrays that can be viewed as a [`SyncEstimatorArray`].
+#[cfg(not(target_arch = "wasm32"))]
 pub trait AsSyncArray<L: EstimationLogic + ?Sized> {
     type SyncEstimatorArray<'a>: SyncEstimatorArray<L>
     where
@@ -95,6 +460,7 @@ pub trait AsSyncArray<L: EstimationLogic + ?Sized> {
 /// The methods of this trait are unsafe because multiple thread can
 /// concurrently access the same estimator array. The caller must ensure that
 /// there are no data races.
+#[cfg(not(target_arch = "wasm32"))]
 pub trait SyncEstimatorArray<L: EstimationLogic + ?Sized>: Sync {
     /// Returns the logic used by the estimators in the array.
     fn logic(&self) -> &L;
@@ -124,6 +490,27 @@ pub trait SyncEstimatorArray<L: EstimationLogic + ?Sized>: Sync {
     /// The caller must ensure that there are no data races.
     unsafe fn clear(&self);
 
+    /// Adds an element to the estimator at `index`, using a shared reference
+    /// to the estimator array.
+    ///
+    /// The default implementation [gets](SyncEstimatorArray::get) the backend
+    /// of the estimator into a new estimator, adds the element, and
+    /// [sets](SyncEstimatorArray::set) the backend back. Implementations can
+    /// provide atomic alternatives (e.g.,
+    /// [`SyncEstimatorArrayAtomic`](crate::impls::SyncEstimatorArrayAtomic)).
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that there are no data races; in particular,
+    /// with the default implementation no other thread may modify the
+    /// estimator at `index` concurrently, or its updates might be lost.
+    unsafe fn scatter_add(&self, index: usize, element: impl std::borrow::Borrow<L::Item>) {
+        let mut estimator = self.logic().new_estimator();
+        unsafe { self.get(index, estimator.as_mut()) };
+        estimator.add(element);
+        unsafe { self.set(index, estimator.as_ref()) };
+    }
+
     /// Returns the number of estimators in the array.
     fn len(&self) -> usize;
 
@@ -133,3 +520,60 @@ pub trait SyncEstimatorArray<L: EstimationLogic + ?Sized>: Sync {
         self.len() == 0
     }
 }
+
+/// Returns the indices of the `k` largest estimates returned by an iterator,
+/// sorted by decreasing estimate, breaking ties by increasing index.
+///
+/// This function uses a heap of size `k`, so it is faster than a full sort
+/// when `k` is small.
+pub(crate) fn top_k(estimates: impl IntoIterator<Item = f64>, k: usize) -> Vec<usize> {
+    if k == 0 {
+        return vec![];
+    }
+    // A min-heap of the best k estimators found so far
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for (index, estimate) in estimates.into_iter().enumerate() {
+        let ranked = Ranked { estimate, index };
+        if heap.len() < k {
+            heap.push(Reverse(ranked));
+        } else if heap.peek().is_some_and(|Reverse(worst)| ranked > *worst) {
+            heap.pop();
+            heap.push(Reverse(ranked));
+        }
+    }
+    // Sorting the reversed elements in increasing order yields the
+    // estimators in decreasing order of rank
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(ranked)| ranked.index)
+        .collect()
+}
+
+/// An index ranked by estimate: larger estimates, and then smaller indices,
+/// are ranked higher.
+struct Ranked {
+    estimate: f64,
+    index: usize,
+}
+
+impl PartialEq for Ranked {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Ranked {}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.estimate
+            .total_cmp(&other.estimate)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
@@ -9,3 +9,6 @@ mod estimator;
 pub use estimator::*;
 mod estimator_array;
 pub use estimator_array::*;
+
+#[cfg(feature = "derive")]
+pub use card_est_array_derive::{EstimationLogic, MergeEstimationLogic, SliceEstimationLogic};
@@ -0,0 +1,79 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::impls::SliceEstimatorArray;
+use crate::traits::*;
+use sux::traits::Word;
+
+/// Greedily clusters the estimators of an array by Jaccard similarity.
+///
+/// Estimators are examined in index order. Each estimator is added to the
+/// existing cluster whose merged backend (i.e., the union of the estimators
+/// in the cluster) has the largest Jaccard similarity with the estimator,
+/// provided that the similarity is larger than `threshold`; otherwise, the
+/// estimator starts a new cluster. The merged backend of a cluster is updated
+/// every time an estimator is added to it.
+///
+/// The Jaccard similarity |*A* ∩ *B*| / |*A* ∪ *B*| is estimated using the
+/// inclusion–exclusion principle, and two empty estimators have similarity
+/// one.
+///
+/// Returns the clusters as lists of indices, in order of creation; each list
+/// is sorted.
+pub fn cluster_by_jaccard<
+    L: SliceEstimationLogic<W> + MergeEstimationLogic + Clone,
+    W: Word,
+    S: AsRef<[W]>,
+>(
+    array: &SliceEstimatorArray<L, W, S>,
+    threshold: f64,
+) -> Vec<Vec<usize>> {
+    let logic = array.logic();
+    let backend_len = logic.backend_len();
+    let mut helper = logic.new_helper();
+    let mut clusters: Vec<Vec<usize>> = vec![];
+    // The merged backends of the clusters, concatenated
+    let mut merged: Vec<W> = vec![];
+    // The estimates of the merged backends
+    let mut merged_estimates: Vec<f64> = vec![];
+    let mut union = vec![W::ZERO; backend_len];
+
+    for i in 0..array.len() {
+        let backend = array.get_backend(i);
+        let estimate = logic.estimate(backend);
+
+        let mut best: Option<(usize, f64)> = None;
+        for (c, cluster_backend) in merged.chunks(backend_len).enumerate() {
+            union.copy_from_slice(cluster_backend);
+            logic.merge_with_helper(&mut union, backend, &mut helper);
+            let union_estimate = logic.estimate(&union);
+            let jaccard = if union_estimate == 0.0 {
+                1.0
+            } else {
+                ((estimate + merged_estimates[c] - union_estimate) / union_estimate).clamp(0.0, 1.0)
+            };
+            if jaccard > threshold && best.is_none_or(|(_, best_jaccard)| jaccard > best_jaccard) {
+                best = Some((c, jaccard));
+            }
+        }
+
+        match best {
+            Some((c, _)) => {
+                let cluster_backend = &mut merged[c * backend_len..][..backend_len];
+                logic.merge_with_helper(cluster_backend, backend, &mut helper);
+                merged_estimates[c] = logic.estimate(cluster_backend);
+                clusters[c].push(i);
+            }
+            None => {
+                merged.extend_from_slice(backend);
+                merged_estimates.push(estimate);
+                clusters.push(vec![i]);
+            }
+        }
+    }
+
+    clusters
+}
@@ -0,0 +1,118 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::*;
+use std::fmt::{Display, Formatter};
+use sux::traits::Word;
+
+/// The result of a [comparison between two logics](compare_logics).
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicComparisonReport {
+    /// The backend length of the first logic.
+    pub backend_len_1: usize,
+    /// The backend length of the second logic.
+    pub backend_len_2: usize,
+    /// The estimate of the first logic.
+    pub estimate_1: f64,
+    /// The estimate of the second logic.
+    pub estimate_2: f64,
+    /// The number of words at which the two backends differ, or `None` if the
+    /// backends have different lengths.
+    pub differing_words: Option<usize>,
+}
+
+impl LogicComparisonReport {
+    /// Returns `true` if the backend lengths are equal.
+    pub fn same_backend_len(&self) -> bool {
+        self.backend_len_1 == self.backend_len_2
+    }
+
+    /// Returns the difference between the two estimates relative to the
+    /// largest of them, or zero if both estimates are zero.
+    pub fn relative_difference(&self) -> f64 {
+        let max = self.estimate_1.max(self.estimate_2);
+        if max == 0.0 {
+            0.0
+        } else {
+            (self.estimate_1 - self.estimate_2).abs() / max
+        }
+    }
+
+    /// Returns `true` if the backend lengths are equal and the [relative
+    /// difference](Self::relative_difference) of the estimates is at most
+    /// `tolerance`.
+    pub fn is_compatible(&self, tolerance: f64) -> bool {
+        self.same_backend_len() && self.relative_difference() <= tolerance
+    }
+}
+
+impl Display for LogicComparisonReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Backend lengths: {} and {}",
+            self.backend_len_1, self.backend_len_2
+        )?;
+        writeln!(
+            f,
+            "Estimates: {} and {} (relative difference: {:.3}%)",
+            self.estimate_1,
+            self.estimate_2,
+            100.0 * self.relative_difference()
+        )?;
+        match self.differing_words {
+            Some(differing_words) => write!(
+                f,
+                "Differing words: {} out of {}",
+                differing_words, self.backend_len_1
+            ),
+            None => write!(f, "Differing words: n/a (different backend lengths)"),
+        }
+    }
+}
+
+/// Compares two logics by adding the same elements to an estimator of each
+/// logic.
+///
+/// This function is useful to check whether a new logic configuration is
+/// compatible with an old one: the returned report contains the backend
+/// lengths, the estimates, and the number of words in which the backends
+/// differ.
+///
+/// # Arguments
+/// * `logic_1`: the first logic.
+/// * `logic_2`: the second logic.
+/// * `elements`: the elements to add.
+pub fn compare_logics<T, W: Word, L1, L2>(
+    logic_1: &L1,
+    logic_2: &L2,
+    elements: impl IntoIterator<Item = T>,
+) -> LogicComparisonReport
+where
+    L1: SliceEstimationLogic<W, Item = T>,
+    L2: SliceEstimationLogic<W, Item = T>,
+{
+    let mut backend_1 = vec![W::ZERO; logic_1.backend_len()];
+    let mut backend_2 = vec![W::ZERO; logic_2.backend_len()];
+    for element in elements {
+        logic_1.add(&mut backend_1, &element);
+        logic_2.add(&mut backend_2, &element);
+    }
+
+    LogicComparisonReport {
+        backend_len_1: backend_1.len(),
+        backend_len_2: backend_2.len(),
+        estimate_1: logic_1.estimate(&backend_1),
+        estimate_2: logic_2.estimate(&backend_2),
+        differing_words: (backend_1.len() == backend_2.len()).then(|| {
+            backend_1
+                .iter()
+                .zip(&backend_2)
+                .filter(|(a, b)| a != b)
+                .count()
+        }),
+    }
+}
@@ -0,0 +1,48 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+/// Returns the percentile ranks of a list of values.
+///
+/// Each value is replaced by its rank in sorted order divided by the number
+/// of values minus one, so that the results are uniformly distributed
+/// between 0 and 1. Equal values are given the average of their ranks; in
+/// particular, if all values are equal (or there is a single value), the
+/// result is 0.5 everywhere.
+///
+/// This rank-based histogram equalization is useful to visualize skewed
+/// distributions of estimates, in which most values are small.
+///
+/// # Panics
+///
+/// This function will panic if a value is NaN.
+pub fn histogram_equalize(values: &[f64]) -> Vec<f64> {
+    assert!(
+        values.iter().all(|v| !v.is_nan()),
+        "cannot equalize NaN values"
+    );
+    let n = values.len();
+    let mut indices = (0..n).collect::<Vec<_>>();
+    indices.sort_unstable_by(|&a, &b| values[a].total_cmp(&values[b]));
+
+    let mut result = vec![0.5; n];
+    if n < 2 {
+        return result;
+    }
+    let mut start = 0;
+    while start < n {
+        let mut end = start + 1;
+        while end < n && values[indices[end]] == values[indices[start]] {
+            end += 1;
+        }
+        // Positions start..end contain equal values
+        let rank = (start + end - 1) as f64 / 2.0 / (n - 1) as f64;
+        for &i in &indices[start..end] {
+            result[i] = rank;
+        }
+        start = end;
+    }
+    result
+}
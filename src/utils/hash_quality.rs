@@ -0,0 +1,234 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::impls::{HyperLogLog, HyperLogLogBuilder};
+use crate::traits::*;
+use anyhow::Result;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::hash::{BuildHasher, Hash};
+
+/// The standard normal quantile used for the bounds of the chi-squared test,
+/// corresponding to a two-sided significance level of about 0.2%.
+const CHI_SQUARED_Z: f64 = 3.09;
+
+/// The number of 64-bit inputs used to measure the avalanche effect.
+const AVALANCHE_SAMPLES: u64 = 1000;
+
+/// The maximum admissible distance from 1/2 of the average fraction of
+/// output bits changed by flipping an input bit.
+const AVALANCHE_TOLERANCE: f64 = 0.05;
+
+/// The maximum admissible distance from 1/2 of the fraction of output bits
+/// changed by flipping a specific input bit.
+const AVALANCHE_BIT_TOLERANCE: f64 = 0.1;
+
+/// The maximum admissible relative error of the estimate, in multiples of
+/// the relative standard deviation.
+const ACCURACY_TOLERANCE: f64 = 3.0;
+
+/// The result of [`test_hash_quality`].
+///
+/// Each criterion can be checked using the corresponding `passes_` method,
+/// and the [`Display`] implementation reports a pass/fail line for each.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HashQualityReport {
+    /// The number of registers of the HyperLogLog logic used for the tests.
+    pub num_registers: usize,
+    /// The chi-squared statistic of the distribution of the elements among
+    /// the registers.
+    pub chi_squared: f64,
+    /// The range of values of [`chi_squared`](HashQualityReport::chi_squared)
+    /// compatible with a uniform distribution.
+    pub chi_squared_bounds: (f64, f64),
+    /// The average fraction of output bits changed by flipping an input bit.
+    pub avalanche: f64,
+    /// The fraction of changed output bits farthest from 1/2 over all input
+    /// bits.
+    pub worst_avalanche: f64,
+    /// The number of distinct elements.
+    pub true_count: usize,
+    /// The estimate of the number of distinct elements.
+    pub estimate: f64,
+    /// The relative standard deviation of the HyperLogLog logic.
+    pub relative_std: f64,
+}
+
+impl HashQualityReport {
+    /// Returns whether the distribution of the elements among the registers
+    /// is compatible with a uniform distribution.
+    ///
+    /// The test is two-sided: distributions that are too uniform, as those
+    /// generated by an identity hash on consecutive integers, fail, too.
+    pub fn passes_chi_squared(&self) -> bool {
+        (self.chi_squared_bounds.0..=self.chi_squared_bounds.1).contains(&self.chi_squared)
+    }
+
+    /// Returns whether flipping an input bit changes about half of the output
+    /// bits, on average and for each input bit.
+    pub fn passes_avalanche(&self) -> bool {
+        (self.avalanche - 0.5).abs() <= AVALANCHE_TOLERANCE
+            && (self.worst_avalanche - 0.5).abs() <= AVALANCHE_BIT_TOLERANCE
+    }
+
+    /// Returns the relative error of the estimate.
+    pub fn relative_error(&self) -> f64 {
+        if self.true_count == 0 {
+            self.estimate
+        } else {
+            (self.estimate - self.true_count as f64) / self.true_count as f64
+        }
+    }
+
+    /// Returns whether the relative error of the estimate is within three
+    /// relative standard deviations.
+    pub fn passes_accuracy(&self) -> bool {
+        self.relative_error().abs() <= ACCURACY_TOLERANCE * self.relative_std
+    }
+
+    /// Returns whether all criteria are satisfied.
+    pub fn passes(&self) -> bool {
+        self.passes_chi_squared() && self.passes_avalanche() && self.passes_accuracy()
+    }
+}
+
+fn pass_fail(pass: bool) -> &'static str {
+    if pass {
+        "PASS"
+    } else {
+        "FAIL"
+    }
+}
+
+impl Display for HashQualityReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "[{}] chi-squared on {} registers: {:.2} (expected between {:.2} and {:.2})",
+            pass_fail(self.passes_chi_squared()),
+            self.num_registers,
+            self.chi_squared,
+            self.chi_squared_bounds.0,
+            self.chi_squared_bounds.1
+        )?;
+        writeln!(
+            f,
+            "[{}] avalanche: {:.4} of output bits changed on average, {:.4} in the worst case (expected 0.5)",
+            pass_fail(self.passes_avalanche()),
+            self.avalanche,
+            self.worst_avalanche
+        )?;
+        write!(
+            f,
+            "[{}] accuracy: estimate {:.2} for {} distinct elements, relative error {:.4} (relative standard deviation {:.4})",
+            pass_fail(self.passes_accuracy()),
+            self.estimate,
+            self.true_count,
+            self.relative_error(),
+            self.relative_std
+        )
+    }
+}
+
+/// Returns the quantile of the chi-squared distribution with the given
+/// degrees of freedom corresponding to a standard normal quantile, using the
+/// Wilson–Hilferty approximation.
+fn chi_squared_quantile(dof: f64, z: f64) -> f64 {
+    let c = 2.0 / (9.0 * dof);
+    dof * (1.0 - c + z * c.sqrt()).powi(3).max(0.0)
+}
+
+/// Tests the quality of a hash function for use with [`HyperLogLog`].
+///
+/// The elements are added to a HyperLogLog estimator using the given hash
+/// function, and three criteria are measured:
+/// 1. the distribution of the distinct elements among the registers must be
+///    compatible with a uniform distribution (chi-squared test);
+/// 2. flipping an input bit must change about half of the output bits
+///    (avalanche criterion): since elements cannot be modified generically,
+///    this criterion is measured on a fixed pseudorandom sample of `u64`
+///    values;
+/// 3. the estimate must be within three relative standard deviations of the
+///    number of distinct elements.
+///
+/// The elements should contain a few times more distinct elements than
+/// registers for the chi-squared test to be meaningful.
+///
+/// # Arguments
+/// * `builder`: the builder of the hash function to test.
+/// * `elements`: the elements to add; duplicates are counted once.
+/// * `log2m`: the base-2 logarithm of the number of registers.
+///
+/// # Errors
+///
+/// This function will return an error if a HyperLogLog logic with the given
+/// number of registers cannot be built.
+pub fn test_hash_quality<H: BuildHasher + Clone, T: Hash + Eq>(
+    builder: H,
+    elements: &[T],
+    log2m: usize,
+) -> Result<HashQualityReport> {
+    let logic: HyperLogLog<T, H, usize> = HyperLogLogBuilder::new(elements.len().max(1))
+        .log_2_num_reg(log2m)
+        .build_hasher(builder.clone())
+        .build()?;
+    let num_registers = logic.num_registers();
+
+    let distinct = elements.iter().collect::<HashSet<_>>();
+    let mut estimator = logic.new_estimator();
+    let mut counts = vec![0_usize; num_registers];
+    for &element in &distinct {
+        estimator.add(element);
+        // This is the register used by HyperLogLog
+        counts[builder.hash_one(element) as usize & (num_registers - 1)] += 1;
+    }
+
+    let expected = distinct.len() as f64 / num_registers as f64;
+    let chi_squared = if expected == 0.0 {
+        0.0
+    } else {
+        counts
+            .iter()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum()
+    };
+    let dof = (num_registers - 1) as f64;
+
+    // SplitMix64 generates the inputs of the avalanche test
+    let mut state = 0_u64;
+    let mut changed = [0_u64; 64];
+    for _ in 0..AVALANCHE_SAMPLES {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut x = state;
+        x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        x ^= x >> 31;
+        let hash = builder.hash_one(x);
+        for (bit, changed) in changed.iter_mut().enumerate() {
+            *changed += (hash ^ builder.hash_one(x ^ (1 << bit))).count_ones() as u64;
+        }
+    }
+    let fractions = changed.map(|changed| changed as f64 / (AVALANCHE_SAMPLES * 64) as f64);
+    let avalanche = fractions.iter().sum::<f64>() / 64.0;
+    let worst_avalanche = fractions
+        .into_iter()
+        .max_by(|a, b| (a - 0.5).abs().total_cmp(&(b - 0.5).abs()))
+        .unwrap();
+
+    Ok(HashQualityReport {
+        num_registers,
+        chi_squared,
+        chi_squared_bounds: (
+            chi_squared_quantile(dof, -CHI_SQUARED_Z),
+            chi_squared_quantile(dof, CHI_SQUARED_Z),
+        ),
+        avalanche,
+        worst_avalanche,
+        true_count: distinct.len(),
+        estimate: estimator.estimate(),
+        relative_std: HyperLogLog::rel_std(log2m),
+    })
+}
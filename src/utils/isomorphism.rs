@@ -0,0 +1,84 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::traits::*;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Returns the estimates of an array in increasing order.
+fn sorted_estimates<L: EstimationLogic + ?Sized>(array: &impl EstimatorArray<L>) -> Vec<f64> {
+    let logic = array.logic();
+    let mut estimates = (0..array.len())
+        .map(|i| logic.estimate(array.get_backend(i)))
+        .collect::<Vec<_>>();
+    estimates.sort_unstable_by(f64::total_cmp);
+    estimates
+}
+
+/// Returns `true` if two arrays have the same estimates, up to a permutation
+/// of the indices.
+///
+/// The estimates of both arrays are sorted and compared element-wise. This
+/// check is useful to verify that two arrays containing the same estimators
+/// in different orders produce the same analytics.
+///
+/// # Arguments
+/// * `a`: the first array.
+/// * `b`: the second array.
+/// * `tolerance`: the maximum absolute difference between corresponding
+///   estimates.
+pub fn are_isomorphic_by_estimate<L: EstimationLogic + ?Sized, A, B>(
+    a: &A,
+    b: &B,
+    tolerance: f64,
+) -> bool
+where
+    A: EstimatorArray<L>,
+    B: EstimatorArray<L>,
+{
+    if a.len() != b.len() {
+        return false;
+    }
+    let a_estimates = sorted_estimates(a);
+    let b_estimates = sorted_estimates(b);
+    a_estimates
+        .iter()
+        .zip(&b_estimates)
+        .all(|(x, y)| (x - y).abs() <= tolerance)
+}
+
+/// Returns a permutation of the indices mapping the backends of an array to
+/// identical backends of another array, if it exists.
+///
+/// If the result is `Some(perm)`, the backend at index `i` of `a` is equal to
+/// the backend at index `perm[i]` of `b`. Identical backends are matched in
+/// increasing index order.
+///
+/// Differently from [`are_isomorphic_by_estimate`], this function compares
+/// backends, rather than estimates, so different estimators with the same
+/// estimate are not considered equivalent.
+pub fn are_structurally_isomorphic<L: EstimationLogic + ?Sized, A, B>(
+    a: &A,
+    b: &B,
+) -> Option<Vec<usize>>
+where
+    A: EstimatorArray<L>,
+    B: EstimatorArray<L>,
+    L::Backend: Hash + Eq,
+{
+    if a.len() != b.len() {
+        return None;
+    }
+    let mut positions = HashMap::<&L::Backend, Vec<usize>>::new();
+    // We insert indices in reverse order so that popping returns the
+    // smallest index first
+    for i in (0..b.len()).rev() {
+        positions.entry(b.get_backend(i)).or_default().push(i);
+    }
+    (0..a.len())
+        .map(|i| positions.get_mut(a.get_backend(i))?.pop())
+        .collect()
+}
@@ -0,0 +1,183 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::impls::SliceEstimatorArray;
+use crate::traits::*;
+use common_traits::ToBytes;
+use sux::traits::Word;
+use xxhash_rust::xxh3::{xxh3_64, Xxh3};
+
+/// A Merkle tree of the backends of an array of estimators.
+///
+/// The leaves of the tree are the [XXH3](xxhash_rust::xxh3) hashes of the
+/// little-endian representation of the backends, and each internal node is
+/// the hash of the concatenation of the hashes of its two children (or of
+/// its only child, for the last node of a level with an odd number of
+/// nodes), so hashes do not depend on the platform.
+///
+/// Two machines holding versions of the same array can check that they are
+/// identical by comparing their [root hashes](MerkleVerifier::root_hash), and
+/// if not, [find the indices of the differing
+/// backends](MerkleVerifier::diff_indices) by visiting only the subtrees
+/// whose hashes differ: a single changed backend is found using
+/// *O*(log *n*) hash comparisons, where *n* is the number of estimators.
+///
+/// A verifier is a snapshot of the array at the time of construction, and
+/// must be rebuilt after the array has been modified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleVerifier<W> {
+    /// The levels of the tree, from the leaves to the root.
+    levels: Vec<Box<[u64]>>,
+    _marker: std::marker::PhantomData<W>,
+}
+
+impl<W: Word + ToBytes> MerkleVerifier<W> {
+    /// Creates a new verifier for a sequence of backends stored
+    /// contiguously.
+    ///
+    /// # Arguments
+    /// * `backends`: the concatenation of the backends, as in the storage of a
+    ///   [`SliceEstimatorArray`].
+    /// * `backend_len`: the length of each backend.
+    ///
+    /// # Panics
+    ///
+    /// If `backend_len` is zero or the length of `backends` is not a multiple
+    /// of `backend_len`.
+    pub fn new(backends: &[W], backend_len: usize) -> Self {
+        assert!(backend_len > 0, "the backend length must be positive");
+        assert_eq!(
+            backends.len() % backend_len,
+            0,
+            "the length of the backends ({}) is not a multiple of the backend length ({})",
+            backends.len(),
+            backend_len
+        );
+        let leaves = backends
+            .chunks_exact(backend_len)
+            .map(|backend| {
+                let mut hasher = Xxh3::new();
+                for &word in backend {
+                    hasher.update(word.to_le_bytes().as_ref());
+                }
+                hasher.digest()
+            })
+            .collect::<Box<[u64]>>();
+
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| {
+                    let mut bytes = [0; 16];
+                    for (chunk, hash) in bytes.chunks_exact_mut(8).zip(pair) {
+                        chunk.copy_from_slice(&hash.to_le_bytes());
+                    }
+                    xxh3_64(&bytes[..8 * pair.len()])
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        Self {
+            levels,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Creates a new verifier for the backends of an array.
+    pub fn from_array<L: SliceEstimationLogic<W> + Clone, S: AsRef<[W]>>(
+        array: &SliceEstimatorArray<L, W, S>,
+    ) -> Self {
+        Self::new(array.as_ref(), array.logic().backend_len())
+    }
+}
+
+impl<W> MerkleVerifier<W> {
+    /// Returns the number of estimators.
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Returns whether there are no estimators.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the hash of the backend of the estimator of given index.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    pub fn leaf_hash(&self, index: usize) -> u64 {
+        self.levels[0][index]
+    }
+
+    /// Returns the root hash of the tree.
+    ///
+    /// The root hash of a verifier with no estimators is the hash of the
+    /// empty string.
+    pub fn root_hash(&self) -> u64 {
+        self.levels
+            .last()
+            .unwrap()
+            .first()
+            .copied()
+            .unwrap_or_else(|| xxh3_64(&[]))
+    }
+
+    /// Returns the sorted indices of the estimators whose backends differ in
+    /// two versions of an array.
+    ///
+    /// # Panics
+    ///
+    /// If the two verifiers have a different number of estimators.
+    pub fn diff_indices(&self, other: &MerkleVerifier<W>) -> Vec<usize> {
+        self.diff_indices_with_comparisons(other).0
+    }
+
+    /// Returns the sorted indices of the estimators whose backends differ in
+    /// two versions of an array, and the number of hash comparisons
+    /// performed to find them.
+    ///
+    /// # Panics
+    ///
+    /// If the two verifiers have a different number of estimators.
+    pub fn diff_indices_with_comparisons(&self, other: &MerkleVerifier<W>) -> (Vec<usize>, usize) {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "the verifiers have a different number of estimators"
+        );
+        let mut indices = vec![];
+        let mut comparisons = 0;
+        if self.is_empty() {
+            return (indices, comparisons);
+        }
+
+        // Depth-first visit visiting left children first, so that indices
+        // are found in increasing order
+        let mut stack = vec![(self.levels.len() - 1, 0)];
+        while let Some((level, node)) = stack.pop() {
+            comparisons += 1;
+            if self.levels[level][node] == other.levels[level][node] {
+                continue;
+            }
+            if level == 0 {
+                indices.push(node);
+            } else {
+                let children = self.levels[level - 1].len();
+                if 2 * node + 1 < children {
+                    stack.push((level - 1, 2 * node + 1));
+                }
+                stack.push((level - 1, 2 * node));
+            }
+        }
+        (indices, comparisons)
+    }
+}
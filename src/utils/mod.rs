@@ -0,0 +1,36 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+mod clustering;
+pub use clustering::*;
+
+mod reconciliation;
+pub use reconciliation::*;
+
+mod compare;
+pub use compare::*;
+
+mod isomorphism;
+pub use isomorphism::*;
+
+mod tree;
+pub use tree::*;
+
+mod equalization;
+pub use equalization::*;
+
+mod hash_quality;
+pub use hash_quality::*;
+
+#[cfg(feature = "xxhash-rust")]
+mod merkle;
+#[cfg(feature = "xxhash-rust")]
+pub use merkle::*;
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+mod parallel_merge;
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+pub use parallel_merge::*;
@@ -0,0 +1,156 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::impls::SliceEstimatorArray;
+use crate::traits::*;
+use sux::traits::Word;
+
+/// Merges in parallel all the estimators of an array, returning the merged
+/// backend.
+///
+/// The array is split recursively in halves, which are merged in parallel
+/// using [`rayon::join`]; ranges of estimators shorter than a fraction of the
+/// array depending on the number of threads are merged sequentially. The
+/// left half of a split reuses the [helper](MergeEstimationLogic::Helper) of
+/// its parent, whereas the right half, which might be stolen by another
+/// thread, allocates a new one, so the number of helpers is proportional to
+/// the number of threads.
+///
+/// The result is the same as that of
+/// [`merge_all`](SliceEstimatorArray::merge_all) on an empty backend. An empty
+/// backend is returned if the array is empty.
+pub fn parallel_merge_all<L, W, S>(array: &SliceEstimatorArray<L, W, S>) -> Vec<W>
+where
+    L: SliceEstimationLogic<W> + MergeEstimationLogic + Clone + Sync,
+    L::Helper: Send,
+    W: Word,
+    S: AsRef<[W]>,
+{
+    let logic = array.logic();
+    let min_len = (array.len() / (4 * rayon::current_num_threads())).max(1);
+    let mut helper = logic.new_helper();
+    merge_tree(
+        logic,
+        array.as_ref(),
+        logic.backend_len(),
+        min_len,
+        &mut helper,
+    )
+}
+
+/// Merges recursively a concatenation of backends.
+fn merge_tree<L, W>(
+    logic: &L,
+    backends: &[W],
+    backend_len: usize,
+    min_len: usize,
+    helper: &mut L::Helper,
+) -> Vec<W>
+where
+    L: SliceEstimationLogic<W> + MergeEstimationLogic + Sync,
+    L::Helper: Send,
+    W: Word,
+{
+    let len = backends.len() / backend_len;
+    if len <= min_len {
+        let mut merged = vec![W::ZERO; backend_len];
+        for backend in backends.chunks(backend_len) {
+            logic.merge_with_helper(&mut merged, backend, helper);
+        }
+        return merged;
+    }
+
+    let (left, right) = backends.split_at(len / 2 * backend_len);
+    let (mut merged, right) = rayon::join(
+        || merge_tree(logic, left, backend_len, min_len, helper),
+        || {
+            let mut helper = logic.new_helper();
+            merge_tree(logic, right, backend_len, min_len, &mut helper)
+        },
+    );
+    logic.merge_with_helper(&mut merged, &right, helper);
+    merged
+}
+
+/// Merges in parallel the estimators in the range `start..end` of a
+/// [`SyncEstimatorArray`], storing the result in `result`.
+///
+/// The backends in the range are first copied in parallel into a buffer
+/// allocated by this function; then, the buffer is reduced in place as a
+/// binary tree: at each round, every backend at an even multiple of a
+/// distance *d* is merged in parallel with the backend at distance *d*,
+/// which doubles at each round, starting from one. After ⌈log₂(`end` −
+/// `start`)⌉ rounds the first backend of the buffer is copied into
+/// `result`. If the range is empty, `result` is cleared.
+///
+/// The result is the same as that of merging sequentially the backends
+/// in the range, provided that merging is associative and commutative.
+///
+/// # Arguments
+///
+/// * `array` - the array containing the estimators to merge.
+///
+/// * `start` - the index of the first estimator to merge.
+///
+/// * `end` - the index after the last estimator to merge.
+///
+/// * `result` - a backend that will contain the result of the merge.
+///
+/// # Panics
+///
+/// This function will panic if `start` > `end` or if `end` is greater than
+/// the length of the array.
+///
+/// # Safety
+///
+/// The caller must ensure that no other thread modifies the estimators in
+/// the range during the call.
+pub unsafe fn merge_cascade<L, W>(
+    array: &impl SyncEstimatorArray<L>,
+    start: usize,
+    end: usize,
+    result: &mut [W],
+) where
+    L: SliceEstimationLogic<W> + MergeEstimationLogic + Sync,
+    W: Word,
+{
+    use rayon::prelude::*;
+    assert!(
+        start <= end && end <= array.len(),
+        "invalid range [{} . . {}) for an array of length {}",
+        start,
+        end,
+        array.len()
+    );
+    let logic = array.logic();
+    if start == end {
+        logic.clear(result);
+        return;
+    }
+
+    let backend_len = logic.backend_len();
+    let mut buffer = vec![W::ZERO; (end - start) * backend_len];
+    buffer
+        .par_chunks_mut(backend_len)
+        .enumerate()
+        .for_each(|(i, backend)| unsafe { array.get(start + i, backend) });
+
+    let mut distance = 1;
+    while distance < end - start {
+        buffer
+            .par_chunks_mut(2 * distance * backend_len)
+            .filter(|chunk| chunk.len() > distance * backend_len)
+            .for_each_init(
+                || logic.new_helper(),
+                |helper, chunk| {
+                    let (dst, src) = chunk.split_at_mut(distance * backend_len);
+                    logic.merge_with_helper(&mut dst[..backend_len], &src[..backend_len], helper);
+                },
+            );
+        distance *= 2;
+    }
+    logic.set(result, &buffer[..backend_len]);
+}
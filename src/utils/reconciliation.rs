@@ -0,0 +1,85 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::impls::SliceEstimatorArray;
+use crate::traits::*;
+use common_traits::ToBytes;
+use sux::traits::Word;
+
+/// Returns a checksum of a backend.
+///
+/// The checksum is the 64-bit FNV-1a hash of the little-endian representation
+/// of the words of the backend, so it does not depend on the platform.
+pub fn backend_checksum<W: Word + ToBytes>(backend: &[W]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+    for &word in backend {
+        for &byte in word.to_le_bytes().as_ref() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+/// Returns the [checksums](backend_checksum) of all the backends of an array.
+pub fn backend_checksums<L: SliceEstimationLogic<W> + Clone, W: Word + ToBytes, S: AsRef<[W]>>(
+    array: &SliceEstimatorArray<L, W, S>,
+) -> Vec<u64> {
+    (0..array.len())
+        .map(|i| backend_checksum(array.get_backend(i)))
+        .collect()
+}
+
+/// Returns the indices at which two lists of [checksums](backend_checksums)
+/// differ.
+///
+/// If the lists have different lengths, the additional indices of the longer
+/// list are considered different.
+pub fn differing_indices(local: &[u64], remote: &[u64]) -> Vec<usize> {
+    (0..local.len().max(remote.len()))
+        .filter(|&i| local.get(i) != remote.get(i))
+        .collect()
+}
+
+/// Merges remote backends into a local array, skipping the backends that are
+/// identical to the local ones.
+///
+/// This function is the last step of a simple reconciliation protocol between
+/// two machines holding arrays with the same logic:
+///
+/// 1. the machines exchange the [checksums](backend_checksums) of their
+///    backends;
+/// 2. each machine transmits to the other the backends at the
+///    [indices where the checksums differ](differing_indices);
+/// 3. each machine reconciles its array with the backends received.
+///
+/// At the end of the protocol, both arrays contain, at each index, the union
+/// of the two original estimators. Since a remote backend might have been
+/// transmitted anyway, backends are compared again using their checksums
+/// before merging.
+///
+/// Returns the number of merged backends.
+pub fn reconcile_arrays<
+    'a,
+    L: SliceEstimationLogic<W> + MergeEstimationLogic + Clone,
+    W: Word + ToBytes + 'a,
+    S: AsRef<[W]> + AsMut<[W]>,
+>(
+    local: &mut SliceEstimatorArray<L, W, S>,
+    remote_backends: impl IntoIterator<Item = (usize, &'a [W])>,
+) -> usize {
+    let logic = local.logic().clone();
+    let mut helper = logic.new_helper();
+    let mut merged = 0;
+    for (index, remote) in remote_backends {
+        let backend = local.get_backend_mut(index);
+        if backend_checksum(backend) != backend_checksum(remote) {
+            logic.merge_with_helper(backend, remote, &mut helper);
+            merged += 1;
+        }
+    }
+    merged
+}
@@ -0,0 +1,72 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use crate::impls::SliceEstimatorArray;
+use crate::traits::*;
+use std::collections::VecDeque;
+
+/// Merges into the estimator of each node of a forest the estimators of all
+/// its ancestors.
+///
+/// Nodes are processed in breadth-first order starting from the roots, and
+/// the estimator of the parent of each node is merged into the estimator of
+/// the node. Thus, at the end each estimator contains the union of the
+/// estimators on the path from its node to the root.
+///
+/// # Arguments
+/// * `array`: the array containing the estimators of the nodes.
+/// * `parent`: the parent of each node; roots are their own parent.
+///
+/// # Panics
+///
+/// This function will panic if the length of `parent` is not equal to the
+/// length of the array, if a parent is out of bounds, or if `parent` contains
+/// a cycle not passing through a root.
+pub fn propagate_prefix<L: SliceEstimationLogic<W> + MergeEstimationLogic, W, S>(
+    array: &mut SliceEstimatorArray<L, W, S>,
+    parent: &[usize],
+) where
+    S: AsRef<[W]> + AsMut<[W]>,
+{
+    let num_nodes = array.len();
+    assert_eq!(
+        parent.len(),
+        num_nodes,
+        "there are {} parents, but the array has {} estimators",
+        parent.len(),
+        num_nodes
+    );
+
+    let mut children = vec![vec![]; num_nodes];
+    let mut queue = VecDeque::new();
+    for (node, &p) in parent.iter().enumerate() {
+        assert!(
+            p < num_nodes,
+            "the parent {} of node {} is out of bounds ({} nodes)",
+            p,
+            node,
+            num_nodes
+        );
+        if p == node {
+            queue.push_back(node);
+        } else {
+            children[p].push(node);
+        }
+    }
+
+    let mut visited = 0;
+    while let Some(node) = queue.pop_front() {
+        visited += 1;
+        for &child in &children[node] {
+            array.merge_range_into(child, [node]);
+            queue.push_back(child);
+        }
+    }
+    assert_eq!(
+        visited, num_nodes,
+        "the parent array contains a cycle not passing through a root"
+    );
+}
@@ -0,0 +1,75 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{AccuracyMonitor, HyperLogLog, HyperLogLogBuilder},
+    traits::{EstimationLogic, Estimator},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_accuracy_monitor_exact() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(12)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let rsd = HyperLogLog::rel_std(12);
+
+    let mut monitor = AccuracyMonitor::new(logic.new_estimator(), 100_000, 1000);
+    for i in 0..50_000_u64 {
+        // Each element is added twice
+        monitor.add(i / 2);
+        if i % 1000 == 999 {
+            assert!(monitor.is_exact());
+            assert_eq!(monitor.exact_count(), i.div_ceil(2) as f64);
+            let expected = (monitor.estimate() - monitor.exact_count()) / monitor.exact_count();
+            assert_eq!(monitor.relative_error(), expected);
+            assert!(monitor.relative_error().abs() < 5.0 * rsd);
+        }
+    }
+    let errors = monitor.errors().to_vec();
+    assert_eq!(errors.len(), 50);
+
+    let report = monitor.into_report();
+    assert_eq!(report.elements_added, 50_000);
+    assert_eq!(report.exact_count, 25_000.0);
+    assert!(report.is_exact);
+    assert_eq!(report.num_observations, 50);
+    assert_eq!(report.final_relative_error, errors[49]);
+    let mean = errors.iter().sum::<f64>() / 50.0;
+    assert!((report.mean_relative_error - mean).abs() < 1E-12);
+    let max_abs = errors.iter().map(|e| e.abs()).fold(0.0, f64::max);
+    assert_eq!(report.max_absolute_relative_error, max_abs);
+    assert!(report.mean_absolute_relative_error <= report.rms_relative_error);
+    assert!(report.rms_relative_error <= report.max_absolute_relative_error);
+    assert!(report.rms_relative_error < 3.0 * rsd);
+
+    Ok(())
+}
+
+#[test]
+fn test_accuracy_monitor_sampling() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(12)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+
+    let mut monitor = AccuracyMonitor::new(logic.new_estimator(), 10_000, 10_000);
+    for i in 0..200_000_u64 {
+        monitor.add(i);
+    }
+    assert!(!monitor.is_exact());
+    assert!((monitor.exact_count() - 200_000.0).abs() / 200_000.0 < 0.05);
+    assert!(monitor.relative_error().abs() < 0.1);
+
+    let report = monitor.into_report();
+    assert!(!report.is_exact);
+    assert_eq!(report.num_observations, 20);
+    assert!(report.max_absolute_relative_error < 0.1);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimationLogic, EstimatorArrayMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_add_all_par() -> Result<()> {
+    for log_2_num_registers in [4, 7, 10] {
+        let logic = HyperLogLogBuilder::new(1_000_000)
+            .log_2_num_reg(log_2_num_registers)
+            .word_type::<u16>()
+            .build_hasher(Xxh3Builder::new().with_seed(0))
+            .build::<u64>()?;
+        let mut array = SliceEstimatorArray::new(logic.clone(), 2);
+        for n in [0, 1, 100, 100_000] {
+            let elements = (0..n).map(|i| i * 7 % 50_000).collect::<Vec<_>>();
+            array.clear();
+            // Preexisting content must be preserved
+            logic.add_many(array.get_backend_mut(0), &[1_000_000, 2_000_000]);
+            logic.add_many(array.get_backend_mut(1), &[1_000_000, 2_000_000]);
+            logic.add_many(array.get_backend_mut(0), &elements);
+            logic.add_all_par(array.get_backend_mut(1), &elements);
+            assert_eq!(
+                array.get_backend_mut(0).to_vec(),
+                array.get_backend_mut(1).to_vec()
+            );
+        }
+    }
+    Ok(())
+}
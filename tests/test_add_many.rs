@@ -0,0 +1,42 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{
+        EstimationLogic, EstimatorArray, EstimatorArrayMut, EstimatorMut, SliceEstimationLogic,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_add_many() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(10)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+
+    // Lengths that are not a multiple of the block size exercise the remainder
+    for n in [0, 1, 7, 8, 9, 1000, 100_003] {
+        let elements = (0..n).collect::<Vec<u64>>();
+        let mut array = SliceEstimatorArray::new(logic.clone(), 2);
+        {
+            let mut estimator = array.get_estimator_mut(0);
+            for element in &elements {
+                estimator.add(element);
+            }
+        }
+        array.get_estimator_mut(1).add_many(&elements);
+        assert_eq!(array.get_backend(0), array.get_backend(1));
+
+        let mut backend = vec![0; logic.backend_len()];
+        logic.add_many(&mut backend, &elements);
+        assert_eq!(array.get_backend(0), backend.as_slice());
+    }
+
+    Ok(())
+}
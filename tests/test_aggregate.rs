@@ -0,0 +1,45 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimationLogic, Estimator, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 50;
+
+#[test]
+fn test_aggregate() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let mut array = SliceEstimatorArray::new(logic.clone(), LEN);
+    let mut all = logic.new_estimator();
+    let mut even = logic.new_estimator();
+    for i in 0..LEN {
+        for x in 100 * i..100 * i + 200 {
+            array.get_estimator_mut(i).add(x);
+            all.add(x);
+            if i % 2 == 0 {
+                even.add(x);
+            }
+        }
+    }
+
+    assert_eq!(array.aggregate_filtered(0..LEN), all.estimate());
+    assert_eq!(array.aggregate_all(), all.estimate());
+    assert_eq!(
+        array.aggregate_filtered((0..LEN).filter(|i| i % 2 == 0)),
+        even.estimate()
+    );
+    assert_eq!(array.aggregate_filtered([]), 0.0);
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use card_est_array::impls::alpha_m;
+
+/// Evaluated at compile time.
+const ALPHA_16: f64 = alpha_m(16);
+
+#[test]
+fn test_alpha_m() {
+    assert_eq!(ALPHA_16, 0.673);
+    assert_eq!(alpha_m(32), 0.697);
+    assert_eq!(alpha_m(64), 0.709);
+    assert!((alpha_m(128) - 0.7213 / (1.0 + 1.079 / 128.0)).abs() < 1E-15);
+    assert!((alpha_m(128) - 0.7153).abs() < 1E-4);
+    // The asymptotic value
+    assert!((alpha_m(1 << 30) - 0.7213).abs() < 1E-6);
+    // Monotonicity from 64 registers on
+    for log2m in 6..20 {
+        assert!(alpha_m(1 << log2m) < alpha_m(1 << (log2m + 1)));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_alpha_m_too_few_registers() {
+    let _ = alpha_m(8);
+}
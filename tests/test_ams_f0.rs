@@ -0,0 +1,74 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{AmsF0, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{
+        EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut,
+        MergeEstimator, SliceEstimationLogic,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_ams_f0_accuracy() -> Result<()> {
+    let ams = AmsF0::<u64, _, 64>::new(Xxh3Builder::new().with_seed(0));
+    // The same memory budget: 64 words of 64 bits
+    let hll = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(9)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    assert!(hll.backend_len() * usize::BITS as usize <= ams.backend_len() * 64);
+
+    let mut ams_estimator = ams.new_estimator();
+    let mut hll_estimator = hll.new_estimator();
+    assert_eq!(ams_estimator.estimate(), 0.0);
+
+    let mut ams_error = 0.0;
+    let mut hll_error = 0.0;
+    let mut n = 0;
+    for target in [100, 1000, 10_000, 100_000, 1_000_000] {
+        while n < target {
+            ams_estimator.add(n);
+            hll_estimator.add(n);
+            n += 1;
+        }
+        let estimate = ams_estimator.estimate();
+        assert!(
+            estimate > n as f64 / 3.0 && estimate < n as f64 * 3.0,
+            "estimate {} for {} elements",
+            estimate,
+            n
+        );
+        ams_error += (estimate - n as f64).abs() / n as f64;
+        hll_error += (hll_estimator.estimate() - n as f64).abs() / n as f64;
+    }
+    assert!(hll_error <= ams_error);
+
+    Ok(())
+}
+
+#[test]
+fn test_ams_f0_merge() -> Result<()> {
+    let logic = AmsF0::<u64, _, 16>::new(Xxh3Builder::new().with_seed(0));
+    let mut array = SliceEstimatorArray::new(logic.clone(), 3);
+    for x in 0..2000 {
+        array.get_estimator_mut(0).add(x);
+        array.get_estimator_mut(2).add(x);
+    }
+    for x in 1000..5000 {
+        array.get_estimator_mut(1).add(x);
+        array.get_estimator_mut(2).add(x);
+    }
+    let mut union = logic.new_estimator();
+    union.set(array.get_backend(0));
+    union.merge(array.get_backend(1));
+    assert_eq!(union.as_ref(), array.get_backend(2));
+    assert_eq!(union.estimate(), array.get_estimator(2).estimate());
+
+    Ok(())
+}
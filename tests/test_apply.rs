@@ -0,0 +1,85 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{
+        EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut,
+        MergeEstimationLogic,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 100;
+
+#[test]
+fn test_apply() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(10_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let mut array = SliceEstimatorArray::new(logic.clone(), LEN);
+    array.apply_indexed(|i, backend| {
+        for x in 0..10 * i {
+            logic.add(backend, x);
+        }
+    });
+
+    let mut expected = SliceEstimatorArray::new(logic.clone(), LEN);
+    for i in 0..LEN {
+        for x in 0..10 * i {
+            expected.get_estimator_mut(i).add(x);
+        }
+    }
+    assert_eq!(array.as_ref(), expected.as_ref());
+
+    // Merging an estimator can only increase the estimates
+    let mut other = logic.new_estimator();
+    for x in 1000..2000 {
+        other.add(x);
+    }
+    let before = (0..LEN)
+        .map(|i| array.get_estimator(i).estimate())
+        .collect::<Vec<_>>();
+    array.apply(|backend| logic.merge(backend, other.as_ref()));
+    for (i, &estimate) in before.iter().enumerate() {
+        assert!(array.get_estimator(i).estimate() >= estimate);
+        assert!(array.get_estimator(i).estimate() >= other.estimate());
+    }
+
+    array.apply(|backend| logic.clear(backend));
+    assert!(array.as_ref().iter().all(|&w| w == 0));
+
+    Ok(())
+}
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+#[test]
+fn test_apply_par() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(10_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let mut seq = SliceEstimatorArray::new(logic.clone(), LEN);
+    let mut par = SliceEstimatorArray::new(logic.clone(), LEN);
+    let f = |i: usize, backend: &mut [usize]| {
+        for x in 0..10 * i {
+            logic.add(backend, x);
+        }
+    };
+    seq.apply_indexed(f);
+    par.apply_indexed_par(f);
+    assert_eq!(seq.as_ref(), par.as_ref());
+
+    seq.apply(|backend| logic.add(backend, usize::MAX));
+    par.apply_par(|backend| logic.add(backend, usize::MAX));
+    assert_eq!(seq.as_ref(), par.as_ref());
+
+    Ok(())
+}
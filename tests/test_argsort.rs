@@ -0,0 +1,90 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimationLogic, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+type Array = SliceEstimatorArray<HyperLogLog<u64, Xxh3Builder, usize>, usize, Box<[usize]>>;
+
+/// Returns an array in which the estimator of index i contains (i * 37) % 20
+/// elements, so there are ties.
+fn array() -> Result<Array> {
+    let logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut array = SliceEstimatorArray::new(logic, 40);
+    for i in 0..40 {
+        let mut estimator = array.get_estimator_mut(i);
+        for x in 0..(i as u64 * 37) % 20 {
+            estimator.add(x);
+        }
+    }
+    Ok(array)
+}
+
+fn estimates(array: &Array) -> Vec<f64> {
+    (0..array.len())
+        .map(|i| array.logic().estimate(array.get_backend(i)))
+        .collect()
+}
+
+#[test]
+fn test_argsort_by_estimate() -> Result<()> {
+    let array = array()?;
+    let estimates = estimates(&array);
+
+    let ascending = array.argsort_by_estimate();
+    assert_eq!(ascending.len(), 40);
+    for w in ascending.windows(2) {
+        assert!(estimates[w[0]] <= estimates[w[1]]);
+        if estimates[w[0]] == estimates[w[1]] {
+            assert!(w[0] < w[1]);
+        }
+    }
+
+    let descending = array.argsort_by_estimate_desc();
+    for w in descending.windows(2) {
+        assert!(estimates[w[0]] >= estimates[w[1]]);
+        if estimates[w[0]] == estimates[w[1]] {
+            assert!(w[0] < w[1]);
+        }
+    }
+
+    #[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+    assert_eq!(array.argsort_by_estimate_par(), ascending);
+
+    Ok(())
+}
+
+#[test]
+fn test_top_k_indices() -> Result<()> {
+    let array = array()?;
+    let estimates = estimates(&array);
+    let descending = array.argsort_by_estimate_desc();
+
+    let top = array.top_k_indices(5);
+    assert_eq!(top, descending[..5]);
+    let min_top = top
+        .iter()
+        .map(|&i| estimates[i])
+        .fold(f64::INFINITY, f64::min);
+    for (i, &estimate) in estimates.iter().enumerate() {
+        if !top.contains(&i) {
+            assert!(estimate <= min_top);
+        }
+    }
+
+    for k in [0, 1, 2, 39, 40, 100] {
+        assert_eq!(array.top_k_indices(k), descending[..k.min(40)]);
+    }
+
+    Ok(())
+}
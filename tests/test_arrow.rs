@@ -0,0 +1,75 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "arrow2")]
+
+use anyhow::Result;
+use arrow2::{array::Array, datatypes::DataType};
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut, SliceEstimationLogic},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_arrow_round_trip() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(6)
+        .word_type::<u16>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let mut array = SliceEstimatorArray::new(logic.clone(), 10);
+    for i in 0..10 {
+        for x in 0..100 * i {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+
+    let buffer = array.to_arrow_buffer();
+    assert_eq!(buffer.len(), 10 * logic.backend_len() * 2);
+    let loaded = SliceEstimatorArray::<_, u16, _>::from_arrow_buffer(logic.clone(), buffer)?;
+    assert_eq!(loaded.len(), 10);
+    for i in 0..10 {
+        assert_eq!(loaded.get_backend(i), array.get_backend(i));
+        assert_eq!(
+            loaded.get_estimator(i).estimate(),
+            array.get_estimator(i).estimate()
+        );
+    }
+
+    let arrow_array = array.to_arrow_array();
+    assert_eq!(arrow_array.len(), 10);
+    assert_eq!(
+        arrow_array.data_type(),
+        &DataType::FixedSizeBinary(logic.backend_len() * 2)
+    );
+    assert_eq!(
+        arrow_array.value(3),
+        &array.to_bytes()[3 * logic.backend_len() * 2..][..logic.backend_len() * 2]
+    );
+    let loaded =
+        SliceEstimatorArray::<_, u16, _>::from_arrow_buffer(logic, arrow_array.values().clone())?;
+    assert_eq!(loaded.as_ref(), array.as_ref());
+
+    Ok(())
+}
+
+#[test]
+fn test_arrow_wrong_length() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(6)
+        .word_type::<u16>()
+        .build::<usize>()?;
+    let buffer = SliceEstimatorArray::new(logic, 10).to_arrow_buffer();
+
+    let other_logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(8)
+        .word_type::<u16>()
+        .build::<usize>()?;
+    assert!(SliceEstimatorArray::<_, u16, _>::from_arrow_buffer(other_logic, buffer).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "tokio")]
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 100;
+
+#[tokio::test]
+async fn test_async_io() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .word_type::<u32>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let mut array = SliceEstimatorArray::new(logic.clone(), LEN);
+    for i in 0..LEN {
+        for x in 0..i * 10 {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+
+    // A small buffer forces reads and writes to interleave
+    let (mut sink, mut source) = tokio::io::duplex(64);
+    let (written, read) = tokio::join!(
+        array.write_to_sink(&mut sink),
+        SliceEstimatorArray::read_from_source(logic.clone(), &mut source, LEN)
+    );
+    written?;
+    let read = read?;
+    assert_eq!(read.as_ref(), array.as_ref());
+
+    // Same format as synchronous serialization
+    let bytes = array.to_bytes();
+    let mut cursor = bytes.as_slice();
+    let read = SliceEstimatorArray::read_from_source(logic.clone(), &mut cursor, LEN).await?;
+    assert_eq!(read.as_ref(), array.as_ref());
+    let mut written = vec![];
+    array.write_to_sink(&mut written).await?;
+    assert_eq!(written, bytes);
+    let from_bytes = SliceEstimatorArray::from_bytes(logic.clone(), &written)?;
+    assert_eq!(from_bytes.len(), LEN);
+    assert_eq!(from_bytes.as_ref(), array.as_ref());
+
+    // Truncated data
+    let mut cursor = &bytes[..bytes.len() - 1];
+    let err = SliceEstimatorArray::read_from_source(logic.clone(), &mut cursor, LEN)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    assert!(SliceEstimatorArray::from_bytes(logic, &bytes[..bytes.len() - 1]).is_err());
+
+    Ok(())
+}
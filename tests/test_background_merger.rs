@@ -0,0 +1,109 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(not(target_arch = "wasm32"))]
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{BackgroundMerger, HyperLogLog, HyperLogLogBuilder},
+    traits::{EstimationLogic, EstimatorArray, EstimatorMut, MergeEstimationLogic},
+};
+use std::time::{Duration, Instant};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 8;
+
+#[test]
+fn test_background_merger() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1 << 30)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let merger = BackgroundMerger::new(logic.clone(), LEN, Duration::from_millis(10));
+    let mut num_elements = 0;
+    let mut num_snapshots = 0;
+
+    std::thread::scope(|s| {
+        let producer = s.spawn(|| {
+            let start = Instant::now();
+            let mut x = 0;
+            while start.elapsed() < Duration::from_secs(1) {
+                merger.add(x as usize % LEN, x);
+                x += 1;
+            }
+            x
+        });
+
+        let mut prev = merger.snapshot();
+        while !producer.is_finished() {
+            let snapshot = merger.snapshot();
+            let mut helper = logic.new_helper();
+            for i in 0..LEN {
+                // Merging the previous snapshot must not change the current one
+                let mut merged = snapshot.get_backend(i).to_vec();
+                logic.merge_with_helper(&mut merged, prev.get_backend(i), &mut helper);
+                assert_eq!(merged, snapshot.get_backend(i));
+                assert!(
+                    logic.estimate(snapshot.get_backend(i)) >= logic.estimate(prev.get_backend(i))
+                );
+            }
+            prev = snapshot;
+            num_snapshots += 1;
+        }
+        num_elements = producer.join().unwrap();
+    });
+
+    assert!(num_snapshots > 0);
+    let last = merger.stop();
+    let rsd = HyperLogLog::rel_std(8);
+    let expected = (num_elements / LEN as u64) as f64;
+    for i in 0..LEN {
+        let estimate = logic.estimate(last.get_backend(i));
+        assert!(
+            (estimate - expected).abs() / expected < 5.0 * rsd,
+            "estimate {} too far from {}",
+            estimate,
+            expected
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_background_merger_producers() -> Result<()> {
+    const LEN: usize = 100;
+    const PRODUCERS: u64 = 8;
+    let logic = HyperLogLogBuilder::new(1 << 20)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let merger = BackgroundMerger::new(logic.clone(), LEN, Duration::from_millis(1));
+
+    std::thread::scope(|s| {
+        for p in 0..PRODUCERS {
+            let merger = &merger;
+            s.spawn(move || {
+                for x in (p..100_000).step_by(PRODUCERS as usize) {
+                    merger.add(x as usize % LEN, x);
+                }
+            });
+        }
+    });
+
+    // Additions commute, so the result must not depend on the interleaving
+    let last = merger.stop();
+    let mut expected = logic.new_estimator();
+    for i in 0..LEN {
+        expected.clear();
+        for x in (i as u64..100_000).step_by(LEN) {
+            expected.add(x);
+        }
+        assert_eq!(last.get_backend(i), expected.as_ref());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray, SmallSliceEstimatorArray},
+    traits::{EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 50;
+
+fn pairs() -> Vec<(usize, usize)> {
+    (0..10_000).map(|x| ((x * 7919) % LEN, x)).collect()
+}
+
+#[test]
+fn test_batch_add() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let mut expected = SliceEstimatorArray::new(logic.clone(), LEN);
+    for (index, x) in pairs() {
+        expected.get_estimator_mut(index).add(x);
+    }
+
+    let mut array = SliceEstimatorArray::new(logic.clone(), LEN);
+    array.batch_add(pairs());
+    assert_eq!(array.as_ref(), expected.as_ref());
+
+    let mut sorted = pairs();
+    sorted.sort_by_key(|&(index, _)| index);
+    let mut array = SliceEstimatorArray::new(logic.clone(), LEN);
+    array.batch_add(sorted.iter().copied());
+    assert_eq!(array.as_ref(), expected.as_ref());
+
+    let mut array = SliceEstimatorArray::new(logic, LEN);
+    array.batch_add_sorted(sorted);
+    assert_eq!(array.as_ref(), expected.as_ref());
+    Ok(())
+}
+
+#[test]
+fn test_batch_add_default() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(4)
+        .word_type::<u16>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let mut expected = SmallSliceEstimatorArray::<_, u16, 3, 5>::new(logic.clone());
+    let mut array = SmallSliceEstimatorArray::<_, u16, 3, 5>::new(logic);
+    let pairs = (0..1000).map(|x| (x % 3, x)).collect::<Vec<_>>();
+    for &(index, x) in &pairs {
+        expected.get_estimator_mut(index).add(x);
+    }
+    array.batch_add_sorted(pairs);
+    assert_eq!(array.into_inner().1, expected.into_inner().1);
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_batch_add_sorted_unsorted() {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()
+        .unwrap();
+    let mut array = SliceEstimatorArray::new(logic, LEN);
+    array.batch_add_sorted([(3, 0), (1, 1)]);
+}
@@ -0,0 +1,86 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+type Array = SliceEstimatorArray<HyperLogLog<u64, Xxh3Builder, usize>, usize, Box<[usize]>>;
+
+/// Returns an array with 10 different estimators, and 1000 pseudorandom
+/// distinct indices of a 5000-element array.
+fn setup() -> Result<(Array, Vec<usize>)> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut sources = SliceEstimatorArray::new(logic, 10);
+    for i in 0..10 {
+        for x in 0..(i as u64 + 1) * 100 {
+            sources.get_estimator_mut(i).add(x);
+        }
+    }
+    // 1237 is coprime with 5000, so indices are distinct
+    let indices = (0..1000).map(|i| (i * 1237 + 11) % 5000).collect();
+    Ok((sources, indices))
+}
+
+#[test]
+fn test_batch_set() -> Result<()> {
+    let (sources, indices) = setup()?;
+    let logic = sources.logic().clone();
+    let updates = indices
+        .iter()
+        .enumerate()
+        .map(|(i, &index)| (index, sources.get_backend(i % 10)))
+        .collect::<Vec<_>>();
+
+    let mut array = SliceEstimatorArray::new(logic.clone(), 5000);
+    array.batch_set(updates.iter().copied());
+    let mut unchecked = SliceEstimatorArray::new(logic.clone(), 5000);
+    unsafe { unchecked.batch_set_unchecked(updates.iter().copied()) };
+    assert_eq!(array.as_ref(), unchecked.as_ref());
+
+    for (i, &index) in indices.iter().enumerate() {
+        assert_eq!(array.get_backend(index), sources.get_backend(i % 10));
+    }
+    let set = indices
+        .iter()
+        .copied()
+        .collect::<std::collections::HashSet<_>>();
+    for index in (0..5000).filter(|index| !set.contains(index)) {
+        assert!(array.get_backend(index).iter().all(|&w| w == 0));
+    }
+
+    #[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+    {
+        let mut par = SliceEstimatorArray::new(logic, 5000);
+        par.batch_set_par(updates.iter().copied());
+        assert_eq!(array.as_ref(), par.as_ref());
+    }
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_batch_set_wrong_len() {
+    let (sources, _) = setup().unwrap();
+    let mut array = SliceEstimatorArray::new(sources.logic().clone(), 10);
+    array.batch_set([(0, &sources.get_backend(0)[1..])]);
+}
+
+#[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+#[test]
+#[should_panic]
+fn test_batch_set_par_duplicate() {
+    let (sources, _) = setup().unwrap();
+    let mut array = SliceEstimatorArray::new(sources.logic().clone(), 10);
+    array.batch_set_par([(3, sources.get_backend(0)), (3, sources.get_backend(1))]);
+}
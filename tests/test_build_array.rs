@@ -0,0 +1,83 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut, MergeEstimator},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 10;
+
+fn builder() -> HyperLogLogBuilder<Xxh3Builder, usize> {
+    HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+}
+
+#[test]
+fn test_build_array() -> Result<()> {
+    let mut array = builder().build_array::<usize>(LEN)?;
+    let mut expected = SliceEstimatorArray::new(builder().build::<usize>()?, LEN);
+    assert_eq!(array.len(), LEN);
+    assert_eq!(array.as_ref(), expected.as_ref());
+
+    for i in 0..LEN {
+        for x in 0..1000 * i {
+            array.get_estimator_mut(i).add(x);
+            expected.get_estimator_mut(i).add(x);
+        }
+    }
+    assert_eq!(array.as_ref(), expected.as_ref());
+    let rsd = HyperLogLog::rel_std(8);
+    for i in 1..LEN {
+        let n = (1000 * i) as f64;
+        assert!((array.get_estimator(i).estimate() - n).abs() / n < 4.0 * rsd);
+    }
+
+    let mut union = array.get_estimator(3).into_owned();
+    union.merge(array.get_backend(7));
+    let mut expected_union = expected.get_estimator(3).into_owned();
+    expected_union.merge(expected.get_backend(7));
+    assert_eq!(union.as_ref(), expected_union.as_ref());
+
+    assert!(builder()
+        .log_2_num_reg(2)
+        .build_array::<usize>(LEN)
+        .is_err());
+    Ok(())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn test_build_sync_array() -> Result<()> {
+    use card_est_array::traits::SyncEstimatorArray;
+
+    let sync_array = builder().build_sync_array::<usize>(LEN)?;
+    assert_eq!(sync_array.len(), LEN);
+    let mut expected = builder().build_array::<usize>(LEN)?;
+    for i in 0..LEN {
+        for x in 0..1000 * i {
+            expected.get_estimator_mut(i).add(x);
+        }
+    }
+    // Each thread sets a different estimator
+    std::thread::scope(|s| {
+        for i in 0..LEN {
+            let sync_array = &sync_array;
+            let expected = &expected;
+            s.spawn(move || unsafe { sync_array.set(i, expected.get_backend(i)) });
+        }
+    });
+    let mut backend = vec![0; expected.get_backend(0).len()];
+    unsafe { sync_array.get(5, &mut backend) };
+    assert_eq!(backend.as_slice(), expected.get_backend(5));
+
+    let array = sync_array.into_array();
+    assert_eq!(array.as_ref(), expected.as_ref());
+    Ok(())
+}
@@ -0,0 +1,47 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimatorArray, EstimatorArrayMut, EstimatorMut},
+    utils::cluster_by_jaccard,
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_cluster_by_jaccard() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    // Estimator i contains the elements of group i % 3, plus a few others
+    let mut array = SliceEstimatorArray::new(logic, 12);
+    for i in 0..12 {
+        let group = i % 3;
+        for x in 0..1000 {
+            array.get_estimator_mut(i).add(group * 10_000 + x);
+        }
+        for x in 0..50 {
+            array.get_estimator_mut(i).add(100_000 + 100 * i + x);
+        }
+    }
+
+    let clusters = cluster_by_jaccard(&array, 0.3);
+    assert_eq!(
+        clusters,
+        vec![vec![0, 3, 6, 9], vec![1, 4, 7, 10], vec![2, 5, 8, 11]]
+    );
+
+    // Everything is similar enough with threshold zero
+    assert_eq!(cluster_by_jaccard(&array, 0.0).len(), 1);
+
+    let empty = SliceEstimatorArray::new(array.logic().clone(), 3);
+    assert_eq!(cluster_by_jaccard(&empty, 0.3), vec![vec![0, 1, 2]]);
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLog, HyperLogLogBuilder},
+    utils::compare_logics,
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_compare_logics() -> Result<()> {
+    let builder = HyperLogLogBuilder::new(1_000_000).log_2_num_reg(10);
+    let logic_1 = builder
+        .clone()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let logic_2 = builder
+        .clone()
+        .build_hasher(Xxh3Builder::new().with_seed(1))
+        .build::<u64>()?;
+    let rsd = HyperLogLog::rel_std(10);
+
+    let report = compare_logics(&logic_1, &logic_2, 0..100_000_u64);
+    assert!(report.same_backend_len());
+    assert!(report.differing_words.unwrap() > 0);
+    assert!(report.is_compatible(3.0 * rsd), "{}", report);
+    assert!(report.to_string().contains("Differing words"));
+
+    let report = compare_logics(&logic_1, &logic_1, 0..100_000_u64);
+    assert_eq!(report.differing_words, Some(0));
+    assert_eq!(report.relative_difference(), 0.0);
+
+    let logic_3 = builder
+        .log_2_num_reg(11)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let report = compare_logics(&logic_1, &logic_3, 0..100_000_u64);
+    assert_eq!(report.differing_words, None);
+    assert!(!report.is_compatible(1.0));
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{CountMinSketchBuilder, SliceEstimatorArray},
+    traits::{EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const NUM_KEYS: u64 = 10_000;
+
+/// Returns a skewed frequency for a key.
+fn frequency(key: u64) -> u64 {
+    1 + 1000 / (key + 1)
+}
+
+#[test]
+fn test_count_min_sketch() -> Result<()> {
+    for (epsilon, delta) in [(0.01, 0.01), (0.001, 0.05), (0.05, 0.1)] {
+        let logic = CountMinSketchBuilder::new()
+            .epsilon(epsilon)
+            .delta(delta)
+            .build_hasher(Xxh3Builder::new().with_seed(0))
+            .build::<u64>()?;
+        assert!(logic.epsilon() <= epsilon);
+        assert!(logic.delta() <= delta);
+        let mut est = logic.new_estimator();
+        for key in 0..NUM_KEYS {
+            est.add((key, frequency(key)));
+        }
+        let total = (0..NUM_KEYS).map(frequency).sum::<u64>();
+        assert_eq!(est.estimate(), total as f64);
+
+        let mut failures = 0;
+        for key in 0..2 * NUM_KEYS {
+            let true_frequency = if key < NUM_KEYS { frequency(key) } else { 0 };
+            let estimate = logic.frequency(est.as_ref(), key);
+            assert!(
+                estimate >= true_frequency,
+                "key {}: estimate {} < {}",
+                key,
+                estimate,
+                true_frequency
+            );
+            if (estimate - true_frequency) as f64 > epsilon * total as f64 {
+                failures += 1;
+            }
+        }
+        assert!(
+            failures as f64 <= delta * (2 * NUM_KEYS) as f64,
+            "ε = {}, δ = {}: {} failures",
+            epsilon,
+            delta,
+            failures
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_count_min_sketch_add_sketch() -> Result<()> {
+    let logic = CountMinSketchBuilder::new()
+        .width(100)
+        .depth(4)
+        .word_type::<u32>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<&str>()?;
+    let mut a = logic.new_estimator();
+    let mut b = logic.new_estimator();
+    let mut sum = logic.new_estimator();
+    for (key, count) in [("a", 3), ("b", 5), ("c", 7)] {
+        a.add((key, count));
+        b.add((key, 2 * count));
+        sum.add((key, 3 * count));
+    }
+    logic.add_sketch(a.as_mut(), b.as_ref());
+    assert_eq!(a.as_ref(), sum.as_ref());
+    assert!(logic.frequency(a.as_ref(), "b") >= 15);
+    Ok(())
+}
+
+#[test]
+fn test_count_min_sketch_saturation() -> Result<()> {
+    let logic = CountMinSketchBuilder::new()
+        .width(10)
+        .depth(2)
+        .word_type::<u8>()
+        .build::<u64>()?;
+    let mut est = logic.new_estimator();
+    est.add((0, 200));
+    est.add((0, 200));
+    assert_eq!(logic.frequency(est.as_ref(), 0), u8::MAX as u64);
+    Ok(())
+}
+
+#[test]
+fn test_count_min_sketch_array() -> Result<()> {
+    let logic = CountMinSketchBuilder::new()
+        .epsilon(0.01)
+        .delta(0.01)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut array = SliceEstimatorArray::new(logic.clone(), 10);
+    for i in 0..10 {
+        for key in 0..100 {
+            array.get_estimator_mut(i).add((key, i as u64));
+        }
+    }
+    for i in 0..10 {
+        assert_eq!(array.get_estimator(i).estimate(), 100.0 * i as f64);
+        assert!(logic.frequency(array.get_backend(i), 42) >= i as u64);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_count_min_sketch_builder() {
+    assert!(CountMinSketchBuilder::new()
+        .width(0)
+        .build::<u64>()
+        .is_err());
+    assert!(CountMinSketchBuilder::new()
+        .depth(0)
+        .build::<u64>()
+        .is_err());
+    for value in [0.0, 1.0, -0.5, f64::NAN] {
+        assert!(CountMinSketchBuilder::new()
+            .epsilon(value)
+            .build::<u64>()
+            .is_err());
+        assert!(CountMinSketchBuilder::new()
+            .delta(value)
+            .build::<u64>()
+            .is_err());
+    }
+    assert!(CountMinSketchBuilder::new()
+        .width(usize::MAX)
+        .depth(2)
+        .build::<u64>()
+        .is_err());
+    // The last setting wins
+    let logic = CountMinSketchBuilder::new()
+        .epsilon(0.0)
+        .width(100)
+        .build::<u64>()
+        .unwrap();
+    assert_eq!(logic.width(), 100);
+    let logic = CountMinSketchBuilder::new().build::<u64>().unwrap();
+    assert_eq!(logic.width(), 272);
+    assert_eq!(logic.depth(), 5);
+}
@@ -0,0 +1,135 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{BiasCorrection, DecayingHyperLogLogBuilder, EstimationMode, HyperLogLogBuilder},
+    traits::{EstimationLogic, Estimator, EstimatorMut, MergeEstimationLogic, MergeEstimator},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const HALF_LIFE: f64 = 100.0;
+
+#[test]
+fn test_decaying_hyper_log_log() -> Result<()> {
+    let hll = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(10)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let logic = DecayingHyperLogLogBuilder::new(hll)
+        .half_life(HALF_LIFE)
+        .build()?;
+    let n = 10_000;
+
+    // Old elements at time 0
+    let mut old = logic.new_estimator();
+    for x in 0..n {
+        old.add((x, 0));
+    }
+    // Without decay, the estimate is the usual HyperLogLog estimate
+    assert!((old.estimate() - n as f64).abs() / (n as f64) < 0.1);
+
+    // The contribution of old elements decreases with time
+    let mut previous = old.estimate();
+    for now in [50, 100, 200, 400] {
+        let estimate = logic.estimate_at(old.as_ref(), now);
+        assert!(
+            estimate < previous,
+            "time {}: {} >= {}",
+            now,
+            estimate,
+            previous
+        );
+        previous = estimate;
+    }
+
+    // New elements at time 300
+    let now = 300;
+    let mut new = logic.new_estimator();
+    for x in n..2 * n {
+        new.add((x, now));
+    }
+    let old_contribution = logic.estimate_at(old.as_ref(), now);
+    let new_contribution = logic.estimate_at(new.as_ref(), now);
+    assert!(old_contribution < new_contribution / 2.0);
+
+    // Adding new elements to the old estimator
+    let mut both = logic.new_estimator();
+    both.set(old.as_ref());
+    for x in n..2 * n {
+        both.add((x, now));
+    }
+    let estimate = both.estimate();
+    assert!(estimate >= new_contribution * 0.9);
+    assert!(estimate < 1.5 * n as f64);
+
+    // Merging is equivalent to adding, and commutative
+    let mut merged = logic.new_estimator();
+    merged.set(new.as_ref());
+    merged.merge(old.as_ref());
+    assert_eq!(merged.as_ref(), both.as_ref());
+    let mut merged = logic.new_estimator();
+    merged.set(old.as_ref());
+    merged.merge(new.as_ref());
+    assert_eq!(merged.as_ref(), both.as_ref());
+    assert_eq!(logic.estimate_union(old.as_ref(), new.as_ref()), estimate);
+
+    Ok(())
+}
+
+#[test]
+fn test_decaying_hyper_log_log_recent_elements_win() -> Result<()> {
+    let hll = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let logic = DecayingHyperLogLogBuilder::new(hll)
+        .half_life(HALF_LIFE)
+        .build()?;
+    // The same elements, added again later, refresh the registers
+    let mut est = logic.new_estimator();
+    for x in 0..1000 {
+        est.add((x, 0));
+    }
+    let stale = logic.estimate_at(est.as_ref(), 1000);
+    for x in 0..1000 {
+        est.add((x, 1000));
+    }
+    assert!(logic.estimate_at(est.as_ref(), 1000) > 2.0 * stale);
+    Ok(())
+}
+
+#[test]
+fn test_decaying_hyper_log_log_builder() -> Result<()> {
+    let hll = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(6)
+        .build::<u64>()?;
+    assert!(DecayingHyperLogLogBuilder::new(hll.clone())
+        .half_life(0.0)
+        .build()
+        .is_err());
+    assert!(DecayingHyperLogLogBuilder::new(hll.clone())
+        .half_life(f64::INFINITY)
+        .build()
+        .is_err());
+    // Only the harmonic mean with the standard bias correction is supported
+    let geometric = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(6)
+        .estimation_mode(EstimationMode::GeometricMean)
+        .build::<u64>()?;
+    assert!(DecayingHyperLogLogBuilder::new(geometric).build().is_err());
+    let log_log_beta = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(6)
+        .bias_correction(BiasCorrection::LogLogBeta)
+        .build::<u64>()?;
+    assert!(DecayingHyperLogLogBuilder::new(log_log_beta)
+        .build()
+        .is_err());
+    let logic = DecayingHyperLogLogBuilder::new(hll).build()?;
+    assert_eq!(logic.half_life(), 1.0);
+    assert_eq!(logic.new_estimator().estimate(), 0.0);
+    Ok(())
+}
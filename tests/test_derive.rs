@@ -0,0 +1,90 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "derive")]
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{
+        EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut,
+        MergeEstimationLogic, MergeEstimator, SliceEstimationLogic,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[derive(Clone, EstimationLogic, MergeEstimationLogic, SliceEstimationLogic)]
+struct UserIdLogic(HyperLogLog<u64, Xxh3Builder, usize>);
+
+#[derive(Clone, EstimationLogic, MergeEstimationLogic, SliceEstimationLogic)]
+struct Named<L> {
+    logic: L,
+}
+
+fn hll() -> Result<HyperLogLog<u64, Xxh3Builder, usize>> {
+    HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build()
+}
+
+#[test]
+fn test_derive_forwarding() -> Result<()> {
+    let inner = hll()?;
+    let logic = UserIdLogic(inner.clone());
+    assert_eq!(logic.backend_len(), inner.backend_len());
+
+    let mut expected = inner.new_estimator();
+    let mut estimator = logic.new_estimator();
+    for x in 0..10_000 {
+        expected.add(x);
+        estimator.add(x);
+    }
+    assert_eq!(estimator.as_ref(), expected.as_ref());
+    assert_eq!(estimator.estimate(), expected.estimate());
+
+    let mut other = logic.new_estimator();
+    other.add_many(&(5000..20_000).collect::<Vec<_>>());
+    expected.merge(other.as_ref());
+    let mut helper = logic.new_helper();
+    estimator.merge_with_helper(other.as_ref(), &mut helper);
+    assert_eq!(estimator.as_ref(), expected.as_ref());
+
+    estimator.clear();
+    assert_eq!(estimator.estimate(), 0.0);
+    estimator.set(expected.as_ref());
+    assert_eq!(estimator.estimate(), expected.estimate());
+
+    Ok(())
+}
+
+#[test]
+fn test_derive_named_generic() -> Result<()> {
+    let logic = Named { logic: hll()? };
+    let mut array = SliceEstimatorArray::new(logic.clone(), 3);
+    for x in 0..1000 {
+        array.get_estimator_mut(0).add(x);
+        array.get_estimator_mut(2).add(x);
+    }
+    for x in 500..2000 {
+        array.get_estimator_mut(1).add(x);
+        array.get_estimator_mut(2).add(x);
+    }
+    let mut union = logic.new_estimator();
+    union.set(array.get_backend(0));
+    union.merge(array.get_backend(1));
+    assert_eq!(union.as_ref(), array.get_backend(2));
+    assert_eq!(union.estimate(), logic.logic.estimate(array.get_backend(2)));
+
+    Ok(())
+}
+
+#[test]
+fn test_derive_ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/derive_newtype.rs");
+    t.compile_fail("tests/ui/derive_multi_field.rs");
+}
@@ -0,0 +1,59 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{
+        EstimationLogic, EstimatorArray, EstimatorArrayMut, EstimatorMut, SliceEstimationLogic,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_display() -> Result<()> {
+    let builder = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(10)
+        .build_hasher(Xxh3Builder::new().with_seed(0));
+    assert!(!format!("{:?}", builder).is_empty());
+    let display = builder.to_string();
+    assert!(display.contains("2^10"), "{}", display);
+    assert!(display.contains("usize"), "{}", display);
+    assert!(display.contains("Xxh3Builder"), "{}", display);
+
+    let logic = builder.build::<u64>()?;
+    assert!(!format!("{:?}", logic).is_empty());
+    let display = logic.to_string();
+    assert!(display.contains("2^10"), "{}", display);
+    assert!(display.contains("usize"), "{}", display);
+    assert!(display.contains("Xxh3Builder"), "{}", display);
+
+    let n = 10_000;
+    let mut estimator = logic.new_estimator();
+    assert!(!format!("{:?}", estimator).is_empty());
+    assert_eq!(estimator.to_string(), "0");
+    for x in 0..n {
+        estimator.add(x);
+    }
+    let estimate: u64 = estimator.to_string().parse()?;
+    assert!(estimate.abs_diff(n) < n / 10, "{}", estimate);
+
+    let mut array = SliceEstimatorArray::new(logic.clone(), 100);
+    assert!(!format!("{:?}", array).is_empty());
+    assert_eq!(
+        array.to_string(),
+        format!(
+            "SliceEstimatorArray with 100 estimators ({} bytes/estimator)",
+            logic.backend_len() * size_of::<usize>()
+        )
+    );
+    for x in 0..n {
+        array.get_estimator_mut(7).add(x);
+    }
+    let estimate: u64 = array.get_estimator(7).to_string().parse()?;
+    assert!(estimate.abs_diff(n) < n / 10, "{}", estimate);
+    Ok(())
+}
@@ -0,0 +1,145 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 10;
+
+type Array = SliceEstimatorArray<HyperLogLog<usize, Xxh3Builder, usize>, usize, Box<[usize]>>;
+
+fn array() -> Result<Array> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let mut array = SliceEstimatorArray::new(logic, LEN);
+    for i in 0..LEN {
+        for x in 0..100 * i {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+    Ok(array)
+}
+
+#[test]
+fn test_clone() -> Result<()> {
+    let original = array()?;
+    let mut clone = original.clone();
+    assert_eq!(clone.as_ref(), original.as_ref());
+    for x in 0..10_000 {
+        clone.get_estimator_mut(3).add(x);
+    }
+    clone.get_estimator_mut(5).clear();
+    assert_ne!(clone.as_ref(), original.as_ref());
+    assert_eq!(original.as_ref(), array()?.as_ref());
+    Ok(())
+}
+
+#[test]
+fn test_duplicate_estimator() -> Result<()> {
+    let mut array = array()?;
+    let mut snapshot = array.duplicate_estimator(4);
+    let estimate = array.get_estimator(4).estimate();
+    assert_eq!(snapshot.estimate(), estimate);
+
+    array.get_estimator_mut(4).clear();
+    assert_eq!(snapshot.estimate(), estimate);
+    snapshot.add(1_000_000);
+    assert_eq!(array.get_estimator(4).estimate(), 0.0);
+    Ok(())
+}
+
+#[test]
+fn test_copy_estimator_to() -> Result<()> {
+    let mut array = array()?;
+    array.copy_estimator_to(7, 2);
+    assert_eq!(array.get_backend(2), array.get_backend(7));
+    assert_eq!(
+        array.get_estimator(2).estimate(),
+        array.get_estimator(7).estimate()
+    );
+    array.copy_estimator_to(0, 9);
+    assert_eq!(array.get_estimator(9).estimate(), 0.0);
+    array.copy_estimator_to(5, 5);
+    assert_eq!(array.get_backend(5), self::array()?.get_backend(5));
+    Ok(())
+}
+
+#[test]
+fn test_clear_range() -> Result<()> {
+    let original = array()?;
+    let mut array = array()?;
+    array.clear_range(2, 5);
+    for i in 0..LEN {
+        if (2..5).contains(&i) {
+            assert_eq!(array.get_estimator(i).estimate(), 0.0);
+        } else {
+            assert_eq!(array.get_backend(i), original.get_backend(i));
+        }
+    }
+    array.clear_range(7, 7);
+    assert_eq!(array.get_backend(7), original.get_backend(7));
+    array.clear_range(0, LEN);
+    assert!(array.iter().all(|estimator| estimator.estimate() == 0.0));
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_clear_range_out_of_bounds() {
+    array().unwrap().clear_range(5, LEN + 1);
+}
+
+#[test]
+fn test_copy_from_array() -> Result<()> {
+    let src = array()?;
+    let mut dst = SliceEstimatorArray::new(src.logic().clone(), LEN);
+    dst.copy_from_array(&src, 3..7, 1);
+    for i in 0..LEN {
+        if (1..5).contains(&i) {
+            assert_eq!(dst.get_backend(i), src.get_backend(i + 2));
+        } else {
+            assert_eq!(dst.get_estimator(i).estimate(), 0.0);
+        }
+    }
+    dst.copy_from_array(&src, 0..LEN, 0);
+    assert_eq!(dst.as_ref(), src.as_ref());
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_copy_from_array_out_of_bounds() {
+    let src = array().unwrap();
+    let mut dst = array().unwrap();
+    dst.copy_from_array(&src, 0..5, 6);
+}
+
+#[test]
+fn test_swap_estimators() -> Result<()> {
+    let original = array()?;
+    let mut array = array()?;
+    array.swap_estimators(1, 8);
+    assert_eq!(array.get_backend(1), original.get_backend(8));
+    assert_eq!(array.get_backend(8), original.get_backend(1));
+    assert_eq!(
+        array.get_estimator(1).estimate(),
+        original.get_estimator(8).estimate()
+    );
+    assert_eq!(
+        array.get_estimator(8).estimate(),
+        original.get_estimator(1).estimate()
+    );
+    array.swap_estimators(8, 1);
+    array.swap_estimators(4, 4);
+    assert_eq!(array.as_ref(), original.as_ref());
+    Ok(())
+}
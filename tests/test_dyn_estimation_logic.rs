@@ -0,0 +1,55 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{DynEstimationLogic, HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{
+        EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut, MergeEstimator,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_dyn_estimation_logic() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .word_type::<u8>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let dyn_logic = DynEstimationLogic::new(logic.clone());
+    assert!(dyn_logic
+        .downcast_ref::<HyperLogLog<u64, Xxh3Builder, u8>>()
+        .is_some());
+
+    let mut typed = SliceEstimatorArray::new(logic.clone(), 10);
+    let mut erased = SliceEstimatorArray::<_, u8, _>::new(dyn_logic.clone(), 10);
+    for i in 0..10 {
+        for x in 0..1000 * i as u64 {
+            typed.get_estimator_mut(i).add(x);
+            erased.get_estimator_mut(i).add(x);
+        }
+    }
+    assert_eq!(typed.as_ref(), erased.as_ref());
+    for i in 0..10 {
+        assert_eq!(
+            typed.get_estimator(i).estimate(),
+            erased.get_estimator(i).estimate()
+        );
+    }
+
+    let mut typed_est = logic.new_estimator();
+    let mut erased_est = dyn_logic.new_estimator();
+    typed_est.merge(typed.get_backend(9));
+    erased_est.merge(erased.get_backend(9));
+    typed_est.merge(typed.get_backend(3));
+    erased_est.merge(erased.get_backend(3));
+    assert_eq!(typed_est.as_ref(), erased_est.as_ref());
+    erased_est.clear();
+    assert_eq!(erased_est.estimate(), 0.0);
+
+    Ok(())
+}
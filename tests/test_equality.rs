@@ -0,0 +1,92 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{EstimationMode, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimationLogic, EstimatorArrayMut, EstimatorMut},
+};
+
+#[test]
+fn test_logic_equality() -> Result<()> {
+    let a = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build::<u64>()?;
+    let b = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build::<u64>()?;
+    assert_eq!(a, b);
+    assert_eq!(a, a.clone());
+
+    let c = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(9)
+        .build::<u64>()?;
+    assert_ne!(a, c);
+    let d = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .estimation_mode(EstimationMode::MaxLikelihood)
+        .build::<u64>()?;
+    assert_ne!(a, d);
+    let e = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .hyperloglog_plus_plus(true)
+        .build::<u64>()?;
+    assert_ne!(a, e);
+    Ok(())
+}
+
+#[test]
+fn test_backends_equal() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build::<u64>()?;
+    let mut a = logic.new_estimator();
+    let mut b = logic.new_estimator();
+    assert!(logic.backends_equal(a.as_ref(), b.as_ref()));
+    for x in 0..1000 {
+        a.add(x);
+    }
+    assert!(!logic.backends_equal(a.as_ref(), b.as_ref()));
+    b.set(a.as_ref());
+    assert!(logic.backends_equal(a.as_ref(), b.as_ref()));
+    // Find an element changing a register
+    let mut x = 1000;
+    loop {
+        a.add(x);
+        if !logic.backends_equal(a.as_ref(), b.as_ref()) {
+            break;
+        }
+        x += 1;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_estimator_backends_equal() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build::<u64>()?;
+    let mut array = SliceEstimatorArray::new(logic, 3);
+    assert!(array.estimator_backends_equal(0, 1));
+    array.get_estimator_mut(1).add(0);
+    assert!(!array.estimator_backends_equal(0, 1));
+    assert!(array.estimator_backends_equal(1, 1));
+    array.copy_estimator_to(1, 2);
+    assert!(array.estimator_backends_equal(1, 2));
+    assert!(array.estimator_backends_equal(2, 1));
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_estimator_backends_equal_out_of_bounds() {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build::<u64>()
+        .unwrap();
+    let array = SliceEstimatorArray::new(logic, 3);
+    array.estimator_backends_equal(0, 3);
+}
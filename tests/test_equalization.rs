@@ -0,0 +1,71 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+    utils::histogram_equalize,
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_histogram_equalize() {
+    assert!(histogram_equalize(&[]).is_empty());
+    assert_eq!(histogram_equalize(&[3.0]), vec![0.5]);
+    assert_eq!(histogram_equalize(&[2.0, 2.0, 2.0]), vec![0.5; 3]);
+    assert_eq!(
+        histogram_equalize(&[100.0, 1.0, 1E6, 10.0, 1000.0]),
+        vec![0.5, 0.0, 1.0, 0.25, 0.75]
+    );
+    // Ties get the average rank
+    assert_eq!(
+        histogram_equalize(&[1.0, 5.0, 5.0, 9.0, 10.0]),
+        vec![0.0, 0.375, 0.375, 0.75, 1.0]
+    );
+}
+
+#[test]
+fn test_equalized_estimates() -> Result<()> {
+    const LEN: usize = 1000;
+    let logic = HyperLogLogBuilder::new(1 << 20)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    // A skewed distribution: estimator i contains i² / 64 + 1 elements
+    let mut array = SliceEstimatorArray::new(logic, LEN);
+    for i in 0..LEN {
+        for x in 0..i * i / 64 + 1 {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+
+    let equalized = array.equalized_estimates();
+    assert_eq!(equalized.len(), LEN);
+    assert!(equalized.iter().all(|&e| (0.0..=1.0).contains(&e)));
+    // Uniform distribution: each tenth of [0..1] contains a tenth of the
+    // values (up to ties)
+    for decile in 0..10 {
+        let count = equalized
+            .iter()
+            .filter(|&&e| e >= decile as f64 / 10.0 && e < (decile + 1) as f64 / 10.0)
+            .count();
+        assert!((count as isize - (LEN / 10) as isize).abs() <= 20);
+    }
+    let mean = equalized.iter().sum::<f64>() / LEN as f64;
+    assert!((mean - 0.5).abs() < 1E-9);
+
+    let log_estimates = array.log_estimates();
+    assert!(log_estimates.iter().all(|&e| e >= 0.0));
+    for (i, &e) in log_estimates.iter().enumerate() {
+        assert_eq!(e, array.get_estimator(i).estimate().ln_1p());
+    }
+    array.clear();
+    assert!(array.log_estimates().iter().all(|&e| e == 0.0));
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray, SmallSliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_estimate_all() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(10_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let len = 20;
+    let mut array = SliceEstimatorArray::new(logic.clone(), len);
+    for i in 0..len {
+        for x in 0..100 * i {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+
+    let estimates = array.estimate_all();
+    assert_eq!(estimates.len(), len);
+    for (i, &estimate) in estimates.iter().enumerate() {
+        assert_eq!(estimate, array.get_estimator(i).estimate());
+    }
+
+    let threshold = estimates[10];
+    let above = array.estimates_above(threshold);
+    assert_eq!(
+        above,
+        (0..len)
+            .filter(|&i| estimates[i] > threshold)
+            .map(|i| (i, estimates[i]))
+            .collect::<Vec<_>>()
+    );
+    assert!(above.iter().all(|&(i, _)| i > 10));
+    assert_eq!(array.estimates_above(-1.0).len(), len);
+    assert!(array.estimates_above(f64::INFINITY).is_empty());
+
+    assert!(SliceEstimatorArray::new(logic, 0).estimate_all().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_estimate_all_default() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(4)
+        .word_type::<u16>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let mut array = SmallSliceEstimatorArray::<_, u16, 3, 5>::new(logic);
+    for i in 0..3 {
+        for x in 0..10 * i {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+    let estimates = array.estimate_all();
+    assert_eq!(estimates.len(), 3);
+    for (i, &estimate) in estimates.iter().enumerate() {
+        assert_eq!(estimate, array.get_estimator(i).estimate());
+    }
+    assert_eq!(
+        array.estimates_above(0.0),
+        vec![(1, estimates[1]), (2, estimates[2])]
+    );
+    Ok(())
+}
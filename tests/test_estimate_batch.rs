@@ -0,0 +1,75 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{EstimationMode, HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimationLogic, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+fn check<const K: usize>(
+    logic: &HyperLogLog<usize, Xxh3Builder, u16>,
+    array: &SliceEstimatorArray<HyperLogLog<usize, Xxh3Builder, u16>, u16, Box<[u16]>>,
+) {
+    let mut output = vec![f64::NAN; array.len()];
+    logic.estimate_batch_simd::<K>(array.as_ref(), &mut output);
+    for (i, &estimate) in output.iter().enumerate() {
+        // The results must be identical, not just close
+        assert_eq!(
+            estimate,
+            logic.estimate(array.get_backend(i)),
+            "K = {}, index {}",
+            K,
+            i
+        );
+    }
+}
+
+#[test]
+fn test_estimate_batch_simd() -> Result<()> {
+    for mode in [
+        EstimationMode::HarmonicMean,
+        EstimationMode::GeometricMean,
+        EstimationMode::MaxLikelihood,
+    ] {
+        let logic = HyperLogLogBuilder::new(1_000_000)
+            .log_2_num_reg(6)
+            .word_type::<u16>()
+            .estimation_mode(mode)
+            .build_hasher(Xxh3Builder::new().with_seed(0))
+            .build::<usize>()?;
+        // 37 is not a multiple of the number of lanes
+        let mut array = SliceEstimatorArray::new(logic.clone(), 37);
+        for i in 0..37 {
+            for x in 0..i * i * 10 {
+                array.get_estimator_mut(i).add(x);
+            }
+        }
+
+        check::<1>(&logic, &array);
+        check::<4>(&logic, &array);
+        check::<8>(&logic, &array);
+        check::<16>(&logic, &array);
+        check::<64>(&logic, &array);
+    }
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_estimate_batch_simd_wrong_length() {
+    let logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(6)
+        .word_type::<u16>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()
+        .unwrap();
+    let array = SliceEstimatorArray::new(logic.clone(), 4);
+    let mut output = [0.0; 3];
+    logic.estimate_batch_simd::<4>(array.as_ref(), &mut output);
+}
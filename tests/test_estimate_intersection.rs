@@ -0,0 +1,90 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::HyperLogLogBuilder,
+    traits::{
+        EstimationGuarantees, EstimationLogic, EstimatorMut, MergeEstimationLogic, MergeEstimator,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_estimate_intersection() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(12)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let rsd = logic.relative_std();
+    let n = 10_000_u64;
+    for overlap in [0, 2500, 5000, 7500, 10_000] {
+        let mut a = logic.new_estimator();
+        let mut b = logic.new_estimator();
+        for x in 0..n {
+            a.add(x);
+            b.add(n - overlap + x);
+        }
+        // The estimates combine three estimates, so their error is bounded
+        // by the sum of the errors of |A|, |B|, and |A ∪ B|
+        let union = (2 * n - overlap) as f64;
+        let tolerance = 2.0 * rsd * (2 * n) as f64 + 2.0 * rsd * union;
+
+        let intersection = logic.estimate_intersection(a.as_ref(), b.as_ref());
+        assert!(
+            (intersection - overlap as f64).abs() < tolerance,
+            "estimate {} for an intersection of {} elements",
+            intersection,
+            overlap
+        );
+        let difference = logic.estimate_difference(a.as_ref(), b.as_ref());
+        assert!(
+            (difference - (n - overlap) as f64).abs() < tolerance,
+            "estimate {} for a difference of {} elements",
+            difference,
+            n - overlap
+        );
+        let jaccard = logic.estimate_jaccard(a.as_ref(), b.as_ref());
+        assert!((0.0..=1.0).contains(&jaccard));
+        assert!(
+            (jaccard - overlap as f64 / union).abs() < 2.0 * tolerance / union,
+            "estimate {} for a Jaccard similarity of {}",
+            jaccard,
+            overlap as f64 / union
+        );
+
+        assert_eq!(a.estimate_intersection_with(b.as_ref()), intersection);
+        assert_eq!(a.estimate_difference_with(b.as_ref()), difference);
+        assert_eq!(a.estimate_jaccard_with(b.as_ref()), jaccard);
+        assert_eq!(b.estimate_intersection_with(a.as_ref()), intersection);
+        assert_eq!(b.estimate_jaccard_with(a.as_ref()), jaccard);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_estimate_jaccard_bounds() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut a = logic.new_estimator();
+    let mut b = logic.new_estimator();
+    assert_eq!(a.estimate_jaccard_with(b.as_ref()), 1.0);
+    assert_eq!(a.estimate_intersection_with(b.as_ref()), 0.0);
+    for x in 0..1000 {
+        a.add(x);
+        b.add(x + 1000);
+        let jaccard = a.estimate_jaccard_with(b.as_ref());
+        assert!((0.0..=1.0).contains(&jaccard), "{}", jaccard);
+        assert!(a.estimate_intersection_with(b.as_ref()) >= 0.0);
+        assert!(a.estimate_difference_with(b.as_ref()) >= 0.0);
+    }
+    // Identical sets
+    let jaccard = a.estimate_jaccard_with(a.as_ref());
+    assert!((jaccard - 1.0).abs() < 1E-9);
+    Ok(())
+}
@@ -0,0 +1,75 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, MinHashBuilder},
+    traits::{
+        EstimationGuarantees, EstimationLogic, Estimator, EstimatorMut, MergeEstimationLogic,
+        MergeEstimator,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_estimate_union() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(10)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut helper = logic.new_helper();
+    let n = 10_000_u64;
+    for overlap in [0, 2500, 5000, 10_000] {
+        let mut a = logic.new_estimator();
+        let mut b = logic.new_estimator();
+        for x in 0..n {
+            a.add(x);
+            b.add(n - overlap + x);
+        }
+        let a_backend = a.as_ref().to_vec();
+        let b_backend = b.as_ref().to_vec();
+
+        let union = (2 * n - overlap) as f64;
+        let estimate = logic.estimate_union(a.as_ref(), b.as_ref());
+        assert!(
+            (estimate - union).abs() / union < 4.0 * logic.relative_std(),
+            "estimate {} for a union of {} elements",
+            estimate,
+            union
+        );
+        assert_eq!(
+            logic.estimate_union_with_helper(a.as_ref(), b.as_ref(), &mut helper),
+            estimate
+        );
+        assert_eq!(a.estimate_union_with(b.as_ref()), estimate);
+        assert_eq!(b.estimate_union_with(a.as_ref()), estimate);
+
+        // Inputs are not modified
+        assert_eq!(a.as_ref(), a_backend.as_slice());
+        assert_eq!(b.as_ref(), b_backend.as_slice());
+
+        a.merge(b.as_ref());
+        assert_eq!(a.estimate(), estimate);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_estimate_union_min_hash() -> Result<()> {
+    let logic = MinHashBuilder::new()
+        .num_hashes(256)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut a = logic.new_estimator();
+    let mut b = logic.new_estimator();
+    for x in 0..1000 {
+        a.add(x);
+        b.add(x + 500);
+    }
+    let estimate = a.estimate_union_with(b.as_ref());
+    assert!((estimate - 1500.0).abs() / 1500.0 < 4.0 * logic.relative_std());
+    Ok(())
+}
@@ -0,0 +1,110 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{EstimationMode, HyperLogLog, HyperLogLogBuilder},
+    traits::{EstimationLogic, Estimator, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const NUM_TRIALS: u64 = 20;
+
+#[test]
+fn test_estimation_modes() -> Result<()> {
+    let sizes = [1, 10, 100, 1000, 100_000];
+    let log2ms = [4, 6, 8, 12];
+
+    for log2m in log2ms {
+        let rsd = HyperLogLog::rel_std(log2m);
+        // The geometric mean has a larger standard deviation
+        let geometric_rsd = 1.30 / ((1 << log2m) as f64).sqrt();
+        let mut errors = [0.0; 3];
+        for size in sizes {
+            for trial in 0..NUM_TRIALS {
+                let logic = HyperLogLogBuilder::new(size)
+                    .word_type::<u16>()
+                    .log_2_num_reg(log2m)
+                    .build_hasher(Xxh3Builder::new().with_seed(trial))
+                    .build()?;
+                let mut est = logic.new_estimator();
+                let incr = (1 << 32) / size as i64;
+                let mut x = i64::MIN;
+                for _ in 0..size {
+                    est.add(x);
+                    x += incr;
+                }
+
+                let size = size as f64;
+                let harmonic = est.estimate();
+                let geometric = logic.estimate_geometric(est.as_ref());
+                let max_likelihood = logic.estimate_max_likelihood(est.as_ref());
+                for (error, estimate) in
+                    errors.iter_mut().zip([harmonic, geometric, max_likelihood])
+                {
+                    *error += ((estimate - size) / size).powi(2);
+                }
+                assert!(
+                    (max_likelihood - size).abs() / size < 4.0 * rsd,
+                    "size {}, log2m {}: maximum-likelihood estimate {}",
+                    size,
+                    log2m,
+                    max_likelihood
+                );
+            }
+        }
+
+        // Root-mean-square relative errors
+        let [harmonic, geometric, max_likelihood] =
+            errors.map(|error| (error / (sizes.len() as u64 * NUM_TRIALS) as f64).sqrt());
+        assert!(harmonic < 2.0 * rsd, "log2m {}: {}", log2m, harmonic);
+        assert!(
+            geometric < 2.0 * geometric_rsd,
+            "log2m {}: {}",
+            log2m,
+            geometric
+        );
+        assert!(
+            max_likelihood < 2.0 * rsd,
+            "log2m {}: {}",
+            log2m,
+            max_likelihood
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_estimation_mode_builder() -> Result<()> {
+    let builder = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0));
+    let harmonic = builder.clone().build::<usize>()?;
+    assert_eq!(harmonic.estimation_mode(), EstimationMode::HarmonicMean);
+
+    let mut est = harmonic.new_estimator();
+    assert_eq!(harmonic.estimate_geometric(est.as_ref()), 0.0);
+    assert_eq!(harmonic.estimate_max_likelihood(est.as_ref()), 0.0);
+    est.add_many(&(0..100_000).collect::<Vec<_>>());
+
+    for mode in [
+        EstimationMode::HarmonicMean,
+        EstimationMode::GeometricMean,
+        EstimationMode::MaxLikelihood,
+    ] {
+        let logic = builder.clone().estimation_mode(mode).build::<usize>()?;
+        assert_eq!(logic.estimation_mode(), mode);
+        let expected = match mode {
+            EstimationMode::HarmonicMean => harmonic.estimate(est.as_ref()),
+            EstimationMode::GeometricMean => harmonic.estimate_geometric(est.as_ref()),
+            EstimationMode::MaxLikelihood => harmonic.estimate_max_likelihood(est.as_ref()),
+        };
+        assert_eq!(logic.estimate(est.as_ref()), expected);
+    }
+
+    Ok(())
+}
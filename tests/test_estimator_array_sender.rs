@@ -0,0 +1,60 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(not(target_arch = "wasm32"))]
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{EstimatorArraySender, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 100;
+const THREADS: usize = 8;
+const PER_THREAD: usize = 100_000;
+
+/// The (index, element) pairs sent by a thread.
+fn pairs(thread: usize) -> impl Iterator<Item = (usize, usize)> {
+    (0..PER_THREAD).map(move |i| ((i * 31 + thread) % LEN, i * THREADS + thread))
+}
+
+#[test]
+fn test_estimator_array_sender() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let sender = EstimatorArraySender::new(SliceEstimatorArray::new(logic.clone(), LEN));
+    assert_eq!(sender.len(), LEN);
+    std::thread::scope(|scope| {
+        for thread in 0..THREADS {
+            let sender = sender.clone();
+            scope.spawn(move || {
+                for (index, element) in pairs(thread) {
+                    sender.send(index, element).unwrap();
+                }
+            });
+        }
+    });
+    let array = sender.flush();
+
+    let mut expected = SliceEstimatorArray::new(logic, LEN);
+    for thread in 0..THREADS {
+        for (index, element) in pairs(thread) {
+            expected.get_estimator_mut(index).add(element);
+        }
+    }
+    assert_eq!(array.as_ref(), expected.as_ref());
+
+    // The sender is still usable after a flush
+    sender.send(0, usize::MAX).unwrap();
+    expected.get_estimator_mut(0).add(usize::MAX);
+    assert_eq!(sender.flush().as_ref(), expected.as_ref());
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{FlowCardinalityTracker, HyperLogLog, HyperLogLogBuilder},
+    traits::{EstimationLogic, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_flow_cardinality_tracker() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(10)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+
+    // A tree with root 0, internal nodes 1 and 2, and leaves 3, 4, 5, 6;
+    // arcs go from children to parents, and are listed in topological order.
+    let arcs = [(3, 1), (4, 1), (5, 2), (6, 2), (1, 0), (2, 0)];
+    let mut tracker = FlowCardinalityTracker::new(logic.clone(), 7);
+    assert_eq!(tracker.num_nodes(), 7);
+
+    // Leaf i receives sources [i * 5000, i * 5000 + 10000), so adjacent leaves
+    // overlap
+    let mut all = logic.new_estimator();
+    for leaf in 3..7 {
+        for x in leaf as u64 * 5000..leaf as u64 * 5000 + 10_000 {
+            tracker.inject_source(leaf, x);
+            all.add(x);
+        }
+    }
+
+    for &(source, dest) in &arcs {
+        tracker.propagate_flow(source, dest);
+    }
+    // Self loops are ignored
+    tracker.propagate_flow(0, 0);
+
+    let root = tracker.cardinality_at(0);
+    let rsd = HyperLogLog::rel_std(10);
+    assert!(
+        (root - 25_000.0).abs() / 25_000.0 < 4.0 * rsd,
+        "root estimate {}",
+        root
+    );
+    // The root contains exactly the union of all sources
+    assert_eq!(root, logic.estimate(all.as_ref()));
+    assert!(tracker.cardinality_at(1) < root);
+    assert!(tracker.cardinality_at(1) > tracker.cardinality_at(3));
+
+    Ok(())
+}
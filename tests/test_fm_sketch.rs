@@ -0,0 +1,114 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{FMSketchBuilder, SliceEstimatorArray, FM_PHI},
+    traits::{
+        EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut, MergeEstimator,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const NUM_TRIALS: u64 = 200;
+
+/// Returns the estimate obtained by averaging the positions of the lowest
+/// zero bit over many trials, as suggested by Flajolet and Martin.
+fn averaged_estimate(sizes: impl Fn(u64) -> (u64, u64)) -> Result<f64> {
+    let mut sum = 0;
+    for trial in 0..NUM_TRIALS {
+        let logic = FMSketchBuilder::new()
+            .build_hasher(Xxh3Builder::new().with_seed(trial))
+            .build::<u64>()?;
+        let (start, end) = sizes(trial);
+        let mut est = logic.new_estimator();
+        for x in start..end {
+            est.add(x);
+        }
+        sum += logic.lowest_zero_bit(est.as_ref());
+    }
+    Ok((sum as f64 / NUM_TRIALS as f64).exp2() / FM_PHI)
+}
+
+#[test]
+fn test_fm_sketch() -> Result<()> {
+    for n in [100, 1000, 10_000, 100_000] {
+        let estimate = averaged_estimate(|_| (0, n))?;
+        assert!(
+            (estimate - n as f64).abs() / (n as f64) < 0.2,
+            "estimate {} for {} elements",
+            estimate,
+            n
+        );
+    }
+
+    // Single estimates are in a reasonable range
+    let n = 10_000;
+    let mut correct = 0;
+    for trial in 0..NUM_TRIALS {
+        let logic = FMSketchBuilder::new()
+            .build_hasher(Xxh3Builder::new().with_seed(trial))
+            .build::<u64>()?;
+        let mut est = logic.new_estimator();
+        for x in 0..n {
+            est.add(x);
+        }
+        let estimate = est.estimate();
+        if estimate > n as f64 / 8.0 && estimate < 8.0 * n as f64 {
+            correct += 1;
+        }
+    }
+    assert!(correct >= 9 * NUM_TRIALS / 10, "{} correct", correct);
+    Ok(())
+}
+
+#[test]
+fn test_fm_sketch_merge() -> Result<()> {
+    let logic = FMSketchBuilder::new()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut a = logic.new_estimator();
+    let mut b = logic.new_estimator();
+    let mut union = logic.new_estimator();
+    for x in 0..5000 {
+        a.add(x);
+        union.add(x);
+    }
+    for x in 5000..10_000 {
+        b.add(x);
+        union.add(x);
+    }
+    a.merge(b.as_ref());
+    assert_eq!(a.as_ref(), union.as_ref());
+
+    // Averaged over many trials, the merge estimates the union
+    let n = 10_000;
+    let mut sum = 0;
+    for trial in 0..NUM_TRIALS {
+        let logic = FMSketchBuilder::new()
+            .build_hasher(Xxh3Builder::new().with_seed(trial))
+            .build::<u64>()?;
+        let mut array = SliceEstimatorArray::new(logic.clone(), 2);
+        for x in 0..n / 2 {
+            array.get_estimator_mut(0).add(x);
+            array.get_estimator_mut(1).add(n / 2 + x);
+        }
+        let mut merged = logic.new_estimator();
+        merged.set(array.get_backend(0));
+        merged.merge(array.get_backend(1));
+        sum += logic.lowest_zero_bit(merged.as_ref());
+    }
+    let estimate = (sum as f64 / NUM_TRIALS as f64).exp2() / FM_PHI;
+    assert!(
+        (estimate - n as f64).abs() / (n as f64) < 0.2,
+        "estimate {} for a union of {} elements",
+        estimate,
+        n
+    );
+
+    assert_eq!(logic.new_estimator().estimate(), 0.0);
+    Ok(())
+}
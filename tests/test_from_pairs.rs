@@ -0,0 +1,56 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const NUM_NODES: usize = 100;
+
+/// The arcs of a graph in which node `i` has `10 * i` successors, some of
+/// which are repeated.
+fn arcs() -> impl Iterator<Item = (usize, usize)> {
+    (0..NUM_NODES).flat_map(|node| (0..20 * node).map(move |i| (node, (node + i / 2) % 5000)))
+}
+
+#[test]
+fn test_from_pairs() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(10_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let rsd = HyperLogLog::rel_std(8);
+
+    let array = SliceEstimatorArray::from_pairs(logic.clone(), NUM_NODES, arcs());
+    assert_eq!(array.len(), NUM_NODES);
+    for node in 0..NUM_NODES {
+        let degree = 10 * node;
+        let estimate = array.get_estimator(node).estimate();
+        assert!(
+            (estimate - degree as f64).abs() <= 4.0 * rsd * degree as f64,
+            "node {}: estimate {}, degree {}",
+            node,
+            estimate,
+            degree
+        );
+    }
+
+    // Extending is the same as adding each arc
+    let mut expected = SliceEstimatorArray::new(logic.clone(), NUM_NODES);
+    for (node, succ) in arcs() {
+        expected.get_estimator_mut(node).add(succ);
+    }
+    assert_eq!(array.as_ref(), expected.as_ref());
+
+    let mut extended = SliceEstimatorArray::new(logic, NUM_NODES);
+    extended.extend(arcs().filter(|&(node, _)| node % 2 == 0));
+    extended.extend(arcs().filter(|&(node, _)| node % 2 == 1));
+    assert_eq!(extended.as_ref(), expected.as_ref());
+    Ok(())
+}
@@ -0,0 +1,52 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::impls::{HyperLogLog, HyperLogLogBuilder};
+
+#[test]
+fn test_from_rsd() -> Result<()> {
+    for k in 4..=HyperLogLogBuilder::MAX_LOG_2_NUM_REG_FOR_RSD {
+        let logic = HyperLogLogBuilder::from_rsd(HyperLogLog::rel_std(k))?
+            .word_type::<u16>()
+            .build::<u64>()?;
+        assert_eq!(logic.log_2_num_registers(), k);
+        // Slightly larger targets need the same number of registers
+        let logic = HyperLogLogBuilder::from_error_rate(HyperLogLog::rel_std(k) * 1.01)?
+            .word_type::<u16>()
+            .build::<u64>()?;
+        assert_eq!(logic.log_2_num_registers(), k);
+    }
+    // Large targets use the minimum number of registers
+    let logic = HyperLogLogBuilder::from_rsd(0.5)?
+        .word_type::<u16>()
+        .build::<u64>()?;
+    assert_eq!(logic.log_2_num_registers(), 4);
+    // 2% error needs 2^12 registers
+    let logic = HyperLogLogBuilder::from_rsd(0.02)?.build::<u64>()?;
+    assert_eq!(logic.log_2_num_registers(), 12);
+    Ok(())
+}
+
+#[test]
+fn test_from_rsd_errors() {
+    assert!(HyperLogLogBuilder::from_rsd(0.0).is_err());
+    assert!(HyperLogLogBuilder::from_rsd(-0.1).is_err());
+    assert!(HyperLogLogBuilder::from_rsd(0.001).is_err());
+    assert!(HyperLogLogBuilder::from_error_rate(0.001).is_err());
+}
+
+#[test]
+fn test_achievable_rsd() {
+    const RSD: f64 = HyperLogLog::achievable_rsd(10);
+    assert_eq!(RSD, 1.04 / 32.0);
+    for k in 4..32 {
+        let expected = HyperLogLog::rel_std(k) * ((1_u64 << k) as f64).sqrt();
+        let actual = HyperLogLog::achievable_rsd(k) * ((1_u64 << k) as f64).sqrt();
+        assert!((actual - expected).abs() < 1E-12);
+        assert!(HyperLogLog::achievable_rsd(k + 1) < HyperLogLog::achievable_rsd(k));
+    }
+}
@@ -0,0 +1,316 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{GoogleHllError, GoogleHllFormat, SparkCompatHyperLogLog},
+    traits::{EstimationLogic, EstimatorMut},
+};
+use xxhash_rust::xxh64::Xxh64Builder;
+
+/// A hand-encoded sketch with precision 4 and dense registers 1, 5, and 15
+/// set to 3, 2, and 61.
+const DENSE_SKETCH: [u8; 27] = [
+    0x08, 0x70, // type: HYPERLOGLOG_PLUS_UNIQUE
+    0x18, 0x02, // encoding version: 2
+    0x82, 0x07, 0x14, // HyperLogLog++ state, 20 bytes
+    0x18, 0x04, // precision: 4
+    0x2A, 0x10, // data, 16 bytes
+    0x00, 0x03, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, //
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3D,
+];
+
+// The sparse sketches below were not exported from BigQuery, which was not
+// reachable when they were produced. They were generated with zetasketch-rs
+// 0.1.3, a port of Google's ZetaSketch library (the implementation behind
+// BigQuery's HLL_COUNT functions) whose conformance test checks that its
+// sketches are byte-for-byte equal to those of the Java library, using
+// HLL_COUNT.INIT's default precision 15 on STRING values. The expected
+// registers come from dense sketches of the same values built by the same
+// library.
+
+/// A sparse sketch with precision 15 and sparse precision 20 of the strings
+/// `element0`, `element1`, …, `element9`.
+const SPARSE_SKETCH_10: [u8; 47] = [
+    0x08, 0x70, 0x10, 0x0A, 0x18, 0x02, 0x20, 0x0B, 0x82, 0x07, 0x24, 0x10, 0x0A, 0x18, 0x0F, 0x20,
+    0x14, 0x32, 0x1C, 0x82, 0xA2, 0x12, 0xD3, 0xCE, 0x01, 0xF3, 0xA5, 0x0B, 0x91, 0x9B, 0x0F, 0xB5,
+    0x77, 0x97, 0x8D, 0x04, 0xA1, 0x92, 0x03, 0xD4, 0xC0, 0x04, 0x88, 0xBA, 0x01, 0xB4, 0x2B,
+];
+
+/// The nonzero registers of the dense sketch of the same strings, as pairs
+/// (register, value).
+const SPARSE_SKETCH_10_REGISTERS: [(usize, u8); 10] = [
+    (9352, 4),
+    (10178, 1),
+    (15962, 2),
+    (23750, 1),
+    (24228, 2),
+    (26329, 3),
+    (27938, 3),
+    (30244, 1),
+    (30989, 4),
+    (31162, 1),
+];
+
+/// A sparse sketch with precision 15 and sparse precision 20 of the strings
+/// `element0`, `element1`, …, `element99`.
+const SPARSE_SKETCH_100: [u8; 244] = [
+    0x08, 0x70, 0x10, 0x64, 0x18, 0x02, 0x20, 0x0B, 0x82, 0x07, 0xE8, 0x01, 0x10, 0x64, 0x18, 0x0F,
+    0x20, 0x14, 0x32, 0xDF, 0x01, 0xDC, 0xAD, 0x02, 0x9F, 0x56, 0xDF, 0x13, 0xCD, 0xA9, 0x02, 0xCE,
+    0x31, 0x90, 0x4B, 0xAC, 0x5B, 0xA0, 0x8F, 0x01, 0xF8, 0x68, 0xA6, 0x61, 0xA7, 0x83, 0x02, 0xA3,
+    0x7C, 0xF3, 0x77, 0xF2, 0x50, 0xBA, 0x5F, 0xFB, 0x66, 0x9E, 0x36, 0xA0, 0x8A, 0x01, 0xE7, 0x3A,
+    0x8A, 0x25, 0x9A, 0x02, 0x9A, 0x2F, 0x9F, 0x9D, 0x01, 0xD8, 0x33, 0xBE, 0x86, 0x02, 0xE3, 0x52,
+    0xFA, 0x44, 0xA1, 0xB2, 0x01, 0x92, 0x11, 0xD6, 0x4E, 0x9A, 0x1C, 0xC4, 0x19, 0xFE, 0x03, 0xA9,
+    0x85, 0x02, 0xE5, 0x8F, 0x01, 0xD3, 0x1B, 0x8A, 0x74, 0xDE, 0x47, 0xD2, 0x1B, 0xE7, 0x17, 0xFB,
+    0x1F, 0x82, 0x22, 0xDD, 0x0F, 0xFB, 0x01, 0xA5, 0x0B, 0x80, 0x3F, 0xA4, 0x31, 0x9F, 0xF0, 0x01,
+    0xCF, 0x34, 0xEA, 0x4E, 0xD0, 0x5F, 0x99, 0x4D, 0xCD, 0x90, 0x01, 0xF1, 0x2D, 0x8B, 0x3B, 0x99,
+    0x40, 0xA1, 0x16, 0xCE, 0x04, 0xE3, 0x39, 0xB6, 0x05, 0xC6, 0xDF, 0x01, 0xE7, 0x05, 0x86, 0xD0,
+    0x02, 0xA0, 0xB0, 0x01, 0x8E, 0x1A, 0xB5, 0x77, 0xCB, 0x5F, 0x80, 0x13, 0xAB, 0x3E, 0xE7, 0x21,
+    0xC4, 0x7C, 0xFA, 0x0E, 0xF1, 0x1A, 0xCA, 0x1A, 0x99, 0x6D, 0xA8, 0x0C, 0x91, 0x22, 0xD2, 0x8E,
+    0x01, 0xAE, 0x29, 0xB1, 0x31, 0xDF, 0x86, 0x01, 0xF2, 0x25, 0xC9, 0x0C, 0x93, 0x65, 0x85, 0x28,
+    0xBB, 0x92, 0x01, 0xB8, 0x29, 0xBB, 0x95, 0x01, 0xD3, 0x2F, 0x8F, 0x5F, 0x92, 0x05, 0xDC, 0x01,
+    0x8B, 0x54, 0xB4, 0x2B, 0xA7, 0x67, 0xE6, 0x5F, 0x83, 0x85, 0x01, 0xDC, 0x90, 0x54, 0xFF, 0x86,
+    0x19, 0xC0, 0xCB, 0x33,
+];
+
+/// The nonzero registers of the dense sketch of the same strings, as pairs
+/// (register, value).
+const SPARSE_SKETCH_100_REGISTERS: [(usize, u8); 100] = [
+    (1206, 1),
+    (1551, 1),
+    (1630, 1),
+    (2821, 3),
+    (3019, 1),
+    (3320, 3),
+    (3685, 1),
+    (4258, 1),
+    (4678, 2),
+    (5015, 7),
+    (5067, 2),
+    (6104, 1),
+    (6601, 1),
+    (7081, 2),
+    (7404, 1),
+    (7786, 1),
+    (8198, 1),
+    (8415, 1),
+    (8968, 1),
+    (9203, 1),
+    (9352, 4),
+    (9360, 1),
+    (9549, 1),
+    (10178, 1),
+    (10385, 2),
+    (11429, 6),
+    (11435, 2),
+    (11766, 2),
+    (12042, 2),
+    (12755, 2),
+    (12823, 1),
+    (13138, 1),
+    (13251, 2),
+    (13353, 2),
+    (13369, 2),
+    (14414, 1),
+    (14989, 1),
+    (15100, 2),
+    (15564, 1),
+    (15851, 1),
+    (15962, 2),
+    (16057, 2),
+    (16185, 2),
+    (16321, 2),
+    (16384, 2),
+    (16392, 3),
+    (16437, 2),
+    (16689, 2),
+    (16886, 2),
+    (17847, 2),
+    (18057, 1),
+    (18373, 3),
+    (18755, 1),
+    (19064, 2),
+    (19642, 1),
+    (19826, 2),
+    (20062, 1),
+    (20319, 1),
+    (20408, 1),
+    (20426, 1),
+    (20658, 4),
+    (20679, 1),
+    (21573, 1),
+    (21597, 3),
+    (22941, 2),
+    (23646, 2),
+    (23750, 1),
+    (24228, 2),
+    (24610, 1),
+    (24636, 6),
+    (24686, 1),
+    (24936, 3),
+    (25071, 2),
+    (25569, 2),
+    (25629, 2),
+    (25736, 1),
+    (25843, 3),
+    (26279, 1),
+    (26329, 3),
+    (26465, 1),
+    (27036, 2),
+    (27201, 1),
+    (27399, 3),
+    (27938, 3),
+    (28089, 1),
+    (28140, 5),
+    (28544, 1),
+    (28704, 1),
+    (29290, 1),
+    (29456, 2),
+    (30054, 3),
+    (30244, 1),
+    (30625, 2),
+    (30645, 1),
+    (30652, 1),
+    (30989, 4),
+    (31162, 1),
+    (31575, 1),
+    (31959, 4),
+    (32491, 3),
+];
+
+/// A hand-encoded empty sketch with empty sparse data.
+const EMPTY_SKETCH: [u8; 13] = [
+    0x08, 0x70, // type: HYPERLOGLOG_PLUS_UNIQUE
+    0x18, 0x02, // encoding version: 2
+    0x82, 0x07, 0x06, // HyperLogLog++ state, 6 bytes
+    0x18, 0x04, // precision: 4
+    0x20, 0x19, // sparse precision: 25
+    0x32, 0x00, // empty sparse data
+];
+
+#[test]
+fn test_dense() -> Result<()> {
+    let (precision, registers) = GoogleHllFormat::deserialize(&DENSE_SKETCH)?;
+    assert_eq!(precision, 4);
+    let mut expected = [0; 16];
+    expected[1] = 3;
+    expected[5] = 2;
+    expected[15] = 61;
+    assert_eq!(registers.as_ref(), &expected);
+    assert_eq!(GoogleHllFormat::serialize(4, &expected), DENSE_SKETCH);
+
+    // Impossible register value
+    let mut invalid = DENSE_SKETCH;
+    invalid[26] = 0x3E;
+    assert_eq!(
+        GoogleHllFormat::deserialize(&invalid),
+        Err(GoogleHllError::InvalidRegisterValue {
+            register: 15,
+            value: 62
+        })
+    );
+    // Wrong type
+    let mut wrong_type = DENSE_SKETCH;
+    wrong_type[1] = 0x71;
+    assert_eq!(
+        GoogleHllFormat::deserialize(&wrong_type),
+        Err(GoogleHllError::InvalidType(113))
+    );
+    assert_eq!(
+        GoogleHllFormat::deserialize(&DENSE_SKETCH[..26]),
+        Err(GoogleHllError::Truncated)
+    );
+    assert_eq!(
+        GoogleHllFormat::deserialize(&DENSE_SKETCH[..4]),
+        Err(GoogleHllError::MissingState)
+    );
+    Ok(())
+}
+
+/// Returns the dense registers with precision 15 described by the given
+/// pairs (register, value).
+fn dense_registers(pairs: &[(usize, u8)]) -> Vec<u8> {
+    let mut registers = vec![0; 1 << 15];
+    for &(register, value) in pairs {
+        registers[register] = value;
+    }
+    registers
+}
+
+#[test]
+fn test_sparse() -> Result<()> {
+    let logic =
+        SparkCompatHyperLogLog::<u64, _>::with_log_2_num_registers(15, Xxh64Builder::new(0))?;
+    for (n, sketch, pairs) in [
+        (10, &SPARSE_SKETCH_10[..], &SPARSE_SKETCH_10_REGISTERS[..]),
+        (
+            100,
+            &SPARSE_SKETCH_100[..],
+            &SPARSE_SKETCH_100_REGISTERS[..],
+        ),
+    ] {
+        let (precision, registers) = GoogleHllFormat::deserialize(sketch)?;
+        assert_eq!(precision, 15);
+        assert_eq!(registers.as_ref(), dense_registers(pairs));
+        assert!((logic.estimate(&registers) - n as f64).abs() < 1.0);
+    }
+
+    // Some registers are rho-encoded, as their values exceed the number of
+    // additional bits of the sparse precision
+    assert!(SPARSE_SKETCH_100_REGISTERS
+        .iter()
+        .any(|&(_, value)| value > 20 - 15));
+
+    let (precision, registers) = GoogleHllFormat::deserialize(&EMPTY_SKETCH)?;
+    assert_eq!(precision, 4);
+    assert_eq!(registers.as_ref(), &[0; 16]);
+    Ok(())
+}
+
+#[test]
+fn test_sparse_invalid() {
+    // Sparse precision smaller than the precision
+    let mut invalid = SPARSE_SKETCH_10;
+    invalid[16] = 14;
+    assert_eq!(
+        GoogleHllFormat::deserialize(&invalid),
+        Err(GoogleHllError::InvalidSparsePrecision(14))
+    );
+    // Sparse indices larger than the sparse precision
+    invalid[16] = 16;
+    assert!(matches!(
+        GoogleHllFormat::deserialize(&invalid),
+        Err(GoogleHllError::InvalidSparseValue(_))
+    ));
+    assert_eq!(
+        GoogleHllFormat::deserialize(&SPARSE_SKETCH_10[..46]),
+        Err(GoogleHllError::Truncated)
+    );
+}
+
+#[test]
+fn test_dense_round_trip() -> Result<()> {
+    let logic =
+        SparkCompatHyperLogLog::<u64, _>::with_log_2_num_registers(12, Xxh64Builder::new(42))?;
+    for n in [0, 10, 1000, 100_000] {
+        let mut estimator = logic.new_estimator();
+        for i in 0..n {
+            estimator.add(i);
+        }
+        let sketch = GoogleHllFormat::serialize(12, estimator.as_ref());
+        let (precision, registers) = GoogleHllFormat::deserialize(&sketch)?;
+        assert_eq!(precision, 12);
+        assert_eq!(registers.as_ref(), estimator.as_ref());
+        let estimate = logic.estimate(&registers);
+        assert!((estimate - n as f64).abs() <= 0.05 * n as f64);
+
+        assert_eq!(
+            GoogleHllFormat::deserialize(&sketch[..sketch.len() - 1]),
+            Err(GoogleHllError::Truncated)
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,108 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::impls::{GraphEstimatorArray, HyperLogLog, HyperLogLogBuilder};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+type Logic = HyperLogLog<usize, Xxh3Builder, usize>;
+
+fn logic() -> Result<Logic> {
+    HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build()
+}
+
+/// Returns a graph array in which each node contains itself.
+fn graph(successors: Vec<Vec<usize>>) -> Result<GraphEstimatorArray<Logic, usize>> {
+    let mut graph = GraphEstimatorArray::new(logic()?, successors);
+    for node in 0..graph.num_nodes() {
+        graph.add(node, node);
+    }
+    Ok(graph)
+}
+
+/// Checks that each node estimates the expected number of reachable nodes.
+fn check(graph: &GraphEstimatorArray<Logic, usize>, expected: impl Fn(usize) -> usize) {
+    for node in 0..graph.num_nodes() {
+        let expected = expected(node) as f64;
+        let estimate = graph.estimate(node);
+        assert!(
+            (estimate - expected).abs() <= 3.0 * HyperLogLog::rel_std(8) * expected + 0.5,
+            "node {}: estimate {}, expected {}",
+            node,
+            estimate,
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_path() -> Result<()> {
+    const N: usize = 50;
+    // Arcs go forwards, so each step propagates one node further
+    let mut g = graph(
+        (0..N)
+            .map(|i| (i + 1..i + 2).filter(|&j| j < N).collect())
+            .collect(),
+    )?;
+    assert_eq!(g.successors(0), &[1]);
+    assert!(g.successors(N - 1).is_empty());
+    assert_eq!(g.propagate_until_convergence(10), 10);
+    check(&g, |node| (N - node).min(11));
+    let steps = g.propagate_until_convergence(100);
+    assert!(steps <= N);
+    check(&g, |node| N - node);
+    assert_eq!(g.propagate_step(), 0);
+
+    // Arcs go backwards, so a single step is sufficient, as merges happen
+    // in place
+    let mut g = graph(
+        (0..N)
+            .map(|i| i.checked_sub(1).into_iter().collect())
+            .collect(),
+    )?;
+    assert_eq!(g.propagate_step(), N - 1);
+    check(&g, |node| node + 1);
+    assert_eq!(g.propagate_until_convergence(100), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_complete() -> Result<()> {
+    const N: usize = 20;
+    let mut g = graph((0..N).map(|_| (0..N).collect()).collect())?;
+    assert_eq!(g.propagate_until_convergence(100), 2);
+    check(&g, |_| N);
+    Ok(())
+}
+
+#[test]
+fn test_star() -> Result<()> {
+    const N: usize = 30;
+    // Symmetric star with center 0
+    let mut g = graph(
+        (0..N)
+            .map(|i| if i == 0 { (1..N).collect() } else { vec![0] })
+            .collect(),
+    )?;
+    assert_eq!(g.propagate_until_convergence(100), 2);
+    check(&g, |_| N);
+
+    // Leaves pointing to the center
+    let mut g = graph(
+        (0..N)
+            .map(|i| if i == 0 { vec![] } else { vec![0] })
+            .collect(),
+    )?;
+    assert_eq!(g.propagate_step(), N - 1);
+    assert_eq!(g.propagate_step(), 0);
+    check(&g, |node| if node == 0 { 1 } else { 2 });
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::utils::test_hash_quality;
+use std::hash::{BuildHasherDefault, Hasher};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+/// A hasher returning its input.
+#[derive(Default)]
+struct IdentityHasher(u64);
+
+impl Hasher for IdentityHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = self.0 << 8 | byte as u64;
+        }
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.0 = n;
+    }
+}
+
+#[test]
+fn test_hash_quality_xxh3() -> Result<()> {
+    let elements = (0..100_000_u64).collect::<Vec<_>>();
+    let report = test_hash_quality(Xxh3Builder::new().with_seed(0), &elements, 10)?;
+    assert!(report.passes_chi_squared(), "{}", report);
+    assert!(report.passes_avalanche(), "{}", report);
+    assert!(report.passes_accuracy(), "{}", report);
+    assert!(report.passes());
+    assert_eq!(report.true_count, 100_000);
+    assert_eq!(report.num_registers, 1 << 10);
+    assert_eq!(report.to_string().matches("[PASS]").count(), 3);
+
+    // Duplicates are counted once
+    let elements = (0..20_000_u64).map(|x| x % 10_000).collect::<Vec<_>>();
+    let report = test_hash_quality(Xxh3Builder::new().with_seed(0), &elements, 8)?;
+    assert_eq!(report.true_count, 10_000);
+    assert!(report.passes(), "{}", report);
+
+    Ok(())
+}
+
+#[test]
+fn test_hash_quality_identity() -> Result<()> {
+    let elements = (0..100_000_u64).collect::<Vec<_>>();
+    let report = test_hash_quality(
+        BuildHasherDefault::<IdentityHasher>::default(),
+        &elements,
+        10,
+    )?;
+    // Consecutive integers are distributed too uniformly
+    assert!(!report.passes_chi_squared(), "{}", report);
+    // Flipping a bit changes a single bit
+    assert!(!report.passes_avalanche(), "{}", report);
+    assert!((report.avalanche - 1.0 / 64.0).abs() < 1E-9);
+    assert!(!report.passes());
+    assert!(report.to_string().contains("[FAIL]"));
+
+    Ok(())
+}
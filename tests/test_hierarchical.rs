@@ -0,0 +1,54 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HierarchicalEstimatorArray, HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_merge_up_level() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let mut hierarchy = HierarchicalEstimatorArray::new(logic.clone(), 3, 100);
+    assert_eq!(hierarchy.len(), 3);
+    let mut expected = [
+        logic.new_estimator(),
+        logic.new_estimator(),
+        logic.new_estimator(),
+    ];
+    for (p, expected) in expected.iter_mut().enumerate() {
+        let children = hierarchy.get_array_mut(p);
+        for c in 0..100 {
+            // Children share half of their elements with the next child
+            for x in 0..100 {
+                let element = p * 1_000_000 + c * 50 + x;
+                children.get_estimator_mut(c).add(element);
+                expected.add(element);
+            }
+        }
+    }
+
+    let mut parent = SliceEstimatorArray::new(logic.clone(), 3);
+    hierarchy.merge_up_level(&mut parent);
+    let rsd = HyperLogLog::rel_std(8);
+    for (p, expected) in expected.iter().enumerate() {
+        assert_eq!(parent.get_backend(p), expected.as_ref());
+        let estimate = parent.get_estimator(p).estimate();
+        assert!((estimate - 5050.0).abs() / 5050.0 < 3.0 * rsd);
+        // The parent estimate is at least the estimate of each child
+        for c in 0..100 {
+            assert!(estimate >= hierarchy.get_array(p).get_estimator(c).estimate());
+        }
+    }
+
+    Ok(())
+}
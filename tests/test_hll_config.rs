@@ -0,0 +1,83 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{DefaultEstimator, HllConfig, HyperLogLog, SliceEstimatorArray},
+    traits::{
+        EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut,
+        MergeEstimator, SliceEstimationLogic,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_hll_config_accuracy() -> Result<()> {
+    let logic = HllConfig::<u64, _, 12, u8>::new(Xxh3Builder::new().with_seed(0));
+    assert_eq!(logic.backend_len(), 1 << 12);
+    assert_eq!(HllConfig::<u64, Xxh3Builder, 12, u8>::BACKEND_LEN, 1 << 12);
+
+    let rsd = HyperLogLog::rel_std(12);
+    let mut estimator = logic.new_estimator();
+    let mut n = 0;
+    for target in [10, 100, 1000, 10_000, 100_000, 1_000_000] {
+        while n < target {
+            estimator.add(n);
+            n += 1;
+        }
+        let estimate = estimator.estimate();
+        let rel_error = (estimate - n as f64).abs() / n as f64;
+        assert!(
+            rel_error < 4.0 * rsd,
+            "estimate {} for {} elements (relative error {})",
+            estimate,
+            n,
+            rel_error
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_hll_config_array_backend() -> Result<()> {
+    let logic = HllConfig::<u64, _, 8, u16>::new(Xxh3Builder::new().with_seed(0));
+    let mut boxed = logic.new_estimator();
+    let mut array: DefaultEstimator<HllConfig<u64, _, 8, u16>, _, _> =
+        DefaultEstimator::new(&logic, [0; 1 << 8]);
+    for x in 0..10_000 {
+        boxed.add(x);
+        array.add(x);
+    }
+    assert_eq!(boxed.as_ref(), array.as_ref());
+    assert_eq!(boxed.estimate(), array.estimate());
+
+    array.clear();
+    assert_eq!(array.estimate(), 0.0);
+
+    Ok(())
+}
+
+#[test]
+fn test_hll_config_merge() -> Result<()> {
+    let logic = HllConfig::<u64, _, 10, u8>::new(Xxh3Builder::new().with_seed(0));
+    let mut array = SliceEstimatorArray::new(logic, 3);
+    for x in 0..20_000 {
+        array.get_estimator_mut(0).add(x);
+        array.get_estimator_mut(2).add(x);
+    }
+    for x in 10_000..50_000 {
+        array.get_estimator_mut(1).add(x);
+        array.get_estimator_mut(2).add(x);
+    }
+
+    let mut union = logic.new_estimator();
+    union.set(array.get_backend(0));
+    union.merge(array.get_backend(1));
+    assert_eq!(union.as_ref(), array.get_backend(2));
+
+    Ok(())
+}
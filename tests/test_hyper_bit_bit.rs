@@ -0,0 +1,99 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperBitBitBuilder, SliceEstimatorArray},
+    traits::{
+        EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut, MergeEstimator,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const NUM_TRIALS: u64 = 100;
+
+#[test]
+fn test_hyper_bit_bit() -> Result<()> {
+    for n in [10, 100, 1000, 10_000, 100_000] {
+        let mut sum = 0.0;
+        let mut sum_sq = 0.0;
+        for trial in 0..NUM_TRIALS {
+            let logic = HyperBitBitBuilder::new()
+                .build_hasher(Xxh3Builder::new().with_seed(trial))
+                .build::<u64>()?;
+            let mut est = logic.new_estimator();
+            for x in 0..n {
+                est.add(x);
+            }
+            let error = est.estimate() / n as f64 - 1.0;
+            sum += error;
+            sum_sq += error * error;
+        }
+        let bias = sum / NUM_TRIALS as f64;
+        let rsd = (sum_sq / NUM_TRIALS as f64).sqrt();
+        assert!(bias.abs() < 0.15, "bias {} for {} elements", bias, n);
+        assert!(
+            rsd < 0.35,
+            "relative standard deviation {} for {} elements",
+            rsd,
+            n
+        );
+    }
+
+    let logic = HyperBitBitBuilder::new().build::<u64>()?;
+    assert_eq!(logic.new_estimator().estimate(), 0.0);
+    Ok(())
+}
+
+#[test]
+fn test_hyper_bit_bit_merge() -> Result<()> {
+    let logic = HyperBitBitBuilder::new()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut a = logic.new_estimator();
+    for x in 0..10_000 {
+        a.add(x);
+    }
+    let backend = a.as_ref().to_vec();
+    // Merging with itself or with an empty estimator is a no-op
+    a.merge(&backend);
+    assert_eq!(a.as_ref(), &backend[..]);
+    a.merge(logic.new_estimator().as_ref());
+    assert_eq!(a.as_ref(), &backend[..]);
+    let mut empty = logic.new_estimator();
+    empty.merge(&backend);
+    assert_eq!(empty.as_ref(), &backend[..]);
+
+    // Averaged over many trials, the merge estimates the union
+    for (n0, n1) in [(5000, 5000), (1000, 10_000), (100, 100_000)] {
+        let mut sum = 0.0;
+        for trial in 0..NUM_TRIALS {
+            let logic = HyperBitBitBuilder::new()
+                .build_hasher(Xxh3Builder::new().with_seed(trial))
+                .build::<u64>()?;
+            let mut array = SliceEstimatorArray::new(logic.clone(), 2);
+            for x in 0..n0 {
+                array.get_estimator_mut(0).add(x);
+            }
+            for x in 0..n1 {
+                array.get_estimator_mut(1).add(n0 + x);
+            }
+            let mut merged = logic.new_estimator();
+            merged.set(array.get_backend(0));
+            merged.merge(array.get_backend(1));
+            sum += merged.estimate() / (n0 + n1) as f64;
+        }
+        let bias = sum / NUM_TRIALS as f64 - 1.0;
+        assert!(
+            bias.abs() < 0.15,
+            "bias {} for a union of {} and {} elements",
+            bias,
+            n0,
+            n1
+        );
+    }
+    Ok(())
+}
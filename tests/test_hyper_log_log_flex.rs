@@ -0,0 +1,80 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::HyperLogLogFlex,
+    traits::{
+        EstimationGuarantees, EstimationLogic, Estimator, EstimatorMut, MergeEstimator,
+        SliceEstimationLogic,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const TRIALS: u64 = 40;
+
+#[test]
+fn test_hyper_log_log_flex() -> Result<()> {
+    for num_registers in [100, 500, 1000, 3000] {
+        let rsd = HyperLogLogFlex::<u64, Xxh3Builder, u8>::new(num_registers, Xxh3Builder::new())?
+            .relative_std();
+        for n in [num_registers as u64 / 10, 20_000] {
+            let mut sum = 0.0;
+            let mut square_sum = 0.0;
+            for seed in 0..TRIALS {
+                let logic = HyperLogLogFlex::<u64, _, u8>::new(
+                    num_registers,
+                    Xxh3Builder::new().with_seed(seed),
+                )?;
+                assert_eq!(logic.backend_len(), num_registers);
+                let mut estimator = logic.new_estimator();
+                for i in 0..n {
+                    estimator.add(i);
+                }
+                let error = (estimator.estimate() - n as f64) / n as f64;
+                sum += error;
+                square_sum += error * error;
+            }
+            let bias = sum / TRIALS as f64;
+            let rms = (square_sum / TRIALS as f64).sqrt();
+            assert!(
+                bias.abs() < 0.5 * rsd,
+                "m = {}, n = {}: bias {}",
+                num_registers,
+                n,
+                bias
+            );
+            assert!(
+                rms < 1.25 * rsd,
+                "m = {}, n = {}: relative rms error {}, expected {}",
+                num_registers,
+                n,
+                rms,
+                rsd
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_hyper_log_log_flex_merge() -> Result<()> {
+    let logic = HyperLogLogFlex::<u64, _, u8>::new(777, Xxh3Builder::new().with_seed(0))?;
+    let mut a = logic.new_estimator();
+    let mut b = logic.new_estimator();
+    let mut union = logic.new_estimator();
+    for i in 0..10_000 {
+        a.add(i);
+        b.add(i + 5000);
+        union.add(i);
+        union.add(i + 5000);
+    }
+    a.merge(b.as_ref());
+    assert_eq!(a.as_ref(), union.as_ref());
+
+    assert!(HyperLogLogFlex::<u64, _, u8>::new(0, Xxh3Builder::new()).is_err());
+    Ok(())
+}
@@ -0,0 +1,61 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_into_owned_from_array() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let mut array = SliceEstimatorArray::new(logic, 10);
+    for i in 0..10 {
+        for x in 0..100 * i {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+
+    for i in 0..10 {
+        let estimate = array.get_estimator(i).estimate();
+        let mut owned = array.get_estimator(i).into_owned();
+        assert_eq!(owned.estimate(), estimate);
+        assert_eq!(owned.as_ref(), array.get_backend(i));
+
+        // The owned copy is independent of the array
+        for x in 10_000..20_000 {
+            owned.add(x);
+        }
+        assert_eq!(array.get_estimator(i).estimate(), estimate);
+        array.get_estimator_mut(i).clear();
+        assert!(owned.estimate() > estimate);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_into_owned_from_estimator() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let mut estimator = logic.new_estimator();
+    for x in 0..1000 {
+        estimator.add(x);
+    }
+    let estimate = estimator.estimate();
+    let owned = estimator.into_owned();
+    drop(logic);
+    assert_eq!(owned.estimate(), estimate);
+    Ok(())
+}
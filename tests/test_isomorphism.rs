@@ -0,0 +1,54 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimatorArray, EstimatorArrayMut, EstimatorMut},
+    utils::{are_isomorphic_by_estimate, are_structurally_isomorphic},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 50;
+
+#[test]
+fn test_isomorphism() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let mut a = SliceEstimatorArray::new(logic.clone(), LEN);
+    for i in 0..LEN {
+        for x in 0..(i + 1) * 20 {
+            a.get_estimator_mut(i).add(x);
+        }
+    }
+
+    // A pseudorandom permutation (7 is coprime with 50)
+    let perm = (0..LEN).map(|i| (i * 7 + 3) % LEN).collect::<Vec<_>>();
+    let mut b = SliceEstimatorArray::new(logic.clone(), LEN);
+    for (i, &p) in perm.iter().enumerate() {
+        b.get_backend_mut(p).copy_from_slice(a.get_backend(i));
+    }
+
+    assert!(are_isomorphic_by_estimate(&a, &b, 0.0));
+    assert_eq!(are_structurally_isomorphic(&a, &b), Some(perm));
+
+    // Adding elements breaks isomorphism
+    for x in 0..1000 {
+        b.get_estimator_mut(0).add(1_000_000 + x);
+    }
+    assert!(!are_isomorphic_by_estimate(&a, &b, 1E-9));
+    assert!(are_structurally_isomorphic(&a, &b).is_none());
+
+    // So do different lengths
+    let c = SliceEstimatorArray::new(logic, LEN + 1);
+    assert!(!are_isomorphic_by_estimate(&a, &c, f64::INFINITY));
+    assert!(are_structurally_isomorphic(&a, &c).is_none());
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray, SmallSliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 20;
+
+#[test]
+fn test_iter() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let mut array = SliceEstimatorArray::new(logic, LEN);
+    for i in 0..LEN {
+        for x in 0..10 * i {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+
+    let mut expected = 0.0;
+    for i in 0..LEN {
+        expected += array.get_estimator(i).estimate();
+    }
+    assert_eq!(array.iter().map(|e| e.estimate()).sum::<f64>(), expected);
+
+    let iter = array.iter();
+    assert_eq!(iter.len(), LEN);
+    let reversed = array.iter().rev().map(|e| e.estimate()).collect::<Vec<_>>();
+    for (i, estimate) in reversed.into_iter().enumerate() {
+        assert_eq!(estimate, array.get_estimator(LEN - 1 - i).estimate());
+    }
+
+    assert_eq!(array.iter_backends().len(), LEN);
+    for (i, backend) in array.iter_backends().enumerate() {
+        assert_eq!(backend, array.get_backend(i));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_iter_mut() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let mut array = SliceEstimatorArray::new(logic.clone(), LEN);
+    let mut expected = SliceEstimatorArray::new(logic, LEN);
+
+    assert_eq!(array.iter_mut().len(), LEN);
+    for (i, mut estimator) in array.iter_mut().enumerate() {
+        for x in 0..10 * i {
+            estimator.add(x);
+        }
+    }
+    for i in 0..LEN {
+        for x in 0..10 * i {
+            expected.get_estimator_mut(i).add(x);
+        }
+    }
+    assert_eq!(array.as_ref(), expected.as_ref());
+
+    for backend in array.iter_backends_mut().rev().take(LEN / 2) {
+        backend.fill(0);
+    }
+    for i in 0..LEN {
+        if i >= LEN / 2 {
+            assert_eq!(array.get_estimator(i).estimate(), 0.0);
+        } else {
+            assert_eq!(array.get_backend(i), expected.get_backend(i));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_iter_small() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(4)
+        .word_type::<u16>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let mut array = SmallSliceEstimatorArray::<_, u16, 4, 8>::new(logic);
+    for (i, mut estimator) in array.iter_mut().enumerate() {
+        for x in 0..100 * i {
+            estimator.add(x);
+        }
+    }
+    assert_eq!(array.iter().len(), 4);
+    for (i, estimator) in array.iter().enumerate() {
+        assert_eq!(estimator.estimate(), array.get_estimator(i).estimate());
+        assert_eq!(estimator.as_ref(), array.get_backend(i));
+    }
+    assert_eq!(array.iter_backends().rev().count(), 4);
+    Ok(())
+}
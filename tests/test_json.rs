@@ -0,0 +1,55 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_write_estimates_json() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut array = SliceEstimatorArray::new(logic.clone(), 10);
+    for i in 0..10 {
+        let mut estimator = array.get_estimator_mut(i);
+        for x in 0..i as u64 * 1000 {
+            estimator.add(x);
+        }
+    }
+    let expected = (0..10)
+        .map(|i| array.get_estimator(i).estimate())
+        .collect::<Vec<_>>();
+
+    let mut compact = vec![];
+    array.write_estimates_json(&mut compact)?;
+    assert!(!compact.contains(&b'\n'));
+    let parsed: Vec<f64> = serde_json::from_slice(&compact)?;
+    assert_eq!(parsed, expected);
+
+    let mut pretty = vec![];
+    array.write_estimates_json_pretty(&mut pretty)?;
+    assert_eq!(String::from_utf8(pretty.clone())?.lines().count(), 12);
+    let parsed: Vec<f64> = serde_json::from_slice(&pretty)?;
+    assert_eq!(parsed, expected);
+
+    let empty = SliceEstimatorArray::new(logic, 0);
+    for pretty in [false, true] {
+        let mut json = vec![];
+        if pretty {
+            empty.write_estimates_json_pretty(&mut json)?;
+        } else {
+            empty.write_estimates_json(&mut json)?;
+        }
+        assert_eq!(json, b"[]");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{KMVSketchBuilder, SliceEstimatorArray},
+    traits::{
+        EstimationGuarantees, EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut,
+        EstimatorMut, MergeEstimator, SliceEstimationLogic,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_kmv_sketch_estimate() -> Result<()> {
+    for k in [16, 64, 256, 1024] {
+        let logic = KMVSketchBuilder::new()
+            .k(k)
+            .build_hasher(Xxh3Builder::new().with_seed(0))
+            .build::<u64>()?;
+        assert_eq!(logic.backend_len(), k);
+        let mut estimator = logic.new_estimator();
+        assert_eq!(estimator.estimate(), 0.0);
+        let mut n = 0;
+        for target in [10, 100, 1000, 10_000, 100_000] {
+            while n < target {
+                estimator.add(n);
+                // Duplicates must not change the sketch
+                estimator.add(n);
+                n += 1;
+            }
+            if (n as usize) < k {
+                assert_eq!(estimator.estimate(), n as f64);
+            }
+            let rel_error = (estimator.estimate() - n as f64).abs() / n as f64;
+            assert!(
+                rel_error < 4.0 * logic.relative_std(),
+                "k = {}: estimate {} for {} elements",
+                k,
+                estimator.estimate(),
+                n
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_kmv_sketch_single_hash() -> Result<()> {
+    // With one hash the estimate has infinite variance, so we average
+    // estimates using different seeds
+    let n = 10_000;
+    let mut sum = 0.0;
+    for seed in 0..100 {
+        let logic = KMVSketchBuilder::new()
+            .k(1)
+            .build_hasher(Xxh3Builder::new().with_seed(seed))
+            .build::<u64>()?;
+        let mut estimator = logic.new_estimator();
+        for x in 0..n {
+            estimator.add(x);
+        }
+        let estimate = estimator.estimate();
+        assert!(estimate > 0.0, "seed {}: estimate {}", seed, estimate);
+        sum += estimate.ln();
+    }
+    // The geometric mean is within a constant factor of n
+    let geometric_mean = (sum / 100.0).exp();
+    assert!(
+        geometric_mean > n as f64 / 10.0 && geometric_mean < 10.0 * n as f64,
+        "geometric mean {}",
+        geometric_mean
+    );
+    Ok(())
+}
+
+#[test]
+fn test_kmv_sketch_jaccard() -> Result<()> {
+    let n = 10_000_u64;
+    for k in [16, 64, 256, 1024] {
+        let logic = KMVSketchBuilder::new()
+            .k(k)
+            .build_hasher(Xxh3Builder::new().with_seed(0))
+            .build::<u64>()?;
+        for overlap in [0, 1000, 5000, 9000, 10_000] {
+            // A = [0..n), B = [n - overlap..2n - overlap)
+            let mut a = logic.new_estimator();
+            let mut b = logic.new_estimator();
+            for x in 0..n {
+                a.add(x);
+                b.add(n - overlap + x);
+            }
+            let expected = overlap as f64 / (2 * n - overlap) as f64;
+            let jaccard = logic.jaccard(a.as_ref(), b.as_ref());
+            assert!(
+                (jaccard - expected).abs() <= 1.0 / (k as f64).sqrt(),
+                "k = {}: estimated Jaccard similarity {}, expected {}",
+                k,
+                jaccard,
+                expected
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_kmv_sketch_merge() -> Result<()> {
+    let logic = KMVSketchBuilder::new()
+        .k(64)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut array = SliceEstimatorArray::new(logic.clone(), 3);
+    for x in 0..1000 {
+        array.get_estimator_mut((x % 2) as usize).add(x);
+        array.get_estimator_mut(2).add(x);
+    }
+    let mut union = array.get_estimator(0).into_owned();
+    union.merge(array.get_backend(1));
+    assert_eq!(union.as_ref(), array.get_backend(2));
+    assert_eq!(logic.jaccard(union.as_ref(), array.get_backend(2)), 1.0);
+
+    // Merging partially filled sketches
+    let mut a = logic.new_estimator();
+    let mut b = logic.new_estimator();
+    for x in 0..20 {
+        a.add(x);
+        b.add(x + 10);
+    }
+    a.merge(b.as_ref());
+    assert_eq!(logic.num_retained(a.as_ref()), 30);
+    assert_eq!(a.estimate(), 30.0);
+
+    let empty = logic.new_estimator();
+    assert_eq!(logic.jaccard(empty.as_ref(), empty.as_ref()), 1.0);
+    assert!(KMVSketchBuilder::new().k(0).build::<u64>().is_err());
+    Ok(())
+}
@@ -0,0 +1,84 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{
+        EstimationLogic, EstimatorArray, EstimatorArrayMut, EstimatorMut, MergeEstimationLogic,
+        SliceEstimationLogic,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_l1_similarity_extremes() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .word_type::<u8>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+
+    let mut estimator = logic.new_estimator();
+    for x in 0..10_000_u64 {
+        estimator.add(x);
+    }
+    let backend = estimator.as_ref();
+    assert_eq!(logic.register_l1_similarity(backend, backend), 1.0);
+
+    let empty = vec![0_u8; logic.backend_len()];
+    let full = vec![u8::MAX; logic.backend_len()];
+    assert_eq!(logic.register_l1_similarity(&empty, &full), 0.0);
+    assert_eq!(logic.register_l1_similarity(&full, &empty), 0.0);
+    assert_eq!(logic.register_l1_similarity(&full, &full), 1.0);
+
+    Ok(())
+}
+
+#[test]
+fn test_l1_similarity_jaccard() -> Result<()> {
+    const N: u64 = 100_000;
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(10)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+
+    // Estimator i contains N elements starting at i * N / 4, so the Jaccard
+    // similarity with estimator 0 decreases with i.
+    let mut array = SliceEstimatorArray::new(logic.clone(), 6);
+    for i in 0..6 {
+        let mut estimator = array.get_estimator_mut(i);
+        for x in i as u64 * N / 4..i as u64 * N / 4 + N {
+            estimator.add(x);
+        }
+    }
+
+    let mut union = logic.new_estimator();
+    let mut jaccard = vec![];
+    let mut l1 = vec![];
+    for i in 0..6 {
+        union.set(array.get_backend(0));
+        logic.merge(union.as_mut(), array.get_backend(i));
+        let a = logic.estimate(array.get_backend(0));
+        let b = logic.estimate(array.get_backend(i));
+        let u = logic.estimate(union.as_ref());
+        jaccard.push(((a + b - u) / u).max(0.0));
+        l1.push(logic.register_l1_similarity(array.get_backend(0), array.get_backend(i)));
+    }
+
+    assert_eq!(l1[0], 1.0);
+    for i in 1..6 {
+        assert!(jaccard[i] <= jaccard[i - 1] + 0.05);
+        assert!(
+            l1[i] < l1[i - 1] || jaccard[i - 1] < 0.05,
+            "similarity does not decrease: {:?} (Jaccard: {:?})",
+            l1,
+            jaccard
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::HyperLogLogBuilder,
+    traits::{EstimationLogic, Estimator, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_linear_counting_threshold_builder() -> Result<()> {
+    let builder = HyperLogLogBuilder::new(1_000_000).log_2_num_reg(12);
+    assert_eq!(
+        builder.clone().build::<u64>()?.linear_counting_threshold(),
+        2.5 * 4096.0
+    );
+    assert_eq!(
+        builder
+            .clone()
+            .hyperloglog_plus_plus(true)
+            .build::<u64>()?
+            .linear_counting_threshold(),
+        5.0 * 4096.0
+    );
+    assert_eq!(
+        builder
+            .clone()
+            .hyperloglog_plus_plus(true)
+            .linear_counting_threshold(1000.0)
+            .build::<u64>()?
+            .linear_counting_threshold(),
+        1000.0
+    );
+    assert_eq!(
+        builder
+            .linear_counting_threshold(1000.0)
+            .hyperloglog_plus_plus(true)
+            .build::<u64>()?
+            .linear_counting_threshold(),
+        5.0 * 4096.0
+    );
+    Ok(())
+}
+
+#[test]
+fn test_linear_counting_small_cardinalities() -> Result<()> {
+    for n in [10, 100, 1000] {
+        let mut error_raw = 0.0;
+        let mut error_linear = 0.0;
+        for seed in 0..10 {
+            let logic = HyperLogLogBuilder::new(1_000_000)
+                .log_2_num_reg(12)
+                .build_hasher(Xxh3Builder::new().with_seed(seed))
+                .hyperloglog_plus_plus(true)
+                .build::<u64>()?;
+            let mut estimator = logic.new_estimator();
+            for x in 0..n {
+                estimator.add(x);
+            }
+            let backend = estimator.as_ref();
+            let raw = logic.estimate_with_linear_counting_threshold(backend, Some(0.0));
+            let linear = logic.estimate_with_linear_counting_threshold(backend, None);
+            assert_eq!(linear, estimator.estimate());
+            error_raw += (raw - n as f64).abs();
+            error_linear += (linear - n as f64).abs();
+        }
+        assert!(
+            error_linear < error_raw,
+            "linear counting is less accurate for {} elements: {} >= {}",
+            n,
+            error_linear,
+            error_raw
+        );
+        assert!(error_linear / 10.0 < 0.05 * n as f64);
+    }
+    Ok(())
+}
@@ -0,0 +1,117 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{BiasCorrection, HyperLogLogBuilder},
+    traits::{EstimationLogic, Estimator, EstimatorMut, SliceEstimationLogic},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const NUM_TRIALS: u64 = 100;
+
+#[test]
+fn test_log_log_beta() -> Result<()> {
+    let log2ms = [6, 8, 10];
+
+    for log2m in log2ms {
+        let m = 1 << log2m;
+        // Cardinalities around the switch from linear counting
+        let sizes = [m / 2, m, 2 * m, 3 * m, 4 * m, 6 * m, 10 * m];
+        let mut errors = [0.0; 2];
+        for size in sizes {
+            for trial in 0..NUM_TRIALS {
+                let builder = HyperLogLogBuilder::new(size)
+                    .word_type::<u16>()
+                    .log_2_num_reg(log2m)
+                    .build_hasher(Xxh3Builder::new().with_seed(trial));
+                let standard = builder.clone().build()?;
+                let log_log_beta = builder
+                    .bias_correction(BiasCorrection::LogLogBeta)
+                    .build::<i64>()?;
+                let mut est = standard.new_estimator();
+                let incr = (1 << 32) / size as i64;
+                let mut x = i64::MIN;
+                for _ in 0..size {
+                    est.add(x);
+                    x += incr;
+                }
+
+                let size = size as f64;
+                let estimates = [est.estimate(), log_log_beta.estimate(est.as_ref())];
+                for (error, estimate) in errors.iter_mut().zip(estimates) {
+                    *error += (estimate - size).abs() / size;
+                }
+            }
+        }
+
+        let [standard, log_log_beta] =
+            errors.map(|error| error / (sizes.len() as u64 * NUM_TRIALS) as f64);
+        assert!(
+            log_log_beta < standard,
+            "log2m {}: LogLog-Beta error {} >= standard error {}",
+            log2m,
+            log_log_beta,
+            standard
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_log_log_beta_small() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(8)
+        .bias_correction(BiasCorrection::LogLogBeta)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut est = logic.new_estimator();
+    assert_eq!(est.estimate(), 0.0);
+    est.add(0);
+    assert!((est.estimate() - 1.0).abs() < 0.1);
+    Ok(())
+}
+
+#[test]
+fn test_log_log_beta_range() {
+    for log2m in [4, 18] {
+        assert!(HyperLogLogBuilder::new(1000)
+            .word_type::<u16>()
+            .log_2_num_reg(log2m)
+            .bias_correction(BiasCorrection::LogLogBeta)
+            .build::<u64>()
+            .is_ok());
+    }
+    assert!(HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(19)
+        .bias_correction(BiasCorrection::LogLogBeta)
+        .build::<u64>()
+        .is_err());
+}
+
+#[test]
+fn test_log_log_beta_batch() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(10_000)
+        .log_2_num_reg(6)
+        .bias_correction(BiasCorrection::LogLogBeta)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut backends = vec![];
+    for i in 0..7 {
+        let mut est = logic.new_estimator();
+        for x in 0..100 * i * i {
+            est.add(x);
+        }
+        backends.extend_from_slice(est.as_ref());
+    }
+    let mut output = [0.0; 7];
+    logic.estimate_batch_simd::<4>(&backends, &mut output);
+    for (backend, &estimate) in backends.chunks(logic.backend_len()).zip(&output) {
+        assert_eq!(estimate, logic.estimate(backend));
+    }
+    Ok(())
+}
@@ -0,0 +1,99 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{DefaultEstimator, SliceEstimatorArray},
+    traits::{EstimationLogic, EstimatorArray, EstimatorArrayMut, SliceEstimationLogic},
+};
+use std::borrow::Borrow;
+
+/// A logic whose estimate is the content of its one-word backend.
+#[derive(Clone)]
+struct ValueLogic;
+
+impl EstimationLogic for ValueLogic {
+    type Item = u64;
+    type Backend = [u64];
+    type Estimator<'a> = DefaultEstimator<Self, &'a Self, Box<[u64]>>;
+
+    fn add(&self, backend: &mut [u64], element: impl Borrow<u64>) {
+        backend[0] = backend[0].max(*element.borrow());
+    }
+
+    fn estimate(&self, backend: &[u64]) -> f64 {
+        backend[0] as f64
+    }
+
+    fn clear(&self, backend: &mut [u64]) {
+        backend[0] = 0;
+    }
+
+    fn set(&self, dst: &mut [u64], src: &[u64]) {
+        dst.copy_from_slice(src);
+    }
+
+    fn new_estimator(&self) -> Self::Estimator<'_> {
+        DefaultEstimator::new(self, vec![0].into_boxed_slice())
+    }
+}
+
+impl SliceEstimationLogic<u64> for ValueLogic {
+    fn backend_len(&self) -> usize {
+        1
+    }
+}
+
+fn array(values: &[u64]) -> SliceEstimatorArray<ValueLogic, u64, Box<[u64]>> {
+    let mut array = SliceEstimatorArray::new(ValueLogic, values.len());
+    array.apply_indexed(|i, backend| backend[0] = values[i]);
+    array
+}
+
+#[test]
+fn test_mean_estimates() -> Result<()> {
+    let array = array(&[1, 2, 4]);
+    assert!((array.harmonic_mean_estimate() - 12.0 / 7.0).abs() < 1E-12);
+    assert!((array.geometric_mean_estimate() - 2.0).abs() < 1E-12);
+    assert!((array.arithmetic_mean_estimate() - 7.0 / 3.0).abs() < 1E-12);
+    assert_eq!(array.trimmed_mean_estimate(0.0), 7.0 / 3.0);
+    assert_eq!(array.trimmed_mean_estimate(0.4), 2.0);
+
+    // Zero estimates are skipped by the harmonic and geometric means
+    let array = self::array(&[0, 1, 2, 4, 0]);
+    assert!((array.harmonic_mean_estimate() - 12.0 / 7.0).abs() < 1E-12);
+    assert!((array.geometric_mean_estimate() - 2.0).abs() < 1E-12);
+    assert_eq!(array.arithmetic_mean_estimate(), 7.0 / 5.0);
+    assert_eq!(array.trimmed_mean_estimate(0.2), 1.0);
+
+    let array = self::array(&[100, 1, 2, 3, 1000, 0]);
+    assert_eq!(array.trimmed_mean_estimate(1.0 / 3.0), 2.5);
+
+    Ok(())
+}
+
+#[test]
+fn test_mean_estimates_edge_cases() -> Result<()> {
+    let array = array(&[0, 0, 0]);
+    assert_eq!(array.harmonic_mean_estimate(), 0.0);
+    assert_eq!(array.geometric_mean_estimate(), 0.0);
+    assert_eq!(array.arithmetic_mean_estimate(), 0.0);
+    assert_eq!(array.trimmed_mean_estimate(0.2), 0.0);
+
+    let array = self::array(&[]);
+    assert_eq!(array.harmonic_mean_estimate(), 0.0);
+    assert_eq!(array.geometric_mean_estimate(), 0.0);
+    assert_eq!(array.arithmetic_mean_estimate(), 0.0);
+    assert_eq!(array.trimmed_mean_estimate(0.2), 0.0);
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_trimmed_mean_invalid_fraction() {
+    array(&[1, 2, 3]).trimmed_mean_estimate(0.5);
+}
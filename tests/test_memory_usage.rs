@@ -0,0 +1,62 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{hll_memory_for_rsd, HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::SliceEstimationLogic,
+};
+
+#[test]
+fn test_memory_usage_bytes() -> Result<()> {
+    for log_2_num_reg in [4, 6, 8, 12] {
+        // With 2^20 elements registers have 5 bits
+        let builder = HyperLogLogBuilder::new(1 << 20).log_2_num_reg(log_2_num_reg);
+        let logic = builder.clone().word_type::<u16>().build::<usize>()?;
+        assert_eq!(logic.memory_usage_bytes(), (5 << log_2_num_reg) / 8);
+        assert_eq!(
+            logic.memory_usage_bytes(),
+            logic.backend_len() * size_of::<u16>()
+        );
+        let logic = builder.clone().word_type::<u8>().build::<usize>()?;
+        assert_eq!(logic.memory_usage_bytes(), (5 << log_2_num_reg) / 8);
+        if log_2_num_reg >= 6 {
+            let logic = builder.clone().word_type::<u64>().build::<usize>()?;
+            assert_eq!(logic.memory_usage_bytes(), (5 << log_2_num_reg) / 8);
+        }
+
+        let builder = builder.word_type::<u16>();
+        let logic = builder.clone().build::<usize>()?;
+        assert_eq!(
+            builder.estimated_array_memory(10),
+            10 * logic.memory_usage_bytes()
+        );
+        let array = SliceEstimatorArray::new(logic.clone(), 10);
+        assert_eq!(
+            array.total_memory_bytes(),
+            10 * logic.memory_usage_bytes() + size_of_val(&logic)
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_hll_memory_for_rsd() -> Result<()> {
+    for rsd in [0.1, 0.05, 0.01] {
+        let num_registers = HyperLogLog::num_registers_for_rsd(rsd);
+        assert_eq!(
+            hll_memory_for_rsd(rsd, 100),
+            100 * num_registers * HyperLogLog::register_size(usize::MAX) / 8
+        );
+        let logic = HyperLogLogBuilder::new(usize::MAX)
+            .log_2_num_reg(num_registers.ilog2() as usize)
+            .word_type::<u8>()
+            .build::<usize>()?;
+        assert!(HyperLogLog::rel_std(logic.log_2_num_registers()) <= rsd);
+        assert_eq!(hll_memory_for_rsd(rsd, 1), logic.memory_usage_bytes());
+    }
+    Ok(())
+}
@@ -0,0 +1,69 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{
+        AsSyncArray, EstimatorArray, EstimatorArrayMut, EstimatorMut, MergeEstimationLogic,
+        SliceEstimationLogic,
+    },
+    utils::merge_cascade,
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_merge_cascade() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let len = 1000;
+    let mut array = SliceEstimatorArray::new(logic.clone(), len);
+    for i in 0..len {
+        for x in 0..100 {
+            array.get_estimator_mut(i).add(i * 50 + x);
+        }
+    }
+
+    for (start, end) in [
+        (0, 0),
+        (5, 5),
+        (0, 1),
+        (3, 5),
+        (7, 24),
+        (0, 1000),
+        (1, 1000),
+    ] {
+        let mut expected = vec![0; logic.backend_len()];
+        for i in start..end {
+            logic.merge(&mut expected, array.get_backend(i));
+        }
+        let mut result = vec![1; logic.backend_len()];
+        let sync_array = array.as_sync_array();
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()?
+            .install(|| unsafe { merge_cascade(&sync_array, start, end, &mut result) });
+        assert_eq!(result, expected, "range [{} . . {})", start, end);
+    }
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_merge_cascade_out_of_bounds() {
+    let logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(4)
+        .build::<usize>()
+        .unwrap();
+    let mut array = SliceEstimatorArray::new(logic.clone(), 10);
+    let mut result = vec![0; logic.backend_len()];
+    unsafe { merge_cascade(&array.as_sync_array(), 5, 11, &mut result) };
+}
@@ -0,0 +1,64 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, MergeForest, SliceEstimatorArray},
+    traits::{EstimationLogic, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_merge_forest() -> Result<()> {
+    const N: usize = 13;
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut array = SliceEstimatorArray::new(logic.clone(), N);
+    for i in 0..N {
+        let mut estimator = array.get_estimator_mut(i);
+        for x in i as u64 * 500..i as u64 * 500 + 1000 {
+            estimator.add(x);
+        }
+    }
+
+    let mut forest = MergeForest::new(&array);
+    assert_eq!(forest.len(), N);
+
+    let mut all = logic.new_estimator();
+    array.merge_all(all.as_mut());
+    assert_eq!(forest.query_union(0, N), logic.estimate(all.as_ref()));
+
+    let check = |forest: &mut MergeForest<_, _>, array: &SliceEstimatorArray<_, _, _>| {
+        for from in 0..=N {
+            for to in from..=N {
+                assert_eq!(
+                    forest.query_union(from, to),
+                    array.aggregate_filtered(from..to),
+                    "range {}..{}",
+                    from,
+                    to
+                );
+            }
+        }
+    };
+    check(&mut forest, &array);
+
+    for (i, x) in [(0, 100_000), (5, 100_001), (12, 100_002), (7, 100_003)] {
+        forest.update(i, x);
+        array.get_estimator_mut(i).add(x);
+    }
+    for i in 0..N {
+        for x in 200_000..201_000 {
+            forest.update(i, x);
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+    check(&mut forest, &array);
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{
+        EstimationLogic, EstimatorArray, EstimatorArrayMut, EstimatorMut, MergeEstimationLogic,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_merge_if_significant() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+
+    let mut a = logic.new_estimator();
+    let mut b = logic.new_estimator();
+    for x in 0..1000 {
+        a.add(x);
+    }
+    for x in 500..5000 {
+        b.add(x);
+    }
+    let new_registers = logic.count_new_registers(a.as_ref(), b.as_ref());
+    assert!(new_registers > 0);
+
+    let mut dst = a.as_ref().to_vec();
+    assert!(!logic.merge_if_significant(&mut dst, b.as_ref(), new_registers + 1));
+    assert_eq!(dst, a.as_ref());
+    assert!(logic.merge_if_significant(&mut dst, b.as_ref(), new_registers));
+    let mut expected = a.as_ref().to_vec();
+    logic.merge(&mut expected, b.as_ref());
+    assert_eq!(dst, expected);
+    assert_eq!(logic.count_new_registers(&dst, b.as_ref()), 0);
+    // A merge changing nothing is never performed
+    assert!(!logic.merge_if_significant(&mut dst, b.as_ref(), 0));
+
+    Ok(())
+}
+
+#[test]
+fn test_merge_array_if_significant() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut dst = SliceEstimatorArray::new(logic.clone(), 10);
+    let mut src = SliceEstimatorArray::new(logic, 10);
+    for i in 0..10 {
+        for x in 0..1000 {
+            dst.get_estimator_mut(i).add(x);
+        }
+        // Only odd sources contain new elements
+        for x in 0..1000 + (i as u64 % 2) * 1000 {
+            src.get_estimator_mut(i).add(x);
+        }
+    }
+
+    assert_eq!(dst.merge_array_if_significant(&src, 1), 5);
+    // After convergence, no merge is performed
+    assert_eq!(dst.merge_array_if_significant(&src, 1), 0);
+    for i in 0..10 {
+        assert_eq!(
+            dst.logic()
+                .count_new_registers(dst.get_backend(i), src.get_backend(i)),
+            0
+        );
+    }
+
+    Ok(())
+}
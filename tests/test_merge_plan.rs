@@ -0,0 +1,70 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLog, HyperLogLogBuilder, MergePlan, SliceEstimatorArray},
+    traits::{EstimatorArray, EstimatorArrayMut, EstimatorMut, MergeEstimationLogic},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+type Array = SliceEstimatorArray<HyperLogLog<u64, Xxh3Builder, usize>, usize, Box<[usize]>>;
+
+// A diamond-shaped DAG with a tail: 0 → 1, 0 → 2, 1 → 3, 2 → 3, 3 → 4, 5 → 4
+const EDGES: [(usize, usize); 6] = [(3, 4), (1, 3), (0, 1), (2, 3), (0, 2), (5, 4)];
+
+fn array() -> Result<Array> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut array = SliceEstimatorArray::new(logic, 6);
+    for i in 0..6 {
+        let mut estimator = array.get_estimator_mut(i);
+        for x in i as u64 * 1000..(i as u64 + 1) * 1000 {
+            estimator.add(x);
+        }
+    }
+    Ok(array)
+}
+
+/// Merges manually in a topological order.
+fn manual(array: &mut Array) {
+    let logic = array.logic().clone();
+    for (u, v) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (5, 4)] {
+        let src = array.get_backend(u).to_vec();
+        logic.merge(array.get_backend_mut(v), &src);
+    }
+}
+
+#[test]
+fn test_merge_plan() -> Result<()> {
+    let plan = MergePlan::from_dag(&EDGES, 6)?;
+    assert_eq!(plan.num_nodes(), 6);
+    assert_eq!(plan.layers(), [vec![0, 5], vec![1, 2], vec![3], vec![4]]);
+
+    let mut expected = array()?;
+    manual(&mut expected);
+
+    let mut array = array()?;
+    plan.execute(&mut array);
+    assert_eq!(array.as_ref(), expected.as_ref());
+
+    #[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+    {
+        let mut array = self::array()?;
+        plan.execute_par(&mut array);
+        assert_eq!(array.as_ref(), expected.as_ref());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_merge_plan_errors() {
+    assert!(MergePlan::from_dag(&[(0, 1), (1, 2), (2, 0)], 3).is_err());
+    assert!(MergePlan::from_dag(&[(0, 3)], 3).is_err());
+}
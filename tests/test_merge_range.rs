@@ -0,0 +1,80 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimationLogic, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 20;
+
+#[test]
+fn test_merge_range_into() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(10_000)
+        .log_2_num_reg(8)
+        .word_type::<u16>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    for target in [0, LEN / 2, LEN - 1] {
+        let mut array = SliceEstimatorArray::new(logic.clone(), LEN);
+        let mut expected = logic.new_estimator();
+        for i in 0..LEN {
+            for x in 100 * i..100 * i + 150 {
+                array.get_estimator_mut(i).add(x);
+                expected.add(x);
+            }
+        }
+        let untouched = array.get_backend((target + 1) % LEN).to_vec();
+
+        array.merge_range_into(target, 0..LEN);
+        assert_eq!(array.get_backend(target), expected.as_ref());
+        // Sources are not modified
+        assert_eq!(array.get_backend((target + 1) % LEN), untouched);
+    }
+
+    let mut array = SliceEstimatorArray::new(logic.clone(), LEN);
+    let mut expected = logic.new_estimator();
+    for i in 0..LEN {
+        for x in 100 * i..100 * i + 150 {
+            array.get_estimator_mut(i).add(x);
+            if i != 0 {
+                expected.add(x);
+            }
+        }
+    }
+    array.merge_range_into(0, 1..LEN);
+    assert_ne!(array.get_backend(0), expected.as_ref());
+    for x in 0..150 {
+        expected.add(x);
+    }
+    assert_eq!(array.get_backend(0), expected.as_ref());
+
+    Ok(())
+}
+
+#[test]
+fn test_reduce_into() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(10_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let mut array = SliceEstimatorArray::new(logic.clone(), LEN);
+    let mut expected = logic.new_estimator();
+    for i in 0..LEN {
+        for x in 100 * i..100 * i + 150 {
+            array.get_estimator_mut(i).add(x);
+            expected.add(x);
+        }
+    }
+    array.reduce_into(3);
+    assert_eq!(array.get_backend(3), expected.as_ref());
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimationLogic, EstimatorArray, EstimatorArrayMut, MergeEstimationLogic},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const NUM_NODES: usize = 100;
+
+fn logic() -> Result<HyperLogLog<usize, Xxh3Builder, usize>> {
+    HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build()
+}
+
+/// Sorts the registers of a backend in nonincreasing order, which does not
+/// change its estimate.
+fn sort_registers(logic: &HyperLogLog<usize, Xxh3Builder, usize>, backend: &mut [usize]) {
+    let mut registers = (0..logic.num_registers())
+        .map(|i| logic.get_register(backend, i))
+        .collect::<Vec<_>>();
+    registers.sort_unstable_by(|a, b| b.cmp(a));
+    for (i, &value) in registers.iter().enumerate() {
+        logic.set_register(backend, i, value);
+    }
+}
+
+#[test]
+fn test_merge_sorted() -> Result<()> {
+    let logic = logic()?;
+    let mut array = SliceEstimatorArray::new(logic.clone(), 3);
+    for i in 0..3 {
+        logic.add_many(
+            array.get_backend_mut(i),
+            &(i * 1000..i * 1000 + 2000).collect::<Vec<_>>(),
+        );
+        sort_registers(&logic, array.get_backend_mut(i));
+    }
+    let expected = {
+        let mut dst = array.get_backend(0).to_vec();
+        logic.merge(&mut dst, array.get_backend(1));
+        dst
+    };
+    let mut dst = array.get_backend(0).to_vec();
+    logic.merge_sorted(&mut dst, array.get_backend(1));
+    assert_eq!(dst, expected);
+    // Merging again stops immediately
+    assert!(logic.merge_sorted(&mut dst, array.get_backend(1)));
+    assert_eq!(dst, expected);
+    // An empty source is skipped entirely
+    assert!(logic.merge_sorted(&mut dst, &vec![0; array.get_backend(0).len()]));
+    Ok(())
+}
+
+#[test]
+fn test_merge_sorted_converged_propagation() -> Result<()> {
+    let logic = logic()?;
+    let mut array = SliceEstimatorArray::new(logic.clone(), NUM_NODES);
+    for node in 0..NUM_NODES {
+        logic.add(array.get_backend_mut(node), node);
+        sort_registers(&logic, array.get_backend_mut(node));
+    }
+    // A cycle with chords
+    let successors = |node: usize| [(node + 1) % NUM_NODES, (node * 7 + 3) % NUM_NODES];
+
+    let mut merges = 0;
+    let mut early_exits = 0;
+    let mut rounds = 0;
+    loop {
+        let mut changed = false;
+        for node in 0..NUM_NODES {
+            for s in successors(node) {
+                let src = array.get_backend(s).to_vec();
+                let mut expected = array.get_backend(node).to_vec();
+                logic.merge(&mut expected, &src);
+                let dst = array.get_backend_mut(node);
+                let old = dst.to_vec();
+                merges += 1;
+                if logic.merge_sorted(dst, &src) {
+                    early_exits += 1;
+                }
+                assert_eq!(dst, expected.as_slice());
+                changed |= old != dst;
+            }
+        }
+        rounds += 1;
+        if !changed {
+            break;
+        }
+    }
+    assert!(rounds > 1);
+    // The graph is strongly connected, so all backends are equal
+    for node in 1..NUM_NODES {
+        assert_eq!(array.get_backend(node), array.get_backend(0));
+    }
+    assert!(
+        early_exits * 2 >= merges,
+        "{} early exits in {} merges",
+        early_exits,
+        merges
+    );
+    Ok(())
+}
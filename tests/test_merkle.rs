@@ -0,0 +1,89 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "xxhash-rust")]
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimatorArrayMut, EstimatorMut, SliceEstimationLogic},
+    utils::MerkleVerifier,
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 1000;
+
+#[test]
+fn test_merkle_single_change() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let mut array = SliceEstimatorArray::new(logic.clone(), LEN);
+    for i in 0..LEN {
+        for x in 0..i {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+    let original = MerkleVerifier::from_array(&array);
+    assert_eq!(original.len(), LEN);
+    assert_eq!(original, MerkleVerifier::from_array(&array));
+    assert!(original.diff_indices(&original).is_empty());
+
+    // Change a single register
+    let index = 617;
+    let backend = array.get_backend_mut(index);
+    let value = logic.get_register(backend, 3);
+    logic.set_register(backend, 3, value + 1);
+    let changed = MerkleVerifier::new(array.as_ref(), logic.backend_len());
+    assert_ne!(original.root_hash(), changed.root_hash());
+    assert_ne!(original.leaf_hash(index), changed.leaf_hash(index));
+
+    let (indices, comparisons) = original.diff_indices_with_comparisons(&changed);
+    assert_eq!(indices, vec![index]);
+    // The root, plus the two children of each node on the path
+    let depth = LEN.next_power_of_two().ilog2() as usize;
+    assert!(
+        comparisons <= 2 * depth + 1,
+        "{} comparisons for depth {}",
+        comparisons,
+        depth
+    );
+    Ok(())
+}
+
+#[test]
+fn test_merkle_multiple_changes() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    for len in [1, 2, 3, 7, 100] {
+        let mut a = SliceEstimatorArray::new(logic.clone(), len);
+        let mut b = SliceEstimatorArray::new(logic.clone(), len);
+        let mut expected = vec![];
+        for i in 0..len {
+            a.get_estimator_mut(i).add(i);
+            b.get_estimator_mut(i).add(i);
+            if i % 3 == 0 {
+                b.get_estimator_mut(i).add(1000 + i);
+                expected.push(i);
+            }
+        }
+        let a = MerkleVerifier::from_array(&a);
+        let b = MerkleVerifier::from_array(&b);
+        assert_eq!(a.diff_indices(&b), expected);
+        assert_eq!(b.diff_indices(&a), expected);
+        assert_ne!(a.root_hash(), b.root_hash());
+    }
+
+    let empty = MerkleVerifier::<u32>::new(&[], 4);
+    assert!(empty.is_empty());
+    assert!(empty.diff_indices(&empty).is_empty());
+    Ok(())
+}
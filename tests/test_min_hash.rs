@@ -0,0 +1,91 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{MinHashBuilder, SliceEstimatorArray},
+    traits::{
+        EstimationGuarantees, EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut,
+        EstimatorMut, MergeEstimator, SliceEstimationLogic,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_min_hash_jaccard() -> Result<()> {
+    let n = 10_000_u64;
+    for k in [16, 64, 256, 1024] {
+        let logic = MinHashBuilder::new()
+            .num_hashes(k)
+            .build_hasher(Xxh3Builder::new().with_seed(0))
+            .build::<u64>()?;
+        assert_eq!(logic.backend_len(), k);
+        for overlap in [0, 1000, 5000, 9000, 10_000] {
+            // A = [0..n), B = [n - overlap..2n - overlap)
+            let mut a = logic.new_estimator();
+            let mut b = logic.new_estimator();
+            for x in 0..n {
+                a.add(x);
+                b.add(n - overlap + x);
+            }
+            let expected = overlap as f64 / (2 * n - overlap) as f64;
+            let jaccard = logic.jaccard(a.as_ref(), b.as_ref());
+            assert!(
+                (jaccard - expected).abs() <= 1.0 / (k as f64).sqrt(),
+                "k = {}: estimated Jaccard similarity {}, expected {}",
+                k,
+                jaccard,
+                expected
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_min_hash_estimate() -> Result<()> {
+    let logic = MinHashBuilder::new()
+        .num_hashes(256)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut estimator = logic.new_estimator();
+    assert_eq!(estimator.estimate(), 0.0);
+    let mut n = 0;
+    for target in [10, 100, 1000, 10_000, 100_000] {
+        while n < target {
+            estimator.add(n);
+            n += 1;
+        }
+        let rel_error = (estimator.estimate() - n as f64).abs() / n as f64;
+        assert!(
+            rel_error < 4.0 * logic.relative_std(),
+            "estimate {} for {} elements",
+            estimator.estimate(),
+            n
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_min_hash_merge() -> Result<()> {
+    let logic = MinHashBuilder::new()
+        .num_hashes(64)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut array = SliceEstimatorArray::new(logic.clone(), 3);
+    for x in 0..1000 {
+        array.get_estimator_mut((x % 2) as usize).add(x);
+        array.get_estimator_mut(2).add(x);
+    }
+    let mut union = array.get_estimator(0).into_owned();
+    union.merge(array.get_backend(1));
+    assert_eq!(union.as_ref(), array.get_backend(2));
+    assert_eq!(logic.jaccard(union.as_ref(), array.get_backend(2)), 1.0);
+
+    assert!(MinHashBuilder::new().num_hashes(0).build::<u64>().is_err());
+    Ok(())
+}
@@ -0,0 +1,83 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "memmap2")]
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, MmapSliceEstimatorArray, SliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 100;
+
+#[test]
+fn test_mmap_round_trip() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(10_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("array.bin");
+
+    let mut in_memory = SliceEstimatorArray::new(logic.clone(), LEN);
+    let mut array = unsafe { MmapSliceEstimatorArray::create(&path, logic.clone(), LEN)? };
+    assert_eq!(array.len(), LEN);
+    for i in 0..LEN {
+        assert_eq!(array.get_estimator(i).estimate(), 0.0);
+        for x in 0..10 * i {
+            array.get_estimator_mut(i).add(x);
+            in_memory.get_estimator_mut(i).add(x);
+        }
+    }
+    array.flush()?;
+    let estimates: Vec<f64> = (0..LEN)
+        .map(|i| array.get_estimator(i).estimate())
+        .collect();
+    drop(array);
+
+    let mut array = unsafe { MmapSliceEstimatorArray::open(&path, logic.clone())? };
+    assert_eq!(array.len(), LEN);
+    assert_eq!(array.as_ref(), in_memory.as_ref());
+    for (i, &estimate) in estimates.iter().enumerate() {
+        assert_eq!(array.get_estimator(i).estimate(), estimate);
+    }
+
+    // Modifications after reopening are persistent, too
+    array.get_estimator_mut(0).add(0);
+    drop(array);
+    let array = unsafe { MmapSliceEstimatorArray::open(&path, logic)? };
+    assert!(array.get_estimator(0).estimate() > 0.0);
+    Ok(())
+}
+
+#[test]
+fn test_mmap_empty() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(10_000)
+        .log_2_num_reg(8)
+        .build::<usize>()?;
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("array.bin");
+    let array = unsafe { MmapSliceEstimatorArray::create(&path, logic.clone(), 0)? };
+    assert!(array.is_empty());
+    drop(array);
+    let array = unsafe { MmapSliceEstimatorArray::open(&path, logic)? };
+    assert!(array.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_mmap_wrong_length() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(10_000)
+        .log_2_num_reg(8)
+        .build::<usize>()?;
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("array.bin");
+    std::fs::write(&path, [0; 3])?;
+    assert!(unsafe { MmapSliceEstimatorArray::open(&path, logic) }.is_err());
+    Ok(())
+}
@@ -0,0 +1,50 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+// Adding an element should never decrease the estimate. For HyperLogLog this
+// holds both for the raw estimate and for linear counting, but the estimate
+// can decrease when switching from linear counting to the raw estimate, so
+// we just check that violations are rare.
+
+use card_est_array::{
+    impls::HyperLogLogBuilder,
+    traits::{EstimationLogic, Estimator, EstimatorMut},
+};
+use proptest::prelude::*;
+use xxhash_rust::xxh3::Xxh3Builder;
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn test_estimate_monotonicity(
+        seed in any::<u64>(),
+        elements in prop::collection::vec(0..20_000_u64, 2001..=10_000),
+    ) {
+        let logic = HyperLogLogBuilder::new(100_000)
+            .log_2_num_reg(8)
+            .build_hasher(Xxh3Builder::new().with_seed(seed))
+            .build::<u64>()
+            .unwrap();
+        let mut estimator = logic.new_estimator();
+        let mut last_estimate = estimator.estimate();
+        let mut violations = 0;
+        for &x in &elements {
+            estimator.add(x);
+            let estimate = estimator.estimate();
+            if estimate < last_estimate {
+                violations += 1;
+            }
+            last_estimate = estimate;
+        }
+        prop_assert!(
+            (violations as f64) < 0.001 * elements.len() as f64,
+            "{} violations in {} additions",
+            violations,
+            elements.len()
+        );
+    }
+}
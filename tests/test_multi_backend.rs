@@ -0,0 +1,54 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLog, HyperLogLogBuilder, MultiBackend},
+    traits::{EstimationLogic, Estimator, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_multi_backend() -> Result<()> {
+    const N: u64 = 10_000;
+    // 16 registers require 16-bit words
+    let builder = HyperLogLogBuilder::new(1_000_000)
+        .word_type::<u16>()
+        .build_hasher(Xxh3Builder::new().with_seed(0));
+    let small = builder.clone().log_2_num_reg(4).build::<u64>()?;
+    let large = builder.log_2_num_reg(12).build::<u64>()?;
+
+    let mut multi = MultiBackend::new([small.clone(), large.clone()]);
+    let mut small_estimator = small.new_estimator();
+    let mut large_estimator = large.new_estimator();
+    for x in 0..N {
+        multi.add(x);
+        small_estimator.add(x);
+        large_estimator.add(x);
+    }
+
+    assert_eq!(multi.backend(0), small_estimator.as_ref());
+    assert_eq!(multi.backend(1), large_estimator.as_ref());
+    let estimates = multi.estimates();
+    assert_eq!(
+        estimates,
+        [small_estimator.estimate(), large_estimator.estimate()]
+    );
+    for (estimate, log_2_num_reg) in estimates.into_iter().zip([4, 12]) {
+        let rel_error = (estimate - N as f64).abs() / N as f64;
+        assert!(
+            rel_error < 3.0 * HyperLogLog::rel_std(log_2_num_reg),
+            "estimate {} with 2^{} registers",
+            estimate,
+            log_2_num_reg
+        );
+    }
+
+    multi.clear();
+    assert_eq!(multi.estimates(), [0.0, 0.0]);
+
+    Ok(())
+}
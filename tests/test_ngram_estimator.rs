@@ -0,0 +1,76 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, NGramEstimator, SequenceHasher},
+    traits::EstimationLogic,
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_sequence_hasher() {
+    let mut hasher = SequenceHasher::<_, 3>::new(Xxh3Builder::new().with_seed(0));
+    let sequence = "ABRACADABRA".chars().collect::<Vec<_>>();
+    assert_eq!(hasher.push(sequence[0]), None);
+    assert_eq!(hasher.push(sequence[1]), None);
+    for window in sequence.windows(3) {
+        let hash = hasher.push(window[2]);
+        assert_eq!(hash, Some(hasher.hash_window(window.try_into().unwrap())));
+    }
+    // "ABR" appears twice, "BRA" too
+    let expected = hasher.hash_window(&['A', 'B', 'R']);
+    hasher.reset();
+    assert_eq!(hasher.push('A'), None);
+    assert_eq!(hasher.push('B'), None);
+    assert_eq!(hasher.push('R'), Some(expected));
+    // Order matters
+    assert_ne!(
+        hasher.hash_window(&['A', 'B', 'R']),
+        hasher.hash_window(&['R', 'B', 'A'])
+    );
+}
+
+#[test]
+fn test_ngram_estimator() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+
+    // "ABCD" and "DCBA" contain the same elements but no common bigram
+    let mut forward =
+        NGramEstimator::<_, _, _, 2>::new(logic.new_estimator(), Xxh3Builder::new().with_seed(1));
+    forward.add_sequence("ABCD".chars());
+    assert_eq!(forward.estimate().round(), 3.0);
+
+    let mut both =
+        NGramEstimator::<_, _, _, 2>::new(logic.new_estimator(), Xxh3Builder::new().with_seed(1));
+    both.add_sequence("ABCD".chars());
+    both.end_sequence();
+    both.add_sequence("DCBA".chars());
+    assert_eq!(both.estimate().round(), 6.0);
+
+    // Without ending the sequence, "DD" is an additional bigram
+    both.clear();
+    assert_eq!(both.estimate(), 0.0);
+    both.add_sequence("ABCDDCBA".chars());
+    assert_eq!(both.estimate().round(), 7.0);
+
+    // Repeated bigrams are counted once
+    let mut repeated =
+        NGramEstimator::<_, _, _, 2>::new(logic.new_estimator(), Xxh3Builder::new().with_seed(1));
+    repeated.add_sequence("ABABABAB".chars());
+    assert_eq!(repeated.estimate().round(), 2.0);
+
+    // Sequences shorter than N contain no n-grams
+    let mut short =
+        NGramEstimator::<_, _, _, 5>::new(logic.new_estimator(), Xxh3Builder::new().with_seed(1));
+    short.add_sequence("ABCD".chars());
+    assert_eq!(short.estimate(), 0.0);
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, NormalizedEstimatorArray, SliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_normalize_sum() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut array = NormalizedEstimatorArray::new(SliceEstimatorArray::new(logic, 10));
+
+    // Empty arrays have correction factor one
+    array.normalize_sum(100.0);
+    assert_eq!(array.correction_factor(), 1.0);
+    assert_eq!(array.estimates(), vec![0.0; 10]);
+
+    for i in 0..10 {
+        let mut estimator = array.array_mut().get_estimator_mut(i);
+        for x in 0..(i as u64 + 1) * 1000 {
+            estimator.add(x);
+        }
+    }
+    assert_eq!(array.estimate(3), array.array().get_estimator(3).estimate());
+
+    for target in [1.0, 1000.0, 1e9] {
+        array.normalize_sum(target);
+        let sum: f64 = array.estimates().iter().sum();
+        assert!((sum - target).abs() / target < 1E-9);
+        assert_eq!(
+            array.estimate(3),
+            array.correction_factor() * array.array().get_estimator(3).estimate()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimatorArray, EstimatorArrayMut, EstimatorMut, SliceEstimationLogic},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+/// Parses a version 1.0 .npy file, returning the descriptor, the shape, and
+/// the raw data.
+fn parse_npy(bytes: &[u8]) -> (String, Vec<usize>, &[u8]) {
+    assert_eq!(&bytes[..6], b"\x93NUMPY");
+    assert_eq!(&bytes[6..8], &[1, 0]);
+    let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+    assert_eq!((10 + header_len) % 64, 0);
+    let header = std::str::from_utf8(&bytes[10..10 + header_len]).unwrap();
+    assert!(header.ends_with('\n'));
+    assert!(header.contains("'fortran_order': False"));
+
+    let descr_start = header.find("'descr': '").unwrap() + 10;
+    let descr_len = header[descr_start..].find('\'').unwrap();
+    let descr = header[descr_start..][..descr_len].to_string();
+
+    let shape_start = header.find("'shape': (").unwrap() + 10;
+    let shape_len = header[shape_start..].find(')').unwrap();
+    let shape = header[shape_start..][..shape_len]
+        .split(',')
+        .map(|s| s.trim().parse().unwrap())
+        .collect();
+
+    (descr, shape, &bytes[10 + header_len..])
+}
+
+#[test]
+fn test_npy_round_trip() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("array.npy");
+
+    let logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(6)
+        .word_type::<u16>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let mut array = SliceEstimatorArray::new(logic.clone(), 10);
+    for i in 0..10 {
+        for x in 0..100 * i {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+    array.to_npy(&path)?;
+
+    let bytes = std::fs::read(&path)?;
+    let (descr, shape, data) = parse_npy(&bytes);
+    assert_eq!(descr, "<u2");
+    assert_eq!(shape, vec![10, logic.backend_len()]);
+    let words = data
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect::<Vec<_>>();
+    assert_eq!(words, array.as_ref());
+
+    let loaded = SliceEstimatorArray::<_, u16, _>::from_npy(&path, logic.clone())?;
+    assert_eq!(loaded.len(), 10);
+    assert_eq!(loaded.as_ref(), array.as_ref());
+    for i in 0..10 {
+        assert_eq!(loaded.get_backend(i), array.get_backend(i));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_npy_wrong_shape() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("array.npy");
+
+    let logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(6)
+        .word_type::<u16>()
+        .build::<usize>()?;
+    SliceEstimatorArray::new(logic, 10).to_npy(&path)?;
+
+    let other_logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(8)
+        .word_type::<u16>()
+        .build::<usize>()?;
+    assert!(SliceEstimatorArray::<_, u16, _>::from_npy(&path, other_logic).is_err());
+
+    let other_type = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(6)
+        .word_type::<u32>()
+        .build::<usize>()?;
+    assert!(SliceEstimatorArray::<_, u32, _>::from_npy(&path, other_type).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_npy_corrupted() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("array.npy");
+
+    let logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(6)
+        .word_type::<u16>()
+        .build::<usize>()?;
+    SliceEstimatorArray::new(logic.clone(), 10).to_npy(&path)?;
+    let bytes = std::fs::read(&path)?;
+
+    // Truncated data
+    std::fs::write(&path, &bytes[..bytes.len() - 1])?;
+    assert!(SliceEstimatorArray::<_, u16, _>::from_npy(&path, logic.clone()).is_err());
+
+    // A huge number of rows must not cause an allocation
+    let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+    let header = std::str::from_utf8(&bytes[10..10 + header_len])?;
+    let huge = header.replacen("(10,", "(99999999999999999,", 1);
+    let huge = format!("{:<1$}\n", huge.trim_end(), header_len - 1);
+    assert_eq!(huge.len(), header_len);
+    let mut corrupted = bytes[..10].to_vec();
+    corrupted.extend_from_slice(huge.as_bytes());
+    corrupted.extend_from_slice(&bytes[10 + header_len..]);
+    std::fs::write(&path, &corrupted)?;
+    let err = SliceEstimatorArray::<_, u16, _>::from_npy(&path, logic.clone()).unwrap_err();
+    assert!(err.to_string().contains("requires"), "{}", err);
+
+    // Truncated header
+    std::fs::write(&path, &bytes[..20])?;
+    assert!(SliceEstimatorArray::<_, u16, _>::from_npy(&path, logic).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{EstimatorEvent, HyperLogLogBuilder, ObservableEstimator},
+    traits::{EstimationLogic, Estimator, EstimatorMut, MergeEstimator},
+};
+use std::cell::RefCell;
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_observable_estimator() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+
+    let events = RefCell::new(vec![]);
+    let mut plain = logic.new_estimator();
+    let mut observed = ObservableEstimator::new(logic.new_estimator(), |event| {
+        events.borrow_mut().push(event)
+    });
+
+    for x in 0..1000 {
+        plain.add(x);
+        observed.add(x);
+    }
+    assert_eq!(events.borrow().len(), 1000);
+    assert!(events
+        .borrow()
+        .iter()
+        .all(|event| matches!(event, EstimatorEvent::Added { .. })));
+    assert_eq!(plain.estimate(), observed.estimate());
+    assert_eq!(plain.as_ref(), observed.as_ref());
+
+    let mut other = logic.new_estimator();
+    for x in 500..3000 {
+        other.add(x);
+    }
+    events.borrow_mut().clear();
+    plain.merge(other.as_ref());
+    observed.merge(other.as_ref());
+    assert_eq!(plain.estimate(), observed.estimate());
+    match events.borrow().as_slice() {
+        [EstimatorEvent::Merged {
+            dst_estimate,
+            src_estimate,
+            new_estimate,
+        }] => {
+            assert_eq!(*src_estimate, other.estimate());
+            assert_eq!(*new_estimate, plain.estimate());
+            assert!(dst_estimate <= new_estimate);
+        }
+        events => panic!("unexpected events {:?}", events),
+    }
+
+    events.borrow_mut().clear();
+    observed.set(other.as_ref());
+    observed.clear();
+    assert_eq!(
+        events.borrow().as_slice(),
+        [EstimatorEvent::Set, EstimatorEvent::Cleared]
+    );
+    assert_eq!(observed.estimate(), 0.0);
+
+    Ok(())
+}
+
+#[test]
+fn test_observable_estimator_fn_pointer() -> Result<()> {
+    fn ignore(_event: EstimatorEvent) {}
+
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut observed = ObservableEstimator::new(logic.new_estimator(), ignore as fn(_));
+    let mut plain = logic.new_estimator();
+    for x in 0..1000 {
+        plain.add(x);
+        observed.add(x);
+    }
+    assert_eq!(plain.as_ref(), observed.into_inner().as_ref());
+
+    Ok(())
+}
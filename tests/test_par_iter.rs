@@ -0,0 +1,70 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimationLogic, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use rayon::prelude::*;
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 1000;
+
+#[test]
+fn test_par_iter_backends() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let mut array = SliceEstimatorArray::new(logic.clone(), LEN);
+    for i in 0..LEN {
+        for x in 0..i {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+
+    assert_eq!(array.par_iter_backends().len(), LEN);
+    let estimates = array
+        .par_iter_backends()
+        .map(|backend| logic.estimate(backend))
+        .collect::<Vec<_>>();
+    let expected = array
+        .iter_backends()
+        .map(|backend| logic.estimate(backend))
+        .collect::<Vec<_>>();
+    assert_eq!(estimates, expected);
+    Ok(())
+}
+
+#[test]
+fn test_par_iter_backends_mut() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let mut array = SliceEstimatorArray::new(logic.clone(), LEN);
+    let mut expected = SliceEstimatorArray::new(logic.clone(), LEN);
+
+    assert_eq!(array.par_iter_backends_mut().len(), LEN);
+    array
+        .par_iter_backends_mut()
+        .enumerate()
+        .for_each(|(i, backend)| {
+            for x in 0..i {
+                logic.add(backend, x);
+            }
+        });
+    for i in 0..LEN {
+        for x in 0..i {
+            expected.get_estimator_mut(i).add(x);
+        }
+    }
+    assert_eq!(array.as_ref(), expected.as_ref());
+    Ok(())
+}
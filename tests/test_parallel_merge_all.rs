@@ -0,0 +1,42 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimatorArrayMut, EstimatorMut, SliceEstimationLogic},
+    utils::parallel_merge_all,
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_parallel_merge_all() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    for len in [0, 1, 2, 3, 17, 1000] {
+        let mut array = SliceEstimatorArray::new(logic.clone(), len);
+        for i in 0..len {
+            for x in 0..100 {
+                array.get_estimator_mut(i).add(i * 50 + x);
+            }
+        }
+        let mut expected = vec![0; logic.backend_len()];
+        array.merge_all(&mut expected);
+
+        let merged = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()?
+            .install(|| parallel_merge_all(&array));
+        assert_eq!(merged, expected, "len = {}", len);
+        assert_eq!(parallel_merge_all(&array), expected);
+    }
+    Ok(())
+}
@@ -0,0 +1,79 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut, MergeEstimator},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 20;
+const PER_SLOT: usize = 1000;
+
+#[test]
+fn test_prefix_merge_estimates() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(10)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let rsd = HyperLogLog::rel_std(10);
+
+    // Each slot contains a disjoint set
+    let mut array = SliceEstimatorArray::new(logic, LEN);
+    for i in 0..LEN {
+        for x in 0..PER_SLOT {
+            array.get_estimator_mut(i).add(i * PER_SLOT + x);
+        }
+    }
+
+    let prefix = array.prefix_merge_estimates();
+    let suffix = array.suffix_merge_estimates();
+    assert_eq!(prefix.len(), LEN);
+    assert_eq!(suffix.len(), LEN);
+    let mut union = array.get_estimator(0).into_owned();
+    for i in 0..LEN {
+        let expected = ((i + 1) * PER_SLOT) as f64;
+        assert!(
+            (prefix[i] - expected).abs() / expected < 4.0 * rsd,
+            "prefix {}: estimate {}, expected {}",
+            i,
+            prefix[i],
+            expected
+        );
+        let expected = ((LEN - i) * PER_SLOT) as f64;
+        assert!(
+            (suffix[i] - expected).abs() / expected < 4.0 * rsd,
+            "suffix {}: estimate {}, expected {}",
+            i,
+            suffix[i],
+            expected
+        );
+
+        // Estimates are exactly those of the merged estimators
+        union.merge(array.get_backend(i));
+        assert_eq!(prefix[i], union.estimate());
+        assert_eq!(array.range_merge_estimate(0, i + 1), prefix[i]);
+        assert_eq!(array.range_merge_estimate(i, LEN), suffix[i]);
+    }
+
+    let expected = (5 * PER_SLOT) as f64;
+    let estimate = array.range_merge_estimate(7, 12);
+    assert!((estimate - expected).abs() / expected < 4.0 * rsd);
+    assert_eq!(array.range_merge_estimate(3, 3), 0.0);
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_range_merge_estimate_out_of_bounds() {
+    let logic = HyperLogLogBuilder::new(100)
+        .log_2_num_reg(4)
+        .build::<usize>()
+        .unwrap();
+    let array = SliceEstimatorArray::new(logic, 3);
+    array.range_merge_estimate(1, 4);
+}
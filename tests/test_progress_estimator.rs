@@ -0,0 +1,67 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, ProgressEstimator, ProgressEstimatorArray, SliceEstimatorArray},
+    traits::{EstimationLogic, Estimator, EstimatorArray, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_progress_estimator() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+
+    let mut reports = vec![];
+    let mut estimator =
+        ProgressEstimator::new(logic.new_estimator(), 1000, |n, e| reports.push((n, e)));
+    for x in 0..10_500 {
+        estimator.add(x);
+    }
+    assert_eq!(estimator.elements_added(), 10_500);
+    let estimate = estimator.estimate();
+    drop(estimator);
+    assert_eq!(reports.len(), 10);
+    for (i, &(n, _)) in reports.iter().enumerate() {
+        assert_eq!(n, (i + 1) * 1000);
+    }
+    assert!(reports.last().unwrap().1 <= estimate);
+
+    Ok(())
+}
+
+#[test]
+fn test_progress_estimator_array() -> Result<()> {
+    const N: usize = 1_000_000;
+    let logic = HyperLogLogBuilder::new(N)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+
+    let mut reports = vec![];
+    let mut array =
+        ProgressEstimatorArray::new(SliceEstimatorArray::new(logic, 10), 100_000, |n, e| {
+            reports.push((n, e))
+        });
+    for x in 0..N as u64 {
+        array.add(x as usize % 10, x);
+    }
+    assert_eq!(array.elements_added(), N);
+    let array = array.into_inner();
+    let sum: f64 = (0..10).map(|i| array.get_estimator(i).estimate()).sum();
+
+    assert_eq!(reports.len(), 10);
+    for (i, w) in reports.windows(2).enumerate() {
+        assert_eq!(w[0].0, (i + 1) * 100_000);
+        assert!(w[0].0 < w[1].0);
+    }
+    assert_eq!(reports.last().unwrap(), &(N, sum));
+
+    Ok(())
+}
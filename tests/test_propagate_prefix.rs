@@ -0,0 +1,66 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+    utils::propagate_prefix,
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const NUM_NODES: usize = 31;
+const PER_NODE: usize = 500;
+
+#[test]
+fn test_propagate_prefix() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(10)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    // A balanced binary tree with root 0
+    let parent = (0..NUM_NODES)
+        .map(|i| i.saturating_sub(1) / 2)
+        .collect::<Vec<_>>();
+
+    let mut array = SliceEstimatorArray::new(logic, NUM_NODES);
+    for node in 0..NUM_NODES {
+        for x in 0..PER_NODE {
+            array.get_estimator_mut(node).add(node * PER_NODE + x);
+        }
+    }
+
+    propagate_prefix(&mut array, &parent);
+
+    for node in 0..NUM_NODES {
+        // The number of nodes on the path to the root
+        let path_len = (usize::BITS - (node + 1).leading_zeros()) as usize;
+        let expected = (path_len * PER_NODE) as f64;
+        let estimate = array.get_estimator(node).estimate();
+        assert!(
+            (estimate - expected).abs() / expected < 0.15,
+            "node {}: estimate {} expected {}",
+            node,
+            estimate,
+            expected
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_propagate_prefix_cycle() {
+    let logic = HyperLogLogBuilder::new(100)
+        .log_2_num_reg(4)
+        .word_type::<u16>()
+        .build::<usize>()
+        .unwrap();
+    let mut array = SliceEstimatorArray::new(logic, 3);
+    propagate_prefix(&mut array, &[0, 2, 1]);
+}
@@ -0,0 +1,65 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 10;
+
+#[test]
+fn test_raw_parts() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let mut array = SliceEstimatorArray::new(logic, LEN);
+    for i in 0..LEN {
+        for x in 0..100 * i {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+    let estimates = array.iter().map(|e| e.estimate()).collect::<Vec<_>>();
+
+    // Round trip
+    let (logic, storage) = array.into_raw_parts();
+    let array = SliceEstimatorArray::from_raw_parts(logic.clone(), storage);
+    assert_eq!(
+        array.iter().map(|e| e.estimate()).collect::<Vec<_>>(),
+        estimates
+    );
+
+    // Borrowed storage
+    let (_, storage) = array.into_raw_parts();
+    let view = SliceEstimatorArray::from_raw_parts(logic.clone(), &storage[..]);
+    assert_eq!(view.len(), LEN);
+    for (i, &estimate) in estimates.iter().enumerate() {
+        assert_eq!(view.get_estimator(i).estimate(), estimate);
+    }
+
+    // A subset of the estimators
+    let backend_len = storage.len() / LEN;
+    let view = SliceEstimatorArray::from_raw_parts(logic, &storage[3 * backend_len..]);
+    assert_eq!(view.len(), LEN - 3);
+    assert_eq!(view.get_estimator(0).estimate(), estimates[3]);
+    Ok(())
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn test_from_raw_parts_wrong_length() {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()
+        .unwrap();
+    let storage = vec![0_usize; 7];
+    SliceEstimatorArray::from_raw_parts(logic, storage);
+}
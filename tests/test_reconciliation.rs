@@ -0,0 +1,75 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimatorArray, EstimatorArrayMut, EstimatorMut},
+    utils::{backend_checksums, differing_indices, reconcile_arrays},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 100;
+
+#[test]
+fn test_reconcile_arrays() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let mut a = SliceEstimatorArray::new(logic.clone(), LEN);
+    let mut b = SliceEstimatorArray::new(logic.clone(), LEN);
+    let mut expected = SliceEstimatorArray::new(logic.clone(), LEN);
+    for i in 0..LEN {
+        for x in 0..100 {
+            // Same data on both machines
+            a.get_estimator_mut(i).add(x);
+            b.get_estimator_mut(i).add(x);
+            expected.get_estimator_mut(i).add(x);
+        }
+        // Additional data on one of the two machines
+        if i % 5 == 0 {
+            a.get_estimator_mut(i).add(1000 + i);
+            expected.get_estimator_mut(i).add(1000 + i);
+        } else if i % 5 == 1 {
+            b.get_estimator_mut(i).add(2000 + i);
+            expected.get_estimator_mut(i).add(2000 + i);
+        }
+    }
+
+    let diff = differing_indices(&backend_checksums(&a), &backend_checksums(&b));
+    // Some additions might not change the registers
+    assert!(diff.len() <= 2 * LEN / 5);
+    assert!(diff.iter().all(|&i| i % 5 < 2));
+
+    let a_backends = diff
+        .iter()
+        .map(|&i| (i, a.get_backend(i).to_vec()))
+        .collect::<Vec<_>>();
+    let b_backends = diff
+        .iter()
+        .map(|&i| (i, b.get_backend(i).to_vec()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        reconcile_arrays(&mut a, b_backends.iter().map(|(i, v)| (*i, v.as_slice()))),
+        diff.len()
+    );
+    assert_eq!(
+        reconcile_arrays(&mut b, a_backends.iter().map(|(i, v)| (*i, v.as_slice()))),
+        diff.len()
+    );
+
+    assert_eq!(a.as_ref(), expected.as_ref());
+    assert_eq!(b.as_ref(), expected.as_ref());
+    // Reconciling again is a no-op
+    assert_eq!(
+        reconcile_arrays(&mut a, (0..LEN).map(|i| (i, expected.get_backend(i)))),
+        0
+    );
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLog, HyperLogLogBuilder},
+    traits::{EstimationLogic, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_register_histogram() -> Result<()> {
+    let log_2_num_registers = 8;
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(log_2_num_registers)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut estimator = logic.new_estimator();
+    estimator.clear();
+    let histogram = logic.register_histogram(estimator.as_ref());
+    assert_eq!(histogram.len(), 1 << HyperLogLog::register_size(1_000_000));
+    assert_eq!(histogram[0], 1 << log_2_num_registers);
+    assert_eq!(
+        logic.empty_register_count(estimator.as_ref()),
+        1 << log_2_num_registers
+    );
+    assert_eq!(logic.fill_factor(estimator.as_ref()), 0.0);
+
+    let mut previous = 0.0;
+    for n in [100, 1000, 10_000] {
+        for x in 0..n {
+            estimator.add(x);
+        }
+        let fill_factor = logic.fill_factor(estimator.as_ref());
+        assert!(fill_factor >= previous);
+        previous = fill_factor;
+
+        let histogram = logic.register_histogram(estimator.as_ref());
+        assert_eq!(histogram.iter().sum::<u64>(), 1 << log_2_num_registers);
+        assert_eq!(
+            histogram[0] as usize,
+            logic.empty_register_count(estimator.as_ref())
+        );
+        for (value, &count) in histogram.iter().enumerate() {
+            let expected = (0..logic.num_registers())
+                .filter(|&i| logic.get_register(estimator.as_ref(), i) == value)
+                .count();
+            assert_eq!(count as usize, expected);
+        }
+    }
+    assert!(previous > 0.99);
+    Ok(())
+}
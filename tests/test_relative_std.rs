@@ -0,0 +1,108 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HllConfig, HyperLogLog, HyperLogLogBuilder},
+    traits::{EstimationGuarantees, EstimationLogic, Estimator, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_relative_std() -> Result<()> {
+    for log_2_num_registers in 4..16 {
+        let logic = HyperLogLogBuilder::new(1000)
+            .log_2_num_reg(log_2_num_registers)
+            .word_type::<u16>()
+            .build_hasher(Xxh3Builder::new().with_seed(0))
+            .build::<u64>()?;
+        assert_eq!(
+            logic.relative_std(),
+            HyperLogLog::rel_std(log_2_num_registers)
+        );
+    }
+
+    let logic = HllConfig::<u64, _, 10, u8>::new(Xxh3Builder::new().with_seed(0));
+    assert_eq!(logic.relative_std(), HyperLogLog::rel_std(10));
+
+    Ok(())
+}
+
+#[test]
+fn test_num_registers_for_rsd() {
+    for log_2_num_registers in 4..20 {
+        let rsd = HyperLogLog::rel_std(log_2_num_registers);
+        assert_eq!(
+            HyperLogLog::num_registers_for_rsd(rsd),
+            1 << log_2_num_registers
+        );
+        assert_eq!(
+            HyperLogLog::num_registers_for_rsd(rsd * 0.99),
+            1 << (log_2_num_registers + 1)
+        );
+    }
+    // Large targets need the minimum number of registers
+    assert_eq!(HyperLogLog::num_registers_for_rsd(1.0), 16);
+}
+
+#[test]
+fn test_confidence_interval() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(10)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut estimator = logic.new_estimator();
+    for i in 0..10_000_u64 {
+        estimator.add(i);
+    }
+    let backend = estimator.as_ref();
+    let estimate = logic.estimate(backend);
+    let (lower, upper) = logic.confidence_interval(backend, 3.0);
+    let radius = 3.0 * logic.relative_std() * estimate;
+    assert!((lower - (estimate - radius)).abs() < 1E-9);
+    assert!((upper - (estimate + radius)).abs() < 1E-9);
+    assert!(lower <= 10_000.0 && 10_000.0 <= upper);
+
+    let (lower, _) = logic.confidence_interval(backend, 1000.0);
+    assert_eq!(lower, 0.0);
+
+    Ok(())
+}
+
+#[test]
+fn test_estimate_with_ci() -> Result<()> {
+    const TRIALS: u64 = 100;
+    const N: u64 = 10_000;
+    let z = HyperLogLog::NINETY_FIVE_PERCENT_Z;
+    let mut covered = 0;
+    for seed in 0..TRIALS {
+        let logic = HyperLogLogBuilder::new(100_000)
+            .log_2_num_reg(8)
+            .build_hasher(Xxh3Builder::new().with_seed(seed))
+            .build::<u64>()?;
+        let mut estimator = logic.new_estimator();
+        for i in 0..N {
+            estimator.add(i);
+        }
+        let (lower, upper) = estimator.estimate_with_ci(z);
+        assert_eq!(
+            (lower, upper),
+            logic.confidence_interval(estimator.as_ref(), z)
+        );
+        let standard_error = logic.standard_error(estimator.as_ref());
+        assert!((upper - lower - 2.0 * z * standard_error).abs() < 1E-9);
+        if lower <= N as f64 && N as f64 <= upper {
+            covered += 1;
+        }
+    }
+    assert!(
+        covered >= 90,
+        "only {} intervals out of {}",
+        covered,
+        TRIALS
+    );
+    Ok(())
+}
@@ -0,0 +1,74 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::impls::{HyperLogLog, HyperLogLogBuilder, ReservoirEstimator};
+use std::hash::{BuildHasher, BuildHasherDefault, DefaultHasher};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_reservoir_estimator_exact() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(10)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut estimator = ReservoirEstimator::<_, _, 32>::new(logic);
+    for x in 0..20 {
+        for _ in 0..=x {
+            estimator.add(x);
+        }
+    }
+    assert_eq!(estimator.evictions(), 0);
+    assert_eq!(estimator.estimate(), 20.0);
+    let top_k = estimator.top_k();
+    assert_eq!(top_k.len(), 20);
+    assert_eq!(top_k[0].1, 20);
+    assert_eq!(top_k[19].1, 1);
+    Ok(())
+}
+
+#[test]
+fn test_reservoir_estimator_long_tail() -> Result<()> {
+    const FREQUENT: u64 = 10;
+    const ROUNDS: u64 = 20_000;
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(12)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut estimator = ReservoirEstimator::<_, _, 32>::new(logic);
+
+    // The frequent elements 0..FREQUENT appear in every round, together
+    // with FREQUENT new elements.
+    let mut next = FREQUENT;
+    for _ in 0..ROUNDS {
+        for x in 0..FREQUENT {
+            estimator.add(x);
+        }
+        for _ in 0..FREQUENT {
+            estimator.add(next);
+            next += 1;
+        }
+    }
+
+    let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+    let mut expected = (0..FREQUENT)
+        .map(|x| (hash_builder.hash_one(x), ROUNDS))
+        .collect::<Vec<_>>();
+    expected.sort();
+    let mut top = estimator.top_k()[..FREQUENT as usize].to_vec();
+    top.sort();
+    assert_eq!(top, expected);
+
+    let n = next as f64;
+    let rel_error = (estimator.estimate() - n).abs() / n;
+    assert!(
+        rel_error < 4.0 * HyperLogLog::rel_std(12),
+        "estimate {} for {} elements",
+        estimator.estimate(),
+        n
+    );
+    Ok(())
+}
@@ -0,0 +1,47 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::HyperLogLogBuilder,
+    traits::{EstimationLogic, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_saturation() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(4)
+        .word_type::<u16>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    assert_eq!(logic.max_register_value(), 31);
+    assert_eq!(
+        logic.max_estimable_cardinality(),
+        2_f64.powi(31) * 16.0 / 30.0
+    );
+
+    let mut est = logic.new_estimator();
+    assert_eq!(logic.saturation_fraction(est.as_ref()), 0.0);
+    assert!(!logic.is_saturated(est.as_ref()));
+    for x in 0..100_000 {
+        est.add(x);
+    }
+    assert!(!logic.is_saturated(est.as_ref()));
+
+    let mut backend = est.as_ref().to_vec();
+    for i in 0..8 {
+        logic.set_register(&mut backend, i, 31);
+    }
+    assert_eq!(logic.saturation_fraction(&backend), 0.5);
+    for i in 8..16 {
+        logic.set_register(&mut backend, i, 31);
+    }
+    assert_eq!(logic.saturation_fraction(&backend), 1.0);
+    assert!(logic.is_saturated(&backend));
+    assert!(logic.estimate(&backend) > logic.max_estimable_cardinality());
+    Ok(())
+}
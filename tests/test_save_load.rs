@@ -0,0 +1,100 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{BiasCorrection, EstimationMode, HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use std::io;
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_save_load() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(10_000)
+        .log_2_num_reg(6)
+        .word_type::<u16>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .estimation_mode(EstimationMode::MaxLikelihood)
+        .bias_correction(BiasCorrection::LogLogBeta)
+        .build::<usize>()?;
+    let mut array = SliceEstimatorArray::new(logic.clone(), 10);
+    for i in 0..10 {
+        for x in 0..100 * i {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+
+    let mut logic_bytes = vec![];
+    logic.save_logic(&mut logic_bytes)?;
+    let mut array_bytes = vec![];
+    array.save(&mut array_bytes)?;
+
+    let loaded_logic = HyperLogLog::<usize, _, u16>::load_logic(
+        &logic_bytes[..],
+        Xxh3Builder::new().with_seed(0),
+    )?;
+    assert_eq!(
+        loaded_logic.log_2_num_registers(),
+        logic.log_2_num_registers()
+    );
+    assert_eq!(loaded_logic.num_elements(), logic.num_elements());
+    assert_eq!(
+        loaded_logic.linear_counting_threshold(),
+        logic.linear_counting_threshold()
+    );
+    assert_eq!(loaded_logic.estimation_mode(), logic.estimation_mode());
+    assert_eq!(loaded_logic.bias_correction(), logic.bias_correction());
+    let loaded = SliceEstimatorArray::load(loaded_logic, &array_bytes[..])?;
+    assert_eq!(loaded.len(), 10);
+    assert_eq!(loaded.as_ref(), array.as_ref());
+    for i in 0..10 {
+        assert_eq!(
+            loaded.get_estimator(i).estimate(),
+            array.get_estimator(i).estimate()
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_load_errors() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(6)
+        .word_type::<u16>()
+        .build::<usize>()?;
+    let mut bytes = vec![];
+    SliceEstimatorArray::new(logic.clone(), 10).save(&mut bytes)?;
+
+    // Wrong magic bytes
+    let mut wrong = bytes.clone();
+    wrong[0] = b'X';
+    let err = SliceEstimatorArray::load(logic.clone(), &wrong[..]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert!(err.to_string().contains("magic"), "{}", err);
+
+    // A logic is not an array
+    let mut logic_bytes = vec![];
+    logic.save_logic(&mut logic_bytes)?;
+    let err = SliceEstimatorArray::load(logic.clone(), &logic_bytes[..]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    let err = HyperLogLog::<usize, _, u16>::load_logic(&bytes[..], logic.build_hasher().clone())
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    // Wrong logic
+    let other_logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(8)
+        .word_type::<u16>()
+        .build::<usize>()?;
+    let err = SliceEstimatorArray::load(other_logic, &bytes[..]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    // Truncated data
+    let err = SliceEstimatorArray::load(logic, &bytes[..bytes.len() - 1]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    Ok(())
+}
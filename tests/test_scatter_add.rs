@@ -0,0 +1,129 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(not(target_arch = "wasm32"))]
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{
+        HyperLogLog, HyperLogLogBuilder, OwnedSyncSliceEstimatorArray, SharedEstimatorArray,
+        SliceEstimatorArray, SyncEstimatorArrayAtomic,
+    },
+    traits::{
+        EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut,
+        SyncEstimatorArray,
+    },
+};
+use common_traits::{CastableFrom, IntoAtomic, UpcastableInto};
+use sux::traits::Word;
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const THREADS: u64 = 16;
+const N: u64 = 160_000;
+
+fn test_scatter_add_atomic<W>() -> Result<()>
+where
+    W: Word + IntoAtomic + CastableFrom<u64> + UpcastableInto<u64>,
+{
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(10)
+        .word_type::<W>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    // Five-bit registers straddle word boundaries
+    assert_eq!(HyperLogLog::register_size(1_000_000), 5);
+    let array = OwnedSyncSliceEstimatorArray::new(logic.clone(), 3);
+    // All threads add their share to the same estimator
+    std::thread::scope(|s| {
+        for t in 0..THREADS {
+            let array = &array;
+            s.spawn(move || {
+                for x in t * N / THREADS..(t + 1) * N / THREADS {
+                    array.scatter_add_atomic(1, x);
+                }
+            });
+        }
+    });
+
+    let mut expected = logic.new_estimator();
+    for x in 0..N {
+        expected.add(x);
+    }
+    let array = array.into_array();
+    // Updates take the maximum, so no update can be lost
+    assert_eq!(array.get_backend(1), expected.as_ref());
+    assert_eq!(array.get_backend(0), logic.new_estimator().as_ref());
+    assert_eq!(array.get_backend(2), logic.new_estimator().as_ref());
+    let estimate = array.get_estimator(1).estimate();
+    let rsd = HyperLogLog::rel_std(10);
+    assert!((estimate - N as f64).abs() / (N as f64) < 2.0 * rsd);
+    Ok(())
+}
+
+#[test]
+fn test_scatter_add_atomic_u64() -> Result<()> {
+    test_scatter_add_atomic::<u64>()
+}
+
+#[test]
+fn test_scatter_add_atomic_u16() -> Result<()> {
+    test_scatter_add_atomic::<u16>()
+}
+
+/// Adds elements concurrently with [`SyncEstimatorArray::scatter_add`].
+///
+/// If `shared` is false, each thread adds to a different estimator.
+fn scatter_add<A: SyncEstimatorArray<HyperLogLog<u64, Xxh3Builder, usize>>>(
+    array: &A,
+    shared: bool,
+) {
+    std::thread::scope(|s| {
+        for t in 0..THREADS {
+            s.spawn(move || {
+                let index = if shared { 0 } else { t as usize };
+                for x in t * N / THREADS..(t + 1) * N / THREADS {
+                    // SAFETY: either each thread has its own estimator, or
+                    // the array supports concurrent additions
+                    unsafe { array.scatter_add(index, x) };
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn test_scatter_add_trait() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(10)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut expected = SliceEstimatorArray::new(logic.clone(), THREADS as usize);
+    let mut all = logic.new_estimator();
+    for t in 0..THREADS {
+        for x in t * N / THREADS..(t + 1) * N / THREADS {
+            expected.get_estimator_mut(t as usize).add(x);
+            all.add(x);
+        }
+    }
+
+    // The default implementation, one estimator per thread
+    let array = OwnedSyncSliceEstimatorArray::new(logic.clone(), THREADS as usize);
+    scatter_add(&array, false);
+    let array = array.into_array();
+    for i in 0..THREADS as usize {
+        assert_eq!(array.get_backend(i), expected.get_backend(i));
+    }
+
+    // The atomic and locked implementations, all threads on one estimator
+    let array = SyncEstimatorArrayAtomic::new(logic.clone(), 1);
+    scatter_add(&array, true);
+    assert_eq!(array.into_array().get_backend(0), all.as_ref());
+
+    let array = SharedEstimatorArray::new(logic.clone(), 1);
+    scatter_add(&array, true);
+    assert_eq!(array.into_inner().unwrap().get_backend(0), all.as_ref());
+    Ok(())
+}
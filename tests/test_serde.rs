@@ -0,0 +1,132 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "serde")]
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{BiasCorrection, EstimationMode, HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use serde::{Deserialize, Serialize};
+use std::hash::BuildHasher;
+use xxhash_rust::xxh3::Xxh3;
+
+/// A serializable seeded hasher.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct SeededXxh3 {
+    seed: u64,
+}
+
+impl BuildHasher for SeededXxh3 {
+    type Hasher = Xxh3;
+
+    fn build_hasher(&self) -> Xxh3 {
+        Xxh3::with_seed(self.seed)
+    }
+}
+
+type SerdeArray = SliceEstimatorArray<HyperLogLog<usize, SeededXxh3, usize>, usize, Box<[usize]>>;
+
+#[test]
+fn test_serde_logic() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .word_type::<u32>()
+        .build_hasher(SeededXxh3 { seed: 42 })
+        .estimation_mode(EstimationMode::MaxLikelihood)
+        .build::<u64>()?;
+
+    let json = serde_json::to_string(&logic)?;
+    assert!(json.contains("\"log_2_num_reg\":8"));
+    assert!(json.contains("\"num_elements\":1000000"));
+    assert!(json.contains("\"seed\":42"));
+    let deserialized: HyperLogLog<u64, SeededXxh3, u32> = serde_json::from_str(&json)?;
+    assert_eq!(deserialized, logic);
+
+    let mut estimator = logic.new_estimator();
+    for x in 0..10_000 {
+        estimator.add(x);
+    }
+    let backend_json = serde_json::to_string(&serde_json::to_value(estimator.as_ref())?)?;
+    let mut serializer = serde_json::Serializer::new(vec![]);
+    logic.serialize_backend(estimator.as_ref(), &mut serializer)?;
+    assert_eq!(String::from_utf8(serializer.into_inner())?, backend_json);
+
+    let mut deserializer = serde_json::Deserializer::from_str(&backend_json);
+    let backend = deserialized.deserialize_backend(&mut deserializer)?;
+    assert_eq!(deserialized.estimate(&backend), estimator.estimate());
+    Ok(())
+}
+
+#[test]
+fn test_serde_array() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(6)
+        .build_hasher(SeededXxh3 { seed: 0 })
+        .build::<usize>()?;
+    let mut array = SliceEstimatorArray::new(logic, 10);
+    for i in 0..10 {
+        for x in 0..100 * i {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+
+    let json = serde_json::to_string(&array)?;
+    let deserialized: SliceEstimatorArray<HyperLogLog<usize, SeededXxh3, usize>, usize, _> =
+        serde_json::from_str(&json)?;
+    assert_eq!(deserialized.len(), array.len());
+    for i in 0..10 {
+        assert_eq!(
+            deserialized.get_estimator(i).estimate(),
+            array.get_estimator(i).estimate()
+        );
+    }
+
+    // The number of words must be a multiple of the backend length
+    let mut value = serde_json::to_value(&array)?;
+    value["backend"].as_array_mut().unwrap().pop();
+    assert!(serde_json::from_value::<
+        SliceEstimatorArray<HyperLogLog<usize, SeededXxh3, usize>, usize, Box<[usize]>>,
+    >(value)
+    .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_serde_bincode() -> Result<()> {
+    let config = bincode::config::standard();
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(6)
+        .build_hasher(SeededXxh3 { seed: 0 })
+        .bias_correction(BiasCorrection::LogLogBeta)
+        .build::<usize>()?;
+
+    let bytes = bincode::serde::encode_to_vec(&logic, config)?;
+    let (deserialized, len): (HyperLogLog<usize, SeededXxh3, usize>, _) =
+        bincode::serde::decode_from_slice(&bytes, config)?;
+    assert_eq!(len, bytes.len());
+    assert_eq!(deserialized, logic);
+
+    let mut array = SliceEstimatorArray::new(logic, 10);
+    for i in 0..10 {
+        for x in 0..100 * i {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+    let bytes = bincode::serde::encode_to_vec(&array, config)?;
+    let (deserialized, len): (SerdeArray, _) = bincode::serde::decode_from_slice(&bytes, config)?;
+    assert_eq!(len, bytes.len());
+    assert_eq!(deserialized.len(), array.len());
+    for i in 0..10 {
+        assert_eq!(deserialized.get_backend(i), array.get_backend(i));
+        assert_eq!(
+            deserialized.get_estimator(i).estimate(),
+            array.get_estimator(i).estimate()
+        );
+    }
+    Ok(())
+}
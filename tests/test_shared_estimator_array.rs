@@ -0,0 +1,90 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(not(target_arch = "wasm32"))]
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{
+        DefaultEstimator, HyperLogLog, HyperLogLogBuilder, SharedEstimatorArray,
+        SliceEstimatorArray,
+    },
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut, SyncEstimatorArray},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+type Logic = HyperLogLog<u64, Xxh3Builder, usize>;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_send_sync() {
+    assert_send_sync::<SliceEstimatorArray<Logic, usize, Box<[usize]>>>();
+    assert_send_sync::<SliceEstimatorArray<Logic, usize, &mut [usize]>>();
+    assert_send_sync::<DefaultEstimator<Logic, &Logic, Box<[usize]>>>();
+    assert_send_sync::<DefaultEstimator<Logic, Logic, &mut [usize]>>();
+    assert_send_sync::<SharedEstimatorArray<Logic, usize>>();
+}
+
+#[test]
+fn test_send_sync_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/slice_array_not_send.rs");
+}
+
+#[test]
+fn test_shared_estimator_array() -> Result<()> {
+    const THREADS: u64 = 4;
+    const N: u64 = 10_000;
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+
+    let array = SharedEstimatorArray::new(logic.clone(), THREADS as usize + 1);
+    assert_eq!(array.len(), THREADS as usize + 1);
+    // Each thread adds to its own estimator and to the last one
+    let handles = (0..THREADS)
+        .map(|t| {
+            let array = array.clone();
+            std::thread::spawn(move || {
+                for x in 0..N {
+                    array.add(t as usize, t * N + x);
+                    array.add(THREADS as usize, t * N + x);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let mut expected = SliceEstimatorArray::new(logic.clone(), THREADS as usize + 1);
+    for t in 0..THREADS {
+        for x in 0..N {
+            expected.get_estimator_mut(t as usize).add(t * N + x);
+            expected.get_estimator_mut(THREADS as usize).add(t * N + x);
+        }
+    }
+    for i in 0..=THREADS as usize {
+        assert_eq!(array.estimate(i), expected.get_estimator(i).estimate());
+    }
+
+    let mut backend = vec![0; expected.get_backend(0).len()];
+    unsafe { array.get(THREADS as usize, &mut backend) };
+    assert_eq!(backend.as_slice(), expected.get_backend(THREADS as usize));
+    unsafe { array.set(0, &backend) };
+    assert_eq!(array.estimate(0), array.estimate(THREADS as usize));
+    unsafe { array.clear() };
+    assert_eq!(array.estimate(0), 0.0);
+
+    // There is another reference to the array
+    let other = array.clone();
+    assert!(other.into_inner().is_none());
+    let array = array.into_inner().unwrap();
+    assert_eq!(array.len(), THREADS as usize + 1);
+    Ok(())
+}
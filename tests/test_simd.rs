@@ -0,0 +1,47 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(feature = "simd")]
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{BiasCorrection, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{EstimationLogic, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_estimate_scalar() -> Result<()> {
+    for log_2_num_reg in [4, 5, 8, 12] {
+        for bias_correction in [BiasCorrection::Standard, BiasCorrection::LogLogBeta] {
+            let logic = HyperLogLogBuilder::new(1_000_000)
+                .log_2_num_reg(log_2_num_reg)
+                .word_type::<u8>()
+                .bias_correction(bias_correction)
+                .build_hasher(Xxh3Builder::new().with_seed(0))
+                .build::<usize>()?;
+            let sizes = [0, 1, 10, 100, 1000, 10_000, 100_000, 1_000_000];
+            let mut array = SliceEstimatorArray::new(logic.clone(), sizes.len());
+            for (i, &size) in sizes.iter().enumerate() {
+                for x in 0..size {
+                    array.get_estimator_mut(i).add(x);
+                }
+            }
+            for (i, size) in sizes.into_iter().enumerate() {
+                let backend = array.get_backend(i);
+                assert_eq!(
+                    logic.estimate(backend),
+                    logic.estimate_scalar(backend),
+                    "log_2_num_reg = {}, {:?}, {} elements",
+                    log_2_num_reg,
+                    bias_correction,
+                    size
+                );
+            }
+        }
+    }
+    Ok(())
+}
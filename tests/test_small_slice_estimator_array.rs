@@ -0,0 +1,118 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{
+        HllConfig, HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray, SmallSliceEstimatorArray,
+    },
+    traits::{
+        EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut,
+        MergeEstimator, SliceEstimationLogic,
+    },
+};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use xxhash_rust::xxh3::Xxh3Builder;
+
+/// An allocator counting the allocations of the current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+#[test]
+fn test_small_slice_estimator_array() -> Result<()> {
+    let logic: HyperLogLog<usize, _, u8> = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(12)
+        .word_type::<u8>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build()?;
+    let mut expected = SliceEstimatorArray::new(logic.clone(), 4);
+    for i in 0..4 {
+        for x in 0..100 * (i + 1) {
+            expected.get_estimator_mut(i).add(x);
+        }
+    }
+
+    // Cloning the logic allocates
+    let small_logic = logic.clone();
+    let before = allocations();
+    let mut array = SmallSliceEstimatorArray::<_, u8, 4, 4096>::new(small_logic);
+    // The backends are stored inline
+    assert!(std::mem::size_of_val(&array) >= 4 * 4096);
+    let address = &array as *const _ as usize;
+    let local = 0_u8;
+    assert!(address.abs_diff(&local as *const _ as usize) < 1 << 20);
+
+    for i in 0..4 {
+        for x in 0..100 * (i + 1) {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+    let mut estimates = [0.0; 4];
+    for (i, estimate) in estimates.iter_mut().enumerate() {
+        *estimate = array.get_estimator(i).estimate();
+    }
+    let (left, right) = (array.get_backend(0).len(), array.get_backend(1).len());
+    assert_eq!(allocations(), before);
+
+    assert_eq!(left, logic.backend_len());
+    assert_eq!(right, logic.backend_len());
+    assert_eq!(array.len(), 4);
+    for (i, &estimate) in estimates.iter().enumerate() {
+        assert_eq!(array.get_backend(i), expected.get_backend(i));
+        assert_eq!(estimate, expected.get_estimator(i).estimate());
+    }
+
+    let mut union = logic.new_estimator();
+    union.set(array.get_backend(0));
+    union.merge(array.get_backend(3));
+    assert_eq!(union.as_ref(), array.get_backend(3));
+
+    array.clear();
+    assert_eq!(array.arithmetic_mean_estimate(), 0.0);
+
+    Ok(())
+}
+
+#[test]
+fn test_small_slice_estimator_array_hll_config() {
+    let logic = HllConfig::<u64, _, 8, u8>::new(Xxh3Builder::new().with_seed(0));
+    let mut array = SmallSliceEstimatorArray::<_, u8, 2, { 1 << 8 }>::new(logic);
+    let before = allocations();
+    for x in 0..1000_u64 {
+        array.get_estimator_mut((x % 2) as usize).add(x);
+    }
+    assert_eq!(allocations(), before);
+    assert!((array.get_estimator(0).estimate() - 500.0).abs() < 100.0);
+}
+
+#[test]
+#[should_panic]
+fn test_small_slice_estimator_array_too_small() {
+    let logic = HllConfig::<u64, _, 8, u8>::new(Xxh3Builder::new().with_seed(0));
+    let _ = SmallSliceEstimatorArray::<_, u8, 2, 128>::new(logic);
+}
@@ -0,0 +1,145 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{export_spark_sketch, import_spark_sketch, SparkCompatError, SparkCompatHyperLogLog},
+    traits::{EstimationLogic, Estimator, EstimatorMut, MergeEstimator, SliceEstimationLogic},
+};
+use xxhash_rust::xxh64::Xxh64Builder;
+
+#[test]
+fn test_spark_precision() -> Result<()> {
+    // Spark's default relative standard deviation
+    let logic = SparkCompatHyperLogLog::<u64, _>::new(0.05, Xxh64Builder::new(42))?;
+    assert_eq!(logic.log_2_num_registers(), 9);
+    assert_eq!(logic.backend_len(), 512);
+    let logic = SparkCompatHyperLogLog::<u64, _>::new(0.01, Xxh64Builder::new(42))?;
+    assert_eq!(logic.log_2_num_registers(), 14);
+
+    assert!(SparkCompatHyperLogLog::<u64, _>::new(0.5, Xxh64Builder::new(42)).is_err());
+    assert!(SparkCompatHyperLogLog::<u64, _>::new(0.0001, Xxh64Builder::new(42)).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_spark_estimate() -> Result<()> {
+    for rsd in [0.05, 0.02, 0.01] {
+        let logic = SparkCompatHyperLogLog::<u64, _>::new(rsd, Xxh64Builder::new(42))?;
+        for n in [0, 1, 10, 100, 1000, 10_000, 100_000] {
+            let mut estimator = logic.new_estimator();
+            for i in 0..n {
+                estimator.add(i);
+                estimator.add(i);
+            }
+            let estimate = estimator.estimate();
+            assert_eq!(estimate, estimate.round());
+            assert!(
+                (estimate - n as f64).abs() <= 4.0 * rsd * n as f64 + 1.0,
+                "rsd {}: estimate {}, expected {}",
+                rsd,
+                estimate,
+                n
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Sketches in Spark's representation together with their estimates. The raw
+/// estimates of all sketches are smaller than five times the number of
+/// registers, and above the linear-counting threshold, so the estimates are
+/// bias corrected.
+///
+/// These are not dumps from a Spark run: the sketches were built outside of
+/// Spark in its register layout, and the expected estimates were computed by
+/// a line-by-line transliteration of `HyperLogLogPlusPlusHelper.query`. They
+/// should be replaced by `approx_count_distinct` buffers dumped from a real
+/// Spark job, with the Spark version recorded here.
+const SPARK_SKETCHES: [(&str, f64); 6] = [
+    ("41300840300441008010088300000000", 23.0),
+    ("012208410110420088100c4a00000000", 36.0),
+    ("0241180311048200c310104100000000", 45.0),
+    (
+        "08600c4401084200032018021000800001000041110481000400040121044501\
+         404010831008010102101441101004004040000000000000",
+        89.0,
+    ),
+    (
+        "833000832008820182300c842008c1008300100510108100c40014414008c300\
+         83010cc410088300422108c4100442014230080000000000",
+        164.0,
+    ),
+    (
+        "863008c620104702c03010c230084401c3401084211c44018240088321048300\
+         833014831024840043000c035220c0000151040000000000",
+        241.0,
+    ),
+];
+
+fn from_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_spark_bias_correction() -> Result<()> {
+    for (hex, expected) in SPARK_SKETCHES {
+        let sketch = from_hex(hex);
+        let backend = import_spark_sketch(&sketch)?;
+        let log_2_num_registers = backend.len().ilog2() as usize;
+        let logic = SparkCompatHyperLogLog::<u64, _>::with_log_2_num_registers(
+            log_2_num_registers,
+            Xxh64Builder::new(42),
+        )?;
+        assert_eq!(logic.estimate(&backend), expected, "sketch {}", hex);
+        assert_eq!(export_spark_sketch(&logic, &backend), sketch);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_spark_import_export() -> Result<()> {
+    let logic = SparkCompatHyperLogLog::<u64, _>::new(0.05, Xxh64Builder::new(42))?;
+    let mut a = logic.new_estimator();
+    let mut b = logic.new_estimator();
+    for i in 0..5000 {
+        a.add(i);
+        b.add(i + 2500);
+    }
+
+    let sketch = export_spark_sketch(&logic, a.as_ref());
+    // 512 registers, 10 per 64-bit word
+    assert_eq!(sketch.len(), (512 / 10 + 1) * 8);
+    assert_eq!(import_spark_sketch(&sketch)?.as_ref(), a.as_ref());
+
+    a.merge(b.as_ref());
+    let estimate = a.estimate();
+    assert!((estimate - 7500.0).abs() < 4.0 * 0.05 * 7500.0);
+    let merged = import_spark_sketch(&export_spark_sketch(&logic, a.as_ref()))?;
+    assert_eq!(logic.estimate(&merged), estimate);
+
+    assert_eq!(
+        import_spark_sketch(&sketch[1..]),
+        Err(SparkCompatError::InvalidLength(sketch.len() - 1))
+    );
+    assert_eq!(
+        import_spark_sketch(&sketch[8..]),
+        Err(SparkCompatError::InvalidNumWords(51))
+    );
+    let mut invalid = sketch.clone();
+    invalid[0] |= 0x3F;
+    assert_eq!(
+        import_spark_sketch(&invalid),
+        Err(SparkCompatError::InvalidRegisterValue {
+            register: 0,
+            value: 0x3F
+        })
+    );
+    Ok(())
+}
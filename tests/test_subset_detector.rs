@@ -0,0 +1,45 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray, SubsetDetector},
+    traits::{EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_subset_detector() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(10)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let mut array = SliceEstimatorArray::new(logic, 4);
+    for x in 0..10_000 {
+        // 0 and 1 are identical
+        array.get_estimator_mut(0).add(x);
+        array.get_estimator_mut(1).add(x);
+        // 2 is disjoint from 0 and 1
+        array.get_estimator_mut(2).add(x + 10_000);
+        // 3 contains 0 and 1
+        array.get_estimator_mut(3).add(x);
+        array.get_estimator_mut(3).add(x + 20_000);
+    }
+
+    let detector = SubsetDetector::new(&array);
+    assert_eq!(detector.is_probable_subset(0, 1), 1.0);
+    assert_eq!(detector.is_probable_subset(1, 0), 1.0);
+    assert_eq!(detector.is_probable_subset(0, 3), 1.0);
+    assert!(detector.is_probable_subset(3, 0) < 0.9);
+    let disjoint = detector.is_probable_subset(0, 2);
+    assert!(disjoint > 0.4 && disjoint < 0.8, "{}", disjoint);
+
+    assert_eq!(detector.find_supersets(0, 1.0), vec![1, 3]);
+    assert_eq!(detector.find_supersets(3, 1.0), Vec::<usize>::new());
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_summary() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let len = 11;
+    let mut array = SliceEstimatorArray::new(logic, len);
+    // Estimator 0 is left empty
+    for i in 1..len {
+        for x in 0..100 * i {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+    let estimates = (0..len)
+        .map(|i| array.get_estimator(i).estimate())
+        .collect::<Vec<_>>();
+
+    let summary = array.summary();
+    assert_eq!(summary.len, len);
+    assert_eq!(summary.non_empty_count, len - 1);
+    assert_eq!(summary.min_estimate, 0.0);
+    assert_eq!(
+        summary.max_estimate,
+        estimates.iter().copied().fold(0.0, f64::max)
+    );
+    let mean = estimates.iter().sum::<f64>() / len as f64;
+    assert!((summary.mean_estimate - mean).abs() < 1E-9);
+    let mut sorted = estimates.clone();
+    sorted.sort_by(f64::total_cmp);
+    assert_eq!(summary.median_estimate, sorted[len / 2]);
+    let variance = estimates
+        .iter()
+        .map(|e| (e - mean) * (e - mean))
+        .sum::<f64>()
+        / len as f64;
+    assert!((summary.std_dev_estimate - variance.sqrt()).abs() < 1E-9);
+    assert_eq!(
+        summary.backend_memory_bytes,
+        std::mem::size_of_val(array.as_ref())
+    );
+
+    let report = summary.to_string();
+    assert_eq!(report.lines().count(), 8);
+    assert!(report.contains("Estimators:      11"));
+
+    let verbose = array.summary_verbose();
+    assert!(verbose.starts_with(&report));
+    assert!(verbose.contains("Histogram:"));
+    assert_eq!(verbose.lines().count(), 8 + 1 + 10);
+    Ok(())
+}
+
+#[test]
+fn test_summary_empty() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+
+    let array = SliceEstimatorArray::new(logic.clone(), 0);
+    let summary = array.summary();
+    assert_eq!(summary.len, 0);
+    assert_eq!(summary.mean_estimate, 0.0);
+    assert_eq!(array.summary_verbose(), summary.to_string());
+
+    let array = SliceEstimatorArray::new(logic, 5);
+    let summary = array.summary();
+    assert_eq!(summary.non_empty_count, 0);
+    assert_eq!(summary.std_dev_estimate, 0.0);
+    assert!(array.summary_verbose().contains("Histogram:"));
+    Ok(())
+}
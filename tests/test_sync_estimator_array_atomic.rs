@@ -0,0 +1,161 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+#![cfg(not(target_arch = "wasm32"))]
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray, SyncEstimatorArrayAtomic},
+    traits::{
+        EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut,
+        SyncEstimatorArray,
+    },
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_sync_estimator_array_atomic() -> Result<()> {
+    const THREADS: u64 = 8;
+    const N: u64 = 100_000;
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(10)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+
+    let array = SyncEstimatorArrayAtomic::new(logic.clone(), 2);
+    assert_eq!(array.len(), 2);
+    // All threads add overlapping ranges to estimator 0, and disjoint ranges
+    // to estimator 1
+    std::thread::scope(|s| {
+        for t in 0..THREADS {
+            let array = &array;
+            s.spawn(move || {
+                for x in 0..N {
+                    array.add(0, x);
+                    array.add(1, t * N + x);
+                }
+            });
+        }
+    });
+
+    let mut expected = SliceEstimatorArray::new(logic.clone(), 2);
+    for x in 0..N {
+        expected.get_estimator_mut(0).add(x);
+    }
+    for x in 0..THREADS * N {
+        expected.get_estimator_mut(1).add(x);
+    }
+
+    assert_eq!(array.estimate(0), expected.get_estimator(0).estimate());
+    assert_eq!(array.estimate(1), expected.get_estimator(1).estimate());
+    let rsd = HyperLogLog::rel_std(10);
+    let n = (THREADS * N) as f64;
+    assert!((array.estimate(1) - n).abs() / n < 4.0 * rsd);
+
+    let mut backend = vec![0; expected.get_backend(0).len()];
+    unsafe { array.get(1, &mut backend) };
+    assert_eq!(backend.as_slice(), expected.get_backend(1));
+    unsafe { array.set(0, expected.get_backend(1)) };
+    assert_eq!(array.estimate(0), array.estimate(1));
+
+    let array = array.into_array();
+    assert_eq!(array.get_backend(1), expected.get_backend(1));
+
+    Ok(())
+}
+
+#[test]
+fn test_sync_estimator_array_atomic_packed() -> Result<()> {
+    const THREADS: u64 = 8;
+    const N: u64 = 100_000;
+    // Five-bit registers in 16-bit words often straddle two words
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .word_type::<u16>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+
+    let array = SyncEstimatorArrayAtomic::new(logic.clone(), 1);
+    std::thread::scope(|s| {
+        for t in 0..THREADS {
+            let array = &array;
+            s.spawn(move || {
+                for x in (t..N).step_by(THREADS as usize) {
+                    array.add(0, x);
+                }
+            });
+        }
+    });
+
+    let mut expected = SliceEstimatorArray::new(logic, 1);
+    for x in 0..N {
+        expected.get_estimator_mut(0).add(x);
+    }
+    let array = array.into_array();
+    // Registers are packed as in a standard backend
+    assert_eq!(array.as_ref(), expected.as_ref());
+
+    Ok(())
+}
+
+#[test]
+fn test_sync_estimator_array_atomic_concurrent_reads() -> Result<()> {
+    const THREADS: u64 = 4;
+    const N: u64 = 100_000;
+    // Five-bit registers in 16-bit words often straddle two words
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .word_type::<u16>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut expected = logic.new_estimator();
+    for x in 0..N {
+        expected.add(x);
+    }
+
+    let array = SyncEstimatorArrayAtomic::new(logic.clone(), 1);
+    let done = AtomicBool::new(false);
+    std::thread::scope(|s| {
+        let writers = (0..THREADS)
+            .map(|t| {
+                let array = &array;
+                s.spawn(move || {
+                    for x in (t..N).step_by(THREADS as usize) {
+                        array.add(0, x);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        let reader = s.spawn(|| {
+            let mut previous = logic.new_estimator();
+            let mut current = logic.new_estimator();
+            while !done.load(Ordering::Relaxed) {
+                // SAFETY: get only reads the backend
+                unsafe { array.get(0, current.as_mut()) };
+                // Registers can only grow, up to their final value
+                for i in 0..logic.num_registers() {
+                    let value = logic.get_register(current.as_ref(), i);
+                    assert!(value >= logic.get_register(previous.as_ref(), i));
+                    assert!(value <= logic.get_register(expected.as_ref(), i));
+                }
+                previous.set(current.as_ref());
+            }
+        });
+        for writer in writers {
+            writer.join().unwrap();
+        }
+        done.store(true, Ordering::Relaxed);
+        reader.join().unwrap();
+    });
+    let mut backend = logic.new_estimator();
+    // SAFETY: no other thread is accessing the array
+    unsafe { array.get(0, backend.as_mut()) };
+    assert_eq!(backend.as_ref(), expected.as_ref());
+    assert_eq!(array.into_array().get_backend(0), expected.as_ref());
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{SliceEstimatorArray, ThetaSketchBuilder},
+    traits::{
+        EstimationGuarantees, EstimationLogic, Estimator, EstimatorArray, EstimatorArrayMut,
+        EstimatorMut, MergeEstimationLogic, MergeEstimator, SliceEstimationLogic,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+#[test]
+fn test_theta_sketch_estimate() -> Result<()> {
+    for k in [16, 64, 256, 1024] {
+        let logic = ThetaSketchBuilder::new()
+            .k(k)
+            .build_hasher(Xxh3Builder::new().with_seed(0))
+            .build::<u64>()?;
+        assert_eq!(logic.backend_len(), k + 1);
+        let mut estimator = logic.new_estimator();
+        assert_eq!(estimator.estimate(), 0.0);
+        let mut n = 0;
+        for target in [10, 100, 1000, 10_000, 100_000] {
+            while n < target {
+                estimator.add(n);
+                estimator.add(n);
+                n += 1;
+            }
+            if (n as usize) <= k {
+                assert_eq!(estimator.estimate(), n as f64);
+                assert_eq!(logic.theta(estimator.as_ref()), 1.0);
+            } else {
+                assert_eq!(logic.num_retained(estimator.as_ref()), k);
+            }
+            let rel_error = (estimator.estimate() - n as f64).abs() / n as f64;
+            assert!(
+                rel_error < 4.0 * logic.relative_std(),
+                "k = {}: estimate {} for {} elements",
+                k,
+                estimator.estimate(),
+                n
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_theta_sketch_set_operations() -> Result<()> {
+    let n = 10_000_u64;
+    for k in [64, 256, 1024] {
+        let logic = ThetaSketchBuilder::new()
+            .k(k)
+            .build_hasher(Xxh3Builder::new().with_seed(0))
+            .build::<u64>()?;
+        let rsd = logic.relative_std();
+        for overlap in [0, 1000, 5000, 9000, 10_000] {
+            // A = [0..n), B = [n - overlap..2n - overlap)
+            let mut a = logic.new_estimator();
+            let mut b = logic.new_estimator();
+            for x in 0..n {
+                a.add(x);
+                b.add(n - overlap + x);
+            }
+            let (a, b) = (a.as_ref(), b.as_ref());
+
+            let union = (2 * n - overlap) as f64;
+            let estimate = logic.estimate_union_exact(a, b);
+            assert!(
+                (estimate - union).abs() / union < 4.0 * rsd,
+                "k = {}: union estimate {}, expected {}",
+                k,
+                estimate,
+                union
+            );
+            assert_eq!(logic.estimate_union(a, b), estimate);
+
+            let intersection = logic.estimate_intersection_exact(a, b);
+            assert!(intersection >= 0.0);
+            assert!(
+                (intersection - overlap as f64).abs() <= 4.0 * rsd * union,
+                "k = {}: intersection estimate {}, expected {}",
+                k,
+                intersection,
+                overlap
+            );
+            if overlap == 0 {
+                assert_eq!(intersection, 0.0);
+            }
+
+            let expected = overlap as f64 / union;
+            let jaccard = logic.estimate_jaccard_exact(a, b);
+            assert!(
+                (jaccard - expected).abs() <= 1.0 / (k as f64).sqrt(),
+                "k = {}: estimated Jaccard similarity {}, expected {}",
+                k,
+                jaccard,
+                expected
+            );
+            assert_eq!(logic.estimate_jaccard(a, b), jaccard);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_theta_sketch_merge() -> Result<()> {
+    let logic = ThetaSketchBuilder::new()
+        .k(64)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut array = SliceEstimatorArray::new(logic.clone(), 3);
+    for x in 0..1000 {
+        array.get_estimator_mut((x % 2) as usize).add(x);
+        array.get_estimator_mut(2).add(x);
+    }
+    let mut union = array.get_estimator(0).into_owned();
+    union.merge(array.get_backend(1));
+    assert_eq!(union.as_ref(), array.get_backend(2));
+    assert_eq!(
+        logic.estimate_union_exact(array.get_backend(0), array.get_backend(1)),
+        union.estimate()
+    );
+    assert_eq!(
+        logic.estimate_jaccard_exact(union.as_ref(), array.get_backend(2)),
+        1.0
+    );
+
+    // Merging sketches that are not full
+    let mut a = logic.new_estimator();
+    let mut b = logic.new_estimator();
+    for x in 0..20 {
+        a.add(x);
+        b.add(x + 10);
+    }
+    assert_eq!(
+        logic.estimate_intersection_exact(a.as_ref(), b.as_ref()),
+        10.0
+    );
+    a.merge(b.as_ref());
+    assert_eq!(a.estimate(), 30.0);
+
+    let empty = logic.new_estimator();
+    assert_eq!(
+        logic.estimate_jaccard_exact(empty.as_ref(), empty.as_ref()),
+        1.0
+    );
+    assert!(ThetaSketchBuilder::new().k(0).build::<u64>().is_err());
+    Ok(())
+}
@@ -0,0 +1,77 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HllConfig, HyperLogLogBuilder, SliceEstimatorArray, SmallSliceEstimatorArray},
+    traits::{EstimationLogic, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+/// The number of elements added to each estimator.
+const SIZES: [u64; 8] = [300, 10, 5000, 0, 1000, 20_000, 50, 2000];
+
+#[test]
+fn test_top_k_by_estimate() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut array = SliceEstimatorArray::new(logic, SIZES.len());
+    for (i, &size) in SIZES.iter().enumerate() {
+        for x in 0..size {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+
+    assert_eq!(array.top_k_by_estimate(3), vec![5, 2, 7]);
+    assert_eq!(array.top_k_by_estimate(0), Vec::<usize>::new());
+    assert_eq!(array.top_k_by_estimate(100).len(), SIZES.len());
+    assert_eq!(
+        array.top_k_by_estimate(SIZES.len()),
+        array.argsort_by_estimate_desc()
+    );
+    assert_eq!(array.max_estimate_index(), Some(5));
+    assert_eq!(array.min_estimate_index(), Some(3));
+
+    let empty = SliceEstimatorArray::new(array.logic().clone(), 0);
+    assert_eq!(empty.top_k_by_estimate(3), Vec::<usize>::new());
+    assert_eq!(empty.max_estimate_index(), None);
+    assert_eq!(empty.min_estimate_index(), None);
+    Ok(())
+}
+
+#[test]
+fn test_top_k_by_estimate_default() {
+    // SmallSliceEstimatorArray uses the default implementations
+    let logic = HllConfig::<u64, _, 8, u8>::new(Xxh3Builder::new().with_seed(0));
+    let mut array = SmallSliceEstimatorArray::<_, u8, { SIZES.len() }, { 1 << 8 }>::new(logic);
+    for (i, &size) in SIZES.iter().enumerate() {
+        for x in 0..size {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+    assert_eq!(array.top_k_by_estimate(3), vec![5, 2, 7]);
+    assert_eq!(array.max_estimate_index(), Some(5));
+    assert_eq!(array.min_estimate_index(), Some(3));
+}
+
+#[test]
+fn test_top_k_by_estimate_ties() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut array = SliceEstimatorArray::new(logic, 5);
+    for i in [1, 3] {
+        array.get_estimator_mut(i).add(0);
+    }
+    assert_eq!(array.top_k_by_estimate(3), vec![1, 3, 0]);
+    assert_eq!(array.max_estimate_index(), Some(1));
+    assert_eq!(array.min_estimate_index(), Some(0));
+    assert_eq!(array.logic().estimate(array.get_backend(2)), 0.0);
+    Ok(())
+}
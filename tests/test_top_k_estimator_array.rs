@@ -0,0 +1,72 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLog, HyperLogLogBuilder, TopKEstimatorArray},
+    traits::{Estimator, EstimatorArray},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 10_000;
+const K: usize = 10;
+
+/// The ground-truth cardinality of the estimator at a given index: most
+/// estimators are small, and ten of them are much larger than the others.
+fn cardinality(index: usize) -> usize {
+    if index % 997 == 13 {
+        2000 + index
+    } else {
+        index % 50
+    }
+}
+
+#[test]
+fn test_top_k_estimator_array() -> Result<()> {
+    let log_2_num_registers = 10;
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(log_2_num_registers)
+        .word_type::<u16>()
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let rsd = HyperLogLog::rel_std(log_2_num_registers);
+
+    let mut top = TopKEstimatorArray::<_, _, K>::new(logic, LEN);
+    // Before any addition, the top estimators are the first ones
+    assert_eq!(
+        top.top_k().map(|(index, _)| index),
+        std::array::from_fn(|i| i)
+    );
+
+    // Interleave additions to different estimators
+    let max_cardinality = (0..LEN).map(cardinality).max().unwrap();
+    for x in 0..max_cardinality {
+        for index in 0..LEN {
+            if x < cardinality(index) {
+                top.add(index, x);
+            }
+        }
+    }
+
+    let mut expected = (0..LEN).collect::<Vec<_>>();
+    expected.sort_by_key(|&index| std::cmp::Reverse(cardinality(index)));
+    let mut expected_top = expected[..K].to_vec();
+    expected_top.sort();
+
+    let top_k = top.top_k();
+    let mut top_indices = top_k.map(|(index, _)| index).to_vec();
+    top_indices.sort();
+    assert_eq!(top_indices, expected_top);
+    for (i, &(index, estimate)) in top_k.iter().enumerate() {
+        let expected = cardinality(index) as f64;
+        assert!((estimate - expected).abs() <= 2.0 * rsd * expected);
+        assert_eq!(estimate, top.array().get_estimator(index).estimate());
+        if i > 0 {
+            assert!(estimate <= top_k[i - 1].1);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,110 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, TruncatedHyperLogLog},
+    traits::{
+        EstimationGuarantees, EstimationLogic, Estimator, EstimatorMut, MergeEstimator,
+        SliceEstimationLogic,
+    },
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+/// Returns the root-mean-square relative error of the given logic on `trials`
+/// disjoint sets of `n` elements.
+fn rms_error<L>(logic: &L, n: u64, trials: u64) -> f64
+where
+    L: EstimationLogic<Item = u64>,
+{
+    let mut sum = 0.0;
+    for t in 0..trials {
+        let mut estimator = logic.new_estimator();
+        for x in 0..n {
+            estimator.add(t * n + x);
+        }
+        let rel_error = (estimator.estimate() - n as f64) / n as f64;
+        sum += rel_error * rel_error;
+    }
+    (sum / trials as f64).sqrt()
+}
+
+#[test]
+fn test_truncated_memory() -> Result<()> {
+    let truncated = TruncatedHyperLogLog::<u64, _, u16, 4>::new(8, Xxh3Builder::new())?;
+    let standard = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .word_type::<u16>()
+        .build_hasher(Xxh3Builder::new())
+        .build::<u64>()?;
+    assert_eq!(truncated.backend_len(), (256 * 4_usize).div_ceil(16));
+    assert!(truncated.backend_len() < standard.backend_len());
+
+    let three_bits = TruncatedHyperLogLog::<u64, _, u64, 3>::new(5, Xxh3Builder::new())?;
+    assert_eq!(three_bits.backend_len(), (32 * 3_usize).div_ceil(64));
+
+    assert!(TruncatedHyperLogLog::<u64, _, u8, 7>::new(8, Xxh3Builder::new()).is_err());
+    assert!(TruncatedHyperLogLog::<u64, _, u8, 4>::new(3, Xxh3Builder::new()).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_truncated_accuracy() -> Result<()> {
+    let build_hasher = Xxh3Builder::new().with_seed(0);
+    let truncated = TruncatedHyperLogLog::<u64, _, u16, 4>::new(8, build_hasher)?;
+    let standard = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .word_type::<u16>()
+        .build_hasher(build_hasher)
+        .build::<u64>()?;
+
+    for n in [10, 100, 1000, 10_000, 100_000] {
+        let truncated_error = rms_error(&truncated, n, 30);
+        let standard_error = rms_error(&standard, n, 30);
+        assert!(
+            truncated_error <= 2.0 * standard_error.max(truncated.relative_std() / 2.0),
+            "n = {}: truncated error {} vs. standard error {}",
+            n,
+            truncated_error,
+            standard_error
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_truncated_saturation() -> Result<()> {
+    // With three bits registers saturate at 7, so most registers are
+    // saturated after 2^6 elements per register
+    let logic = TruncatedHyperLogLog::<u64, _, u64, 3>::new(8, Xxh3Builder::new().with_seed(0))?;
+    let n = 256 * 100;
+    let error = rms_error(&logic, n, 20);
+    assert!(error < 4.0 * logic.relative_std(), "error {}", error);
+    Ok(())
+}
+
+#[test]
+fn test_truncated_merge() -> Result<()> {
+    let logic = TruncatedHyperLogLog::<u64, _, u8, 5>::new(6, Xxh3Builder::new().with_seed(0))?;
+    let mut a = logic.new_estimator();
+    let mut b = logic.new_estimator();
+    let mut union = logic.new_estimator();
+    for x in 0..5000_u64 {
+        if x % 3 == 0 {
+            a.add(x);
+        } else {
+            b.add(x);
+        }
+        union.add(x);
+    }
+    a.merge(b.as_ref());
+    assert_eq!(a.as_ref(), union.as_ref());
+    assert_eq!(a.estimate(), union.estimate());
+
+    a.clear();
+    assert_eq!(a.estimate(), 0.0);
+    Ok(())
+}
@@ -0,0 +1,57 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLogBuilder, SliceEstimatorArray, TypedEstimatorArray},
+    newtype_index,
+    traits::{Estimator, EstimatorArray, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+newtype_index!(NodeId);
+newtype_index!(
+    /// The index of an arc.
+    pub ArcId
+);
+
+#[test]
+fn test_typed_estimator_array() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(6)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    let mut nodes =
+        TypedEstimatorArray::<_, _, _, NodeId>::new(SliceEstimatorArray::new(logic.clone(), 10));
+    let mut arcs = TypedEstimatorArray::<_, _, _, ArcId>::new(SliceEstimatorArray::new(logic, 20));
+    assert_eq!(nodes.len(), 10);
+    assert_eq!(arcs.len(), 20);
+
+    for x in 0..100 {
+        nodes.get_estimator_mut(NodeId(3)).add(x);
+        arcs.get_estimator_mut(ArcId(15)).add(x);
+    }
+    assert_eq!(nodes.get_backend(NodeId(3)), arcs.get_backend(ArcId(15)));
+    assert_eq!(
+        nodes.get_estimator(NodeId(3)).estimate(),
+        nodes.array().get_estimator(3).estimate()
+    );
+
+    let non_empty = nodes
+        .indices()
+        .filter(|&i| nodes.get_estimator(i).estimate() > 0.0)
+        .collect::<Vec<_>>();
+    assert_eq!(non_empty, [NodeId(3)]);
+
+    nodes
+        .get_backend_mut(NodeId(4))
+        .copy_from_slice(arcs.get_backend(ArcId(15)));
+    assert_eq!(nodes.get_backend(NodeId(4)), nodes.get_backend(NodeId(3)));
+    nodes.clear();
+    assert_eq!(nodes.get_estimator(NodeId(3)).estimate(), 0.0);
+
+    Ok(())
+}
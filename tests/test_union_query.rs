@@ -0,0 +1,153 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{
+        HyperLogLog, HyperLogLogBuilder, IntersectionQuery, QueryBuilder, SliceEstimatorArray,
+        UnionQuery,
+    },
+    traits::{EstimationLogic, EstimatorArrayMut, EstimatorMut, SliceEstimationLogic},
+};
+use std::time::Instant;
+use xxhash_rust::xxh3::Xxh3Builder;
+
+fn logic(log_2_num_registers: usize) -> Result<HyperLogLog<usize, Xxh3Builder, usize>> {
+    HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(log_2_num_registers)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build()
+}
+
+#[test]
+fn test_union_query() -> Result<()> {
+    let logic = logic(8)?;
+    let mut array = SliceEstimatorArray::new(logic.clone(), 10_000);
+    for i in 0..10_000 {
+        for x in 0..10 {
+            array.get_estimator_mut(i).add(i * 7 + x);
+        }
+    }
+
+    let mut expected = vec![0; logic.backend_len()];
+    array.merge_all(&mut expected);
+    let expected = logic.estimate(&expected);
+
+    let query = UnionQuery::new(&array).indices(0..10_000);
+    let start = Instant::now();
+    assert_eq!(query.estimate(), expected);
+    let first = start.elapsed();
+    let start = Instant::now();
+    assert_eq!(query.estimate(), expected);
+    let cached = start.elapsed();
+    assert!(cached < first, "cached: {:?}, first: {:?}", cached, first);
+
+    // Adding indices invalidates the cache
+    let query = UnionQuery::new(&array).indices(0..10).index(5000);
+    assert_eq!(query.get_indices().len(), 11);
+    assert_eq!(
+        query.estimate(),
+        array.aggregate_filtered((0..10).chain([5000]))
+    );
+    let query = query.indices(10..10_000);
+    assert_eq!(query.estimate(), expected);
+
+    // No indices
+    assert_eq!(UnionQuery::new(&array).estimate(), 0.0);
+
+    #[cfg(all(feature = "rayon", not(target_arch = "wasm32")))]
+    {
+        let query = UnionQuery::new(&array).indices(0..10_000);
+        assert_eq!(
+            query.merged_par(),
+            UnionQuery::new(&array).indices(0..10_000).merged()
+        );
+        assert_eq!(query.estimate_par(), expected);
+        assert_eq!(UnionQuery::new(&array).estimate_par(), 0.0);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_intersection_query() -> Result<()> {
+    let mut array = SliceEstimatorArray::new(logic(12)?, 6);
+    // Sets 0..2000, 1000..3000, 1500..4000, each split between two estimators
+    for (i, range) in [
+        0..1000,
+        1000..2000,
+        1000..2000,
+        2000..3000,
+        1500..3000,
+        3000..4000,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        for x in range {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+
+    let query = IntersectionQuery::new(&array);
+    assert_eq!(query.estimate(), 0.0);
+    let query = query.set([0, 1]);
+    assert!((query.estimate() - 2000.0).abs() < 100.0);
+    let query = query.set([2, 3]);
+    assert_eq!(query.num_sets(), 2);
+    assert!((query.estimate() - 1000.0).abs() < 150.0);
+    let query = query.set([4, 5]);
+    assert!((query.estimate() - 500.0).abs() < 200.0);
+
+    // Disjoint sets
+    let estimate = IntersectionQuery::new(&array).set([0]).set([5]).estimate();
+    assert!(estimate < 100.0);
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_intersection_query_too_many_sets() {
+    let array = SliceEstimatorArray::new(logic(4).unwrap(), 5);
+    let _ = IntersectionQuery::new(&array)
+        .set([0])
+        .set([1])
+        .set([2])
+        .set([3])
+        .set([4]);
+}
+
+#[test]
+fn test_query_builder() -> Result<()> {
+    let mut array = SliceEstimatorArray::new(logic(12)?, 3);
+    for (i, range) in [0..2000, 1000..3000, 1500..4000].into_iter().enumerate() {
+        for x in range {
+            array.get_estimator_mut(i).add(x);
+        }
+    }
+
+    // A plain union
+    let query = QueryBuilder::new(&array, [0]).union([1]);
+    assert_eq!(query.estimate(), array.aggregate_filtered([0, 1]));
+    // (A ∪ C) ∩ B = 1000..3000
+    let query = QueryBuilder::new(&array, [0]).union([2]).intersect([1]);
+    assert!((query.estimate() - 2000.0).abs() < 150.0);
+    // (A ∩ B) ∪ C = 1000..4000
+    let query = QueryBuilder::new(&array, [0]).intersect([1]).union([2]);
+    assert!((query.estimate() - 3000.0).abs() < 150.0);
+    // (A ∩ B) ∩ C = 1500..2000
+    let query = QueryBuilder::new(&array, [0]).intersect([1]).intersect([2]);
+    let expected = IntersectionQuery::new(&array)
+        .set([0])
+        .set([1])
+        .set([2])
+        .estimate();
+    assert!((query.estimate() - expected).abs() < 1E-6);
+    assert!((query.estimate() - 500.0).abs() < 200.0);
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+//! These tests run natively and, using `wasm-pack test --node -- --features
+//! wasm-bindgen --no-default-features`, in WebAssembly.
+
+#![cfg(feature = "wasm-bindgen")]
+
+use card_est_array::impls::WasmHyperLogLog;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+#[test]
+fn test_wasm_hyper_log_log() {
+    let Ok(mut counter) = WasmHyperLogLog::new(1_000_000, 10) else {
+        panic!("invalid parameters");
+    };
+    assert_eq!(counter.num_registers(), 1 << 10);
+    assert_eq!(counter.estimate(), 0.0);
+    for n in 0..10_000 {
+        counter.add_integer(n);
+    }
+    let estimate = counter.estimate();
+    assert!((estimate - 10_000.0).abs() < 10_000.0 * 0.1);
+
+    let Ok(mut other) = WasmHyperLogLog::new(1_000_000, 10) else {
+        panic!("invalid parameters");
+    };
+    for n in 5_000..20_000 {
+        other.add_integer(n);
+    }
+    let bytes = other.to_bytes();
+    assert_eq!(bytes.len() % 4, 0);
+    assert!(counter.merge_bytes(&bytes).is_ok());
+    let estimate = counter.estimate();
+    assert!((estimate - 20_000.0).abs() < 20_000.0 * 0.1);
+
+    // Merging is idempotent
+    let merged = counter.to_bytes();
+    assert!(counter.merge_bytes(&bytes).is_ok());
+    assert_eq!(counter.to_bytes(), merged);
+
+    counter.clear();
+    assert_eq!(counter.estimate(), 0.0);
+}
+
+// Errors can be built only in JavaScript
+#[cfg(target_arch = "wasm32")]
+#[test]
+fn test_wasm_hyper_log_log_errors() {
+    assert!(WasmHyperLogLog::new(1_000_000, 2).is_err());
+    let Ok(mut counter) = WasmHyperLogLog::new(1_000_000, 10) else {
+        panic!("invalid parameters");
+    };
+    assert!(counter.merge_bytes(&[0; 3]).is_err());
+}
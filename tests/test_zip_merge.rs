@@ -0,0 +1,84 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{HyperLogLog, HyperLogLogBuilder, SliceEstimatorArray},
+    traits::{Estimator, EstimatorArray, EstimatorArrayMut, EstimatorMut},
+};
+use xxhash_rust::xxh3::Xxh3Builder;
+
+const LEN: usize = 10;
+
+type Array = SliceEstimatorArray<HyperLogLog<usize, Xxh3Builder, usize>, usize, Box<[usize]>>;
+
+/// Returns two arrays in which the sets at index `i` are [0 . . 1000i) and
+/// [500i . . 1500i), so their union is [0 . . 1500i).
+fn arrays() -> Result<(Array, Array)> {
+    let logic = HyperLogLogBuilder::new(100_000)
+        .log_2_num_reg(10)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<usize>()?;
+    let mut a = SliceEstimatorArray::new(logic.clone(), LEN);
+    let mut b = SliceEstimatorArray::new(logic, LEN);
+    for i in 0..LEN {
+        for x in 0..1000 * i {
+            a.get_estimator_mut(i).add(x);
+        }
+        for x in 500 * i..1500 * i {
+            b.get_estimator_mut(i).add(x);
+        }
+    }
+    Ok((a, b))
+}
+
+#[test]
+fn test_zip_merge() -> Result<()> {
+    let (mut a, b) = arrays()?;
+    let rsd = HyperLogLog::rel_std(10);
+    a.zip_merge(&b);
+    for i in 0..LEN {
+        let union = 1500.0 * i as f64;
+        let estimate = a.get_estimator(i).estimate();
+        assert!(
+            (estimate - union).abs() <= 3.0 * rsd * union,
+            "index {}: estimate {}, union {}",
+            i,
+            estimate,
+            union
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_zip_merge_into() -> Result<()> {
+    let (a, b) = arrays()?;
+    let merged = SliceEstimatorArray::zip_merge_into(&a, &b);
+    // The inputs are left untouched
+    assert_eq!(a.as_ref(), arrays()?.0.as_ref());
+
+    let mut expected = a.clone();
+    expected.zip_merge(&b);
+    assert_eq!(merged.as_ref(), expected.as_ref());
+
+    // Merging is commutative
+    let swapped = SliceEstimatorArray::zip_merge_into(&b, &a);
+    assert_eq!(merged.as_ref(), swapped.as_ref());
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn test_zip_merge_different_lengths() {
+    let logic = HyperLogLogBuilder::new(1000)
+        .log_2_num_reg(6)
+        .build::<usize>()
+        .unwrap();
+    let mut a = SliceEstimatorArray::new(logic.clone(), 3);
+    let b = SliceEstimatorArray::new(logic, 4);
+    a.zip_merge(&b);
+}
@@ -0,0 +1,186 @@
+/*
+ * SPDX-FileCopyrightText: 2025 Sebastiano Vigna
+ *
+ * SPDX-License-Identifier: Apache-2.0 OR LGPL-2.1-or-later
+ */
+
+// Invariants that every estimation logic should satisfy. New logics can be
+// tested by calling the generic verification functions below.
+
+use anyhow::Result;
+use card_est_array::{
+    impls::{
+        AmsF0, DynEstimationLogic, HllConfig, HyperLogLogBuilder, HyperLogLogFlex,
+        SparkCompatHyperLogLog,
+    },
+    traits::{EstimationLogic, Estimator, EstimatorMut, MergeEstimationLogic},
+};
+use std::fmt::Debug;
+use xxhash_rust::{xxh3::Xxh3Builder, xxh64::Xxh64Builder};
+
+/// The relative error margin used by the checks.
+const MARGIN: f64 = 0.1;
+
+/// The number of checkpoints used to verify monotonicity.
+const CHECKPOINTS: usize = 10;
+
+/// Verifies the invariants of an estimation logic on a list of distinct
+/// elements.
+///
+/// The invariants are:
+/// 1. the estimate of an empty estimator is (close to) zero;
+/// 2. the estimate is monotone in the cardinality, up to an error margin;
+/// 3. clearing an estimator and adding elements gives the same state as
+///    adding the same elements to a fresh estimator.
+fn verify_estimation_logic_invariants<L: EstimationLogic>(logic: L, test_elements: Vec<L::Item>)
+where
+    L::Backend: PartialEq + Debug,
+{
+    assert!(test_elements.len() >= CHECKPOINTS);
+
+    // (1) Empty estimators
+    let mut estimator = logic.new_estimator();
+    assert!(estimator.estimate().abs() < 1.0);
+
+    // (2) Monotonicity
+    let step = test_elements.len() / CHECKPOINTS;
+    let mut last_estimate = 0.0;
+    for chunk in test_elements.chunks(step) {
+        for element in chunk {
+            estimator.add(element);
+        }
+        let estimate = estimator.estimate();
+        assert!(
+            estimate >= last_estimate * (1.0 - MARGIN),
+            "the estimate decreased from {} to {}",
+            last_estimate,
+            estimate
+        );
+        last_estimate = estimate;
+    }
+    assert!(last_estimate > 0.0);
+
+    // (3) Clearing
+    estimator.clear();
+    let mut fresh = logic.new_estimator();
+    assert_eq!(estimator.as_ref(), fresh.as_ref());
+    assert!(estimator.estimate().abs() < 1.0);
+    for element in &test_elements[..step] {
+        estimator.add(element);
+        fresh.add(element);
+    }
+    assert_eq!(estimator.as_ref(), fresh.as_ref());
+
+    // Clearing at the logic level
+    let mut backend = logic.new_estimator();
+    backend.add(&test_elements[0]);
+    logic.clear(backend.as_mut());
+    assert_eq!(backend.as_ref(), logic.new_estimator().as_ref());
+}
+
+/// Verifies the invariants of a mergeable estimation logic on a list of
+/// distinct elements.
+///
+/// Besides the invariants checked by [`verify_estimation_logic_invariants`],
+/// the estimate of a merge is at least the maximum of the estimates of the
+/// merged estimators, up to an error margin, and merging is idempotent,
+/// commutative, and equivalent to adding the elements of both estimators.
+fn verify_merge_estimation_logic_invariants<L: MergeEstimationLogic>(
+    logic: L,
+    test_elements: Vec<L::Item>,
+) where
+    L::Backend: PartialEq + Debug,
+{
+    {
+        let n = test_elements.len();
+        let mut a = logic.new_estimator();
+        let mut b = logic.new_estimator();
+        let mut union = logic.new_estimator();
+        // Overlapping thirds
+        for element in &test_elements[..2 * n / 3] {
+            a.add(element);
+        }
+        for element in &test_elements[n / 3..] {
+            b.add(element);
+        }
+        for element in &test_elements {
+            union.add(element);
+        }
+
+        let mut ab = logic.new_estimator();
+        logic.set(ab.as_mut(), a.as_ref());
+        logic.merge(ab.as_mut(), b.as_ref());
+        let mut ba = logic.new_estimator();
+        logic.set(ba.as_mut(), b.as_ref());
+        logic.merge(ba.as_mut(), a.as_ref());
+
+        let max = a.estimate().max(b.estimate());
+        assert!(
+            ab.estimate() >= max * (1.0 - MARGIN),
+            "the estimate of the merge ({}) is smaller than the maximum estimate ({})",
+            ab.estimate(),
+            max
+        );
+        assert_eq!(ab.as_ref(), ba.as_ref());
+        assert_eq!(ab.as_ref(), union.as_ref());
+
+        // Idempotence
+        logic.merge(ab.as_mut(), b.as_ref());
+        assert_eq!(ab.as_ref(), union.as_ref());
+    }
+
+    verify_estimation_logic_invariants(logic, test_elements);
+}
+
+fn elements() -> Vec<u64> {
+    (0..20_000).map(|i| i * 0x9E37_79B9).collect()
+}
+
+#[test]
+fn test_hyper_log_log() -> Result<()> {
+    for log_2_num_registers in [4, 8, 12] {
+        let logic = HyperLogLogBuilder::new(1_000_000)
+            .log_2_num_reg(log_2_num_registers)
+            .word_type::<u16>()
+            .build_hasher(Xxh3Builder::new().with_seed(0))
+            .build::<u64>()?;
+        verify_merge_estimation_logic_invariants(logic, elements());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_hll_config() {
+    let logic = HllConfig::<u64, _, 10, u8>::new(Xxh3Builder::new().with_seed(0));
+    verify_merge_estimation_logic_invariants(logic, elements());
+}
+
+#[test]
+fn test_hyper_log_log_flex() -> Result<()> {
+    let logic = HyperLogLogFlex::<u64, _, u8>::new(1000, Xxh3Builder::new().with_seed(0))?;
+    verify_merge_estimation_logic_invariants(logic, elements());
+    Ok(())
+}
+
+#[test]
+fn test_ams_f0() {
+    let logic = AmsF0::<u64, _, 64>::new(Xxh3Builder::new().with_seed(0));
+    verify_merge_estimation_logic_invariants(logic, elements());
+}
+
+#[test]
+fn test_spark_compat() -> Result<()> {
+    let logic = SparkCompatHyperLogLog::<u64, _>::new(0.02, Xxh64Builder::new(42))?;
+    verify_merge_estimation_logic_invariants(logic, elements());
+    Ok(())
+}
+
+#[test]
+fn test_dyn_estimation_logic() -> Result<()> {
+    let logic = HyperLogLogBuilder::new(1_000_000)
+        .log_2_num_reg(8)
+        .build_hasher(Xxh3Builder::new().with_seed(0))
+        .build::<u64>()?;
+    verify_merge_estimation_logic_invariants(DynEstimationLogic::new(logic), elements());
+    Ok(())
+}
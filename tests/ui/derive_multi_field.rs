@@ -0,0 +1,17 @@
+use card_est_array::impls::HyperLogLog;
+use card_est_array::traits::EstimationLogic;
+use std::hash::BuildHasherDefault;
+use std::collections::hash_map::DefaultHasher;
+
+#[derive(Clone, EstimationLogic)]
+struct TwoFields {
+    logic: HyperLogLog<u64, BuildHasherDefault<DefaultHasher>, u32>,
+    name: String,
+}
+
+#[derive(Clone, EstimationLogic)]
+enum NotAStruct {
+    A(HyperLogLog<u64, BuildHasherDefault<DefaultHasher>, u32>),
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use card_est_array::impls::HyperLogLog;
+use card_est_array::traits::{
+    EstimationLogic, MergeEstimationLogic, SliceEstimationLogic,
+};
+use std::hash::BuildHasherDefault;
+use std::collections::hash_map::DefaultHasher;
+
+#[derive(Clone, EstimationLogic, MergeEstimationLogic, SliceEstimationLogic)]
+struct MyLogic(HyperLogLog<u64, BuildHasherDefault<DefaultHasher>, u32>);
+
+fn check<L: MergeEstimationLogic + SliceEstimationLogic<u32>>() {}
+
+fn main() {
+    check::<MyLogic>();
+}
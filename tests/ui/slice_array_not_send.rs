@@ -0,0 +1,9 @@
+use card_est_array::impls::SliceEstimatorArray;
+use std::rc::Rc;
+
+fn assert_send<T: Send>() {}
+
+fn main() {
+    // A logic that is not Send makes the array not Send
+    assert_send::<SliceEstimatorArray<Rc<()>, usize, Box<[usize]>>>();
+}